@@ -0,0 +1,23 @@
+// Flat team-colored silhouette for combatants hidden behind walls, pillars
+// and props. The pipeline's depth test is flipped (see silhouettes.rs), so
+// only the parts of the mesh that are behind something get drawn.
+
+#import bevy_pbr::forward_io::VertexOutput
+
+struct OccludedSilhouetteMaterial {
+    color: vec4<f32>,
+};
+
+@group(2) @binding(0) var<uniform> material: OccludedSilhouetteMaterial;
+
+// Combatant capsules dip slightly below the arena floor; without this cut the
+// floor would "occlude" their feet and draw a colored sliver under every unit.
+const FLOOR_CUTOFF_Y: f32 = 0.05;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if in.world_position.y < FLOOR_CUTOFF_Y {
+        discard;
+    }
+    return material.color;
+}
@@ -3,5 +3,20 @@ fn main() {
     if std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32") {
         println!("cargo:rustc-cfg=wasm_js");
     }
-}
 
+    // Short commit hash for MatchMetadata (absent outside a git checkout)
+    let git_hash = std::process::Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(hash) = git_hash {
+        println!("cargo:rustc-env=ARENASIM_GIT_HASH={}", hash.trim());
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+    if std::path::Path::new(".git").exists() {
+        println!("cargo:rerun-if-changed=.git/HEAD");
+        println!("cargo:rerun-if-changed=.git/refs/heads");
+    }
+}
@@ -1,7 +1,7 @@
 #!/usr/bin/env python3
 """Aggregate `arenasim --batch` per-match CSV into matchup winrates.
 
-The batch CSV has columns: label,team1,team2,seed,winner,end_reason,duration_secs
+The batch CSV has columns: label,team1,team2,seed,winner,end_reason,duration_secs,killing_blows
 This groups by `label` (falling back to team1|team2) and reports team1's winrate
 with a Wilson 95% confidence interval, so you can tell a real difference from
 sampling noise.
@@ -50,7 +50,8 @@ def wilson_halfwidth(wins, n, z=1.96):
 def load(path):
     groups = OrderedDict()
     with open(path) as f:
-        for row in csv.DictReader(f):
+        # Skip the "# " metadata header lines
+        for row in csv.DictReader(line for line in f if not line.startswith("#")):
             key = row.get("label") or f"{row['team1']}|{row['team2']}"
             g = groups.setdefault(key, {"t1": row["team1"], "t2": row["team2"],
                                         "w1": 0, "w2": 0, "dr": 0, "n": 0, "err": 0})
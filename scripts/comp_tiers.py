@@ -1,7 +1,7 @@
 import csv, sys, math
 from collections import defaultdict
 
-def load(p): return list(csv.DictReader(open(p)))
+def load(p): return list(csv.DictReader(l for l in open(p) if not l.startswith("#")))
 
 def class_tiers(rows):
     """Winrate of comps containing the class: for each match, each side's
@@ -14,6 +14,9 @@
 #   -o, --output DIR   Output directory for logs (default: match_logs/regression_<timestamp>)
 #   -b, --baseline DIR Compare results against baseline directory
 #   -q, --quiet        Only show summary, not individual test progress
+#   -a, --allow-sim-version-mismatch
+#                      Run scenarios / compare baselines recorded against a
+#                      different simulation version (known-compatible changes)
 #   -h, --help         Show this help message
 #
 
@@ -26,6 +29,7 @@ SUITE_FILE="tests/combat/test_suite.json"
 OUTPUT_DIR=""
 BASELINE_DIR=""
 QUIET=false
+ALLOW_SIM_VERSION_MISMATCH=false
 
 # Colors for output
 RED='\033[0;31m'
@@ -93,8 +97,12 @@ while [[ $# -gt 0 ]]; do
             QUIET=true
             shift
             ;;
+        -a|--allow-sim-version-mismatch)
+            ALLOW_SIM_VERSION_MISMATCH=true
+            shift
+            ;;
         -h|--help)
-            head -25 "$0" | tail -20
+            head -28 "$0" | tail -23
             exit 0
             ;;
         *)
@@ -142,6 +150,13 @@ mkdir -p "$OUTPUT_DIR"
 TEST_CASES=$(jq -r '.test_cases | length' "$SUITE_FILE")
 DEFAULT_SEED=$(jq -r '.default_settings.random_seed // 42' "$SUITE_FILE")
 DEFAULT_MAX_DURATION=$(jq -r '.default_settings.max_duration_secs // 120' "$SUITE_FILE")
+# Simulation version the suite's expectations were recorded against; the
+# binary refuses the scenarios if its SIM_VERSION differs
+SUITE_SIM_VERSION=$(jq -r '.sim_version // "null"' "$SUITE_FILE")
+SIM_VERSION_FLAG=""
+if [[ "$ALLOW_SIM_VERSION_MISMATCH" == "true" ]]; then
+    SIM_VERSION_FLAG="--allow-sim-version-mismatch"
+fi
 
 echo -e "${BLUE}========================================${NC}"
 echo -e "${BLUE}Combat System Regression Test Suite${NC}"
@@ -172,6 +187,7 @@ for i in $(seq 0 $((TEST_CASES - 1))); do
   "team1": $TEAM1,
   "team2": $TEAM2,
   "random_seed": $DEFAULT_SEED,
+  "sim_version": $SUITE_SIM_VERSION,
   "max_duration_secs": $MAX_DUR,
   "output_path": "$LOG_PATH"
 }
@@ -184,16 +200,20 @@ run_test() {
     local config_file="$TEMP_DIR/${test_name}.json"
     local log_file="$OUTPUT_DIR/${test_name}.txt"
     local result_file="$TEMP_DIR/${test_name}.result"
+    local error_file="$TEMP_DIR/${test_name}.err"
 
     local start_time=$(date +%s.%N)
 
     # Run with timeout (log is written directly to output_path in config)
-    if run_with_timeout "$TIMEOUT" "$BINARY_PATH" --headless "$config_file" > /dev/null 2>&1; then
+    if run_with_timeout "$TIMEOUT" "$BINARY_PATH" --headless "$config_file" $SIM_VERSION_FLAG > /dev/null 2> "$error_file"; then
         local status="PASS"
     else
         local exit_code=$?
         if [[ $exit_code -eq 124 ]]; then
             local status="TIMEOUT"
+        elif [[ $exit_code -eq 5 || $exit_code -eq 6 ]]; then
+            # Stalemate / duration cap: the match ran fine and ended in a draw
+            local status="PASS"
         else
             local status="FAIL"
         fi
@@ -223,6 +243,7 @@ run_test() {
                 ;;
             FAIL)
                 echo -e "  ${RED}FAIL${NC} $test_name (${duration}s)"
+                grep -m1 "^Error" "$error_file" | sed 's/^/       /'
                 ;;
             TIMEOUT)
                 echo -e "  ${YELLOW}TIMEOUT${NC} $test_name (exceeded ${TIMEOUT}s)"
@@ -233,7 +254,7 @@ run_test() {
 
 export -f run_with_timeout
 export -f run_test
-export TEMP_DIR OUTPUT_DIR TIMEOUT QUIET RED GREEN YELLOW NC BINARY_PATH
+export TEMP_DIR OUTPUT_DIR TIMEOUT QUIET RED GREEN YELLOW NC BINARY_PATH SIM_VERSION_FLAG
 
 # Run tests with parallel jobs
 echo ""
@@ -306,6 +327,18 @@ if [[ -n "$BASELINE_DIR" && -d "$BASELINE_DIR" ]]; then
         baseline_file="$BASELINE_DIR/$test_name"
 
         if [[ -f "$baseline_file" ]]; then
+            # Golden logs from a different simulation version can't be compared
+            new_sim=$(grep -m1 "^Sim version:" "$log_file" 2>/dev/null | awk '{print $3}')
+            old_sim=$(grep -m1 "^Sim version:" "$baseline_file" 2>/dev/null | awk '{print $3}')
+            if [[ "$new_sim" != "$old_sim" ]]; then
+                if [[ "$ALLOW_SIM_VERSION_MISMATCH" != "true" ]]; then
+                    echo -e "${RED}Error:${NC} baseline $baseline_file was recorded with sim version ${old_sim:-<none>}, but this build is sim version ${new_sim:-<none>}."
+                    echo "Re-record the baseline, or pass --allow-sim-version-mismatch if the change is known-compatible."
+                    exit 1
+                fi
+                echo -e "${YELLOW}WARN${NC} $test_name: baseline sim version ${old_sim:-<none>} != ${new_sim:-<none>} (allowed)"
+            fi
+
             # Compare winner and duration
             new_winner=$(grep "^Winner:" "$log_file" 2>/dev/null | awk '{print $2}')
             old_winner=$(grep "^Winner:" "$baseline_file" 2>/dev/null | awk '{print $2}')
@@ -70,6 +70,9 @@ fn handle_escape_key(
                 // Future: Could show pause menu instead
                 next_state.set(GameState::MainMenu);
             }
+            GameState::RestartMatch => {
+                // Transient - moves on to PlayMatch by itself
+            }
             GameState::Results => {
                 next_state.set(GameState::MainMenu);
             }
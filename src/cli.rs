@@ -2,7 +2,9 @@
 //!
 //! Supports both graphical (default) and headless modes.
 
-use clap::{Parser, ValueEnum};
+use clap::error::ErrorKind;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
 use std::path::PathBuf;
 
 /// AI decision trace output mode.
@@ -31,12 +33,224 @@ impl TraceMode {
     }
 }
 
+/// How `optimize` explores the candidate compositions.
+///
+/// `exhaustive` — simulate every composition the pool can form (with repeats).
+/// `hill-climb` — start from a few seeded random comps and repeatedly move to
+/// the best single-slot swap until none improves; far fewer matches for big
+/// pools or 4v4/5v5, at the cost of possibly missing the global best.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+#[clap(rename_all = "kebab-case")]
+pub enum SearchMode {
+    #[default]
+    Exhaustive,
+    HillClimb,
+}
+
+/// How a failing headless run reports the failure on stderr.
+///
+/// `text` — a human-readable line.
+/// `json` — one JSON object, `{"status": "config_error", "exit_code": 3,
+/// "message": "..."}`, and panics are reported the same way instead of
+/// through the default panic message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+#[clap(rename_all = "kebab-case")]
+pub enum ErrorFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Exit codes of the headless modes. Code 2 keeps clap's meaning, a usage
+/// error (unknown flag, bad value).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExitStatus {
+    /// Finished normally. A single `--headless` match ended with a kill.
+    Success,
+    /// Any other failure: I/O, a match that produced no result, fuzz cases
+    /// that failed.
+    Failure,
+    /// The command line didn't parse (unknown flag, bad value).
+    UsageError,
+    /// A config couldn't be read, parsed or validated, or was recorded
+    /// against another `SIM_VERSION`.
+    ConfigError,
+    /// The simulation panicked.
+    Panic,
+    /// A single `--headless` match ended in a stalemate draw.
+    Stalemate,
+    /// A single `--headless` match hit its duration cap.
+    Timeout,
+}
+
+impl ExitStatus {
+    /// Process exit code.
+    pub fn code(self) -> i32 {
+        match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Failure => 1,
+            ExitStatus::UsageError => 2,
+            ExitStatus::ConfigError => 3,
+            ExitStatus::Panic => 4,
+            ExitStatus::Stalemate => 5,
+            ExitStatus::Timeout => 6,
+        }
+    }
+
+    /// Stable token for `--error-format json`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExitStatus::Success => "success",
+            ExitStatus::Failure => "failure",
+            ExitStatus::UsageError => "usage_error",
+            ExitStatus::ConfigError => "config_error",
+            ExitStatus::Panic => "panic",
+            ExitStatus::Stalemate => "stalemate",
+            ExitStatus::Timeout => "timeout",
+        }
+    }
+}
+
+/// A headless run that ended with a non-zero [`ExitStatus`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CliError {
+    pub status: ExitStatus,
+    pub message: String,
+}
+
+impl CliError {
+    pub fn new(status: ExitStatus, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+
+    /// The stderr report in `format`.
+    pub fn render(&self, format: ErrorFormat) -> String {
+        match format {
+            ErrorFormat::Text => self.message.clone(),
+            ErrorFormat::Json => serde_json::json!({
+                "status": self.status.as_str(),
+                "exit_code": self.status.code(),
+                "message": self.message,
+            })
+            .to_string(),
+        }
+    }
+}
+
+/// Subcommands. Running without one keeps the flag-driven modes below
+/// (graphical, `--headless`, `--matrix`, `--batch`, `--fuzz`).
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Search team compositions for the best counter to a fixed enemy comp,
+    /// ranked by simulated win rate with 95% confidence intervals.
+    Optimize(OptimizeArgs),
+    /// Experimental: binary-search one numeric ability parameter until a
+    /// matchup's win rate approaches 50%, and report the suggested value.
+    Tune(TuneArgs),
+}
+
+/// Options for `arenasim optimize`.
+#[derive(clap::Args, Debug)]
+pub struct OptimizeArgs {
+    /// Enemy composition to counter, e.g. "Warrior,Paladin,Rogue". Its size
+    /// sets the team size searched.
+    #[arg(long, value_name = "CLASSES")]
+    pub against: String,
+
+    /// Classes candidates are built from: "all" or a comma-separated list.
+    #[arg(long, value_name = "CLASSES", default_value = "all")]
+    pub pool: String,
+
+    /// Matches simulated per candidate composition.
+    #[arg(long, value_name = "N", default_value_t = 100)]
+    pub runs: u32,
+
+    /// How many of the best compositions to report.
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    pub top: usize,
+
+    /// Search strategy over the candidate compositions.
+    #[arg(long, value_name = "MODE", value_enum, default_value_t = SearchMode::Exhaustive)]
+    pub search: SearchMode,
+
+    /// Base RNG seed. Every candidate plays the same seeds (base + run index),
+    /// so the same --seed-base reproduces the same ranking.
+    #[arg(long, value_name = "SEED", default_value_t = 0)]
+    pub seed_base: u64,
+
+    /// Worker thread count (default: cores - 2).
+    #[arg(long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Output CSV path for every evaluated composition
+    /// (default: match_logs/optimize_<timestamp>.csv).
+    #[arg(long, value_name = "CSV_FILE")]
+    pub out: Option<PathBuf>,
+}
+
+/// Options for `arenasim tune`.
+#[derive(clap::Args, Debug)]
+pub struct TuneArgs {
+    /// Ability to tune, by its `abilities.ron` key (e.g. MortalStrike).
+    #[arg(long, value_name = "ABILITY")]
+    pub ability: String,
+
+    /// Numeric field of the ability to adjust (e.g. damage_coefficient).
+    /// Nested fields use dots: applies_aura.magnitude.
+    #[arg(long, value_name = "FIELD")]
+    pub param: String,
+
+    /// Team 1 of the matchup to balance, e.g. "Warrior,Priest".
+    #[arg(long, value_name = "CLASSES")]
+    pub team1: String,
+
+    /// Team 2 of the matchup to balance.
+    #[arg(long, value_name = "CLASSES")]
+    pub team2: String,
+
+    /// Lower end of the search range.
+    #[arg(long, value_name = "VALUE")]
+    pub min: f32,
+
+    /// Upper end of the search range.
+    #[arg(long, value_name = "VALUE")]
+    pub max: f32,
+
+    /// Matches simulated per probed value.
+    #[arg(long, value_name = "N", default_value_t = 200)]
+    pub runs: u32,
+
+    /// Stop once team 1's win rate is within this distance of 50%.
+    #[arg(long, value_name = "RATE", default_value_t = 0.02)]
+    pub tolerance: f32,
+
+    /// Maximum bisection steps after the two range endpoints.
+    #[arg(long, value_name = "N", default_value_t = 10)]
+    pub max_iterations: u32,
+
+    /// Base RNG seed. Every probe plays the same seeds (base + run index).
+    #[arg(long, value_name = "SEED", default_value_t = 0)]
+    pub seed_base: u64,
+
+    /// Worker thread count (default: cores - 2).
+    #[arg(long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Output CSV path for the probe data
+    /// (default: match_logs/tune_<timestamp>.csv).
+    #[arg(long, value_name = "CSV_FILE")]
+    pub out: Option<PathBuf>,
+}
+
 /// Arena combat autobattler simulator
 #[derive(Parser, Debug)]
 #[command(name = "arenasim")]
 #[command(about = "Arena combat autobattler simulator")]
 #[command(version)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Run in headless mode with the specified JSON config file
     #[arg(long, value_name = "CONFIG_FILE")]
     pub headless: Option<PathBuf>,
@@ -45,6 +259,17 @@ pub struct Args {
     #[arg(long, value_name = "OUTPUT_PATH")]
     pub output: Option<PathBuf>,
 
+    /// Write the match result (winner, end reason, per-combatant stats,
+    /// killing blows, highlights) as JSON to this path (headless mode only)
+    #[arg(long, value_name = "JSON_FILE")]
+    pub results_json: Option<PathBuf>,
+
+    /// Write a compact replay file (the config with its seed pinned) to this
+    /// path; replay it with `--headless <file>`. Unseeded configs get a random
+    /// seed (headless mode only)
+    #[arg(long, value_name = "REPLAY_FILE")]
+    pub replay: Option<PathBuf>,
+
     /// Maximum match duration in seconds (headless mode only, overrides config file)
     #[arg(long)]
     pub max_duration: Option<f32>,
@@ -65,12 +290,30 @@ pub struct Args {
     #[arg(long, value_name = "CSV_FILE")]
     pub out: Option<PathBuf>,
 
+    /// Also write --batch results to a database: `sqlite:<path>` or
+    /// `postgres://...`. Needs a build with `--features db-sink` and the
+    /// `sqlite3` / `psql` client on PATH.
+    #[arg(long, value_name = "URL")]
+    pub db: Option<String>,
+
     /// Worker thread count for --batch mode (default: cores - 2).
     #[arg(long, value_name = "N")]
     pub jobs: Option<usize>,
 
-    /// Base RNG seed for matrix mode. Each match gets seed = base + run_index,
-    /// so the same --seed-base reproduces the same matrix exactly. Default: 0.
+    /// Run N seeded fuzz cases (random comps, strategy overrides and scripted
+    /// CC timings) hunting for panics and invariant violations. Failing cases
+    /// are minimized and saved as replayable configs under match_logs/fuzz/.
+    #[arg(long, value_name = "N")]
+    pub fuzz: Option<u32>,
+
+    /// Benchmark simulation throughput: one warmup match, then N timed runs
+    /// of a fixed seeded 3v3, reporting steady-state ticks/sec and its
+    /// spread. Use a release build.
+    #[arg(long, value_name = "N")]
+    pub calibrate: Option<u32>,
+
+    /// Base RNG seed for matrix and fuzz modes. Each match gets seed = base + run_index,
+    /// so the same --seed-base reproduces the same run exactly. Default: 0.
     #[arg(long, value_name = "SEED", default_value_t = 0)]
     pub seed_base: u64,
 
@@ -84,8 +327,100 @@ pub struct Args {
     /// `on` for `--matrix`.
     #[arg(long, value_name = "MODE", value_enum)]
     pub trace_mode: Option<TraceMode>,
+
+    /// Run configs recorded against a different simulation version
+    /// (`sim_version` in --headless / --batch configs) instead of refusing.
+    /// Only for behavior changes known not to affect them.
+    #[arg(long)]
+    pub allow_sim_version_mismatch: bool,
+
+    /// How headless modes report a failure on stderr: `text` or one `json`
+    /// object. The exit code is the same either way (see `ExitStatus`).
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = ErrorFormat::Text)]
+    pub error_format: ErrorFormat,
+
+    /// Start the graphical game in presentation mode: large class labels over
+    /// combatants, debug UI hidden (toggle in-game with F8).
+    #[arg(long)]
+    pub presentation: bool,
+
+    /// Camera path (RON keyframes) to play back in graphical matches; toggle
+    /// playback with P, record a new path with F7.
+    #[arg(long, value_name = "FILE")]
+    pub camera_path: Option<PathBuf>,
+
+    /// Share code (copied from ConfigureMatch or Results) to play in the
+    /// graphical game: starts straight into that match, with its seed when
+    /// the code has one.
+    #[arg(long, value_name = "CODE")]
+    pub code: Option<String>,
 }
 
+/// Parse the command line. A usage error is reported like any other headless
+/// failure, so `--error-format json` applies to it too; `--help` and
+/// `--version` print as usual.
 pub fn parse_args() -> Args {
-    Args::parse()
+    let argv: Vec<OsString> = std::env::args_os().collect();
+    Args::try_parse_from(&argv).unwrap_or_else(|e| {
+        let Some(error) = usage_error(&e) else { e.exit() };
+        eprintln!("{}", error.render(requested_error_format(&argv)));
+        std::process::exit(error.status.code());
+    })
+}
+
+/// A clap parse failure as an `ExitStatus::UsageError`, or `None` for the
+/// help and version requests clap reports through the same error type.
+fn usage_error(e: &clap::Error) -> Option<CliError> {
+    match e.kind() {
+        ErrorKind::DisplayHelp | ErrorKind::DisplayVersion | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => None,
+        _ => Some(CliError::new(ExitStatus::UsageError, e.to_string().trim_end())),
+    }
+}
+
+/// `--error-format` read straight from `argv`, for reporting a command line
+/// clap rejected. Falls back to text when the flag is absent or invalid.
+fn requested_error_format(argv: &[OsString]) -> ErrorFormat {
+    let mut args = argv.iter().filter_map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--error-format") {
+            Some("") => args.next(),
+            Some(rest) => rest.strip_prefix('='),
+            None => None,
+        };
+        if let Some(format) = value.and_then(|v| ErrorFormat::from_str(v, true).ok()) {
+            return format;
+        }
+    }
+    ErrorFormat::Text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_error_report_carries_status_and_code() {
+        let error = CliError::new(ExitStatus::ConfigError, "Error loading config: team1 must have 1-5 members");
+        let report: serde_json::Value = serde_json::from_str(&error.render(ErrorFormat::Json)).unwrap();
+        assert_eq!(report["status"], "config_error");
+        assert_eq!(report["exit_code"], 3);
+        assert_eq!(report["message"], "Error loading config: team1 must have 1-5 members");
+        assert_eq!(error.render(ErrorFormat::Text), "Error loading config: team1 must have 1-5 members");
+    }
+
+    #[test]
+    fn usage_errors_honor_the_requested_error_format() {
+        let argv: Vec<OsString> = ["arenasim", "--matrix", "many", "--error-format=json"].iter().map(OsString::from).collect();
+        let error = usage_error(&Args::try_parse_from(&argv).unwrap_err()).expect("a bad value is a usage error");
+        assert_eq!(error.status, ExitStatus::UsageError);
+        assert_eq!(requested_error_format(&argv), ErrorFormat::Json);
+        let report: serde_json::Value = serde_json::from_str(&error.render(ErrorFormat::Json)).unwrap();
+        assert_eq!(report["status"], "usage_error");
+        assert_eq!(report["exit_code"], 2);
+
+        let help = Args::try_parse_from(["arenasim", "--help"]).unwrap_err();
+        assert!(usage_error(&help).is_none());
+        let argv: Vec<OsString> = ["arenasim", "--error-format", "yaml"].iter().map(OsString::from).collect();
+        assert_eq!(requested_error_format(&argv), ErrorFormat::Text);
+    }
 }
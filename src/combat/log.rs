@@ -11,9 +11,24 @@
 //! - `HealingEvent`: source, target, ability, amount
 //! - `CrowdControlEvent`: source, target, cc_type, duration
 //! - `DeathEvent`: victim, killer (optional)
+//!
+//! ## Queries
+//! Consumers should read through the query API rather than re-scanning
+//! `entries`: `CombatLog::query` filters by combatant (served from a
+//! per-combatant index kept as entries are logged), event type and time range
+//! (binary search over the chronological entries), and the aggregation methods
+//! (`damage_by_pair`, `damage_by_ability`, ...) build on the same index.
+//!
+//! ## Display Rows
+//! `CombatLog::coalesced_rows` merges runs of DoT/HoT/channel ticks into one
+//! row per run (count and total) for the in-match log panel; `CoalescedRows`
+//! keeps the same rows up to date as entries arrive. It only builds a view;
+//! `entries` and every export keep each tick.
 
 use bevy::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use super::metadata::MatchMetadata;
 
 /// Unique identifier for a combatant in the combat log
 /// Format: "Team {team} {class}" e.g. "Team 1 Warrior"
@@ -45,6 +60,8 @@ pub enum StructuredEventData {
         amount: f32,
         is_killing_blow: bool,
         is_crit: bool,
+        /// DoT tick rather than a direct hit
+        is_periodic: bool,
     },
     /// Healing done from one combatant to another (or self)
     Healing {
@@ -53,6 +70,8 @@ pub enum StructuredEventData {
         ability: String,
         amount: f32,
         is_crit: bool,
+        /// HoT tick rather than a direct heal
+        is_periodic: bool,
     },
     /// Crowd control applied
     CrowdControl {
@@ -74,6 +93,41 @@ pub enum StructuredEventData {
         /// Whether this cast was interrupted before completing
         interrupted: bool,
     },
+    /// A cast or channel was interrupted (school lockout applied)
+    Interrupt {
+        source: CombatantId,
+        target: CombatantId,
+        /// The spell that was interrupted
+        ability: String,
+    },
+    /// An aura was dispelled or purged
+    Dispel {
+        source: CombatantId,
+        target: CombatantId,
+        /// Name of the removed aura
+        aura: String,
+        /// Whether the removed aura was crowd control (any DR category but slows)
+        removed_crowd_control: bool,
+    },
+}
+
+impl StructuredEventData {
+    /// Every combatant involved in the event (source/caster first)
+    pub fn participants(&self) -> Vec<&CombatantId> {
+        match self {
+            StructuredEventData::Damage { source, target, .. }
+            | StructuredEventData::Healing { source, target, .. }
+            | StructuredEventData::CrowdControl { source, target, .. }
+            | StructuredEventData::Interrupt { source, target, .. }
+            | StructuredEventData::Dispel { source, target, .. } => vec![source, target],
+            StructuredEventData::Death { victim, killer } => {
+                killer.iter().chain(std::iter::once(victim)).collect()
+            }
+            StructuredEventData::AbilityCast { caster, target, .. } => {
+                std::iter::once(caster).chain(target.iter()).collect()
+            }
+        }
+    }
 }
 
 /// Position data for debugging combat events
@@ -111,7 +165,7 @@ pub enum CombatLogEventType {
 }
 
 /// The combat log resource storing all events
-#[derive(Resource, Default)]
+#[derive(Resource, Clone, Default)]
 pub struct CombatLog {
     /// All log entries in chronological order
     pub entries: Vec<CombatLogEntry>,
@@ -119,6 +173,14 @@ pub struct CombatLog {
     pub match_time: f32,
     /// All combatants registered at match start (for timeline display)
     pub registered_combatants: Vec<CombatantId>,
+    /// Build/config/seed provenance captured at match start, written at the
+    /// top of every export of this match
+    pub metadata: Option<MatchMetadata>,
+    /// Indices into `entries` of each combatant's structured events, ascending
+    combatant_index: HashMap<CombatantId, Vec<usize>>,
+    /// Set if an entry was ever logged with an earlier timestamp than the one
+    /// before it; time-range queries then scan instead of binary searching
+    out_of_order: bool,
 }
 
 impl CombatLog {
@@ -127,6 +189,27 @@ impl CombatLog {
         self.entries.clear();
         self.match_time = 0.0;
         self.registered_combatants.clear();
+        self.metadata = None;
+        self.combatant_index.clear();
+        self.out_of_order = false;
+    }
+
+    /// Append an entry, indexing it under every combatant it involves
+    fn push(&mut self, entry: CombatLogEntry) {
+        let index = self.entries.len();
+        if self.entries.last().is_some_and(|last| entry.timestamp < last.timestamp) {
+            self.out_of_order = true;
+        }
+        if let Some(data) = &entry.structured_data {
+            for combatant in data.participants() {
+                let indices = self.combatant_index.entry(combatant.clone()).or_default();
+                // A self-targeted event lists the combatant twice
+                if indices.last() != Some(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        self.entries.push(entry);
     }
 
     /// Register a combatant at match start (for timeline display)
@@ -138,7 +221,7 @@ impl CombatLog {
 
     /// Add a new entry to the log (without structured data - for simple events)
     pub fn log(&mut self, event_type: CombatLogEventType, message: String) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type,
             message,
@@ -154,7 +237,7 @@ impl CombatLog {
         message: String,
         position_data: PositionData,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type,
             message,
@@ -174,7 +257,7 @@ impl CombatLog {
         is_crit: bool,
         message: String,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type: CombatLogEventType::Damage,
             message,
@@ -186,6 +269,34 @@ impl CombatLog {
                 amount,
                 is_killing_blow,
                 is_crit,
+                is_periodic: false,
+            }),
+        });
+    }
+
+    /// Add a structured DoT tick (never crits)
+    pub fn log_periodic_damage(
+        &mut self,
+        source: CombatantId,
+        target: CombatantId,
+        ability: String,
+        amount: f32,
+        is_killing_blow: bool,
+        message: String,
+    ) {
+        self.push(CombatLogEntry {
+            timestamp: self.match_time,
+            event_type: CombatLogEventType::Damage,
+            message,
+            position_data: None,
+            structured_data: Some(StructuredEventData::Damage {
+                source,
+                target,
+                ability,
+                amount,
+                is_killing_blow,
+                is_crit: false,
+                is_periodic: true,
             }),
         });
     }
@@ -200,7 +311,7 @@ impl CombatLog {
         is_crit: bool,
         message: String,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type: CombatLogEventType::Healing,
             message,
@@ -211,6 +322,32 @@ impl CombatLog {
                 ability,
                 amount,
                 is_crit,
+                is_periodic: false,
+            }),
+        });
+    }
+
+    /// Add a structured HoT tick (never crits)
+    pub fn log_periodic_healing(
+        &mut self,
+        source: CombatantId,
+        target: CombatantId,
+        ability: String,
+        amount: f32,
+        message: String,
+    ) {
+        self.push(CombatLogEntry {
+            timestamp: self.match_time,
+            event_type: CombatLogEventType::Healing,
+            message,
+            position_data: None,
+            structured_data: Some(StructuredEventData::Healing {
+                source,
+                target,
+                ability,
+                amount,
+                is_crit: false,
+                is_periodic: true,
             }),
         });
     }
@@ -224,7 +361,7 @@ impl CombatLog {
         duration_secs: f32,
         message: String,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type: CombatLogEventType::CrowdControl,
             message,
@@ -245,7 +382,7 @@ impl CombatLog {
         killer: Option<CombatantId>,
         message: String,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type: CombatLogEventType::Death,
             message,
@@ -262,7 +399,7 @@ impl CombatLog {
         target: Option<CombatantId>,
         message: String,
     ) {
-        self.entries.push(CombatLogEntry {
+        self.push(CombatLogEntry {
             timestamp: self.match_time,
             event_type: CombatLogEventType::AbilityUsed,
             message,
@@ -276,6 +413,46 @@ impl CombatLog {
         });
     }
 
+    /// Add a structured interrupt event
+    pub fn log_interrupt(
+        &mut self,
+        source: CombatantId,
+        target: CombatantId,
+        ability: String,
+        message: String,
+    ) {
+        self.push(CombatLogEntry {
+            timestamp: self.match_time,
+            event_type: CombatLogEventType::AbilityUsed,
+            message,
+            position_data: None,
+            structured_data: Some(StructuredEventData::Interrupt { source, target, ability }),
+        });
+    }
+
+    /// Add a structured dispel event
+    pub fn log_dispel(
+        &mut self,
+        source: CombatantId,
+        target: CombatantId,
+        aura: String,
+        removed_crowd_control: bool,
+        message: String,
+    ) {
+        self.push(CombatLogEntry {
+            timestamp: self.match_time,
+            event_type: CombatLogEventType::Buff,
+            message,
+            position_data: None,
+            structured_data: Some(StructuredEventData::Dispel {
+                source,
+                target,
+                aura,
+                removed_crowd_control,
+            }),
+        });
+    }
+
     /// Mark the most recent ability cast by a combatant as interrupted
     pub fn mark_cast_interrupted(&mut self, caster_id: &str, ability_name: &str) {
         // Find the most recent matching ability cast and mark it interrupted
@@ -293,12 +470,28 @@ impl CombatLog {
     // Query Methods
     // =========================================================================
 
+    /// Start a filtered query over the log (all entries until narrowed)
+    pub fn query(&self) -> CombatLogQuery<'_> {
+        CombatLogQuery {
+            log: self,
+            combatant: None,
+            event_type: None,
+            time_range: None,
+        }
+    }
+
+    /// Entries with structured data involving a combatant, in log order
+    pub fn entries_for<'a>(&'a self, combatant_id: &str) -> impl Iterator<Item = &'a CombatLogEntry> + 'a {
+        self.combatant_index
+            .get(combatant_id)
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.entries[i])
+    }
+
     /// Get entries filtered by event type
     pub fn filter_by_type(&self, event_type: CombatLogEventType) -> Vec<&CombatLogEntry> {
-        self.entries
-            .iter()
-            .filter(|e| e.event_type == event_type)
-            .collect()
+        self.query().event_type(event_type).entries().collect()
     }
 
     /// Get only HP-changing events (damage and healing)
@@ -322,8 +515,7 @@ impl CombatLog {
     /// Get all ability casts for a specific combatant (for timeline visualization)
     /// Returns Vec<(timestamp, ability_name, was_interrupted)> sorted by timestamp
     pub fn ability_casts_for(&self, combatant_id: &str) -> Vec<(f32, &str, bool)> {
-        self.entries
-            .iter()
+        self.entries_for(combatant_id)
             .filter_map(|e| {
                 if let Some(StructuredEventData::AbilityCast { caster, ability, interrupted, .. }) = &e.structured_data {
                     if caster == combatant_id {
@@ -347,7 +539,7 @@ impl CombatLog {
     pub fn damage_by_ability(&self, combatant_id: &str) -> HashMap<String, f32> {
         let mut result: HashMap<String, f32> = HashMap::new();
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::Damage { source, ability, amount, .. }) = &entry.structured_data {
                 if source == combatant_id {
                     *result.entry(ability.clone()).or_insert(0.0) += amount;
@@ -363,7 +555,7 @@ impl CombatLog {
     pub fn healing_by_ability(&self, combatant_id: &str) -> HashMap<String, f32> {
         let mut result: HashMap<String, f32> = HashMap::new();
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::Healing { source, ability, amount, .. }) = &entry.structured_data {
                 if source == combatant_id {
                     *result.entry(ability.clone()).or_insert(0.0) += amount;
@@ -374,6 +566,34 @@ impl CombatLog {
         result
     }
 
+    /// Total damage across the match for every source → target pair.
+    /// Returns BTreeMap<(Source, Target), TotalDamage>
+    pub fn damage_by_pair(&self) -> BTreeMap<(CombatantId, CombatantId), f32> {
+        let mut result: BTreeMap<(CombatantId, CombatantId), f32> = BTreeMap::new();
+
+        for entry in &self.entries {
+            if let Some(StructuredEventData::Damage { source, target, amount, .. }) = &entry.structured_data {
+                *result.entry((source.clone(), target.clone())).or_insert(0.0) += amount;
+            }
+        }
+
+        result
+    }
+
+    /// Total healing across the match for every source → target pair.
+    /// Returns BTreeMap<(Source, Target), TotalHealing>
+    pub fn healing_by_pair(&self) -> BTreeMap<(CombatantId, CombatantId), f32> {
+        let mut result: BTreeMap<(CombatantId, CombatantId), f32> = BTreeMap::new();
+
+        for entry in &self.entries {
+            if let Some(StructuredEventData::Healing { source, target, amount, .. }) = &entry.structured_data {
+                *result.entry((source.clone(), target.clone())).or_insert(0.0) += amount;
+            }
+        }
+
+        result
+    }
+
     /// Get total damage dealt by a combatant (sum of all abilities)
     pub fn total_damage_dealt(&self, combatant_id: &str) -> f32 {
         self.damage_by_ability(combatant_id).values().sum()
@@ -384,11 +604,24 @@ impl CombatLog {
         self.healing_by_ability(combatant_id).values().sum()
     }
 
+    /// Log entries for display, with each run of ticks from one periodic
+    /// effect (same source, ability, target and damage/healing) merged into a
+    /// single row. A run continues across unrelated entries as long as its
+    /// ticks are at most `TICK_MERGE_GAP_SECS` apart, and its row sits where
+    /// the latest tick is. Non-periodic entries pass through unchanged.
+    ///
+    /// Rebuilds from scratch; per-frame callers keep a [`CoalescedRows`].
+    pub fn coalesced_rows(&self) -> Vec<LogDisplayRow<'_>> {
+        let mut coalesced = CoalescedRows::default();
+        coalesced.update(self);
+        coalesced.rows(self).collect()
+    }
+
     /// Get total damage taken by a combatant
     pub fn total_damage_taken(&self, combatant_id: &str) -> f32 {
         let mut total = 0.0;
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::Damage { target, amount, .. }) = &entry.structured_data {
                 if target == combatant_id {
                     total += amount;
@@ -403,7 +636,7 @@ impl CombatLog {
     pub fn killing_blows(&self, combatant_id: &str) -> u32 {
         let mut count = 0;
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::Damage { source, is_killing_blow: true, .. }) = &entry.structured_data {
                 if source == combatant_id {
                     count += 1;
@@ -414,11 +647,26 @@ impl CombatLog {
         count
     }
 
+    /// Killing blows across the whole match, broken down by the ability that
+    /// landed them. Returns BTreeMap<AbilityName, Count> so reports list
+    /// abilities in a stable order.
+    pub fn killing_blows_by_ability(&self) -> BTreeMap<String, u32> {
+        let mut result: BTreeMap<String, u32> = BTreeMap::new();
+
+        for entry in &self.entries {
+            if let Some(StructuredEventData::Damage { ability, is_killing_blow: true, .. }) = &entry.structured_data {
+                *result.entry(ability.clone()).or_insert(0) += 1;
+            }
+        }
+
+        result
+    }
+
     /// Get total CC time done by a combatant (in seconds)
     pub fn cc_done_seconds(&self, combatant_id: &str) -> f32 {
         let mut total = 0.0;
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::CrowdControl { source, duration_secs, .. }) = &entry.structured_data {
                 if source == combatant_id {
                     total += duration_secs;
@@ -433,7 +681,7 @@ impl CombatLog {
     pub fn cc_received_seconds(&self, combatant_id: &str) -> f32 {
         let mut total = 0.0;
 
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::CrowdControl { target, duration_secs, .. }) = &entry.structured_data {
                 if target == combatant_id {
                     total += duration_secs;
@@ -451,45 +699,13 @@ impl CombatLog {
             return self.registered_combatants.clone();
         }
 
-        // Fallback: extract from log entries
-        let mut combatants: std::collections::HashSet<String> = std::collections::HashSet::new();
-
-        for entry in &self.entries {
-            match &entry.structured_data {
-                Some(StructuredEventData::Damage { source, target, .. }) => {
-                    combatants.insert(source.clone());
-                    combatants.insert(target.clone());
-                }
-                Some(StructuredEventData::Healing { source, target, .. }) => {
-                    combatants.insert(source.clone());
-                    combatants.insert(target.clone());
-                }
-                Some(StructuredEventData::CrowdControl { source, target, .. }) => {
-                    combatants.insert(source.clone());
-                    combatants.insert(target.clone());
-                }
-                Some(StructuredEventData::Death { victim, killer }) => {
-                    combatants.insert(victim.clone());
-                    if let Some(k) = killer {
-                        combatants.insert(k.clone());
-                    }
-                }
-                Some(StructuredEventData::AbilityCast { caster, target, .. }) => {
-                    combatants.insert(caster.clone());
-                    if let Some(t) = target {
-                        combatants.insert(t.clone());
-                    }
-                }
-                None => {}
-            }
-        }
-
-        combatants.into_iter().collect()
+        // Fallback: every combatant seen in a structured event
+        self.combatant_index.keys().cloned().collect()
     }
 
     /// Check if a combatant survived (no death event recorded for them)
     pub fn combatant_survived(&self, combatant_id: &str) -> bool {
-        for entry in &self.entries {
+        for entry in self.entries_for(combatant_id) {
             if let Some(StructuredEventData::Death { victim, .. }) = &entry.structured_data {
                 if victim == combatant_id {
                     return false;
@@ -502,7 +718,7 @@ impl CombatLog {
     /// Save the combat log to a file with match metadata
     /// If `output_path` is provided, saves to that exact path.
     /// Otherwise, generates a timestamped filename in match_logs/
-    pub fn save_to_file(&self, match_metadata: &MatchMetadata, output_path: Option<&str>) -> std::io::Result<String> {
+    pub fn save_to_file(&self, report: &MatchReport, output_path: Option<&str>) -> std::io::Result<String> {
         use std::fs::{self, File};
         use std::io::Write;
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -538,34 +754,44 @@ impl CombatLog {
         // Write match metadata
         writeln!(file, "MATCH METADATA")?;
         writeln!(file, "{}", "-".repeat(80))?;
-        writeln!(file, "Arena: {}", match_metadata.arena_name)?;
+        if let Some(metadata) = &self.metadata {
+            metadata.write_header(&mut file, "")?;
+        }
+        writeln!(file, "Arena: {}", report.arena_name)?;
         writeln!(file, "Duration: {:.2}s", self.match_time)?;
-        writeln!(file, "Winner: {}", match match_metadata.winner {
+        writeln!(file, "Winner: {}", match report.winner {
             None => "DRAW".to_string(),
             Some(1) => "Team 1".to_string(),
             Some(2) => "Team 2".to_string(),
             Some(n) => format!("Team {} (invalid)", n),
         })?;
-        writeln!(file, "Seed: {}", match match_metadata.random_seed {
-            Some(seed) => seed.to_string(),
-            None => "<unseeded>".to_string(),
-        })?;
         writeln!(file)?;
         
         // Write team compositions
         writeln!(file, "TEAM 1 COMPOSITION")?;
         writeln!(file, "{}", "-".repeat(80))?;
-        for (i, combatant) in match_metadata.team1.iter().enumerate() {
+        for (i, combatant) in report.team1.iter().enumerate() {
             write_combatant_block(&mut file, i + 1, combatant)?;
         }
         writeln!(file)?;
 
         writeln!(file, "TEAM 2 COMPOSITION")?;
         writeln!(file, "{}", "-".repeat(80))?;
-        for (i, combatant) in match_metadata.team2.iter().enumerate() {
+        for (i, combatant) in report.team2.iter().enumerate() {
             write_combatant_block(&mut file, i + 1, combatant)?;
         }
         writeln!(file)?;
+
+        // Write killing-blow distribution (which abilities closed out kills)
+        let killing_blows = self.killing_blows_by_ability();
+        if !killing_blows.is_empty() {
+            writeln!(file, "KILLING BLOWS")?;
+            writeln!(file, "{}", "-".repeat(80))?;
+            for (ability, count) in &killing_blows {
+                writeln!(file, "  {}: {}", ability, count)?;
+            }
+            writeln!(file)?;
+        }
         
         // Write combat log
         writeln!(file, "COMBAT LOG")?;
@@ -616,14 +842,200 @@ impl CombatLog {
     }
 }
 
-/// Match metadata for saving combat logs
+/// A filtered view of a `CombatLog`, built with `CombatLog::query`. Each
+/// criterion narrows the result; unset criteria match everything.
+///
+/// ```ignore
+/// let recent_hits = log.query()
+///     .combatant("Team 2 Priest")
+///     .event_type(CombatLogEventType::Damage)
+///     .between(10.0, 20.0)
+///     .entries();
+/// ```
+#[derive(Clone, Copy)]
+pub struct CombatLogQuery<'a> {
+    log: &'a CombatLog,
+    combatant: Option<&'a str>,
+    event_type: Option<CombatLogEventType>,
+    /// Half-open [from, until) in match seconds
+    time_range: Option<(f32, f32)>,
+}
+
+impl<'a> CombatLogQuery<'a> {
+    /// Only entries whose structured data involves this combatant (as source,
+    /// target, caster, victim or killer)
+    pub fn combatant(mut self, combatant_id: &'a str) -> Self {
+        self.combatant = Some(combatant_id);
+        self
+    }
+
+    /// Only entries of this event type
+    pub fn event_type(mut self, event_type: CombatLogEventType) -> Self {
+        self.event_type = Some(event_type);
+        self
+    }
+
+    /// Only entries with `from <= timestamp < until`
+    pub fn between(mut self, from: f32, until: f32) -> Self {
+        self.time_range = Some((from, until));
+        self
+    }
+
+    /// The matching entries, in log order
+    pub fn entries(self) -> Box<dyn Iterator<Item = &'a CombatLogEntry> + 'a> {
+        let log = self.log;
+        let in_range = move |e: &CombatLogEntry| {
+            self.time_range.is_none_or(|(from, until)| e.timestamp >= from && e.timestamp < until)
+        };
+        let of_type = move |e: &CombatLogEntry| self.event_type.is_none_or(|t| e.event_type == t);
+
+        match (self.combatant, self.time_range) {
+            (Some(combatant), _) => {
+                Box::new(log.entries_for(combatant).filter(move |e| in_range(e) && of_type(e)))
+            }
+            // Entries are chronological: binary search for the window
+            (None, Some((from, until))) if !log.out_of_order => {
+                let start = log.entries.partition_point(|e| e.timestamp < from);
+                let end = log.entries.partition_point(|e| e.timestamp < until).max(start);
+                Box::new(log.entries[start..end].iter().filter(move |e| of_type(e)))
+            }
+            (None, _) => Box::new(log.entries.iter().filter(move |e| in_range(e) && of_type(e))),
+        }
+    }
+
+    /// Number of matching entries
+    pub fn count(self) -> usize {
+        self.entries().count()
+    }
+}
+
+/// Longest gap between two ticks of one periodic effect that still merges
+/// them into one display row (the slowest ticks are 3s apart)
+pub const TICK_MERGE_GAP_SECS: f32 = 5.0;
+
+/// One row of the combat log display: a raw entry, or a merged run of
+/// periodic ticks (see `CombatLog::coalesced_rows`)
+#[derive(Debug, Clone)]
+pub struct LogDisplayRow<'a> {
+    /// The entry itself, or the run's latest tick
+    pub entry: &'a CombatLogEntry,
+    /// Timestamp of the run's first tick (`entry.timestamp` for single entries)
+    pub first_timestamp: f32,
+    /// Number of entries in this row
+    pub count: usize,
+    /// Summed damage/healing of a periodic run (0 for other entries)
+    pub total: f32,
+}
+
+impl LogDisplayRow<'_> {
+    /// Whether this row stands for more than one tick
+    pub fn is_merged(&self) -> bool {
+        self.count > 1
+    }
+
+    /// Text to show: the raw message, or a summary of a merged run, e.g.
+    /// "Team 2 Warlock's Corruption ticks x4 for 40 damage on Team 1 Priest"
+    pub fn message(&self) -> std::borrow::Cow<'_, str> {
+        if !self.is_merged() {
+            return std::borrow::Cow::Borrowed(&self.entry.message);
+        }
+        let (source, ability, target, kind) = match &self.entry.structured_data {
+            Some(StructuredEventData::Damage { source, ability, target, .. }) => (source, ability, target, "damage"),
+            Some(StructuredEventData::Healing { source, ability, target, .. }) => (source, ability, target, "healing"),
+            _ => return std::borrow::Cow::Borrowed(&self.entry.message),
+        };
+        // Channel ticks are logged as "<Spell> (tick)"
+        let ability = ability.strip_suffix(" (tick)").unwrap_or(ability);
+        std::borrow::Cow::Owned(format!(
+            "{}'s {} ticks x{} for {:.0} {} on {}",
+            source, ability, self.count, self.total, kind, target
+        ))
+    }
+}
+
+/// `CombatLog::coalesced_rows` kept up to date incrementally: `update` only
+/// folds in entries logged since the previous call, so the log panel doesn't
+/// re-merge the whole match every frame.
+#[derive(Debug, Default)]
+pub struct CoalescedRows {
+    /// Merged rows by entry index; `None` where a tick was folded into a
+    /// later row of its run
+    rows: Vec<Option<CoalescedRow>>,
+    /// Row index of the latest tick of each periodic effect, keyed by
+    /// (is_healing, source, ability, target)
+    open_runs: HashMap<(bool, String, String, String), usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CoalescedRow {
+    first_timestamp: f32,
+    count: usize,
+    total: f32,
+}
+
+impl CoalescedRows {
+    /// Fold in entries logged since the last call. A log that shrank (a new
+    /// match, or a what-if branch rewinding it) is rebuilt from the start.
+    pub fn update(&mut self, log: &CombatLog) {
+        if log.entries.len() < self.rows.len() {
+            self.rows.clear();
+            self.open_runs.clear();
+        }
+
+        for entry in &log.entries[self.rows.len()..] {
+            let mut row = CoalescedRow {
+                first_timestamp: entry.timestamp,
+                count: 1,
+                total: 0.0,
+            };
+            let key = match &entry.structured_data {
+                Some(StructuredEventData::Damage { source, target, ability, amount, is_periodic: true, .. }) => {
+                    row.total = *amount;
+                    (false, source.clone(), ability.clone(), target.clone())
+                }
+                Some(StructuredEventData::Healing { source, target, ability, amount, is_periodic: true, .. }) => {
+                    row.total = *amount;
+                    (true, source.clone(), ability.clone(), target.clone())
+                }
+                _ => {
+                    self.rows.push(Some(row));
+                    continue;
+                }
+            };
+
+            if let Some(&previous) = self.open_runs.get(&key) {
+                let previous_timestamp = log.entries[previous].timestamp;
+                let run = self.rows[previous]
+                    .take_if(|_| entry.timestamp - previous_timestamp <= TICK_MERGE_GAP_SECS);
+                if let Some(run) = run {
+                    row.first_timestamp = run.first_timestamp;
+                    row.count += run.count;
+                    row.total += run.total;
+                }
+            }
+            self.open_runs.insert(key, self.rows.len());
+            self.rows.push(Some(row));
+        }
+    }
+
+    /// The display rows, borrowing entries from the log last passed to `update`
+    pub fn rows<'s, 'a: 's>(&'s self, log: &'a CombatLog) -> impl Iterator<Item = LogDisplayRow<'a>> + 's {
+        self.rows.iter().enumerate().filter_map(|(index, row)| {
+            row.map(|row| LogDisplayRow {
+                entry: &log.entries[index],
+                first_timestamp: row.first_timestamp,
+                count: row.count,
+                total: row.total,
+            })
+        })
+    }
+}
+
+/// Match outcome and team stats for saving combat logs
 #[derive(Debug, Clone)]
-pub struct MatchMetadata {
+pub struct MatchReport {
     pub arena_name: String,
     pub winner: Option<u8>,
-    /// Seed used for deterministic RNG (None = unseeded entropy).
-    /// Embedded in the log header so a saved match can be reproduced.
-    pub random_seed: Option<u64>,
     pub team1: Vec<CombatantMetadata>,
     pub team2: Vec<CombatantMetadata>,
 }
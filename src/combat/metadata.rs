@@ -0,0 +1,144 @@
+//! Match Metadata
+//!
+//! Provenance embedded at the top of every export (combat logs, highlights
+//! JSON, batch/matrix CSVs and reports) so an artifact can still be
+//! interpreted months later: which build produced it, from which config and
+//! seed, against which ability definitions, and when.
+
+use serde::Serialize;
+
+/// Ability definitions the checksum covers
+const ABILITY_CONFIG_PATH: &str = "assets/config/abilities.ron";
+
+/// Simulation behavior version. Bump on ANY change that can alter the outcome
+/// of a seeded match (ability numbers, AI decisions, movement, RNG draw order),
+/// so recorded replays, scenarios and golden logs fail loudly instead of
+/// silently diverging. See `check_sim_version`.
+pub const SIM_VERSION: u32 = 6;
+
+/// Build, config and timing provenance for one match (or one batch run).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MatchMetadata {
+    /// `CARGO_PKG_VERSION` of the build
+    pub crate_version: String,
+    /// Short git commit hash of the build, when built from a git checkout
+    pub git_hash: Option<String>,
+    /// `SIM_VERSION` of the build
+    pub sim_version: u32,
+    /// Fingerprint of the match (or run) configuration
+    pub config_hash: String,
+    /// Seed used for deterministic RNG (None = unseeded entropy)
+    pub random_seed: Option<u64>,
+    /// Fingerprint of `assets/config/abilities.ron` ("unavailable" if unreadable)
+    pub ability_config_checksum: String,
+    /// Unix time (seconds) the match or run started
+    pub start_time: u64,
+}
+
+impl MatchMetadata {
+    /// Capture metadata now, for a configuration with the given fingerprint.
+    pub fn capture(config_hash: String, random_seed: Option<u64>) -> Self {
+        let ability_config_checksum = std::fs::read(ABILITY_CONFIG_PATH)
+            .map(|bytes| fingerprint(&bytes))
+            .unwrap_or_else(|_| "unavailable".to_string());
+        let start_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_hash: option_env!("ARENASIM_GIT_HASH").map(str::to_string),
+            sim_version: SIM_VERSION,
+            config_hash,
+            random_seed,
+            ability_config_checksum,
+            start_time,
+        }
+    }
+
+    /// Write one `Key: value` line per field, each starting with `prefix`
+    /// (e.g. `"# "` for CSV comment lines, `"- "` for Markdown lists).
+    pub fn write_header(&self, w: &mut impl std::io::Write, prefix: &str) -> std::io::Result<()> {
+        writeln!(w, "{}Version: {}", prefix, self.crate_version)?;
+        writeln!(w, "{}Git hash: {}", prefix, self.git_hash.as_deref().unwrap_or("<unknown>"))?;
+        writeln!(w, "{}Sim version: {}", prefix, self.sim_version)?;
+        writeln!(w, "{}Config hash: {}", prefix, self.config_hash)?;
+        writeln!(w, "{}Seed: {}", prefix, match self.random_seed {
+            Some(seed) => seed.to_string(),
+            None => "<unseeded>".to_string(),
+        })?;
+        writeln!(w, "{}Ability config checksum: {}", prefix, self.ability_config_checksum)?;
+        writeln!(w, "{}Start time: {} (unix)", prefix, self.start_time)
+    }
+}
+
+/// Refuse to load an artifact (`what`, e.g. a config path) recorded against a
+/// different `SIM_VERSION`. `allow_mismatch` (`--allow-sim-version-mismatch`)
+/// overrides the check for changes known not to affect it, with a warning.
+pub fn check_sim_version(recorded: u32, what: &str, allow_mismatch: bool) -> Result<(), String> {
+    if recorded == SIM_VERSION {
+        return Ok(());
+    }
+    if allow_mismatch {
+        eprintln!(
+            "Warning: {} was recorded with sim version {} (this build: {}); running anyway",
+            what, recorded, SIM_VERSION
+        );
+        return Ok(());
+    }
+    Err(format!(
+        "{} was recorded with sim version {}, but this build is sim version {}; results would not \
+         reproduce. Re-record it, or pass --allow-sim-version-mismatch if the change is known-compatible",
+        what, recorded, SIM_VERSION
+    ))
+}
+
+/// Stable 64-bit FNV-1a fingerprint of `bytes`, as 16 hex digits. Unlike
+/// `DefaultHasher`, the value is fixed across Rust versions and platforms.
+pub fn fingerprint(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_is_stable_fnv1a() {
+        // Reference FNV-1a 64 values
+        assert_eq!(fingerprint(b""), "cbf29ce484222325");
+        assert_eq!(fingerprint(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn header_lists_every_field() {
+        let metadata = MatchMetadata {
+            crate_version: "0.1.0".to_string(),
+            git_hash: None,
+            sim_version: 3,
+            config_hash: "00ff".to_string(),
+            random_seed: Some(7),
+            ability_config_checksum: "abcd".to_string(),
+            start_time: 1_700_000_000,
+        };
+        let mut out = Vec::new();
+        metadata.write_header(&mut out, "# ").unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "# Version: 0.1.0\n# Git hash: <unknown>\n# Sim version: 3\n# Config hash: 00ff\n# Seed: 7\n\
+             # Ability config checksum: abcd\n# Start time: 1700000000 (unix)\n"
+        );
+    }
+
+    #[test]
+    fn sim_version_mismatch_needs_override() {
+        assert!(check_sim_version(SIM_VERSION, "replay.json", false).is_ok());
+        let err = check_sim_version(SIM_VERSION + 1, "replay.json", false).unwrap_err();
+        assert!(err.contains("replay.json") && err.contains("--allow-sim-version-mismatch"));
+        assert!(check_sim_version(SIM_VERSION + 1, "replay.json", true).is_ok());
+    }
+}
@@ -6,8 +6,10 @@
 use bevy::prelude::*;
 
 pub mod log;
+pub mod metadata;
 
-pub use log::{CombatLog, CombatLogEntry, CombatLogEventType, MatchMetadata, CombatantMetadata, PositionData, StructuredEventData, CombatantId};
+pub use log::{CombatLog, CombatLogEntry, CombatLogEventType, CombatLogQuery, MatchReport, CombatantMetadata, PositionData, StructuredEventData, CombatantId};
+pub use metadata::MatchMetadata;
 
 /// Plugin for the combat system.
 /// 
@@ -20,11 +20,13 @@
 //! sequential ones (validated in `tests`/by the balance harness).
 
 use std::io::{BufRead, BufReader, BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use crate::combat::metadata::{fingerprint, MatchMetadata};
 use crate::headless::config::HeadlessMatchConfig;
+use crate::headless::error::ModeError;
 use crate::headless::runner::{run_headless_match_prepared, MatchResult, PreloadedConfigs};
 
 /// Pin Bevy's global task pools to a single thread each. Each match's internal
@@ -32,7 +34,7 @@ use crate::headless::runner::{run_headless_match_prepared, MatchResult, Preloade
 /// parallelism — while THIS module parallelizes at the match level via OS
 /// threads. Idempotent: `get_or_init` is a no-op once a pool exists, and the
 /// later `MinimalPlugins` `TaskPoolPlugin` sees the pools already initialized.
-fn pin_task_pools_single_threaded() {
+pub(super) fn pin_task_pools_single_threaded() {
     use bevy::tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
     ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(1).build());
     AsyncComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(1).build());
@@ -40,16 +42,17 @@ fn pin_task_pools_single_threaded() {
 }
 
 /// Default worker count: leave a couple of cores for the OS / aggregation.
-fn default_jobs() -> usize {
+pub(super) fn default_jobs() -> usize {
     std::thread::available_parallelism()
         .map(|n| n.get().saturating_sub(2).max(1))
         .unwrap_or(4)
 }
 
-/// Run a batch of matches from a JSONL config file and write a per-match CSV.
-pub fn run_batch(input: PathBuf, output: PathBuf, jobs: Option<usize>) -> Result<(), String> {
-    // 1. Read & parse all match configs (one JSON object per non-blank line).
-    let file = std::fs::File::open(&input)
+/// Read a batch input: one `HeadlessMatchConfig` JSON object per non-blank
+/// line. Lines recorded against a different `SIM_VERSION` are rejected unless
+/// `allow_sim_version_mismatch`.
+pub fn load_batch_configs(input: &Path, allow_sim_version_mismatch: bool) -> Result<Vec<HeadlessMatchConfig>, String> {
+    let file = std::fs::File::open(input)
         .map_err(|e| format!("open batch input {}: {}", input.display(), e))?;
     let reader = BufReader::new(file);
     let mut configs: Vec<HeadlessMatchConfig> = Vec::new();
@@ -61,37 +64,133 @@ pub fn run_batch(input: PathBuf, output: PathBuf, jobs: Option<usize>) -> Result
         }
         let cfg: HeadlessMatchConfig = serde_json::from_str(trimmed)
             .map_err(|e| format!("parse batch line {}: {}", i + 1, e))?;
+        cfg.check_sim_version(&format!("batch line {}", i + 1), allow_sim_version_mismatch)?;
         configs.push(cfg);
     }
-    let total = configs.len();
-    if total == 0 {
+    if configs.is_empty() {
         return Err("batch input contained no match configs".to_string());
     }
+    Ok(configs)
+}
+
+/// Matches per database transaction with `--db`: the batch runs a chunk at a
+/// time and commits each before starting the next, so a sweep that crashes
+/// keeps what it already finished.
+#[cfg(feature = "db-sink")]
+const DB_CHUNK_MATCHES: usize = 256;
+
+/// Run a batch of matches read from `input` (see [`load_batch_configs`]) and
+/// write a per-match CSV. With `db` (a `--db` URL, `db-sink` feature) the
+/// results also go to that database, chunk by chunk as they finish.
+pub fn run_batch(
+    input: PathBuf,
+    configs: Vec<HeadlessMatchConfig>,
+    output: PathBuf,
+    jobs: Option<usize>,
+    db: Option<String>,
+) -> Result<(), ModeError> {
+    // Check the database before spending minutes on matches it can't take
+    #[cfg(feature = "db-sink")]
+    let db = match db {
+        Some(url) => {
+            let target = super::db_sink::DbTarget::parse(&url).map_err(ModeError::Config)?;
+            super::db_sink::ensure_schema(&target)?;
+            Some(target)
+        }
+        None => None,
+    };
+    #[cfg(not(feature = "db-sink"))]
+    if db.is_some() {
+        return Err(ModeError::Config("--db needs a build with `--features db-sink`".to_string()));
+    }
 
-    // 2. Parse the three game-config RON files once for the whole run.
+    let total = configs.len();
+
+    // Run-level provenance for the CSV header (each row carries its own seed)
+    let config_hashes: Vec<String> = configs.iter().map(HeadlessMatchConfig::fingerprint).collect();
+    let metadata = MatchMetadata::capture(fingerprint(config_hashes.join(",").as_bytes()), None);
+    #[cfg(feature = "db-sink")]
+    let db = match db {
+        Some(target) => {
+            let run_id = super::db_sink::begin_run(&target, &input.display().to_string(), &metadata)?;
+            Some((target, run_id))
+        }
+        None => None,
+    };
+
+    // 1. Parse the three game-config RON files once for the whole run.
     let preloaded = Arc::new(PreloadedConfigs::load()?);
 
-    // 3. Pin task pools so each match is internally single-threaded.
+    // 2. Pin task pools so each match is internally single-threaded.
     pin_task_pools_single_threaded();
 
     let n_jobs = jobs.unwrap_or_else(default_jobs).max(1);
     let started = std::time::Instant::now();
-    eprintln!("Batch: {} matches across {} workers", total, n_jobs);
+    eprintln!("Batch: {} matches from {} across {} workers", total, input.display(), n_jobs);
 
-    // 4. Run in parallel. A shared atomic cursor hands out indices (load-balances
-    //    naturally across uneven match durations); each worker returns its own
-    //    (index, result) pairs which we merge back into input order.
-    let configs = Arc::new(configs);
-    let cursor = Arc::new(AtomicUsize::new(0));
+    // 3. Run in parallel; results come back in input order. With a database
+    //    the run goes a chunk at a time, each committed once it finishes.
+    #[cfg(feature = "db-sink")]
+    let chunk_size = if db.is_some() { DB_CHUNK_MATCHES } else { total.max(1) };
+    #[cfg(not(feature = "db-sink"))]
+    let chunk_size = total.max(1);
+    let mut slots: Vec<Option<MatchResult>> = Vec::with_capacity(total);
+    for chunk_start in (0..total).step_by(chunk_size) {
+        let chunk = Arc::new(configs[chunk_start..(chunk_start + chunk_size).min(total)].to_vec());
+        let what = match chunk_start {
+            0 => "batch".to_string(),
+            _ => format!("batch (from index {})", chunk_start),
+        };
+        let chunk_slots = run_parallel(&chunk, &preloaded, n_jobs, &what);
+        #[cfg(feature = "db-sink")]
+        if let Some((target, run_id)) = &db {
+            super::db_sink::write_matches(target, run_id, chunk_start, &chunk, &chunk_slots)?;
+        }
+        slots.extend(chunk_slots);
+    }
+    #[cfg(feature = "db-sink")]
+    if let Some((_, run_id)) = &db {
+        eprintln!("Batch results written to the database as run {}", run_id);
+    }
 
+    // 4. Write per-match CSV in input order.
+    write_results_csv(&output, &metadata, &configs, &slots)?;
+
+    let elapsed = started.elapsed().as_secs_f32();
+    let completed = slots.iter().filter(|s| s.is_some()).count();
+    eprintln!(
+        "Batch complete: {}/{} matches in {:.1}s ({:.0}/s) -> {}",
+        completed,
+        total,
+        elapsed,
+        completed as f32 / elapsed.max(0.001),
+        output.display()
+    );
+    Ok(())
+}
+
+/// Run every config across `n_jobs` worker threads and return the results in
+/// input order (`None` where a match errored — logged to stderr with `what`
+/// as the prefix). A shared atomic cursor hands out indices, which
+/// load-balances naturally across uneven match durations; each worker returns
+/// its own (index, result) pairs which are merged back into input order.
+/// Callers pin the task pools first (see [`pin_task_pools_single_threaded`]).
+pub(super) fn run_parallel(
+    configs: &Arc<Vec<HeadlessMatchConfig>>,
+    preloaded: &Arc<PreloadedConfigs>,
+    n_jobs: usize,
+    what: &str,
+) -> Vec<Option<MatchResult>> {
+    let total = configs.len();
+    let cursor = Arc::new(AtomicUsize::new(0));
     let mut slots: Vec<Option<MatchResult>> = (0..total).map(|_| None).collect();
 
     std::thread::scope(|scope| {
         let mut handles = Vec::with_capacity(n_jobs);
         for _ in 0..n_jobs {
-            let configs = Arc::clone(&configs);
+            let configs = Arc::clone(configs);
             let cursor = Arc::clone(&cursor);
-            let preloaded = Arc::clone(&preloaded);
+            let preloaded = Arc::clone(preloaded);
             handles.push(scope.spawn(move || {
                 let mut local: Vec<(usize, MatchResult)> = Vec::new();
                 loop {
@@ -102,7 +201,8 @@ pub fn run_batch(input: PathBuf, output: PathBuf, jobs: Option<usize>) -> Result
                     match run_headless_match_prepared(configs[idx].clone(), &preloaded, true, None) {
                         Ok(r) => local.push((idx, r)),
                         Err(e) => eprintln!(
-                            "batch match {} ({} v {}) failed: {}",
+                            "{} match {} ({} v {}) failed: {}",
+                            what,
                             idx,
                             configs[idx].team1.join("+"),
                             configs[idx].team2.join("+"),
@@ -115,32 +215,21 @@ pub fn run_batch(input: PathBuf, output: PathBuf, jobs: Option<usize>) -> Result
         }
         for h in handles {
             // A worker panic is a bug (e.g. a non-deterministic global); surface it.
-            for (idx, r) in h.join().expect("batch worker thread panicked") {
+            for (idx, r) in h.join().expect("match worker thread panicked") {
                 slots[idx] = Some(r);
             }
         }
     });
-
-    // 5. Write per-match CSV in input order.
-    write_results_csv(&output, &configs, &slots)?;
-
-    let elapsed = started.elapsed().as_secs_f32();
-    let completed = slots.iter().filter(|s| s.is_some()).count();
-    eprintln!(
-        "Batch complete: {}/{} matches in {:.1}s ({:.0}/s) -> {}",
-        completed,
-        total,
-        elapsed,
-        completed as f32 / elapsed.max(0.001),
-        output.display()
-    );
-    Ok(())
+    slots
 }
 
-/// Write one CSV row per match: the matchup identity, seed, outcome, and why it
-/// ended. Aggregation (winrates per matchup) is left to cheap downstream tools.
+/// Write one CSV row per match: the matchup identity, seed, outcome, why it
+/// ended, and which abilities landed the killing blows (`Execute:1;Shadowburn:1`).
+/// The run's `MatchMetadata` comes first as `# ` comment lines.
+/// Aggregation (winrates per matchup) is left to cheap downstream tools.
 fn write_results_csv(
     output: &PathBuf,
+    metadata: &MatchMetadata,
     configs: &[HeadlessMatchConfig],
     slots: &[Option<MatchResult>],
 ) -> Result<(), String> {
@@ -154,7 +243,8 @@ fn write_results_csv(
         .map_err(|e| format!("create {}: {}", output.display(), e))?;
     let mut w = BufWriter::new(file);
 
-    writeln!(w, "label,team1,team2,seed,winner,end_reason,duration_secs")
+    metadata.write_header(&mut w, "# ").map_err(|e| e.to_string())?;
+    writeln!(w, "label,team1,team2,seed,winner,end_reason,duration_secs,killing_blows")
         .map_err(|e| e.to_string())?;
     for (cfg, slot) in configs.iter().zip(slots.iter()) {
         let team1 = cfg.team1.join("+");
@@ -168,10 +258,16 @@ fn write_results_csv(
                     Some(2) => "team2",
                     _ => "draw",
                 };
+                let killing_blows = r
+                    .killing_blows
+                    .iter()
+                    .map(|(ability, count)| format!("{}:{}", ability, count))
+                    .collect::<Vec<_>>()
+                    .join(";");
                 writeln!(
                     w,
-                    "{},{},{},{},{},{},{:.2}",
-                    label, team1, team2, seed, winner, r.end_reason.as_str(), r.match_time
+                    "{},{},{},{},{},{},{:.2},{}",
+                    label, team1, team2, seed, winner, r.end_reason.as_str(), r.match_time, killing_blows
                 )
                 .map_err(|e| e.to_string())?;
             }
@@ -180,7 +276,7 @@ fn write_results_csv(
                 // counts stay aligned with the input.
                 writeln!(
                     w,
-                    "{},{},{},{},error,error,0.00",
+                    "{},{},{},{},error,error,0.00,",
                     label, team1, team2, seed
                 )
                 .map_err(|e| e.to_string())?;
@@ -0,0 +1,146 @@
+//! Simulation throughput benchmark (`--calibrate N`).
+//!
+//! Plays one short warmup match, then the same fixed benchmark match N times,
+//! and reports steady-state ticks per second (one tick = one 1/60s
+//! `app.update()`) with its spread across runs. The warmup absorbs first-run
+//! costs — page faults, allocator growth, cold caches — that would otherwise
+//! skew the first measured run, and its rate is printed for comparison.
+//!
+//! Only the update loop is timed: app construction and config parsing are
+//! excluded (the RON configs are parsed once up front), and the task pools
+//! are pinned to one thread as in `--batch`, so the number is a per-core
+//! figure comparable between branches on the same machine. Compare release
+//! builds; debug numbers say little about real throughput.
+//!
+//! The benchmark match is seeded, so every run simulates the same ticks.
+
+use std::io::Write;
+
+use crate::combat::metadata::MatchMetadata;
+use super::batch::pin_task_pools_single_threaded;
+use super::config::HeadlessMatchConfig;
+use super::error::ModeError;
+use super::runner::{run_headless_match_timed, LoopTiming, PreloadedConfigs};
+
+/// Simulated seconds of the warmup match
+const WARMUP_SECS: f32 = 15.0;
+
+/// The benchmark match: a 3v3 with a pet, melee, casters and healers, so every
+/// major system (projectiles, auras, pet AI, healing) is exercised.
+fn benchmark_config() -> HeadlessMatchConfig {
+    HeadlessMatchConfig {
+        team1: vec!["Warrior".to_string(), "Hunter".to_string(), "Priest".to_string()],
+        team2: vec!["Rogue".to_string(), "Warlock".to_string(), "Paladin".to_string()],
+        random_seed: Some(1),
+        max_duration_secs: 90.0,
+        ..Default::default()
+    }
+}
+
+/// Spread of per-run ticks/sec.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThroughputStats {
+    pub mean: f64,
+    /// Sample standard deviation (0 for a single run)
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ThroughputStats {
+    /// `None` for no samples.
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Some(Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: samples.iter().copied().fold(f64::INFINITY, f64::min),
+            max: samples.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+
+    /// Coefficient of variation, in percent.
+    pub fn cv_percent(&self) -> f64 {
+        if self.mean == 0.0 { 0.0 } else { 100.0 * self.std_dev / self.mean }
+    }
+}
+
+/// Run the warmup and `runs` measured benchmark matches and print the report.
+pub fn run_calibrate(runs: u32) -> Result<(), ModeError> {
+    if runs == 0 {
+        return Err(ModeError::Config("--calibrate N requires N >= 1".to_string()));
+    }
+
+    let config = benchmark_config();
+    let preloaded = PreloadedConfigs::load().map_err(ModeError::Config)?;
+    pin_task_pools_single_threaded();
+
+    let metadata = MatchMetadata::capture(config.fingerprint(), config.random_seed);
+    let mut out = std::io::stdout().lock();
+    metadata.write_header(&mut out, "# ").map_err(|e| e.to_string())?;
+    writeln!(out, "# Benchmark: {} v {}", config.team1.join("+"), config.team2.join("+")).map_err(|e| e.to_string())?;
+    if cfg!(debug_assertions) {
+        writeln!(out, "# Debug build: build with --release for meaningful numbers").map_err(|e| e.to_string())?;
+    }
+
+    let warmup = HeadlessMatchConfig { max_duration_secs: WARMUP_SECS, ..config.clone() };
+    let (_, warmup_timing) = run_headless_match_timed(warmup, &preloaded)?;
+    writeln!(out, "warmup  {}", format_timing(&warmup_timing)).map_err(|e| e.to_string())?;
+
+    let mut samples = Vec::with_capacity(runs as usize);
+    for run in 1..=runs {
+        let (_, timing) = run_headless_match_timed(config.clone(), &preloaded)?;
+        writeln!(out, "run {:<3} {}", run, format_timing(&timing)).map_err(|e| e.to_string())?;
+        samples.push(timing.ticks_per_sec());
+    }
+
+    let stats = ThroughputStats::from_samples(&samples).expect("runs >= 1");
+    writeln!(
+        out,
+        "steady state: {:.0} ticks/s ± {:.0} (cv {:.1}%, min {:.0}, max {:.0}, {} runs)",
+        stats.mean,
+        stats.std_dev,
+        stats.cv_percent(),
+        stats.min,
+        stats.max,
+        runs
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn format_timing(timing: &LoopTiming) -> String {
+    format!(
+        "{:>6} ticks in {:>7.3}s = {:>8.0} ticks/s",
+        timing.ticks,
+        timing.elapsed.as_secs_f64(),
+        timing.ticks_per_sec()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throughput_stats_use_sample_std_dev() {
+        let stats = ThroughputStats::from_samples(&[900.0, 1000.0, 1100.0]).unwrap();
+        assert_eq!(stats.mean, 1000.0);
+        assert_eq!(stats.std_dev, 100.0);
+        assert_eq!((stats.min, stats.max), (900.0, 1100.0));
+        assert!((stats.cv_percent() - 10.0).abs() < 1e-9);
+
+        let single = ThroughputStats::from_samples(&[1234.0]).unwrap();
+        assert_eq!(single.std_dev, 0.0);
+        assert_eq!(ThroughputStats::from_samples(&[]), None);
+    }
+}
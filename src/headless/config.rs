@@ -6,19 +6,25 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 
-use crate::states::match_config::{ArenaMap, CharacterClass, HunterPetType, MageArmor, MatchConfig, PaladinAura, RogueOpener, RoguePoison, WarlockCurse, WarriorShout};
+use crate::states::play_match::components::AuraType;
+use crate::states::play_match::sandbox::SandboxEvent;
+use crate::states::match_config::{ArenaMap, CharacterClass, HunterPetType, MageArmor, MatchConfig, MatchMode, PaladinAura, Personality, MAX_TEAM_SIZE, RogueOpener, RoguePoison, WarlockCurse, WarriorShout};
 use crate::states::play_match::equipment::{ItemId, ItemSlot};
 
 /// Headless match configuration loaded from JSON
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeadlessMatchConfig {
-    /// Team 1 composition (1-3 class names)
+    /// Team 1 composition (1-5 class names)
     pub team1: Vec<String>,
-    /// Team 2 composition (1-3 class names)
+    /// Team 2 composition (1-5 class names)
     pub team2: Vec<String>,
     /// Arena map name (default: "BasicArena")
     #[serde(default = "default_map")]
     pub map: String,
+    /// Match mode: "Standard" (default) or "Duel" (1v1 only, 5s countdown).
+    /// Pair "Duel" with map "DuelRing" to mirror the in-game Duel preset.
+    #[serde(default = "default_mode")]
+    pub mode: String,
     /// Team 1's kill target priority (0-based index into enemy team)
     #[serde(default)]
     pub team1_kill_target: Option<usize>,
@@ -37,10 +43,21 @@ pub struct HeadlessMatchConfig {
     /// Maximum match duration in seconds (default: 300)
     #[serde(default = "default_max_duration")]
     pub max_duration_secs: f32,
+    /// End the match as a stalemate draw once neither team has set a new
+    /// health or mana low for this many seconds (default: 60, 0 = never).
+    /// See `headless::stalemate`.
+    #[serde(default = "default_stalemate_secs")]
+    pub stalemate_secs: f32,
     /// Random seed for deterministic match reproduction
     /// If provided, the match will use a seeded RNG for reproducible results
     #[serde(default)]
     pub random_seed: Option<u64>,
+    /// `SIM_VERSION` this config was recorded against. Set on replayable
+    /// configs (fuzz repros, regression scenarios) so loading them on a build
+    /// with different simulation behavior fails instead of silently diverging.
+    /// Unset = version-agnostic (hand-written configs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sim_version: Option<u32>,
     /// Optional grouping label echoed verbatim into batch-runner output. Lets a
     /// strategy-var sweep keep variants distinct (e.g. "Hunter+Spider vs Mage"
     /// vs "Hunter+Boar vs Mage") when two configs share the same class lists.
@@ -96,16 +113,80 @@ pub struct HeadlessMatchConfig {
     /// Team 2's paladin aura preferences (one per slot)
     #[serde(default)]
     pub team2_paladin_auras: Vec<String>,
+    /// Team 1's AI personalities (one per slot)
+    #[serde(default)]
+    pub team1_personalities: Vec<String>,
+    /// Team 2's AI personalities (one per slot)
+    #[serde(default)]
+    pub team2_personalities: Vec<String>,
+    /// Crowd control forced onto specific combatants at fixed match times,
+    /// independent of any AI decision. Used by the fuzzer to provoke aura
+    /// interactions (CC landing mid-cast, on pets' owners, on dying targets)
+    /// that normal play only reaches by chance.
+    #[serde(default)]
+    pub scripted_cc: Vec<ScriptedCc>,
+    /// Sandbox events (damage, healing, auras) injected at fixed match times.
+    /// Used to check how combat systems and the log react to a specific hit.
+    #[serde(default)]
+    pub sandbox: Vec<ScheduledSandboxEvent>,
+}
+
+/// A sandbox event injected at a fixed time after the gates open. `command`
+/// uses the sandbox console syntax (see `SandboxEvent::parse`), e.g.
+/// `"damage 1 0 500"` or `"aura 2 1 Stun 4"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledSandboxEvent {
+    /// Seconds after the gates open at which the event is injected
+    pub at_secs: f32,
+    /// Sandbox console command
+    pub command: String,
+}
+
+/// A crowd-control aura applied to one combatant at a fixed time after the
+/// gates open. Goes through the normal `AuraPending` path, so DR, immunity
+/// and charge/disengage rules apply exactly as for an AI-cast effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptedCc {
+    /// Seconds after the gates open at which the aura is applied
+    pub at_secs: f32,
+    /// Team of the target (1 or 2)
+    pub team: u8,
+    /// 0-based slot of the target within its team (pets are not targetable)
+    pub slot: usize,
+    /// Aura to apply: Stun, Root, Fear, Polymorph, Incapacitate or Silence
+    pub aura: AuraType,
+    /// Aura duration in seconds (before diminishing returns)
+    pub duration: f32,
+}
+
+impl ScriptedCc {
+    /// Aura types a scripted CC entry may carry.
+    pub const ALLOWED_AURAS: [AuraType; 6] = [
+        AuraType::Stun,
+        AuraType::Root,
+        AuraType::Fear,
+        AuraType::Polymorph,
+        AuraType::Incapacitate,
+        AuraType::Silence,
+    ];
 }
 
 fn default_map() -> String {
     "BasicArena".to_string()
 }
 
+fn default_mode() -> String {
+    "Standard".to_string()
+}
+
 fn default_max_duration() -> f32 {
     300.0
 }
 
+fn default_stalemate_secs() -> f32 {
+    super::stalemate::DEFAULT_STALEMATE_SECS
+}
+
 /// Mirrors the serde per-field defaults: empty teams (callers must fill them
 /// in — `validate()` rejects empty teams), `BasicArena`, 300s timeout, no
 /// seed, and empty per-class strategy/equipment preference vectors. Lets test
@@ -118,13 +199,16 @@ impl Default for HeadlessMatchConfig {
             team2: Vec::new(),
             label: None,
             map: default_map(),
+            mode: default_mode(),
             team1_kill_target: None,
             team2_kill_target: None,
             team1_cc_target: None,
             team2_cc_target: None,
             output_path: None,
             max_duration_secs: default_max_duration(),
+            stalemate_secs: default_stalemate_secs(),
             random_seed: None,
+            sim_version: None,
             team1_rogue_openers: Vec::new(),
             team2_rogue_openers: Vec::new(),
             team1_rogue_poisons: Vec::new(),
@@ -141,31 +225,54 @@ impl Default for HeadlessMatchConfig {
             team2_mage_armors: Vec::new(),
             team1_paladin_auras: Vec::new(),
             team2_paladin_auras: Vec::new(),
+            team1_personalities: Vec::new(),
+            team2_personalities: Vec::new(),
+            scripted_cc: Vec::new(),
+            sandbox: Vec::new(),
         }
     }
 }
 
 impl HeadlessMatchConfig {
-    /// Load configuration from a JSON file
-    pub fn load_from_file(path: &Path) -> Result<Self, String> {
+    /// Load configuration from a JSON file. A config recorded against a
+    /// different `SIM_VERSION` is rejected unless `allow_sim_version_mismatch`.
+    pub fn load_from_file(path: &Path, allow_sim_version_mismatch: bool) -> Result<Self, String> {
         let contents = std::fs::read_to_string(path)
             .map_err(|e| format!("Failed to read config file: {}", e))?;
 
         let config: HeadlessMatchConfig = serde_json::from_str(&contents)
             .map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
+        config.check_sim_version(&path.display().to_string(), allow_sim_version_mismatch)?;
         config.validate()?;
         Ok(config)
     }
 
+    /// Check `sim_version` (when recorded) against this build's `SIM_VERSION`.
+    pub fn check_sim_version(&self, what: &str, allow_mismatch: bool) -> Result<(), String> {
+        match self.sim_version {
+            Some(recorded) => crate::combat::metadata::check_sim_version(recorded, what, allow_mismatch),
+            None => Ok(()),
+        }
+    }
+
+    /// Stable fingerprint of the match setup for `MatchMetadata`. Hashes the
+    /// JSON form with sorted keys, so equipment map order doesn't matter;
+    /// `output_path` is left out since it doesn't change the match.
+    pub fn fingerprint(&self) -> String {
+        let setup = HeadlessMatchConfig { output_path: None, ..self.clone() };
+        let canonical = serde_json::to_value(&setup).map(|v| v.to_string()).unwrap_or_default();
+        crate::combat::metadata::fingerprint(canonical.as_bytes())
+    }
+
     /// Validate the configuration
-    fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), String> {
         // Validate team sizes
-        if self.team1.is_empty() || self.team1.len() > 3 {
-            return Err("team1 must have 1-3 members".to_string());
+        if self.team1.is_empty() || self.team1.len() > MAX_TEAM_SIZE {
+            return Err(format!("team1 must have 1-{} members", MAX_TEAM_SIZE));
         }
-        if self.team2.is_empty() || self.team2.len() > 3 {
-            return Err("team2 must have 1-3 members".to_string());
+        if self.team2.is_empty() || self.team2.len() > MAX_TEAM_SIZE {
+            return Err(format!("team2 must have 1-{} members", MAX_TEAM_SIZE));
         }
 
         // Validate class names
@@ -176,6 +283,13 @@ impl HeadlessMatchConfig {
         // Validate map name
         Self::parse_map(&self.map)?;
 
+        // Validate mode (duels are strictly 1v1)
+        if Self::parse_mode(&self.mode)? == MatchMode::Duel
+            && (self.team1.len() != 1 || self.team2.len() != 1)
+        {
+            return Err("Duel mode requires exactly one member per team".to_string());
+        }
+
         // Validate kill targets
         if let Some(target) = self.team1_kill_target {
             if target >= self.team2.len() {
@@ -220,12 +334,61 @@ impl HeadlessMatchConfig {
         if self.max_duration_secs <= 0.0 {
             return Err("max_duration_secs must be positive".to_string());
         }
+        if self.stalemate_secs < 0.0 {
+            return Err("stalemate_secs must be 0 (disabled) or positive".to_string());
+        }
+
+        // Validate scripted CC
+        for (i, cc) in self.scripted_cc.iter().enumerate() {
+            let team_size = match cc.team {
+                1 => self.team1.len(),
+                2 => self.team2.len(),
+                other => {
+                    return Err(format!("scripted_cc[{}]: team must be 1 or 2, got {}", i, other));
+                }
+            };
+            if cc.slot >= team_size {
+                return Err(format!(
+                    "scripted_cc[{}]: slot {} is out of range (team{} has {} members)",
+                    i, cc.slot, cc.team, team_size
+                ));
+            }
+            if !ScriptedCc::ALLOWED_AURAS.contains(&cc.aura) {
+                return Err(format!(
+                    "scripted_cc[{}]: {:?} is not a crowd-control aura. Valid: Stun, Root, Fear, Polymorph, Incapacitate, Silence",
+                    i, cc.aura
+                ));
+            }
+            if !cc.at_secs.is_finite() || cc.at_secs < 0.0 || !cc.duration.is_finite() || cc.duration <= 0.0 {
+                return Err(format!(
+                    "scripted_cc[{}]: at_secs must be >= 0 and duration must be positive",
+                    i
+                ));
+            }
+        }
+
+        // Validate sandbox events
+        for (i, scheduled) in self.sandbox.iter().enumerate() {
+            let event = SandboxEvent::parse(&scheduled.command)
+                .map_err(|e| format!("sandbox[{}]: {}", i, e))?;
+            let (team, slot) = event.target();
+            let team_size = if team == 1 { self.team1.len() } else { self.team2.len() };
+            if slot >= team_size {
+                return Err(format!(
+                    "sandbox[{}]: slot {} is out of range (team{} has {} members)",
+                    i, slot, team, team_size
+                ));
+            }
+            if !scheduled.at_secs.is_finite() || scheduled.at_secs < 0.0 {
+                return Err(format!("sandbox[{}]: at_secs must be >= 0", i));
+            }
+        }
 
         Ok(())
     }
 
     /// Parse a class name string into CharacterClass
-    fn parse_class(name: &str) -> Result<CharacterClass, String> {
+    pub(crate) fn parse_class(name: &str) -> Result<CharacterClass, String> {
         match name {
             "Warrior" => Ok(CharacterClass::Warrior),
             "Mage" => Ok(CharacterClass::Mage),
@@ -247,13 +410,23 @@ impl HeadlessMatchConfig {
         match name {
             "BasicArena" => Ok(ArenaMap::BasicArena),
             "PillaredArena" => Ok(ArenaMap::PillaredArena),
+            "DuelRing" => Ok(ArenaMap::DuelRing),
             _ => Err(format!(
-                "Unknown map: '{}'. Valid maps: BasicArena, PillaredArena",
+                "Unknown map: '{}'. Valid maps: BasicArena, PillaredArena, DuelRing",
                 name
             )),
         }
     }
 
+    /// Parse a mode name string into MatchMode
+    fn parse_mode(name: &str) -> Result<MatchMode, String> {
+        match name {
+            "Standard" => Ok(MatchMode::Standard),
+            "Duel" => Ok(MatchMode::Duel),
+            _ => Err(format!("Unknown mode: '{}'. Valid modes: Standard, Duel", name)),
+        }
+    }
+
     /// Parse a rogue opener name string into RogueOpener
     fn parse_rogue_opener(name: &str) -> RogueOpener {
         match name {
@@ -308,6 +481,17 @@ impl HeadlessMatchConfig {
         }
     }
 
+    /// Parse a personality name string into Personality
+    fn parse_personality(name: &str) -> Personality {
+        match name.to_lowercase().as_str() {
+            "aggressive" => Personality::Aggressive,
+            "cautious" => Personality::Cautious,
+            "killhungry" | "kill_hungry" | "kill hungry" | "greedy" | "greedy for kills" => Personality::KillHungry,
+            "peel" | "peelfocused" | "peel_focused" | "peel-focused" | "peel focused" => Personality::PeelFocused,
+            _ => Personality::default(),
+        }
+    }
+
     /// Parse a warlock curse name string into WarlockCurse
     fn parse_warlock_curse(name: &str) -> WarlockCurse {
         match name {
@@ -411,8 +595,9 @@ impl HeadlessMatchConfig {
             // Off Hand
             "TomeOfKnowledge" => Ok(ItemId::TomeOfKnowledge),
             "WallOfTheDeadShield" => Ok(ItemId::WallOfTheDeadShield),
-            _ => Err(format!(
-                "Unknown item: '{}'. Valid items: LionheartHelm, OnslaughtHeadGuard, ConquerorsChestplate, LegplatesOfWrath, GauntletsOfMight, SabatonsBattleBorn, WaistguardOfHeroism, WristguardsOfStability, ShoulderplatesOfValor, BeaststalkerHelm, BeaststalkerTunic, BeaststalkerLegs, BeaststalkerGloves, BeaststalkerBoots, BeaststalkerBelt, BeaststalkerBracers, BeaststalkerMantle, NightstalkerCowl, NightstalkerTunic, NightstalkerLegs, NightstalkerGloves, NightstalkerBoots, NightstalkerBelt, NightstalkerBracers, NightstalkerMantle, MagistersCrown, MagistersRobes, MagistersLeggings, MagistersGloves, MagistersBoots, MagistersBelt, MagistersBracers, MagistersMantle, CloakOfTheShieldWall, CloakOfConcentration, AmuletOfPower, AmuletOfResilience, BandOfAccuria, SignetOfFocus, RingOfProtection, MarkOfTheChampion, EssenceOfEternalLife, ArcaniteReaper, FrostbiteBlade, SerpentFangDagger, HammerOfTheRighteous, CrescentStaff, WandOfShadows, StaffOfDominance, AshwoodBow, SniperScope, TomeOfKnowledge, WallOfTheDeadShield",
+            // Any other item by its ItemId variant name
+            _ => serde_json::from_value(serde_json::Value::String(name.to_string())).map_err(|_| format!(
+                "Unknown item: '{}'. Valid items: any ItemId name in equipment.rs, e.g. LionheartHelm, ArcaniteReaper",
                 name
             )),
         }
@@ -487,6 +672,7 @@ impl HeadlessMatchConfig {
             .collect::<Result<Vec<_>, _>>()?;
 
         let map = Self::parse_map(&self.map)?;
+        let mode = Self::parse_mode(&self.mode)?;
 
         // Parse rogue openers, defaulting to Ambush for missing entries
         let mut team1_rogue_openers: Vec<RogueOpener> = self
@@ -589,6 +775,21 @@ impl HeadlessMatchConfig {
             .collect();
         team2_paladin_auras.resize(team2.len(), PaladinAura::default());
 
+        // Parse AI personalities, defaulting to Balanced for missing entries
+        let mut team1_personalities: Vec<Personality> = self
+            .team1_personalities
+            .iter()
+            .map(|s| Self::parse_personality(s))
+            .collect();
+        team1_personalities.resize(team1.len(), Personality::default());
+
+        let mut team2_personalities: Vec<Personality> = self
+            .team2_personalities
+            .iter()
+            .map(|s| Self::parse_personality(s))
+            .collect();
+        team2_personalities.resize(team2.len(), Personality::default());
+
         // Parse equipment overrides, defaulting to empty maps for missing entries
         let team1_equipment = Self::parse_equipment_overrides(&self.team1_equipment, team1.len())?;
         let team2_equipment = Self::parse_equipment_overrides(&self.team2_equipment, team2.len())?;
@@ -599,6 +800,7 @@ impl HeadlessMatchConfig {
             team1,
             team2,
             map,
+            mode,
             team1_kill_target: self.team1_kill_target,
             team2_kill_target: self.team2_kill_target,
             team1_cc_target: self.team1_cc_target,
@@ -617,8 +819,85 @@ impl HeadlessMatchConfig {
             team2_mage_armors,
             team1_paladin_auras,
             team2_paladin_auras,
+            team1_personalities,
+            team2_personalities,
             team1_equipment,
             team2_equipment,
+            arena_seed: self.random_seed,
+            lighting: None,
+        })
+    }
+
+    /// Convert a fully assigned MatchConfig back to this format, the inverse
+    /// of `to_match_config`. Per-slot preferences that are all defaults are
+    /// left empty. Fails on empty slots and Gauntlet mode (later rounds are
+    /// rolled at runtime, so a config can't describe them).
+    pub fn from_match_config(config: &MatchConfig, random_seed: Option<u64>) -> Result<Self, String> {
+        let classes = |team: &[Option<CharacterClass>], label: &str| -> Result<Vec<String>, String> {
+            team.iter()
+                .map(|slot| slot.map(|class| format!("{:?}", class)).ok_or_else(|| format!("{} has an empty slot", label)))
+                .collect()
+        };
+        let mode = match config.mode {
+            MatchMode::Standard => "Standard",
+            MatchMode::Duel => "Duel",
+            MatchMode::Gauntlet => return Err("Gauntlet runs can't be described by a single match config".to_string()),
+        };
+
+        Ok(HeadlessMatchConfig {
+            team1: classes(&config.team1, "team1")?,
+            team2: classes(&config.team2, "team2")?,
+            map: format!("{:?}", config.map),
+            mode: mode.to_string(),
+            team1_kill_target: config.team1_kill_target,
+            team2_kill_target: config.team2_kill_target,
+            team1_cc_target: config.team1_cc_target,
+            team2_cc_target: config.team2_cc_target,
+            random_seed,
+            team1_rogue_openers: Self::pref_names(&config.team1_rogue_openers),
+            team2_rogue_openers: Self::pref_names(&config.team2_rogue_openers),
+            team1_rogue_poisons: Self::pref_names(&config.team1_rogue_poisons),
+            team2_rogue_poisons: Self::pref_names(&config.team2_rogue_poisons),
+            team1_warlock_curse_prefs: Self::curse_pref_names(&config.team1_warlock_curse_prefs),
+            team2_warlock_curse_prefs: Self::curse_pref_names(&config.team2_warlock_curse_prefs),
+            team1_hunter_pet_types: Self::pref_names(&config.team1_hunter_pet_types),
+            team2_hunter_pet_types: Self::pref_names(&config.team2_hunter_pet_types),
+            team1_equipment: Self::equipment_names(&config.team1_equipment),
+            team2_equipment: Self::equipment_names(&config.team2_equipment),
+            team1_warrior_shouts: Self::pref_names(&config.team1_warrior_shouts),
+            team2_warrior_shouts: Self::pref_names(&config.team2_warrior_shouts),
+            team1_mage_armors: Self::pref_names(&config.team1_mage_armors),
+            team2_mage_armors: Self::pref_names(&config.team2_mage_armors),
+            team1_paladin_auras: Self::pref_names(&config.team1_paladin_auras),
+            team2_paladin_auras: Self::pref_names(&config.team2_paladin_auras),
+            team1_personalities: Self::pref_names(&config.team1_personalities),
+            team2_personalities: Self::pref_names(&config.team2_personalities),
+            ..Default::default()
         })
     }
+
+    /// Per-slot preference names, or none at all when every slot has the default.
+    fn pref_names<T: std::fmt::Debug + Default + PartialEq>(prefs: &[T]) -> Vec<String> {
+        if prefs.iter().all(|p| *p == T::default()) {
+            return Vec::new();
+        }
+        prefs.iter().map(|p| format!("{:?}", p)).collect()
+    }
+
+    fn curse_pref_names(prefs: &[Vec<WarlockCurse>]) -> Vec<Option<Vec<String>>> {
+        if prefs.iter().flatten().all(|c| *c == WarlockCurse::default()) {
+            return Vec::new();
+        }
+        prefs.iter().map(|slot| Some(slot.iter().map(|c| format!("{:?}", c)).collect())).collect()
+    }
+
+    fn equipment_names(overrides: &[HashMap<ItemSlot, ItemId>]) -> Vec<HashMap<String, String>> {
+        if overrides.iter().all(HashMap::is_empty) {
+            return Vec::new();
+        }
+        overrides
+            .iter()
+            .map(|slot| slot.iter().map(|(item_slot, item)| (format!("{:?}", item_slot), format!("{:?}", item))).collect())
+            .collect()
+    }
 }
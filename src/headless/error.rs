@@ -0,0 +1,31 @@
+//! Errors from the multi-match headless modes
+
+use std::fmt;
+
+/// Why `optimize`, `tune`, `--batch`, `--matrix`, `--fuzz` or `--calibrate`
+/// stopped, split the way `cli::ExitStatus` reports it: bad input exits
+/// `ConfigError`, everything else `Failure`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModeError {
+    /// An invalid flag value (unknown class or ability, `--runs 0`,
+    /// `--min >= --max`) or a config that couldn't be loaded.
+    Config(String),
+    /// The run itself failed: I/O, a match with no result, failing fuzz cases.
+    Run(String),
+}
+
+/// Plain `String` errors from the shared helpers are run failures; input
+/// checks build `ModeError::Config` explicitly.
+impl From<String> for ModeError {
+    fn from(message: String) -> Self {
+        ModeError::Run(message)
+    }
+}
+
+impl fmt::Display for ModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModeError::Config(message) | ModeError::Run(message) => f.write_str(message),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Single-match outputs for analysis pipelines
+//!
+//! A `--headless` run can write two files from the same match:
+//! - a results JSON (`--results-json`): metadata, winner, end reason,
+//!   per-combatant stats, killing blows and highlights
+//! - a replay file (`--replay`): the match config with its seed pinned and
+//!   `SIM_VERSION` stamped, as one line of JSON. Replaying is just
+//!   `--headless <replay file>`.
+//!
+//! Tournament pipelines analyze every results JSON and keep the replay only
+//! for the matches analysis flags as anomalous. A replay needs a seed, so
+//! `--replay` on an unseeded config rolls one before the match starts.
+
+use serde::Serialize;
+use std::path::Path;
+
+use crate::combat::metadata::SIM_VERSION;
+use crate::combat::MatchMetadata;
+use super::config::HeadlessMatchConfig;
+use super::runner::MatchResult;
+
+/// JSON form of a results file: `{"metadata": {...}, "winner": 1, "match_time": 42.5, "end_reason": "kill", ...}`
+#[derive(Serialize)]
+struct ResultsExport<'a> {
+    metadata: MatchMetadata,
+    #[serde(flatten)]
+    result: &'a MatchResult,
+}
+
+/// Give `config` a seed if it has none, so the match can be replayed.
+/// Returns the seed the match will run with.
+pub fn pin_seed(config: &mut HeadlessMatchConfig) -> u64 {
+    *config.random_seed.get_or_insert_with(rand::random)
+}
+
+/// The replay for a match run from `config`: the same setup with the log
+/// path dropped and `SIM_VERSION` stamped. `None` if the config is unseeded.
+pub fn replay_config(config: &HeadlessMatchConfig) -> Option<HeadlessMatchConfig> {
+    config.random_seed?;
+    Some(HeadlessMatchConfig {
+        output_path: None,
+        sim_version: Some(SIM_VERSION),
+        ..config.clone()
+    })
+}
+
+/// Write the replay for a match run from `config` as compact JSON.
+pub fn write_replay(path: &Path, config: &HeadlessMatchConfig) -> Result<(), String> {
+    let replay = replay_config(config).ok_or_else(|| "a replay needs a seeded config".to_string())?;
+    let json = serde_json::to_string(&replay).map_err(|e| format!("serialize {}: {}", path.display(), e))?;
+    write_file(path, json)
+}
+
+/// Write the analytical results of a match run from `config`.
+pub fn write_results_json(path: &Path, config: &HeadlessMatchConfig, result: &MatchResult) -> Result<(), String> {
+    let export = ResultsExport {
+        metadata: MatchMetadata::capture(config.fingerprint(), result.random_seed),
+        result,
+    };
+    let json = serde_json::to_string_pretty(&export).map_err(|e| format!("serialize {}: {}", path.display(), e))?;
+    write_file(path, json)
+}
+
+fn write_file(path: &Path, contents: String) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("create {}: {}", parent.display(), e))?;
+        }
+    }
+    std::fs::write(path, contents).map_err(|e| format!("write {}: {}", path.display(), e))
+}
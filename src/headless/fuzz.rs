@@ -0,0 +1,488 @@
+//! Seeded fuzz runner for ability-interaction crashes (`--fuzz N`).
+//!
+//! Each case is generated from a single `u64` seed: random team comps (1 to
+//! `MAX_TEAM_SIZE` per side, any class), random map, kill/CC target
+//! priorities, per-class strategy overrides (openers, pets, curses, shouts,
+//! armors, auras), and a handful of scripted CC entries that land at
+//! arbitrary times — mid-cast, on a pet's owner, on a target that is already
+//! dying. The match itself is seeded from the same value, so a case is fully
+//! reproducible from its seed.
+//!
+//! A case fails if the simulation panics, if a per-frame invariant is violated
+//! (non-finite or out-of-range health, non-finite positions, a non-stacking
+//! buff stacking past its cap, a `*Pending` effect pointing at an entity that
+//! is no longer a combatant), or if the match never produces a result. Failing
+//! cases are greedily minimized — dropping scripted CC, team members and
+//! overrides while the failure persists — and the minimized config is written
+//! as plain `HeadlessMatchConfig` JSON to
+//! `match_logs/fuzz/<timestamp>/seed_<seed>.json`, replayable with
+//! `--headless`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::combat::metadata::{fingerprint, MatchMetadata, SIM_VERSION};
+use crate::states::match_config::{CharacterClass, MAX_TEAM_SIZE};
+
+use super::config::{HeadlessMatchConfig, ScriptedCc};
+use super::error::ModeError;
+use super::runner::{run_headless_match_observed, FrameObservation};
+
+/// Simulated match cap for fuzz cases. Crashes in aura / pet / pending-effect
+/// handling show up early; long timeouts only cost throughput.
+const FUZZ_MAX_DURATION_SECS: f32 = 90.0;
+
+/// Upper bound on scripted CC entries per generated case.
+const MAX_SCRIPTED_CC: usize = 6;
+
+/// Upper bound on candidate runs spent minimizing one failing case.
+const MAX_MINIMIZE_RUNS: u32 = 64;
+
+/// Tolerance for `current_health <= max_health` (float accumulation noise).
+const HEALTH_EPSILON: f32 = 0.01;
+
+/// Most instances of one non-stacking buff (`AuraType::refuses_stacking`)
+/// per ability on one target. Debuffs and DoTs are not capped.
+const MAX_AURA_STACKS: usize = 1;
+
+/// How a fuzz case failed. Minimization only keeps a reduction if the
+/// reduced case still fails with the same kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzFailureKind {
+    /// The simulation panicked.
+    Panic,
+    /// A per-frame invariant check failed.
+    Invariant,
+    /// The match ran out of frames without producing a result.
+    NoResult,
+}
+
+impl FuzzFailureKind {
+    /// Stable lowercase token for the summary file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FuzzFailureKind::Panic => "panic",
+            FuzzFailureKind::Invariant => "invariant",
+            FuzzFailureKind::NoResult => "no_result",
+        }
+    }
+}
+
+/// A failed fuzz case: what went wrong and a human-readable detail message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzFailure {
+    pub kind: FuzzFailureKind,
+    pub message: String,
+}
+
+/// Generate the fuzz case for `seed`. Pure function of the seed — the same
+/// seed always yields the same config, and every generated config passes
+/// `HeadlessMatchConfig::validate`.
+pub fn generate_case(seed: u64) -> HeadlessMatchConfig {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let classes = CharacterClass::all();
+
+    let team1_size = rng.random_range(1..=MAX_TEAM_SIZE);
+    let team2_size = rng.random_range(1..=MAX_TEAM_SIZE);
+    let pick_team = |size: usize, rng: &mut StdRng| -> Vec<String> {
+        (0..size)
+            .map(|_| classes[rng.random_range(0..classes.len())].name().to_string())
+            .collect()
+    };
+    let team1 = pick_team(team1_size, &mut rng);
+    let team2 = pick_team(team2_size, &mut rng);
+
+    let map = pick(&mut rng, &["BasicArena", "PillaredArena"]).to_string();
+    let team1_kill_target = maybe_index(&mut rng, team2_size);
+    let team2_kill_target = maybe_index(&mut rng, team1_size);
+    let team1_cc_target = maybe_index(&mut rng, team2_size);
+    let team2_cc_target = maybe_index(&mut rng, team1_size);
+
+    // Strategy overrides are filled for every slot regardless of class; the
+    // runner ignores preferences that do not apply to a slot's class.
+    let per_slot = |size: usize, options: &[&str], rng: &mut StdRng| -> Vec<String> {
+        (0..size).map(|_| pick(rng, options).to_string()).collect()
+    };
+    let team1_rogue_openers = per_slot(team1_size, &["Ambush", "CheapShot"], &mut rng);
+    let team2_rogue_openers = per_slot(team2_size, &["Ambush", "CheapShot"], &mut rng);
+    let team1_hunter_pet_types = per_slot(team1_size, &["Spider", "Boar", "Bird"], &mut rng);
+    let team2_hunter_pet_types = per_slot(team2_size, &["Spider", "Boar", "Bird"], &mut rng);
+    let team1_warrior_shouts = per_slot(team1_size, &["Battle", "Demoralizing", "Commanding"], &mut rng);
+    let team2_warrior_shouts = per_slot(team2_size, &["Battle", "Demoralizing", "Commanding"], &mut rng);
+    let team1_mage_armors = per_slot(team1_size, &["Frost", "Mage", "Molten"], &mut rng);
+    let team2_mage_armors = per_slot(team2_size, &["Frost", "Mage", "Molten"], &mut rng);
+    let team1_paladin_auras = per_slot(team1_size, &["Devotion", "Shadow", "Concentration"], &mut rng);
+    let team2_paladin_auras = per_slot(team2_size, &["Devotion", "Shadow", "Concentration"], &mut rng);
+    let team1_warlock_curse_prefs = curse_prefs(&mut rng, team1_size, team2_size);
+    let team2_warlock_curse_prefs = curse_prefs(&mut rng, team2_size, team1_size);
+
+    let cc_count = rng.random_range(0..=MAX_SCRIPTED_CC);
+    let scripted_cc = (0..cc_count)
+        .map(|_| {
+            let team = rng.random_range(1..=2u8);
+            let team_size = if team == 1 { team1_size } else { team2_size };
+            ScriptedCc {
+                at_secs: rng.random_range(0.0..40.0f32),
+                team,
+                slot: rng.random_range(0..team_size),
+                aura: *pick(&mut rng, &ScriptedCc::ALLOWED_AURAS),
+                duration: rng.random_range(0.5..8.0f32),
+            }
+        })
+        .collect();
+    let personalities = ["Balanced", "Aggressive", "Cautious", "KillHungry", "PeelFocused"];
+    let team1_personalities = per_slot(team1_size, &personalities, &mut rng);
+    let team2_personalities = per_slot(team2_size, &personalities, &mut rng);
+
+    HeadlessMatchConfig {
+        team1,
+        team2,
+        map,
+        team1_kill_target,
+        team2_kill_target,
+        team1_cc_target,
+        team2_cc_target,
+        max_duration_secs: FUZZ_MAX_DURATION_SECS,
+        random_seed: Some(seed),
+        label: Some(format!("fuzz_{}", seed)),
+        team1_rogue_openers,
+        team2_rogue_openers,
+        team1_warlock_curse_prefs,
+        team2_warlock_curse_prefs,
+        team1_hunter_pet_types,
+        team2_hunter_pet_types,
+        team1_warrior_shouts,
+        team2_warrior_shouts,
+        team1_mage_armors,
+        team2_mage_armors,
+        team1_paladin_auras,
+        team2_paladin_auras,
+        team1_personalities,
+        team2_personalities,
+        scripted_cc,
+        ..Default::default()
+    }
+}
+
+fn pick<'a, T>(rng: &mut StdRng, options: &'a [T]) -> &'a T {
+    &options[rng.random_range(0..options.len())]
+}
+
+fn maybe_index(rng: &mut StdRng, len: usize) -> Option<usize> {
+    if rng.random_bool(0.5) {
+        Some(rng.random_range(0..len))
+    } else {
+        None
+    }
+}
+
+fn curse_prefs(rng: &mut StdRng, team_size: usize, enemy_size: usize) -> Vec<Option<Vec<String>>> {
+    (0..team_size)
+        .map(|_| {
+            rng.random_bool(0.5).then(|| {
+                (0..enemy_size)
+                    .map(|_| pick(rng, &["Agony", "Weakness", "Tongues"]).to_string())
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+/// Run one case and report how it failed, or `None` if it completed cleanly.
+///
+/// The match runs with its log suppressed and inside `catch_unwind`, so a
+/// panicking case is reported rather than aborting the whole fuzz run.
+pub fn check_case(config: &HeadlessMatchConfig) -> Option<FuzzFailure> {
+    let mut violation: Option<String> = None;
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_headless_match_observed(config.clone(), true, None, |frame| {
+            if violation.is_none() {
+                violation = check_invariants(frame).err();
+            }
+        })
+    }));
+
+    match outcome {
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "non-string panic payload".to_string());
+            Some(FuzzFailure { kind: FuzzFailureKind::Panic, message })
+        }
+        Ok(_) if violation.is_some() => Some(FuzzFailure {
+            kind: FuzzFailureKind::Invariant,
+            message: violation.unwrap_or_default(),
+        }),
+        Ok(Err(e)) => Some(FuzzFailure { kind: FuzzFailureKind::NoResult, message: e }),
+        Ok(Ok(_)) => None,
+    }
+}
+
+/// Per-frame invariants every combatant (pets included) must satisfy.
+fn check_invariants(frame: &FrameObservation) -> Result<(), String> {
+    for (entity, c) in &frame.combatants {
+        let who = format!(
+            "t={:.2}s Team {} {}{} slot {} ({:?})",
+            frame.sim_time,
+            c.team,
+            c.class.name(),
+            if c.is_pet { " pet" } else { "" },
+            c.slot,
+            entity
+        );
+        if !c.max_health.is_finite() || c.max_health <= 0.0 {
+            return Err(format!("{}: max_health {} is not positive", who, c.max_health));
+        }
+        if !c.current_health.is_finite() || c.current_health < 0.0 {
+            return Err(format!("{}: current_health {} is negative or non-finite", who, c.current_health));
+        }
+        if c.current_health > c.max_health + HEALTH_EPSILON {
+            return Err(format!(
+                "{}: current_health {} exceeds max_health {}",
+                who, c.current_health, c.max_health
+            ));
+        }
+        if !c.position.is_finite() {
+            return Err(format!("{}: position {:?} is non-finite", who, c.position));
+        }
+        let mut stacks: BTreeMap<(String, &str), usize> = BTreeMap::new();
+        for (aura_type, (ability, _)) in c.aura_types.iter().zip(&c.aura_sources) {
+            if aura_type.refuses_stacking() {
+                *stacks.entry((format!("{:?}", aura_type), ability.as_str())).or_default() += 1;
+            }
+        }
+        if let Some(((aura_type, ability), count)) = stacks.into_iter().find(|(_, count)| *count > MAX_AURA_STACKS) {
+            return Err(format!(
+                "{}: {} stacks of {} ({}) (cap {})",
+                who, count, ability, aura_type, MAX_AURA_STACKS
+            ));
+        }
+    }
+    for pending in &frame.pending_references {
+        if !frame.combatants.contains_key(&pending.entity) {
+            return Err(format!(
+                "t={:.2}s: {} {} {:?} is not a combatant (despawned)",
+                frame.sim_time, pending.kind, pending.role, pending.entity
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Greedily shrink a failing case while it keeps failing with the same
+/// `FuzzFailureKind`. Tries, in order: resetting the map, clearing strategy
+/// overrides and target priorities, dropping each scripted CC entry, and
+/// dropping each team member. Bounded by `MAX_MINIMIZE_RUNS` match runs.
+pub fn minimize(config: &HeadlessMatchConfig, failure: &FuzzFailure) -> HeadlessMatchConfig {
+    let mut best = config.clone();
+    let mut runs = 0;
+
+    loop {
+        let mut improved = false;
+        for candidate in reductions(&best) {
+            if runs >= MAX_MINIMIZE_RUNS {
+                return best;
+            }
+            if candidate.validate().is_err() {
+                continue;
+            }
+            runs += 1;
+            if check_case(&candidate).is_some_and(|f| f.kind == failure.kind) {
+                best = candidate;
+                improved = true;
+                break;
+            }
+        }
+        if !improved {
+            return best;
+        }
+    }
+}
+
+/// One-step reductions of `config`, simplest first. Candidates identical to
+/// `config` are omitted so minimization always makes progress.
+fn reductions(config: &HeadlessMatchConfig) -> Vec<HeadlessMatchConfig> {
+    let mut out = Vec::new();
+
+    if config.map != "BasicArena" {
+        out.push(HeadlessMatchConfig { map: "BasicArena".to_string(), ..config.clone() });
+    }
+
+    let cleared = HeadlessMatchConfig {
+        team1: config.team1.clone(),
+        team2: config.team2.clone(),
+        map: config.map.clone(),
+        max_duration_secs: config.max_duration_secs,
+        random_seed: config.random_seed,
+        label: config.label.clone(),
+        scripted_cc: config.scripted_cc.clone(),
+        ..Default::default()
+    };
+    if serde_json::to_string(&cleared).ok() != serde_json::to_string(config).ok() {
+        out.push(cleared);
+    }
+
+    for i in 0..config.scripted_cc.len() {
+        let mut candidate = config.clone();
+        candidate.scripted_cc.remove(i);
+        out.push(candidate);
+    }
+
+    for team in [1u8, 2] {
+        let size = if team == 1 { config.team1.len() } else { config.team2.len() };
+        if size > 1 {
+            for slot in 0..size {
+                out.push(without_member(config, team, slot));
+            }
+        }
+    }
+
+    out
+}
+
+/// `config` with `team`'s member at `slot` removed. Per-slot preferences,
+/// enemy target indices, enemy curse preferences and scripted CC are all
+/// re-indexed so the result stays valid.
+fn without_member(config: &HeadlessMatchConfig, team: u8, slot: usize) -> HeadlessMatchConfig {
+    fn remove_at<T>(v: &mut Vec<T>, slot: usize) {
+        if slot < v.len() {
+            v.remove(slot);
+        }
+    }
+    fn reindex(target: Option<usize>, slot: usize) -> Option<usize> {
+        match target {
+            Some(t) if t == slot => None,
+            Some(t) if t > slot => Some(t - 1),
+            other => other,
+        }
+    }
+
+    let mut c = config.clone();
+    if team == 1 {
+        remove_at(&mut c.team1, slot);
+        remove_at(&mut c.team1_rogue_openers, slot);
+        remove_at(&mut c.team1_rogue_poisons, slot);
+        remove_at(&mut c.team1_warlock_curse_prefs, slot);
+        remove_at(&mut c.team1_hunter_pet_types, slot);
+        remove_at(&mut c.team1_equipment, slot);
+        remove_at(&mut c.team1_warrior_shouts, slot);
+        remove_at(&mut c.team1_mage_armors, slot);
+        remove_at(&mut c.team1_paladin_auras, slot);
+        remove_at(&mut c.team1_personalities, slot);
+        c.team2_kill_target = reindex(c.team2_kill_target, slot);
+        c.team2_cc_target = reindex(c.team2_cc_target, slot);
+        for prefs in c.team2_warlock_curse_prefs.iter_mut().flatten() {
+            remove_at(prefs, slot);
+        }
+    } else {
+        remove_at(&mut c.team2, slot);
+        remove_at(&mut c.team2_rogue_openers, slot);
+        remove_at(&mut c.team2_rogue_poisons, slot);
+        remove_at(&mut c.team2_warlock_curse_prefs, slot);
+        remove_at(&mut c.team2_hunter_pet_types, slot);
+        remove_at(&mut c.team2_equipment, slot);
+        remove_at(&mut c.team2_warrior_shouts, slot);
+        remove_at(&mut c.team2_mage_armors, slot);
+        remove_at(&mut c.team2_paladin_auras, slot);
+        remove_at(&mut c.team2_personalities, slot);
+        c.team1_kill_target = reindex(c.team1_kill_target, slot);
+        c.team1_cc_target = reindex(c.team1_cc_target, slot);
+        for prefs in c.team1_warlock_curse_prefs.iter_mut().flatten() {
+            remove_at(prefs, slot);
+        }
+    }
+
+    c.scripted_cc.retain(|cc| cc.team != team || cc.slot != slot);
+    for cc in c.scripted_cc.iter_mut().filter(|cc| cc.team == team && cc.slot > slot) {
+        cc.slot -= 1;
+    }
+    c
+}
+
+/// Run `iterations` fuzz cases with seeds `seed_base..seed_base + iterations`.
+///
+/// Failing cases are minimized and written to
+/// `match_logs/fuzz/<timestamp>/seed_<seed>.json` (plus the unminimized
+/// `seed_<seed>_original.json` and a `summary.txt`). Returns `Err` if any case
+/// failed, so CI invocations exit non-zero.
+pub fn run_fuzz(iterations: u32, seed_base: u64) -> Result<(), ModeError> {
+    if iterations == 0 {
+        return Err(ModeError::Config("--fuzz N requires N >= 1".to_string()));
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let out_dir = format!("match_logs/fuzz/{}", timestamp);
+
+    println!("Fuzzing {} cases (seed_base={})", iterations, seed_base);
+
+    let started = Instant::now();
+    let metadata = MatchMetadata::capture(
+        fingerprint(format!("fuzz iterations={} seed_base={}", iterations, seed_base).as_bytes()),
+        Some(seed_base),
+    );
+    let mut summary: Vec<String> = Vec::new();
+
+    for i in 0..iterations {
+        let seed = seed_base.wrapping_add(i as u64);
+        let config = generate_case(seed);
+        config
+            .validate()
+            .map_err(|e| format!("fuzz generator produced an invalid case for seed {}: {}", seed, e))?;
+
+        let Some(failure) = check_case(&config) else {
+            continue;
+        };
+        println!("  seed {}: {} — {}", seed, failure.kind.as_str(), failure.message);
+
+        let minimized = minimize(&config, &failure);
+        fs::create_dir_all(&out_dir).map_err(|e| format!("create {}: {}", out_dir, e))?;
+        write_config(&format!("{}/seed_{}_original.json", out_dir, seed), &config)?;
+        let path = format!("{}/seed_{}.json", out_dir, seed);
+        write_config(&path, &minimized)?;
+        println!("    minimized to {:?} vs {:?} ({} scripted CC) -> {}",
+            minimized.team1, minimized.team2, minimized.scripted_cc.len(), path);
+
+        summary.push(format!("{},{},{}", seed, failure.kind.as_str(), failure.message.replace('\n', " ")));
+    }
+
+    let elapsed = started.elapsed().as_secs_f32();
+    println!("Fuzz complete in {:.1}s: {} of {} cases failed", elapsed, summary.len(), iterations);
+
+    if summary.is_empty() {
+        return Ok(());
+    }
+
+    let summary_path = format!("{}/summary.txt", out_dir);
+    let mut header = Vec::new();
+    metadata
+        .write_header(&mut header, "# ")
+        .map_err(|e| format!("write {}: {}", summary_path, e))?;
+    let body = format!(
+        "{}# Fuzz run: iterations={} seed_base={}\nseed,kind,message\n{}\n",
+        String::from_utf8_lossy(&header),
+        iterations,
+        seed_base,
+        summary.join("\n")
+    );
+    fs::write(&summary_path, body).map_err(|e| format!("write {}: {}", summary_path, e))?;
+    println!("Wrote {}", summary_path);
+
+    Err(ModeError::Run(format!("{} of {} fuzz cases failed (see {})", summary.len(), iterations, out_dir)))
+}
+
+/// Write a replayable config, stamped with this build's `SIM_VERSION`.
+fn write_config(path: &str, config: &HeadlessMatchConfig) -> Result<(), String> {
+    let config = HeadlessMatchConfig { sim_version: Some(SIM_VERSION), ..config.clone() };
+    let json = serde_json::to_string_pretty(&config).map_err(|e| format!("serialize {}: {}", path, e))?;
+    fs::write(path, json).map_err(|e| format!("write {}: {}", path, e))
+}
@@ -11,14 +11,16 @@
 //! the seeded `GameRng` and `BTreeMap`-backed `CombatSnapshot`, replays are
 //! bit-for-bit reproducible.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::Write;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+use crate::combat::metadata::{fingerprint, MatchMetadata};
 use crate::states::match_config::CharacterClass;
 
 use super::config::HeadlessMatchConfig;
+use super::error::ModeError;
 use super::runner::{run_headless_match_with, TraceConfig};
 use crate::cli::TraceMode;
 
@@ -66,9 +68,9 @@ impl CellStats {
 /// trace filenames. If any per-match `TraceWriter::create` (or final flush)
 /// fails, the failure is appended to `match_logs/traces/<timestamp>/_failures.log`
 /// so the user has a single aggregated record alongside the CSV/MD report.
-pub fn run_matrix(n: u32, seed_base: u64, save_logs: bool, trace_mode: TraceMode) -> Result<(), String> {
+pub fn run_matrix(n: u32, seed_base: u64, save_logs: bool, trace_mode: TraceMode) -> Result<(), ModeError> {
     if n == 0 {
-        return Err("--matrix N requires N >= 1".to_string());
+        return Err(ModeError::Config("--matrix N requires N >= 1".to_string()));
     }
 
     let classes = CharacterClass::all();
@@ -95,6 +97,7 @@ pub fn run_matrix(n: u32, seed_base: u64, save_logs: bool, trace_mode: TraceMode
 
     let started = Instant::now();
     let mut stats: HashMap<(CharacterClass, CharacterClass), CellStats> = HashMap::new();
+    let mut killing_blows: BTreeMap<String, u32> = BTreeMap::new();
     let mut global_idx: u64 = 0;
 
     for &c1 in classes {
@@ -114,7 +117,12 @@ pub fn run_matrix(n: u32, seed_base: u64, save_logs: bool, trace_mode: TraceMode
                 });
 
                 match run_headless_match_with(config, !save_logs, trace_config) {
-                    Ok(result) => cell.record(result.winner, result.match_time),
+                    Ok(result) => {
+                        cell.record(result.winner, result.match_time);
+                        for (ability, count) in result.killing_blows {
+                            *killing_blows.entry(ability).or_insert(0) += count;
+                        }
+                    }
                     Err(e) => {
                         eprintln!("  Match {} vs {} run {} failed: {}", c1.name(), c2.name(), run, e);
                     }
@@ -162,13 +170,18 @@ pub fn run_matrix(n: u32, seed_base: u64, save_logs: bool, trace_mode: TraceMode
 
     fs::create_dir_all("match_logs").map_err(|e| format!("create match_logs/: {}", e))?;
 
+    let metadata = MatchMetadata::capture(
+        fingerprint(format!("matrix n={} seed_base={}", n, seed_base).as_bytes()),
+        Some(seed_base),
+    );
+
     let csv_path = format!("match_logs/matrix_{}.csv", timestamp);
-    write_csv(&csv_path, classes, &stats, n, seed_base)
+    write_csv(&csv_path, &metadata, classes, &stats, n, seed_base)
         .map_err(|e| format!("write {}: {}", csv_path, e))?;
     println!("Wrote {}", csv_path);
 
     let md_path = format!("match_logs/matrix_{}.md", timestamp);
-    write_markdown(&md_path, classes, &stats, n, seed_base, elapsed)
+    write_markdown(&md_path, &metadata, classes, &stats, &killing_blows, n, seed_base, elapsed)
         .map_err(|e| format!("write {}: {}", md_path, e))?;
     println!("Wrote {}", md_path);
 
@@ -183,13 +196,16 @@ fn build_config(team1: CharacterClass, team2: CharacterClass, seed: u64) -> Head
         team1: vec![team1.name().to_string()],
         team2: vec![team2.name().to_string()],
         map: "BasicArena".to_string(),
+        mode: "Standard".to_string(),
         team1_kill_target: None,
         team2_kill_target: None,
         team1_cc_target: None,
         team2_cc_target: None,
         output_path: None,
         max_duration_secs: 300.0,
+        stalemate_secs: super::stalemate::DEFAULT_STALEMATE_SECS,
         random_seed: Some(seed),
+        sim_version: None,
         label: None,
         team1_rogue_openers: vec![],
         team2_rogue_openers: vec![],
@@ -207,17 +223,23 @@ fn build_config(team1: CharacterClass, team2: CharacterClass, seed: u64) -> Head
         team2_mage_armors: vec![],
         team1_paladin_auras: vec![],
         team2_paladin_auras: vec![],
+        team1_personalities: vec![],
+        team2_personalities: vec![],
+        scripted_cc: vec![],
+        sandbox: vec![],
     }
 }
 
 fn write_csv(
     path: &str,
+    metadata: &MatchMetadata,
     classes: &[CharacterClass],
     stats: &HashMap<(CharacterClass, CharacterClass), CellStats>,
     n: u32,
     seed_base: u64,
 ) -> std::io::Result<()> {
     let mut f = fs::File::create(path)?;
+    metadata.write_header(&mut f, "# ")?;
     writeln!(f, "# Matrix run: n={} seed_base={}", n, seed_base)?;
     writeln!(f, "team1,team2,runs,team1_wins,team2_wins,draws,team1_winrate,draw_rate,avg_duration_secs")?;
     for &c1 in classes {
@@ -233,10 +255,13 @@ fn write_csv(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_markdown(
     path: &str,
+    metadata: &MatchMetadata,
     classes: &[CharacterClass],
     stats: &HashMap<(CharacterClass, CharacterClass), CellStats>,
+    killing_blows: &BTreeMap<String, u32>,
     n: u32,
     seed_base: u64,
     elapsed_secs: f32,
@@ -245,6 +270,7 @@ fn write_markdown(
 
     writeln!(f, "# Matrix Run")?;
     writeln!(f)?;
+    metadata.write_header(&mut f, "- ")?;
     writeln!(f, "- **Runs per cell:** {}", n)?;
     writeln!(f, "- **Seed base:** {} (cell `(c1, c2)` run `i` uses seed `seed_base + (cell_idx × N + i)`)", seed_base)?;
     writeln!(f, "- **Total matches:** {}", classes.len().pow(2) as u32 * n)?;
@@ -320,6 +346,18 @@ fn write_markdown(
     writeln!(f, "- Draws: {} ({:.1}%)", totals_draw, pct(totals_draw, totals_runs))?;
     writeln!(f)?;
 
+    // Which abilities close out kills — execute abilities should show up here
+    // once a class has one, and a single dominant finisher is worth a look.
+    let total_kills: u32 = killing_blows.values().sum();
+    let mut by_count: Vec<(&String, &u32)> = killing_blows.iter().collect();
+    by_count.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    writeln!(f, "## Killing Blows by Ability")?;
+    writeln!(f)?;
+    for (ability, count) in by_count {
+        writeln!(f, "- {}: {} ({:.1}%)", ability, count, pct(*count, total_kills))?;
+    }
+    writeln!(f)?;
+
     // Mirror-matchup sanity: same class on both sides should converge to ~50%
     // (modulo team-1 spawn-side bias). Big asymmetries here usually mean a
     // determinism leak or a position-dependent bug.
@@ -8,6 +8,9 @@
 //! ```bash
 //! # Run a headless match
 //! cargo run --release -- --headless match_config.json
+//!
+//! # Also write the results JSON and a replay file (see `export`)
+//! cargo run --release -- --headless match_config.json --results-json result.json --replay replay.json
 //! ```
 //!
 //! ## JSON Configuration
@@ -22,11 +25,28 @@
 //! ```
 
 pub mod batch;
+pub mod calibrate;
 pub mod config;
+#[cfg(feature = "db-sink")]
+pub mod db_sink;
+pub mod error;
+pub mod export;
+pub mod fuzz;
 pub mod matrix;
+pub mod optimize;
+pub mod report;
 pub mod runner;
+pub mod share_code;
+pub mod stalemate;
+pub mod tune;
 
-pub use batch::run_batch;
+pub use batch::{load_batch_configs, run_batch};
+pub use calibrate::run_calibrate;
 pub use config::HeadlessMatchConfig;
+pub use error::ModeError;
+pub use fuzz::run_fuzz;
 pub use matrix::run_matrix;
-pub use runner::{run_headless_match, run_headless_match_observed, run_headless_match_with, CombatantResult, EndReason, FrameObservation, HeadlessMatchState, MatchResult, ObservedCombatant};
+pub use optimize::run_optimize;
+pub use share_code::{decode_share_code, encode_share_code, load_share_code};
+pub use tune::run_tune;
+pub use runner::{run_headless_match, run_headless_match_observed, run_headless_match_with, CombatantResult, EndReason, FrameObservation, HeadlessMatchState, MatchResult, ObservedCombatant, ObservedPending};
@@ -0,0 +1,354 @@
+//! Counter-composition search (`arenasim optimize`).
+//!
+//! Given a fixed enemy comp (`--against "Warrior,Paladin,Rogue"`) and a class
+//! pool, simulates candidate comps of the same size against it and ranks them
+//! by win rate. Two search strategies (see [`SearchMode`]):
+//!
+//! - **Exhaustive** — every multiset of pool classes. 8 classes at 3v3 is 120
+//!   comps, so `--runs 100` is 12,000 matches; fine on the batch fast path.
+//! - **Hill-climb** — a few seeded random starts, each repeatedly moving to
+//!   the best single-slot swap until no swap improves. Use it for big pools
+//!   and 4v4/5v5 where the exhaustive count explodes.
+//!
+//! Every candidate plays the same seeds (`seed_base + run`), so comps are
+//! compared on identical dice (common random numbers) and the whole search is
+//! reproducible from `--seed-base`. Odd runs swap sides so team-1 spawn bias
+//! cancels out. Draws count as non-wins. Win rates are reported with 95%
+//! Wilson score intervals — with 100 runs those are roughly ±10 points, so
+//! overlapping intervals at the top of the ranking are a coin flip.
+//!
+//! Matches run in parallel through the batch runner's worker pool; the full
+//! ranking is written to `match_logs/optimize_<timestamp>.csv`.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::cli::{OptimizeArgs, SearchMode};
+use crate::combat::metadata::{fingerprint, MatchMetadata};
+use crate::states::match_config::{CharacterClass, MAX_TEAM_SIZE};
+
+use super::batch::{default_jobs, pin_task_pools_single_threaded, run_parallel};
+use super::config::HeadlessMatchConfig;
+use super::error::ModeError;
+use super::report::{comp_label, default_csv_path, write_report_csv, CONFIDENCE_Z};
+use super::runner::PreloadedConfigs;
+
+/// Random starting comps for hill-climb mode.
+const HILL_CLIMB_RESTARTS: usize = 4;
+
+/// Simulated win/loss/draw record of one candidate comp against the target.
+#[derive(Debug, Default, Clone)]
+pub struct CompStats {
+    pub runs: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    sum_duration: f32,
+}
+
+impl CompStats {
+    pub fn win_rate(&self) -> f32 {
+        if self.runs == 0 { 0.0 } else { self.wins as f32 / self.runs as f32 }
+    }
+
+    /// 95% Wilson score interval on the win rate.
+    pub fn confidence_interval(&self) -> (f32, f32) {
+        wilson_interval(self.wins, self.runs, CONFIDENCE_Z)
+    }
+
+    fn avg_duration(&self) -> f32 {
+        if self.runs == 0 { 0.0 } else { self.sum_duration / self.runs as f32 }
+    }
+}
+
+/// Parse a comma-separated class list (`"Warrior, Paladin,Rogue"`); `"all"`
+/// expands to every class.
+pub fn parse_class_list(list: &str) -> Result<Vec<CharacterClass>, String> {
+    if list.trim().eq_ignore_ascii_case("all") {
+        return Ok(CharacterClass::all().to_vec());
+    }
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(HeadlessMatchConfig::parse_class)
+        .collect()
+}
+
+/// Every team of `size` drawn from `pool` with repeats allowed, each listed
+/// once in pool order (slot order doesn't change who wins often enough to be
+/// worth multiplying the search by `size!`).
+pub fn enumerate_comps(pool: &[CharacterClass], size: usize) -> Vec<Vec<CharacterClass>> {
+    fn extend(pool: &[CharacterClass], start: usize, size: usize, current: &mut Vec<CharacterClass>, out: &mut Vec<Vec<CharacterClass>>) {
+        if current.len() == size {
+            out.push(current.clone());
+            return;
+        }
+        for i in start..pool.len() {
+            current.push(pool[i]);
+            extend(pool, i, size, current, out);
+            current.pop();
+        }
+    }
+    let mut out = Vec::new();
+    if size > 0 {
+        extend(pool, 0, size, &mut Vec::with_capacity(size), &mut out);
+    }
+    out
+}
+
+/// All comps reachable from `comp` by replacing one slot with a different
+/// pool class, normalized to pool order and deduplicated.
+pub fn swap_neighbors(comp: &[CharacterClass], pool: &[CharacterClass]) -> Vec<Vec<CharacterClass>> {
+    let mut out: Vec<Vec<CharacterClass>> = Vec::new();
+    for slot in 0..comp.len() {
+        for &class in pool {
+            if class == comp[slot] {
+                continue;
+            }
+            let mut next = comp.to_vec();
+            next[slot] = class;
+            let next = normalize(next, pool);
+            if !out.contains(&next) {
+                out.push(next);
+            }
+        }
+    }
+    out
+}
+
+/// Wilson score interval for `wins` out of `runs` at z-score `z`, clamped to
+/// [0, 1]. Unlike the normal approximation it stays sensible at 0% and 100%.
+pub fn wilson_interval(wins: u32, runs: u32, z: f32) -> (f32, f32) {
+    if runs == 0 {
+        return (0.0, 1.0);
+    }
+    let n = runs as f32;
+    let p = wins as f32 / n;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denom;
+    let half = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+    ((center - half).max(0.0), (center + half).min(1.0))
+}
+
+/// Sort a comp into pool order so equal multisets compare equal.
+fn normalize(mut comp: Vec<CharacterClass>, pool: &[CharacterClass]) -> Vec<CharacterClass> {
+    comp.sort_by_key(|c| pool.iter().position(|p| p == c).unwrap_or(usize::MAX));
+    comp
+}
+
+/// Shared state for one optimize run: the target, match settings and the
+/// cache of every comp evaluated so far.
+struct Search {
+    against: Vec<CharacterClass>,
+    runs: u32,
+    seed_base: u64,
+    n_jobs: usize,
+    preloaded: Arc<PreloadedConfigs>,
+    evaluated: HashMap<Vec<CharacterClass>, CompStats>,
+    matches_run: usize,
+}
+
+impl Search {
+    /// Candidate on team 1 for even runs, team 2 for odd ones.
+    fn build_config(&self, comp: &[CharacterClass], run: u32) -> HeadlessMatchConfig {
+        let candidate: Vec<String> = comp.iter().map(|c| c.name().to_string()).collect();
+        let target: Vec<String> = self.against.iter().map(|c| c.name().to_string()).collect();
+        let (team1, team2) = if run.is_multiple_of(2) { (candidate, target) } else { (target, candidate) };
+        HeadlessMatchConfig {
+            team1,
+            team2,
+            random_seed: Some(self.seed_base.wrapping_add(run as u64)),
+            label: Some(comp_label(comp)),
+            ..Default::default()
+        }
+    }
+
+    /// Simulate every not-yet-evaluated comp in `comps` (in one parallel batch).
+    fn evaluate(&mut self, comps: &[Vec<CharacterClass>]) {
+        let pending: Vec<&Vec<CharacterClass>> = comps
+            .iter()
+            .filter(|c| !self.evaluated.contains_key(*c))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let configs: Vec<HeadlessMatchConfig> = pending
+            .iter()
+            .flat_map(|comp| (0..self.runs).map(move |run| (comp, run)))
+            .map(|(comp, run)| self.build_config(comp, run))
+            .collect();
+        let configs = Arc::new(configs);
+        let results = run_parallel(&configs, &self.preloaded, self.n_jobs, "optimize");
+        self.matches_run += results.len();
+
+        for (i, comp) in pending.into_iter().enumerate() {
+            let mut stats = CompStats::default();
+            for run in 0..self.runs {
+                // Errored matches (logged by the worker) are left out of the record.
+                let Some(result) = &results[i * self.runs as usize + run as usize] else { continue; };
+                let candidate_team = if run.is_multiple_of(2) { 1 } else { 2 };
+                stats.runs += 1;
+                stats.sum_duration += result.match_time;
+                match result.winner {
+                    Some(team) if team == candidate_team => stats.wins += 1,
+                    Some(_) => stats.losses += 1,
+                    None => stats.draws += 1,
+                }
+            }
+            self.evaluated.insert(comp.clone(), stats);
+        }
+    }
+
+    fn win_rate(&self, comp: &[CharacterClass]) -> f32 {
+        self.evaluated.get(comp).map(CompStats::win_rate).unwrap_or(0.0)
+    }
+
+    /// Steepest-ascent hill climb from `start`; every comp it touches lands in
+    /// the cache, so restarts that wander into known ground are cheap.
+    fn hill_climb(&mut self, start: Vec<CharacterClass>, pool: &[CharacterClass]) {
+        let mut current = start;
+        self.evaluate(std::slice::from_ref(&current));
+        loop {
+            let neighbors = swap_neighbors(&current, pool);
+            self.evaluate(&neighbors);
+            let Some(best) = neighbors
+                .into_iter()
+                .max_by(|a, b| self.win_rate(a).total_cmp(&self.win_rate(b)))
+            else {
+                return;
+            };
+            if self.win_rate(&best) <= self.win_rate(&current) {
+                return;
+            }
+            println!("  climb: {} ({:.0}%) -> {} ({:.0}%)",
+                comp_label(&current), self.win_rate(&current) * 100.0,
+                comp_label(&best), self.win_rate(&best) * 100.0);
+            current = best;
+        }
+    }
+
+    /// Every evaluated comp, best win rate first (ties: higher lower bound).
+    fn ranking(&self) -> Vec<(&Vec<CharacterClass>, &CompStats)> {
+        let mut ranked: Vec<_> = self.evaluated.iter().collect();
+        ranked.sort_by(|a, b| {
+            b.1.win_rate()
+                .total_cmp(&a.1.win_rate())
+                .then_with(|| b.1.confidence_interval().0.total_cmp(&a.1.confidence_interval().0))
+                .then_with(|| comp_label(a.0).cmp(&comp_label(b.0)))
+        });
+        ranked
+    }
+}
+
+/// Run the counter-comp search described by `opts`, print the top N and write
+/// the full ranking CSV.
+pub fn run_optimize(opts: OptimizeArgs) -> Result<(), ModeError> {
+    let against = parse_class_list(&opts.against).map_err(ModeError::Config)?;
+    if against.is_empty() || against.len() > MAX_TEAM_SIZE {
+        return Err(ModeError::Config(format!("--against must name 1-{} classes", MAX_TEAM_SIZE)));
+    }
+    let mut pool = normalize(parse_class_list(&opts.pool).map_err(ModeError::Config)?, CharacterClass::all());
+    pool.dedup();
+    if pool.is_empty() {
+        return Err(ModeError::Config("--pool must name at least one class".to_string()));
+    }
+    if opts.runs == 0 {
+        return Err(ModeError::Config("--runs N requires N >= 1".to_string()));
+    }
+
+    let preloaded = Arc::new(PreloadedConfigs::load().map_err(ModeError::Config)?);
+    pin_task_pools_single_threaded();
+
+    let mut search = Search {
+        against: against.clone(),
+        runs: opts.runs,
+        seed_base: opts.seed_base,
+        n_jobs: opts.jobs.unwrap_or_else(default_jobs).max(1),
+        preloaded,
+        evaluated: HashMap::new(),
+        matches_run: 0,
+    };
+
+    let started = Instant::now();
+    println!("Optimizing against {} (pool: {}, {:?}, {} runs per comp, seed_base={})",
+        comp_label(&against), comp_label(&pool), opts.search, opts.runs, opts.seed_base);
+
+    match opts.search {
+        SearchMode::Exhaustive => {
+            let comps = enumerate_comps(&pool, against.len());
+            println!("  {} candidate comps × {} runs = {} matches",
+                comps.len(), opts.runs, comps.len() * opts.runs as usize);
+            search.evaluate(&comps);
+        }
+        SearchMode::HillClimb => {
+            let mut rng = StdRng::seed_from_u64(opts.seed_base);
+            for restart in 0..HILL_CLIMB_RESTARTS {
+                let start: Vec<CharacterClass> = (0..against.len())
+                    .map(|_| pool[rng.random_range(0..pool.len())])
+                    .collect();
+                let start = normalize(start, &pool);
+                println!("  restart {}: from {}", restart + 1, comp_label(&start));
+                search.hill_climb(start, &pool);
+            }
+        }
+    }
+
+    let elapsed = started.elapsed().as_secs_f32();
+    println!("Evaluated {} comps ({} matches) in {:.1}s",
+        search.evaluated.len(), search.matches_run, elapsed);
+
+    let ranking = search.ranking();
+    println!();
+    println!("Top {} counters to {}:", opts.top.min(ranking.len()), comp_label(&against));
+    for (rank, (comp, stats)) in ranking.iter().take(opts.top).enumerate() {
+        let (low, high) = stats.confidence_interval();
+        println!("  {:>2}. {:<32} {:>5.1}%  [{:.1}% - {:.1}%]  W{} L{} D{}",
+            rank + 1, comp_label(comp.iter()), stats.win_rate() * 100.0,
+            low * 100.0, high * 100.0, stats.wins, stats.losses, stats.draws);
+    }
+
+    let out = opts.out.unwrap_or_else(|| default_csv_path("optimize"));
+    let metadata = MatchMetadata::capture(
+        fingerprint(format!(
+            "optimize against={} pool={} runs={} search={:?} seed_base={}",
+            comp_label(&against), comp_label(&pool), opts.runs, opts.search, opts.seed_base
+        ).as_bytes()),
+        Some(opts.seed_base),
+    );
+    write_ranking_csv(&out, &metadata, &against, &ranking)?;
+    println!();
+    println!("Wrote {}", out.display());
+    Ok(())
+}
+
+/// One row per evaluated comp, best first. The run's `MatchMetadata` comes
+/// first as `# ` comment lines.
+fn write_ranking_csv(
+    output: &Path,
+    metadata: &MatchMetadata,
+    against: &[CharacterClass],
+    ranking: &[(&Vec<CharacterClass>, &CompStats)],
+) -> Result<(), String> {
+    let rows = ranking.iter().enumerate().map(|(rank, (comp, stats))| {
+        let (low, high) = stats.confidence_interval();
+        format!(
+            "{},{},{},{},{},{},{:.4},{:.4},{:.4},{:.2}",
+            rank + 1, comp_label(comp.iter()), stats.runs, stats.wins, stats.losses, stats.draws,
+            stats.win_rate(), low, high, stats.avg_duration()
+        )
+    });
+    write_report_csv(
+        output,
+        metadata,
+        &format!("Against: {}", comp_label(against)),
+        "rank,comp,runs,wins,losses,draws,winrate,ci95_low,ci95_high,avg_duration_secs",
+        rows,
+    )
+}
@@ -0,0 +1,56 @@
+//! Shared pieces of the headless reports: comp labels, the confidence level
+//! of `optimize` and `tune`, and where and how their CSVs are written.
+
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::combat::metadata::MatchMetadata;
+use crate::states::match_config::CharacterClass;
+
+/// z-score for the reported 95% confidence intervals.
+pub const CONFIDENCE_Z: f32 = 1.96;
+
+/// "Warrior+Priest" for a comp.
+pub fn comp_label<'a>(comp: impl IntoIterator<Item = &'a CharacterClass>) -> String {
+    comp.into_iter().map(|c| c.name()).collect::<Vec<_>>().join("+")
+}
+
+/// `match_logs/<mode>_<timestamp>.csv`, the default `--out` of a run.
+pub fn default_csv_path(mode: &str) -> PathBuf {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("match_logs/{}_{}.csv", mode, ts).into()
+}
+
+/// Write a report CSV: the run's `MatchMetadata` and `# <title>` as comment
+/// lines, then the `columns` header and one line per row. Creates the
+/// parent directory if needed.
+pub fn write_report_csv(
+    output: &Path,
+    metadata: &MatchMetadata,
+    title: &str,
+    columns: &str,
+    rows: impl IntoIterator<Item = String>,
+) -> Result<(), String> {
+    if let Some(parent) = output.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("create {}: {}", parent.display(), e))?;
+        }
+    }
+    let file = std::fs::File::create(output)
+        .map_err(|e| format!("create {}: {}", output.display(), e))?;
+    let mut w = BufWriter::new(file);
+
+    metadata.write_header(&mut w, "# ").map_err(|e| e.to_string())?;
+    writeln!(w, "# {}", title).map_err(|e| e.to_string())?;
+    writeln!(w, "{}", columns).map_err(|e| e.to_string())?;
+    for row in rows {
+        writeln!(w, "{}", row).map_err(|e| e.to_string())?;
+    }
+    w.flush().map_err(|e| e.to_string())?;
+    Ok(())
+}
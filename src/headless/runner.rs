@@ -4,10 +4,12 @@
 
 use bevy::prelude::*;
 use bevy::time::TimeUpdateStrategy;
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::time::Duration;
 
-use crate::combat::log::{CombatLog, CombatLogEventType, CombatantMetadata, MatchMetadata};
+use crate::combat::log::{CombatLog, CombatLogEventType, CombatantMetadata, MatchReport};
+use crate::combat::MatchMetadata;
 use crate::states::match_config::MatchConfig;
 use crate::states::play_match::{AbilityConfigPlugin, MovementConfigPlugin};
 use crate::states::play_match::ability_config::{AbilityDefinitions, load_ability_definitions};
@@ -18,12 +20,17 @@ use crate::states::play_match::systems::{
     self, combatant_id, Combatant, FloatingTextState, GameRng, MatchCountdown, ShadowSightState,
     SimulationSpeed,
 };
-use crate::states::play_match::components::{ActiveAuras, AuraType, Pet, PetType, DRTracker, Totem, TotemElement};
+use crate::states::play_match::components::{ActiveAuras, Aura, AuraPending, AuraType, DispelPending, DivineShieldPending, HolyShockDamagePending, HolyShockHealPending, IceBlockPending, InterruptPending, VanishPending, Pet, PetType, DRTracker, ResourceType, SandboxDamagePending, SandboxHealPending, SoulShardPending, Totem, TotemElement};
+use crate::states::play_match::effects::backlash::BacklashPending;
 use crate::states::play_match::constants::PET_SLOT_BASE;
+use crate::states::play_match::utils::formation_z;
 use crate::states::play_match::decision_trace::{DecisionTrace, TraceWriter};
+use crate::states::play_match::highlights::{find_highlights, Highlight};
 use crate::states::match_config::CharacterClass;
 
-use super::config::HeadlessMatchConfig;
+use crate::states::play_match::sandbox::{SandboxEvent, SandboxQueue};
+use super::config::{HeadlessMatchConfig, ScheduledSandboxEvent, ScriptedCc};
+use super::stalemate::{StalemateWatch, TeamVitals};
 
 /// Configuration for the AI decision trace, resolved from `--trace-mode` and
 /// passed into `run_headless_match_with`. When `None`, no trace file is
@@ -60,6 +67,19 @@ pub struct ObservedCombatant {
     /// Lets probes assert a combatant carries (or does not carry) a totem buff
     /// without `&World` access. Empty when the entity has no `ActiveAuras`.
     pub aura_types: Vec<AuraType>,
+    /// Ability name and caster of each active aura, parallel to `aura_types`.
+    pub aura_sources: Vec<(String, Option<Entity>)>,
+}
+
+/// One entity reference held by a deferred effect (`*Pending` entity) still
+/// alive at the end of a frame; the next frame's Phase 1 resolves it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservedPending {
+    /// Component name, e.g. `"AuraPending"`
+    pub kind: &'static str,
+    /// Which reference this is: `"target"`, `"caster"` or `"dispeller"`
+    pub role: &'static str,
+    pub entity: Entity,
 }
 
 /// A read-only snapshot of one Shaman totem on a frame. Totems are NOT
@@ -96,12 +116,14 @@ pub struct FrameObservation {
     /// All live Shaman totems this frame, sorted by entity id (deterministic).
     /// Empty in matches without a Shaman.
     pub totems: Vec<ObservedTotem>,
+    /// Every entity referenced by a pending effect, in entity order.
+    pub pending_references: Vec<ObservedPending>,
 }
 
 /// Result of a completed headless match
 ///
 /// This struct provides programmatic access to match results for testing and analysis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MatchResult {
     /// The winning team (1 or 2), or None for a draw
     pub winner: Option<u8>,
@@ -115,17 +137,30 @@ pub struct MatchResult {
     pub team2_combatants: Vec<CombatantResult>,
     /// Random seed used (if deterministic mode)
     pub random_seed: Option<u64>,
+    /// Killing blows by the ability that landed them (see
+    /// `CombatLog::killing_blows_by_ability`)
+    pub killing_blows: BTreeMap<String, u32>,
+    /// Highlight windows found in the combat log (see `find_highlights`)
+    pub highlights: Vec<Highlight>,
 }
 
 /// Why a match ended. Lets the batch runner distinguish a decisive result
-/// (`Kill`) from a timeout draw (`CapDraw`) — both `Kill` and `CapDraw` carry a
-/// `winner` of `Some`/`None` respectively, but the reason aids analysis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// (`Kill`) from a timeout draw (`CapDraw`) or a detected stalemate
+/// (`Stalemate`) — `Kill` carries a `winner` of `Some`, the draws `None`, but
+/// the reason aids analysis.
+///
+/// Serializes as the same tokens as [`EndReason::as_str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum EndReason {
     /// A team was eliminated — a decisive result.
     Kill,
     /// The hard `max_duration_secs` cap was hit — draw.
+    #[serde(rename = "cap")]
     CapDraw,
+    /// Neither team made progress for `stalemate_secs` — draw, ended early
+    /// (see `headless::stalemate`).
+    Stalemate,
 }
 
 impl EndReason {
@@ -134,12 +169,13 @@ impl EndReason {
         match self {
             EndReason::Kill => "kill",
             EndReason::CapDraw => "cap",
+            EndReason::Stalemate => "stalemate",
         }
     }
 }
 
 /// Statistics for a single combatant after the match
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CombatantResult {
     /// Class name (e.g., "Warrior", "Mage")
     pub class_name: String,
@@ -168,11 +204,52 @@ pub struct HeadlessMatchState {
     pub match_complete: bool,
     /// Random seed for deterministic simulation (if provided)
     pub random_seed: Option<u64>,
+    /// Fingerprint of the `HeadlessMatchConfig` (see `HeadlessMatchConfig::fingerprint`)
+    pub config_hash: String,
     /// If true, the per-match `.txt` log file is NOT written. Set by the
     /// matrix runner where 4,900+ logs would just clutter `match_logs/`.
     pub suppress_log: bool,
     /// Match result (populated when match completes)
     pub result: Option<MatchResult>,
+    /// Whether the cap was reached while someone held a damage immunity and
+    /// the draw is being deferred (the `[OVERTIME]` line is logged once).
+    pub in_overtime: bool,
+    /// Seconds without progress before declaring a stalemate (0 = never)
+    pub stalemate_secs: f32,
+    /// Progress tracker behind the stalemate check
+    pub stalemate: StalemateWatch,
+}
+
+/// Longest the time-out draw may be deferred while a living combatant holds a
+/// damage immunity (Ice Block, Divine Shield). Covers the longest immunity
+/// plus a Cold Snap re-block; beyond it the draw is declared regardless.
+pub const IMMUNITY_OVERTIME_MAX_SECS: f32 = 25.0;
+
+/// Whether the hard time cap has been reached.
+///
+/// At `max_duration` the draw is deferred while anyone alive is immune, so a
+/// match can't be decided (or drawn) by a Mage simply sitting in Ice Block
+/// over the buzzer. Overtime is bounded by [`IMMUNITY_OVERTIME_MAX_SECS`], so
+/// a team that is entirely immune at time-out still ends in a draw.
+pub fn cap_reached(elapsed: f32, max_duration: f32, anyone_immune: bool) -> bool {
+    if elapsed < max_duration {
+        return false;
+    }
+    !anyone_immune || elapsed >= max_duration + IMMUNITY_OVERTIME_MAX_SECS
+}
+
+/// Scripted CC entries from the config that have not fired yet, sorted by
+/// `at_secs` so the apply system only ever looks at the front of the queue.
+#[derive(Resource, Default)]
+struct ScriptedCcQueue {
+    pending: std::collections::VecDeque<ScriptedCc>,
+}
+
+/// Scheduled sandbox events from the config that have not fired yet, sorted
+/// by `at_secs` like `ScriptedCcQueue`.
+#[derive(Resource, Default)]
+struct SandboxSchedule {
+    pending: std::collections::VecDeque<ScheduledSandboxEvent>,
 }
 
 /// Plugin for headless match execution
@@ -197,11 +274,23 @@ impl Plugin for HeadlessPlugin {
                 output_path: self.config.output_path.clone(),
                 match_complete: false,
                 random_seed: self.config.random_seed,
+                config_hash: self.config.fingerprint(),
                 suppress_log: self.suppress_log,
                 result: None,
+                in_overtime: false,
+                stalemate_secs: self.config.stalemate_secs,
+                stalemate: StalemateWatch::default(),
             })
             .init_resource::<CombatLog>();
 
+        let mut scripted_cc = self.config.scripted_cc.clone();
+        scripted_cc.sort_by(|a, b| a.at_secs.total_cmp(&b.at_secs));
+        app.insert_resource(ScriptedCcQueue { pending: scripted_cc.into() });
+
+        let mut sandbox = self.config.sandbox.clone();
+        sandbox.sort_by(|a, b| a.at_secs.total_cmp(&b.at_secs));
+        app.insert_resource(SandboxSchedule { pending: sandbox.into() });
+
         // Configure combat system phase ordering
         systems::configure_combat_system_ordering(app);
 
@@ -210,6 +299,11 @@ impl Plugin for HeadlessPlugin {
 
         // Add headless-specific systems after combat resolution.
         app.add_systems(Startup, headless_setup_match)
+            .add_systems(
+                Update,
+                (headless_apply_scripted_cc, headless_inject_sandbox_events)
+                    .before(systems::CombatSystemPhase::ResourcesAndAuras),
+            )
             .add_systems(
                 Update,
                 (headless_track_time, headless_check_match_end)
@@ -231,6 +325,10 @@ fn headless_setup_match(
 ) {
     // Clear and initialize combat log
     combat_log.clear();
+    combat_log.metadata = Some(MatchMetadata::capture(
+        headless_state.config_hash.clone(),
+        headless_state.random_seed,
+    ));
     combat_log.log(
         CombatLogEventType::MatchEvent,
         "Match started (headless mode)!".to_string(),
@@ -238,7 +336,10 @@ fn headless_setup_match(
 
     // Initialize required resources
     commands.insert_resource(SimulationSpeed { multiplier: 1.0 });
-    commands.insert_resource(MatchCountdown::default());
+    commands.insert_resource(MatchCountdown {
+        time_remaining: config.mode.countdown_secs(),
+        ..Default::default()
+    });
     commands.insert_resource(ShadowSightState::default());
 
     // Initialize GameRng with seed if provided (deterministic mode)
@@ -255,7 +356,7 @@ fn headless_setup_match(
     commands.insert_resource(game_rng);
 
     // Spawn combatants for Team 1
-    let team1_spawn_x = -35.0;
+    let team1_spawn_x = -config.map.spawn_x();
     for (i, character_opt) in config.team1.iter().enumerate() {
         if let Some(character) = character_opt {
             combat_log.register_combatant(combatant_id(1, *character));
@@ -265,14 +366,16 @@ fn headless_setup_match(
             let warrior_shout = config.team1_warrior_shouts.get(i).copied().unwrap_or_default();
             let mage_armor = config.team1_mage_armors.get(i).copied().unwrap_or_default();
             let paladin_aura = config.team1_paladin_auras.get(i).copied().unwrap_or_default();
+            let personality = config.team1_personalities.get(i).copied().unwrap_or_default();
             let equipment_overrides = config.team1_equipment.get(i).cloned().unwrap_or_default();
             let mut loadout = resolve_loadout(*character, &default_loadouts, &equipment_overrides);
             enforce_two_hand_conflicts(&mut loadout, &item_defs);
-            let position = Vec3::new(team1_spawn_x, 1.0, (i as f32 - 1.0) * 3.0);
+            let position = Vec3::new(team1_spawn_x, 1.0, formation_z(i, config.team1.len()));
             let mut combatant = Combatant::new_with_curse_prefs(1, i as u8, *character, rogue_opener, rogue_poison, warlock_curse_prefs);
             combatant.warrior_shout = warrior_shout;
             combatant.mage_armor = mage_armor;
             combatant.paladin_aura = paladin_aura;
+            combatant.personality = personality;
             combatant.apply_equipment(&loadout, &item_defs);
             let combatant_clone = combatant.clone();
             let weapon_poison_buff = combatant.weapon_poison_self_buff();
@@ -336,7 +439,7 @@ fn headless_setup_match(
     }
 
     // Spawn combatants for Team 2
-    let team2_spawn_x = 35.0;
+    let team2_spawn_x = config.map.spawn_x();
     for (i, character_opt) in config.team2.iter().enumerate() {
         if let Some(character) = character_opt {
             combat_log.register_combatant(combatant_id(2, *character));
@@ -346,14 +449,16 @@ fn headless_setup_match(
             let warrior_shout = config.team2_warrior_shouts.get(i).copied().unwrap_or_default();
             let mage_armor = config.team2_mage_armors.get(i).copied().unwrap_or_default();
             let paladin_aura = config.team2_paladin_auras.get(i).copied().unwrap_or_default();
+            let personality = config.team2_personalities.get(i).copied().unwrap_or_default();
             let equipment_overrides = config.team2_equipment.get(i).cloned().unwrap_or_default();
             let mut loadout = resolve_loadout(*character, &default_loadouts, &equipment_overrides);
             enforce_two_hand_conflicts(&mut loadout, &item_defs);
-            let position = Vec3::new(team2_spawn_x, 1.0, (i as f32 - 1.0) * 3.0);
+            let position = Vec3::new(team2_spawn_x, 1.0, formation_z(i, config.team2.len()));
             let mut combatant = Combatant::new_with_curse_prefs(2, i as u8, *character, rogue_opener, rogue_poison, warlock_curse_prefs);
             combatant.warrior_shout = warrior_shout;
             combatant.mage_armor = mage_armor;
             combatant.paladin_aura = paladin_aura;
+            combatant.personality = personality;
             combatant.apply_equipment(&loadout, &item_defs);
             let combatant_clone = combatant.clone();
             let weapon_poison_buff = combatant.weapon_poison_self_buff();
@@ -440,12 +545,105 @@ fn headless_track_time(
     }
 }
 
+/// Fire due scripted CC entries by spawning an `AuraPending` on the target.
+///
+/// Runs before `ResourcesAndAuras` so `apply_pending_auras` picks the aura up
+/// on the same frame, with the same DR / immunity / charge rules as AI casts.
+/// Entries whose slot has no living combatant are logged and dropped.
+fn headless_apply_scripted_cc(
+    mut commands: Commands,
+    mut queue: ResMut<ScriptedCcQueue>,
+    headless_state: Res<HeadlessMatchState>,
+    countdown: Res<MatchCountdown>,
+    combatants: Query<(Entity, &Combatant), Without<Pet>>,
+    mut combat_log: ResMut<CombatLog>,
+) {
+    if !countdown.gates_opened {
+        return;
+    }
+
+    while queue
+        .pending
+        .front()
+        .is_some_and(|cc| cc.at_secs <= headless_state.elapsed_time)
+    {
+        let Some(cc) = queue.pending.pop_front() else {
+            break;
+        };
+        let target = combatants
+            .iter()
+            .find(|(_, c)| c.team == cc.team && c.slot as usize == cc.slot);
+        let Some((entity, combatant)) = target else {
+            combat_log.log(
+                CombatLogEventType::MatchEvent,
+                format!("[SCRIPTED] {:?} skipped: no combatant in team {} slot {}", cc.aura, cc.team, cc.slot),
+            );
+            continue;
+        };
+
+        combat_log.log(
+            CombatLogEventType::MatchEvent,
+            format!(
+                "[SCRIPTED] {:?} ({:.1}s) on {}",
+                cc.aura,
+                cc.duration,
+                combatant_id(combatant.team, combatant.class)
+            ),
+        );
+        // Polymorph and Incapacitate break on any damage, like their real
+        // counterparts; everything else holds for the full duration.
+        let break_on_damage_threshold = match cc.aura {
+            AuraType::Polymorph | AuraType::Incapacitate => 0.0,
+            _ => -1.0,
+        };
+        commands.spawn(AuraPending {
+            target: entity,
+            aura: Aura {
+                effect_type: cc.aura,
+                duration: cc.duration,
+                magnitude: 1.0,
+                break_on_damage_threshold,
+                ability_name: "Scripted CC".to_string(),
+                ..Default::default()
+            },
+        });
+    }
+}
+
+/// Move due scheduled sandbox events into the `SandboxQueue`, where
+/// `dispatch_sandbox_events` picks them up in the same frame's Phase 1.
+fn headless_inject_sandbox_events(
+    mut schedule: ResMut<SandboxSchedule>,
+    mut queue: ResMut<SandboxQueue>,
+    headless_state: Res<HeadlessMatchState>,
+    countdown: Res<MatchCountdown>,
+) {
+    if !countdown.gates_opened {
+        return;
+    }
+
+    while schedule
+        .pending
+        .front()
+        .is_some_and(|s| s.at_secs <= headless_state.elapsed_time)
+    {
+        let Some(scheduled) = schedule.pending.pop_front() else {
+            break;
+        };
+        // Commands were validated when the config was loaded
+        if let Ok(event) = SandboxEvent::parse(&scheduled.command) {
+            queue.inject(event);
+        }
+    }
+}
+
 /// Check if the match has ended (one or both teams eliminated, or timeout)
 fn headless_check_match_end(
     combatants: Query<(Entity, &Combatant, &Transform), Without<Pet>>,
     pets: Query<(&Combatant, &Pet)>,
+    auras: Query<&ActiveAuras>,
     config: Res<MatchConfig>,
-    combat_log: Res<CombatLog>,
+    mut combat_log: ResMut<CombatLog>,
     mut headless_state: ResMut<HeadlessMatchState>,
     countdown: Res<MatchCountdown>,
 ) {
@@ -453,13 +651,27 @@ fn headless_check_match_end(
         return;
     }
 
-    // Check for timeout (hard cap) first.
-    if headless_state.elapsed_time >= headless_state.max_duration {
+    // Check for timeout (hard cap) first, deferring it while anyone is immune.
+    let anyone_immune = headless_state.elapsed_time >= headless_state.max_duration
+        && combatants.iter().any(|(entity, c, _)| {
+            c.is_alive()
+                && auras.get(entity).is_ok_and(|a| {
+                    a.auras.iter().any(|aura| aura.effect_type == AuraType::DamageImmunity)
+                })
+        });
+    if anyone_immune && !headless_state.in_overtime {
+        headless_state.in_overtime = true;
+        combat_log.log(
+            CombatLogEventType::MatchEvent,
+            "[OVERTIME] Time limit reached during a damage immunity - waiting for it to end".to_string(),
+        );
+    }
+    if cap_reached(headless_state.elapsed_time, headless_state.max_duration, anyone_immune) {
         info!(
             "Match timed out after {:.1}s - declaring DRAW",
             headless_state.elapsed_time
         );
-        let result = build_match_result(&combatants, &pets, None, EndReason::CapDraw, &headless_state);
+        let result = build_match_result(&combatants, &pets, &combat_log, None, EndReason::CapDraw, &headless_state);
         if !headless_state.suppress_log {
             save_headless_match_log(&combatants, &pets, &config, &combat_log, None, &headless_state);
         }
@@ -484,12 +696,59 @@ fn headless_check_match_end(
             Some(2)
         };
 
-        let result = build_match_result(&combatants, &pets, winner, EndReason::Kill, &headless_state);
+        let result = build_match_result(&combatants, &pets, &combat_log, winner, EndReason::Kill, &headless_state);
         if !headless_state.suppress_log {
             save_headless_match_log(&combatants, &pets, &config, &combat_log, winner, &headless_state);
         }
         headless_state.result = Some(result);
         headless_state.match_complete = true;
+        return;
+    }
+
+    // Stalemate: neither team any closer to losing for `stalemate_secs`
+    let vitals = [1, 2].map(|team| team_vitals(combatants.iter().map(|(_, c, _)| c).filter(|c| c.team == team)));
+    let total_damage: f32 = combatants.iter().map(|(_, c, _)| c.damage_taken).sum::<f32>()
+        + pets.iter().map(|(c, _)| c.damage_taken).sum::<f32>();
+    let elapsed = headless_state.elapsed_time;
+    let window = headless_state.stalemate_secs;
+    if let Some(cause) = headless_state.stalemate.observe(elapsed, vitals, total_damage, window) {
+        let summary = format!(
+            "[STALEMATE] No progress for {:.0}s ({}; {:.0} damage taken in that time) - declaring DRAW. \
+             Team 1: {:.0}% health, {:.0}% mana. Team 2: {:.0}% health, {:.0}% mana",
+            headless_state.stalemate.stalled_for(elapsed),
+            cause.describe(),
+            headless_state.stalemate.damage_since_progress(total_damage),
+            vitals[0].health * 100.0,
+            vitals[0].mana * 100.0,
+            vitals[1].health * 100.0,
+            vitals[1].mana * 100.0,
+        );
+        info!("{}", summary);
+        combat_log.log(CombatLogEventType::MatchEvent, summary);
+        let result = build_match_result(&combatants, &pets, &combat_log, None, EndReason::Stalemate, &headless_state);
+        if !headless_state.suppress_log {
+            save_headless_match_log(&combatants, &pets, &config, &combat_log, None, &headless_state);
+        }
+        headless_state.result = Some(result);
+        headless_state.match_complete = true;
+    }
+}
+
+/// Health and mana of one team's primaries as fractions of the team totals.
+/// The dead count at 0 health; teams without a mana user report full mana.
+fn team_vitals<'a>(team: impl Iterator<Item = &'a Combatant>) -> TeamVitals {
+    let (mut health, mut max_health, mut mana, mut max_mana) = (0.0, 0.0, 0.0, 0.0);
+    for c in team {
+        health += c.current_health.max(0.0);
+        max_health += c.max_health;
+        if c.resource_type == ResourceType::Mana {
+            mana += c.current_mana;
+            max_mana += c.max_mana;
+        }
+    }
+    TeamVitals {
+        health: if max_health > 0.0 { health / max_health } else { 0.0 },
+        mana: if max_mana > 0.0 { mana / max_mana } else { 1.0 },
     }
 }
 
@@ -497,6 +756,7 @@ fn headless_check_match_end(
 fn build_match_result(
     combatants: &Query<(Entity, &Combatant, &Transform), Without<Pet>>,
     pets: &Query<(&Combatant, &Pet)>,
+    combat_log: &CombatLog,
     winner: Option<u8>,
     end_reason: EndReason,
     headless_state: &HeadlessMatchState,
@@ -531,6 +791,8 @@ fn build_match_result(
         team1_combatants,
         team2_combatants,
         random_seed: headless_state.random_seed,
+        killing_blows: combat_log.killing_blows_by_ability(),
+        highlights: find_highlights(combat_log),
     }
 }
 
@@ -539,7 +801,7 @@ fn save_headless_match_log(
     combatants: &Query<(Entity, &Combatant, &Transform), Without<Pet>>,
     pets: &Query<(&Combatant, &Pet)>,
     config: &Res<MatchConfig>,
-    combat_log: &Res<CombatLog>,
+    combat_log: &CombatLog,
     winner: Option<u8>,
     headless_state: &HeadlessMatchState,
 ) {
@@ -575,19 +837,23 @@ fn save_headless_match_log(
         }
     }
 
-    // Build match metadata
-    let match_metadata = MatchMetadata {
+    // Build match report
+    let report = MatchReport {
         arena_name: config.map.name().to_string(),
         winner,
-        random_seed: headless_state.random_seed,
         team1: team1_metadata,
         team2: team2_metadata,
     };
 
     // Save to file (use custom output path if provided)
-    match combat_log.save_to_file(&match_metadata, headless_state.output_path.as_deref()) {
+    match combat_log.save_to_file(&report, headless_state.output_path.as_deref()) {
         Ok(filename) => {
             println!("Match complete. Log saved to: {}", filename);
+            let highlights_path = std::path::Path::new(&filename).with_extension("highlights.json");
+            match save_highlights(&highlights_path, combat_log, winner, headless_state.elapsed_time) {
+                Ok(()) => println!("Highlights saved to: {}", highlights_path.display()),
+                Err(e) => eprintln!("Failed to save highlights: {}", e),
+            }
         }
         Err(e) => {
             eprintln!("Failed to save combat log: {}", e);
@@ -595,6 +861,35 @@ fn save_headless_match_log(
     }
 }
 
+/// Contents of `<log>.highlights.json`, metadata first
+#[derive(serde::Serialize)]
+struct HighlightsExport<'a> {
+    metadata: Option<&'a MatchMetadata>,
+    winner: Option<u8>,
+    match_time: f32,
+    highlights: Vec<Highlight>,
+}
+
+/// Write the match's highlight windows as JSON. Highlight times are combat
+/// log timestamps, the same clock as the `.txt` log:
+/// `{"metadata": {...}, "winner": 1, "match_time": 42.5, "highlights": [{"kind": "kill", "time": ..., "start": ..., "end": ..., "description": ...}]}`
+fn save_highlights(
+    path: &std::path::Path,
+    combat_log: &CombatLog,
+    winner: Option<u8>,
+    match_time: f32,
+) -> Result<(), String> {
+    let export = HighlightsExport {
+        metadata: combat_log.metadata.as_ref(),
+        winner,
+        match_time,
+        highlights: find_highlights(combat_log),
+    };
+    let contents = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
 /// Match-completion sentinel system (no-op under manual update loops).
 ///
 /// Pre-migration this wrote `AppExit::Success` to signal `ScheduleRunnerPlugin`
@@ -653,7 +948,7 @@ pub fn run_headless_match_with(
     suppress_log: bool,
     trace_config: Option<TraceConfig>,
 ) -> Result<MatchResult, String> {
-    run_match_impl(config, suppress_log, trace_config, None, None)
+    run_match_impl(config, suppress_log, trace_config, None, None, None)
 }
 
 /// Like `run_headless_match_with`, but injects pre-parsed configs instead of
@@ -665,7 +960,7 @@ pub fn run_headless_match_prepared(
     suppress_log: bool,
     trace_config: Option<TraceConfig>,
 ) -> Result<MatchResult, String> {
-    run_match_impl(config, suppress_log, trace_config, Some(configs), None)
+    run_match_impl(config, suppress_log, trace_config, Some(configs), None, None)
 }
 
 /// Observed-run variant of [`run_headless_match_with`] for behavior probes.
@@ -689,7 +984,35 @@ pub fn run_headless_match_observed<F>(
 where
     F: FnMut(&FrameObservation),
 {
-    run_match_impl(config, suppress_log, trace_config, None, Some(&mut observer))
+    run_match_impl(config, suppress_log, trace_config, None, Some(&mut observer), None)
+}
+
+/// Wall-clock cost of a match's update loop, for `headless::calibrate`.
+/// Covers only the `app.update()` ticks, not app construction or config
+/// loading.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoopTiming {
+    /// `app.update()` calls until the match completed (60 per simulated second)
+    pub ticks: u32,
+    /// Wall time spent in those calls
+    pub elapsed: Duration,
+}
+
+impl LoopTiming {
+    pub fn ticks_per_sec(&self) -> f64 {
+        self.ticks as f64 / self.elapsed.as_secs_f64().max(1e-9)
+    }
+}
+
+/// Like [`run_headless_match_prepared`] with the log suppressed and no trace,
+/// also returning how long the update loop took.
+pub fn run_headless_match_timed(
+    config: HeadlessMatchConfig,
+    configs: &PreloadedConfigs,
+) -> Result<(MatchResult, LoopTiming), String> {
+    let mut timing = LoopTiming::default();
+    let result = run_match_impl(config, true, None, Some(configs), None, Some(&mut timing))?;
+    Ok((result, timing))
 }
 
 /// Build a [`FrameObservation`] from read-only world access. Uses
@@ -707,7 +1030,32 @@ fn observe_frame(world: &World) -> FrameObservation {
 
     let mut combatants = BTreeMap::new();
     let mut totems: Vec<(Entity, ObservedTotem)> = Vec::new();
+    let mut pending_references: Vec<(Entity, ObservedPending)> = Vec::new();
     for entity_ref in world.iter_entities() {
+        let references = [
+            ("AuraPending", "target", entity_ref.get::<AuraPending>().map(|p| p.target)),
+            ("AuraPending", "caster", entity_ref.get::<AuraPending>().and_then(|p| p.aura.caster)),
+            ("InterruptPending", "target", entity_ref.get::<InterruptPending>().map(|p| p.target)),
+            ("InterruptPending", "caster", entity_ref.get::<InterruptPending>().map(|p| p.caster)),
+            ("HolyShockHealPending", "target", entity_ref.get::<HolyShockHealPending>().map(|p| p.target)),
+            ("HolyShockDamagePending", "target", entity_ref.get::<HolyShockDamagePending>().map(|p| p.target)),
+            ("SandboxDamagePending", "target", entity_ref.get::<SandboxDamagePending>().map(|p| p.target)),
+            ("SandboxHealPending", "target", entity_ref.get::<SandboxHealPending>().map(|p| p.target)),
+            ("DivineShieldPending", "caster", entity_ref.get::<DivineShieldPending>().map(|p| p.caster)),
+            ("IceBlockPending", "caster", entity_ref.get::<IceBlockPending>().map(|p| p.caster)),
+            ("VanishPending", "caster", entity_ref.get::<VanishPending>().map(|p| p.caster)),
+            ("DispelPending", "target", entity_ref.get::<DispelPending>().map(|p| p.target)),
+            ("DispelPending", "dispeller", entity_ref.get::<DispelPending>().map(|p| p.dispeller)),
+            ("BacklashPending", "dispeller", entity_ref.get::<BacklashPending>().map(|p| p.dispeller)),
+            ("BacklashPending", "caster", entity_ref.get::<BacklashPending>().map(|p| p.caster)),
+            ("SoulShardPending", "killer", entity_ref.get::<SoulShardPending>().map(|p| p.killer)),
+        ];
+        for (kind, role, entity) in references {
+            if let Some(entity) = entity {
+                pending_references.push((entity_ref.id(), ObservedPending { kind, role, entity }));
+            }
+        }
+
         // Totems are not Combatants — observe them on a parallel list.
         if let (Some(totem), Some(transform)) =
             (entity_ref.get::<Totem>(), entity_ref.get::<Transform>())
@@ -732,10 +1080,9 @@ fn observe_frame(world: &World) -> FrameObservation {
         ) else {
             continue;
         };
-        let aura_types = entity_ref
-            .get::<ActiveAuras>()
-            .map(|a| a.auras.iter().map(|aura| aura.effect_type).collect())
-            .unwrap_or_default();
+        let auras = entity_ref.get::<ActiveAuras>().map(|a| a.auras.as_slice()).unwrap_or_default();
+        let aura_types = auras.iter().map(|aura| aura.effect_type).collect();
+        let aura_sources = auras.iter().map(|aura| (aura.ability_name.clone(), aura.caster)).collect();
         combatants.insert(
             entity_ref.id(),
             ObservedCombatant {
@@ -748,6 +1095,7 @@ fn observe_frame(world: &World) -> FrameObservation {
                 current_health: combatant.current_health,
                 max_health: combatant.max_health,
                 aura_types,
+                aura_sources,
             },
         );
     }
@@ -755,12 +1103,15 @@ fn observe_frame(world: &World) -> FrameObservation {
     // Sort by entity id for deterministic iteration order in probes.
     totems.sort_by_key(|(e, _)| *e);
     let totems = totems.into_iter().map(|(_, t)| t).collect();
+    pending_references.sort_by_key(|(e, _)| *e);
+    let pending_references = pending_references.into_iter().map(|(_, p)| p).collect();
 
     FrameObservation {
         sim_time,
         gates_open,
         combatants,
         totems,
+        pending_references,
     }
 }
 
@@ -774,6 +1125,7 @@ fn run_match_impl(
     trace_config: Option<TraceConfig>,
     preloaded: Option<&PreloadedConfigs>,
     mut observer: Option<&mut dyn FnMut(&FrameObservation)>,
+    timing: Option<&mut LoopTiming>,
 ) -> Result<MatchResult, String> {
     if !suppress_log {
         println!("Starting headless match simulation...");
@@ -872,8 +1224,11 @@ fn run_match_impl(
     // and exists only as a safety net for runaway infinite loops; the in-game
     // `max_duration_secs` timeout fires earlier in normal operation.
     const MAX_FRAMES: u32 = 60 * 60 * 30; // 30 simulated minutes — far above any real match.
+    let loop_started = std::time::Instant::now();
+    let mut ticks = 0;
     for _ in 0..MAX_FRAMES {
         app.update();
+        ticks += 1;
         // Per-frame read-only observation hook (behavior probes). Built from
         // `&World` only — see `observe_frame` for the non-perturbation notes.
         if let Some(obs) = observer.as_mut() {
@@ -888,6 +1243,9 @@ fn run_match_impl(
             break;
         }
     }
+    if let Some(timing) = timing {
+        *timing = LoopTiming { ticks, elapsed: loop_started.elapsed() };
+    }
 
     // Explicit flush of any in-flight trace events before reading the result.
     // `flush_decision_trace_system` runs each frame and already drains events,
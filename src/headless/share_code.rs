@@ -0,0 +1,145 @@
+//! Match share codes
+//!
+//! A share code is a match setup (and optionally its seed) as one pasteable
+//! line, for bug reports and balance threads: ConfigureMatch and Results copy
+//! it, ConfigureMatch's "Load from code" dialog and `--code <CODE>` read it.
+//!
+//! Format: `AS1-` followed by URL-safe base64 (no padding) of the deflated
+//! `HeadlessMatchConfig` JSON with `sim_version` stamped. Fields equal to
+//! their defaults are dropped before compressing, so a plain 3v3 is about
+//! 150 characters. The version stamp makes a code from a build
+//! with different simulation behavior fail to load instead of silently
+//! playing a different match. Arena dressing and lighting are cosmetic and
+//! not included.
+
+use std::io::{Read, Write};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+
+use crate::combat::metadata::SIM_VERSION;
+use crate::states::match_config::MatchConfig;
+use super::config::HeadlessMatchConfig;
+
+/// Prefix of every share code; the digit is the format version
+pub const SHARE_CODE_PREFIX: &str = "AS1-";
+
+/// Cap on a share code's inflated setup JSON. A full 5v5 with gear is a few
+/// KB; the cap keeps a crafted code that inflates to gigabytes from
+/// exhausting memory.
+pub const MAX_CONFIG_JSON_BYTES: u64 = 64 * 1024;
+
+/// Encode a match setup, with the seed to replay when known.
+pub fn encode_share_code(config: &MatchConfig, seed: Option<u64>) -> Result<String, String> {
+    let mut setup = HeadlessMatchConfig::from_match_config(config, seed)?;
+    setup.sim_version = Some(SIM_VERSION);
+
+    // Drop every field the decoder would fill in with the same default anyway
+    let mut json = serde_json::to_value(&setup).map_err(|e| e.to_string())?;
+    let defaults = serde_json::to_value(HeadlessMatchConfig::default()).map_err(|e| e.to_string())?;
+    if let (Some(fields), Some(defaults)) = (json.as_object_mut(), defaults.as_object()) {
+        fields.retain(|key, value| defaults.get(key) != Some(value));
+    }
+
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(json.to_string().as_bytes())
+        .and_then(|_| encoder.finish())
+        .map(|deflated| format!("{}{}", SHARE_CODE_PREFIX, URL_SAFE_NO_PAD.encode(deflated)))
+        .map_err(|e| format!("compress share code: {}", e))
+}
+
+/// Decode a share code into a validated match config. Surrounding whitespace
+/// is ignored. A code recorded against a different `SIM_VERSION` is rejected
+/// unless `allow_sim_version_mismatch`.
+pub fn decode_share_code(code: &str, allow_sim_version_mismatch: bool) -> Result<HeadlessMatchConfig, String> {
+    let payload = code
+        .trim()
+        .strip_prefix(SHARE_CODE_PREFIX)
+        .ok_or_else(|| format!("not a share code (expected it to start with {})", SHARE_CODE_PREFIX))?;
+    let deflated = URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|_| "share code is damaged (bad characters or truncated)".to_string())?;
+    let mut json = String::new();
+    DeflateDecoder::new(deflated.as_slice())
+        .take(MAX_CONFIG_JSON_BYTES)
+        .read_to_string(&mut json)
+        .map_err(|_| "share code is damaged (truncated)".to_string())?;
+    if json.len() as u64 >= MAX_CONFIG_JSON_BYTES {
+        return Err(format!("share code is too large (setup over {} KB)", MAX_CONFIG_JSON_BYTES / 1024));
+    }
+
+    let config: HeadlessMatchConfig =
+        serde_json::from_str(&json).map_err(|e| format!("share code has an invalid setup: {}", e))?;
+    config.check_sim_version("share code", allow_sim_version_mismatch)?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Decode a share code straight into the game's MatchConfig and its seed.
+pub fn load_share_code(code: &str, allow_sim_version_mismatch: bool) -> Result<(MatchConfig, Option<u64>), String> {
+    let config = decode_share_code(code, allow_sim_version_mismatch)?;
+    Ok((config.to_match_config()?, config.random_seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::match_config::{CharacterClass, HunterPetType, Personality, RogueOpener, WarlockCurse};
+    use crate::states::play_match::equipment::{ItemId, ItemSlot};
+
+    #[test]
+    fn share_code_round_trips_setup_and_seed() {
+        let mut config = MatchConfig::default();
+        config.set_team1_size(2);
+        config.set_team2_size(3);
+        config.team1 = vec![Some(CharacterClass::Hunter), Some(CharacterClass::Warlock)];
+        config.team2 = vec![Some(CharacterClass::Rogue), Some(CharacterClass::Priest), Some(CharacterClass::Mage)];
+        // set_team*_size leaves pet types short; decoding fills every slot
+        config.team1_hunter_pet_types = vec![HunterPetType::Boar, HunterPetType::default()];
+        config.team2_hunter_pet_types = vec![HunterPetType::default(); 3];
+        config.team1_warlock_curse_prefs[1][2] = WarlockCurse::Tongues;
+        config.team2_rogue_openers[0] = RogueOpener::Ambush;
+        config.team2_personalities[1] = Personality::Cautious;
+        config.team2_cc_target = Some(1);
+        config.team1_equipment[0].insert(ItemSlot::MainHand, ItemId::ArcaniteReaper);
+        config.arena_seed = Some(42);
+
+        let code = encode_share_code(&config, Some(42)).unwrap();
+        assert!(code.starts_with(SHARE_CODE_PREFIX));
+        let (decoded, seed) = load_share_code(&format!("  {}\n", code), false).unwrap();
+        assert_eq!(seed, Some(42));
+        assert_eq!(decoded.fingerprint(), config.fingerprint());
+
+        let mut plain = MatchConfig::default();
+        plain.set_team1_size(3);
+        plain.set_team2_size(3);
+        plain.team1 = vec![Some(CharacterClass::Warrior), Some(CharacterClass::Hunter), Some(CharacterClass::Priest)];
+        plain.team2 = vec![Some(CharacterClass::Rogue), Some(CharacterClass::Mage), Some(CharacterClass::Paladin)];
+        let code = encode_share_code(&plain, None).unwrap();
+        assert!(code.len() < 160, "{} is {} chars", code, code.len());
+        assert_eq!(load_share_code(&code, false).unwrap().1, None);
+    }
+
+    #[test]
+    fn share_code_rejects_garbage_and_gauntlets() {
+        assert!(decode_share_code("hello", false).unwrap_err().contains("not a share code"));
+        assert!(decode_share_code("AS1-!!!", false).unwrap_err().contains("damaged"));
+        assert!(decode_share_code("AS1-AAAA", false).is_err());
+
+        // A deflate bomb stops at the cap instead of inflating in full
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&vec![b' '; 16 * MAX_CONFIG_JSON_BYTES as usize]).unwrap();
+        let bomb = format!("{}{}", SHARE_CODE_PREFIX, URL_SAFE_NO_PAD.encode(encoder.finish().unwrap()));
+        assert!(decode_share_code(&bomb, false).unwrap_err().contains("too large"));
+
+        let mut gauntlet = MatchConfig::gauntlet();
+        gauntlet.team1 = vec![Some(CharacterClass::Warrior)];
+        gauntlet.team2 = vec![Some(CharacterClass::Mage)];
+        assert!(encode_share_code(&gauntlet, None).is_err());
+        assert!(encode_share_code(&MatchConfig::default(), None).unwrap_err().contains("empty slot"));
+    }
+}
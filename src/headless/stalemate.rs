@@ -0,0 +1,155 @@
+//! Stalemate detection for headless matches
+//!
+//! Some matches can never end: nobody can reach anybody (everyone kiting or
+//! stuck out of range), or two healers out-heal every point of incoming
+//! damage without ever running low on mana. Without a detector they burn the
+//! full `max_duration_secs`, which dominates batch and matrix run times.
+//!
+//! Both cases look the same from outside: neither team gets any closer to
+//! losing. [`StalemateWatch`] tracks each team's lowest health and mana
+//! fractions seen so far; when neither team sets a new low (by at least
+//! [`PROGRESS_EPSILON`]) for `stalemate_secs`, the match is a stalemate.
+
+/// How far below its previous low a team's health or mana fraction must fall
+/// to count as progress. Keeps heal-topped-off jitter from resetting the clock.
+pub const PROGRESS_EPSILON: f32 = 0.01;
+
+/// Default `stalemate_secs`: long enough for a slow mana war to show a new
+/// low, short enough to save most of a 300s cap.
+pub const DEFAULT_STALEMATE_SECS: f32 = 60.0;
+
+/// Health and mana of one team, as fractions of the team's maximums.
+/// Teams without a mana user report `mana: 1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeamVitals {
+    pub health: f32,
+    pub mana: f32,
+}
+
+/// Why a match was declared a stalemate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StalemateCause {
+    /// No damage at all landed during the window
+    NoDamage,
+    /// Damage landed, but healing and regeneration kept pace with it
+    Sustained,
+}
+
+impl StalemateCause {
+    pub fn describe(self) -> &'static str {
+        match self {
+            StalemateCause::NoDamage => "no damage dealt",
+            StalemateCause::Sustained => "healing outpaces damage",
+        }
+    }
+}
+
+/// Progress tracker; feed it once per frame after the gates open.
+#[derive(Debug, Clone, Default)]
+pub struct StalemateWatch {
+    /// Lowest vitals per team so far (None until the first observation)
+    lows: [Option<TeamVitals>; 2],
+    /// Match time of the last new low
+    last_progress_at: f32,
+    /// Total damage taken (both teams) at the last new low
+    damage_at_progress: f32,
+}
+
+impl StalemateWatch {
+    /// Record this frame's vitals. Returns the cause once `window` seconds
+    /// pass without either team setting a new health or mana low; a
+    /// non-positive `window` disables detection.
+    pub fn observe(
+        &mut self,
+        elapsed: f32,
+        vitals: [TeamVitals; 2],
+        total_damage: f32,
+        window: f32,
+    ) -> Option<StalemateCause> {
+        let mut progressed = false;
+        for (low, now) in self.lows.iter_mut().zip(vitals) {
+            match low {
+                None => {
+                    *low = Some(now);
+                    progressed = true;
+                }
+                Some(low) => {
+                    if now.health < low.health - PROGRESS_EPSILON {
+                        low.health = now.health;
+                        progressed = true;
+                    }
+                    if now.mana < low.mana - PROGRESS_EPSILON {
+                        low.mana = now.mana;
+                        progressed = true;
+                    }
+                }
+            }
+        }
+        if progressed {
+            self.last_progress_at = elapsed;
+            self.damage_at_progress = total_damage;
+            return None;
+        }
+
+        if window <= 0.0 || elapsed - self.last_progress_at < window {
+            return None;
+        }
+        Some(if total_damage > self.damage_at_progress {
+            StalemateCause::Sustained
+        } else {
+            StalemateCause::NoDamage
+        })
+    }
+
+    /// Seconds since either team last set a new low.
+    pub fn stalled_for(&self, elapsed: f32) -> f32 {
+        elapsed - self.last_progress_at
+    }
+
+    /// Damage taken (both teams) since the last new low.
+    pub fn damage_since_progress(&self, total_damage: f32) -> f32 {
+        total_damage - self.damage_at_progress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vitals(health: f32, mana: f32) -> TeamVitals {
+        TeamVitals { health, mana }
+    }
+
+    #[test]
+    fn no_damage_for_the_window_is_a_stalemate() {
+        let mut watch = StalemateWatch::default();
+        let full = [vitals(1.0, 1.0), vitals(1.0, 1.0)];
+        assert_eq!(watch.observe(0.0, full, 0.0, 30.0), None);
+        assert_eq!(watch.observe(29.9, full, 0.0, 30.0), None);
+        assert_eq!(watch.observe(30.0, full, 0.0, 30.0), Some(StalemateCause::NoDamage));
+    }
+
+    #[test]
+    fn healed_damage_is_sustained_but_new_lows_reset_the_clock() {
+        let mut watch = StalemateWatch::default();
+        watch.observe(0.0, [vitals(1.0, 1.0), vitals(1.0, 1.0)], 0.0, 30.0);
+        // Both teams dip to new lows at 10s: progress
+        assert_eq!(watch.observe(10.0, [vitals(0.8, 1.0), vitals(0.6, 0.9)], 400.0, 30.0), None);
+        // Healed back up and trading damage, but never below the low
+        assert_eq!(watch.observe(39.0, [vitals(0.9, 1.0), vitals(0.95, 0.95)], 2000.0, 30.0), None);
+        assert_eq!(
+            watch.observe(40.0, [vitals(0.9, 1.0), vitals(0.95, 0.95)], 2000.0, 30.0),
+            Some(StalemateCause::Sustained)
+        );
+        assert_eq!(watch.damage_since_progress(2000.0), 1600.0);
+    }
+
+    #[test]
+    fn draining_mana_counts_as_progress_and_zero_window_disables() {
+        let mut watch = StalemateWatch::default();
+        watch.observe(0.0, [vitals(1.0, 1.0), vitals(1.0, 1.0)], 0.0, 30.0);
+        assert_eq!(watch.observe(25.0, [vitals(1.0, 0.7), vitals(1.0, 1.0)], 0.0, 30.0), None);
+        assert_eq!(watch.observe(50.0, [vitals(1.0, 0.7), vitals(1.0, 1.0)], 0.0, 30.0), None);
+        assert_eq!(watch.observe(500.0, [vitals(1.0, 0.7), vitals(1.0, 1.0)], 0.0, 0.0), None);
+    }
+}
@@ -0,0 +1,293 @@
+//! Experimental ability auto-balancer (`arenasim tune`).
+//!
+//! Closed loop over one numeric ability parameter: simulate the matchup at the
+//! two ends of `--min..--max`, then bisect the range toward the value where
+//! team 1 wins ~50%, stopping once within `--tolerance` or after
+//! `--max-iterations` probes. The suggested value and every probe's record are
+//! printed and written to `match_logs/tune_<timestamp>.csv`.
+//!
+//! Assumes team 1's win rate moves monotonically with the parameter across the
+//! range — true for the usual damage / healing / duration knobs. If both ends
+//! land on the same side of 50% there is nothing to bisect; the closer end is
+//! reported and the range should be widened.
+//!
+//! Each probe runs the same seeds (`seed_base + run`) with sides swapped on
+//! odd runs, so probes differ only by the parameter. Draws count as half a win
+//! for each side. The parameter is overridden in memory only —
+//! `abilities.ron` is never written; apply the suggestion by hand and bump
+//! `SIM_VERSION`.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::cli::TuneArgs;
+use crate::combat::metadata::{fingerprint, MatchMetadata};
+use crate::states::match_config::MAX_TEAM_SIZE;
+use crate::states::play_match::abilities::AbilityType;
+use crate::states::play_match::ability_config::AbilityDefinitions;
+
+use super::batch::{default_jobs, pin_task_pools_single_threaded, run_parallel};
+use super::config::HeadlessMatchConfig;
+use super::error::ModeError;
+use super::optimize::{parse_class_list, wilson_interval};
+use super::report::{comp_label, default_csv_path, write_report_csv, CONFIDENCE_Z};
+use super::runner::PreloadedConfigs;
+
+/// Simulated record of the matchup at one parameter value.
+#[derive(Debug, Clone)]
+pub struct Probe {
+    pub value: f32,
+    pub runs: u32,
+    pub team1_wins: u32,
+    pub team2_wins: u32,
+    pub draws: u32,
+}
+
+impl Probe {
+    /// Team 1's win rate with draws counted as half a win.
+    pub fn team1_score(&self) -> f32 {
+        if self.runs == 0 {
+            0.5
+        } else {
+            (self.team1_wins as f32 + 0.5 * self.draws as f32) / self.runs as f32
+        }
+    }
+
+    /// 95% Wilson interval on team 1's outright win rate.
+    fn confidence_interval(&self) -> (f32, f32) {
+        wilson_interval(self.team1_wins, self.runs, CONFIDENCE_Z)
+    }
+}
+
+/// Read a numeric field of an ability's config. `path` is a field name or a
+/// dotted path into nested structs (`applies_aura.magnitude`).
+pub fn read_param(definitions: &AbilityDefinitions, ability: AbilityType, path: &str) -> Result<f32, String> {
+    let config = definitions
+        .get(&ability)
+        .ok_or_else(|| format!("{:?} has no definition", ability))?;
+    let value = serde_json::to_value(config).map_err(|e| e.to_string())?;
+    let field = lookup(&value, path).ok_or_else(|| unknown_param(ability, path))?;
+    field
+        .as_f64()
+        .map(|v| v as f32)
+        .ok_or_else(|| format!("{:?}.{} is not a number ({})", ability, path, field))
+}
+
+/// Overwrite a numeric field of an ability's config (see [`read_param`]).
+/// Round-trips the config through serde, so any numeric field works without
+/// a per-field match.
+pub fn write_param(definitions: &mut AbilityDefinitions, ability: AbilityType, path: &str, new_value: f32) -> Result<(), String> {
+    let config = definitions
+        .get_mut(&ability)
+        .ok_or_else(|| format!("{:?} has no definition", ability))?;
+    let mut value = serde_json::to_value(&*config).map_err(|e| e.to_string())?;
+    let field = lookup_mut(&mut value, path).ok_or_else(|| unknown_param(ability, path))?;
+    if !field.is_number() {
+        return Err(format!("{:?}.{} is not a number ({})", ability, path, field));
+    }
+    *field = serde_json::json!(new_value);
+    *config = serde_json::from_value(value).map_err(|e| format!("{:?}.{}: {}", ability, path, e))?;
+    Ok(())
+}
+
+fn lookup<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.').try_fold(value, |v, key| v.get(key))
+}
+
+fn lookup_mut<'a>(value: &'a mut serde_json::Value, path: &str) -> Option<&'a mut serde_json::Value> {
+    path.split('.').try_fold(value, |v, key| v.get_mut(key))
+}
+
+fn unknown_param(ability: AbilityType, path: &str) -> String {
+    format!("{:?} has no field '{}' (a null Option field can't be tuned)", ability, path)
+}
+
+/// Parse an `abilities.ron` key (`MortalStrike`) into an `AbilityType`.
+pub fn parse_ability(name: &str) -> Result<AbilityType, String> {
+    ron::from_str(name.trim())
+        .map_err(|_| format!("Unknown ability '{}' (use the abilities.ron key, e.g. MortalStrike)", name))
+}
+
+/// Matchup and match settings shared by every probe.
+struct Tuner {
+    ability: AbilityType,
+    param: String,
+    team1: Vec<String>,
+    team2: Vec<String>,
+    runs: u32,
+    seed_base: u64,
+    n_jobs: usize,
+    base: PreloadedConfigs,
+}
+
+impl Tuner {
+    /// Simulate the matchup with the parameter set to `value`.
+    fn probe(&self, value: f32) -> Result<Probe, String> {
+        let mut abilities = self.base.abilities.clone();
+        write_param(&mut abilities, self.ability, &self.param, value)?;
+        let preloaded = Arc::new(PreloadedConfigs {
+            abilities,
+            items: self.base.items.clone(),
+            loadouts: self.base.loadouts.clone(),
+            movement: self.base.movement,
+        });
+
+        // Team 1 spawns on the team-1 side for even runs, team 2's side for odd.
+        let configs: Vec<HeadlessMatchConfig> = (0..self.runs)
+            .map(|run| {
+                let (team1, team2) = if run.is_multiple_of(2) {
+                    (self.team1.clone(), self.team2.clone())
+                } else {
+                    (self.team2.clone(), self.team1.clone())
+                };
+                HeadlessMatchConfig {
+                    team1,
+                    team2,
+                    random_seed: Some(self.seed_base.wrapping_add(run as u64)),
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let results = run_parallel(&Arc::new(configs), &preloaded, self.n_jobs, "tune");
+
+        let mut probe = Probe { value, runs: 0, team1_wins: 0, team2_wins: 0, draws: 0 };
+        for (run, result) in results.iter().enumerate() {
+            // Errored matches (logged by the worker) are left out of the record.
+            let Some(result) = result else { continue; };
+            let team1_side = if run.is_multiple_of(2) { 1 } else { 2 };
+            probe.runs += 1;
+            match result.winner {
+                Some(team) if team == team1_side => probe.team1_wins += 1,
+                Some(_) => probe.team2_wins += 1,
+                None => probe.draws += 1,
+            }
+        }
+        println!("  {} = {:<10.4} team 1 {:>5.1}%  (W{} L{} D{})",
+            self.param, value, probe.team1_score() * 100.0,
+            probe.team1_wins, probe.team2_wins, probe.draws);
+        Ok(probe)
+    }
+}
+
+/// Run the auto-balancer described by `opts`: print the suggested value and
+/// write every probe to CSV.
+pub fn run_tune(opts: TuneArgs) -> Result<(), ModeError> {
+    let ability = parse_ability(&opts.ability).map_err(ModeError::Config)?;
+    let team1 = parse_class_list(&opts.team1).map_err(ModeError::Config)?;
+    let team2 = parse_class_list(&opts.team2).map_err(ModeError::Config)?;
+    for (flag, team) in [("--team1", &team1), ("--team2", &team2)] {
+        if team.is_empty() || team.len() > MAX_TEAM_SIZE {
+            return Err(ModeError::Config(format!("{} must name 1-{} classes", flag, MAX_TEAM_SIZE)));
+        }
+    }
+    if !(opts.min.is_finite() && opts.max.is_finite() && opts.min < opts.max) {
+        return Err(ModeError::Config("--min must be below --max".to_string()));
+    }
+    if opts.runs == 0 {
+        return Err(ModeError::Config("--runs N requires N >= 1".to_string()));
+    }
+
+    let base = PreloadedConfigs::load().map_err(ModeError::Config)?;
+    let current = read_param(&base.abilities, ability, &opts.param).map_err(ModeError::Config)?;
+    pin_task_pools_single_threaded();
+
+    let tuner = Tuner {
+        ability,
+        param: opts.param.clone(),
+        team1: team1.iter().map(|c| c.name().to_string()).collect(),
+        team2: team2.iter().map(|c| c.name().to_string()).collect(),
+        runs: opts.runs,
+        seed_base: opts.seed_base,
+        n_jobs: opts.jobs.unwrap_or_else(default_jobs).max(1),
+        base,
+    };
+
+    let started = Instant::now();
+    println!("Tuning {:?}.{} (currently {}) for {} vs {} over [{}, {}], {} runs per probe",
+        ability, opts.param, current, comp_label(&team1), comp_label(&team2),
+        opts.min, opts.max, opts.runs);
+
+    let mut probes = vec![tuner.probe(opts.min)?, tuner.probe(opts.max)?];
+    let (mut low, mut high) = (probes[0].clone(), probes[1].clone());
+    let bracketed = (low.team1_score() - 0.5) * (high.team1_score() - 0.5) <= 0.0;
+
+    if bracketed {
+        for _ in 0..opts.max_iterations {
+            if closest(&probes).map(|p| (p.team1_score() - 0.5).abs()).unwrap_or(1.0) <= opts.tolerance {
+                break;
+            }
+            let mid = tuner.probe((low.value + high.value) / 2.0)?;
+            // Keep the half whose ends still straddle 50%.
+            if (mid.team1_score() - 0.5) * (low.team1_score() - 0.5) <= 0.0 {
+                high = mid.clone();
+            } else {
+                low = mid.clone();
+            }
+            probes.push(mid);
+        }
+    }
+
+    let best = closest(&probes).cloned().expect("at least two probes");
+    let (ci_low, ci_high) = best.confidence_interval();
+    println!();
+    if bracketed {
+        println!("Suggested {:?}.{} = {:.4} (was {}): team 1 {:.1}% [95% CI {:.1}% - {:.1}%]",
+            ability, opts.param, best.value, current, best.team1_score() * 100.0,
+            ci_low * 100.0, ci_high * 100.0);
+    } else {
+        println!("50% is not inside [{}, {}]: team 1 scores {:.1}% at {} and {:.1}% at {}.",
+            opts.min, opts.max, low.team1_score() * 100.0, low.value,
+            high.team1_score() * 100.0, high.value);
+        println!("Closest: {:?}.{} = {} ({:.1}%) - widen the range or tune another parameter.",
+            ability, opts.param, best.value, best.team1_score() * 100.0);
+    }
+    println!("{} probes in {:.1}s", probes.len(), started.elapsed().as_secs_f32());
+
+    let out = opts.out.unwrap_or_else(|| default_csv_path("tune"));
+    let metadata = MatchMetadata::capture(
+        fingerprint(format!(
+            "tune {:?}.{} team1={} team2={} range=[{},{}] runs={} seed_base={}",
+            ability, opts.param, comp_label(&team1), comp_label(&team2),
+            opts.min, opts.max, opts.runs, opts.seed_base
+        ).as_bytes()),
+        Some(opts.seed_base),
+    );
+    let header = format!("{:?}.{} for {} vs {} (was {}, suggested {:.4})",
+        ability, opts.param, comp_label(&team1), comp_label(&team2), current, best.value);
+    write_probes_csv(&out, &metadata, &header, &probes)?;
+    println!("Wrote {}", out.display());
+    Ok(())
+}
+
+/// The probe whose team 1 score is nearest 50%.
+fn closest(probes: &[Probe]) -> Option<&Probe> {
+    probes.iter().min_by(|a, b| {
+        (a.team1_score() - 0.5).abs().total_cmp(&(b.team1_score() - 0.5).abs())
+    })
+}
+
+/// One row per probe in the order they ran. The run's `MatchMetadata` comes
+/// first as `# ` comment lines.
+fn write_probes_csv(
+    output: &Path,
+    metadata: &MatchMetadata,
+    header: &str,
+    probes: &[Probe],
+) -> Result<(), String> {
+    let rows = probes.iter().enumerate().map(|(i, probe)| {
+        let (low, high) = probe.confidence_interval();
+        format!(
+            "{},{:.6},{},{},{},{},{:.4},{:.4},{:.4}",
+            i + 1, probe.value, probe.runs, probe.team1_wins, probe.team2_wins, probe.draws,
+            probe.team1_score(), low, high
+        )
+    });
+    write_report_csv(
+        output,
+        metadata,
+        &format!("Tuning: {}", header),
+        "probe,value,runs,team1_wins,team2_wins,draws,team1_score,team1_winrate_ci95_low,team1_winrate_ci95_high",
+        rows,
+    )
+}
@@ -22,6 +22,15 @@ pub enum GameAction {
     CameraMoveRight,
     CameraZoomIn,
     CameraZoomOut,
+    FollowSlot1,
+    FollowSlot2,
+    FollowSlot3,
+    FollowSlot4,
+    FollowSlot5,
+    FollowSlot6,
+    JumpToBigEvent,
+    RecordCameraPath,
+    PlayCameraPath,
 
     // Simulation
     PausePlay,
@@ -29,9 +38,16 @@ pub enum GameAction {
     SpeedNormal,
     SpeedFast,
     SpeedVeryFast,
+    ToggleWhatIfBranch,
+    RestartSameSeed,
+    RestartNewSeed,
 
     // Display
     ToggleAuraIcons,
+    ToggleSandboxConsole,
+    ToggleDiagnostics,
+    ToggleAiPlans,
+    TogglePresentationMode,
 }
 
 impl GameAction {
@@ -47,12 +63,28 @@ impl GameAction {
             GameAction::CameraMoveRight => "Camera Right",
             GameAction::CameraZoomIn => "Camera Zoom In",
             GameAction::CameraZoomOut => "Camera Zoom Out",
+            GameAction::FollowSlot1 => "Follow Combatant 1",
+            GameAction::FollowSlot2 => "Follow Combatant 2",
+            GameAction::FollowSlot3 => "Follow Combatant 3",
+            GameAction::FollowSlot4 => "Follow Combatant 4",
+            GameAction::FollowSlot5 => "Follow Combatant 5",
+            GameAction::FollowSlot6 => "Follow Combatant 6",
+            GameAction::JumpToBigEvent => "Jump to Last Big Event",
+            GameAction::RecordCameraPath => "Record Camera Path",
+            GameAction::PlayCameraPath => "Play / Stop Camera Path",
             GameAction::PausePlay => "Pause / Play",
             GameAction::SpeedSlow => "Speed: 0.5x",
             GameAction::SpeedNormal => "Speed: 1x",
             GameAction::SpeedFast => "Speed: 2x",
             GameAction::SpeedVeryFast => "Speed: 3x",
+            GameAction::ToggleWhatIfBranch => "Fork / Return What-If Branch",
+            GameAction::RestartSameSeed => "Restart Match (Same Seed)",
+            GameAction::RestartNewSeed => "Restart Match (New Seed)",
             GameAction::ToggleAuraIcons => "Toggle Aura Icons",
+            GameAction::ToggleSandboxConsole => "Toggle Sandbox Console",
+            GameAction::ToggleDiagnostics => "Toggle Diagnostics Overlay",
+            GameAction::ToggleAiPlans => "Toggle AI Plan Overlay",
+            GameAction::TogglePresentationMode => "Toggle Presentation Mode",
         }
     }
     
@@ -62,11 +94,19 @@ impl GameAction {
             GameAction::CycleCameraMode | GameAction::ResetCamera
             | GameAction::CameraMoveForward | GameAction::CameraMoveBackward
             | GameAction::CameraMoveLeft | GameAction::CameraMoveRight
-            | GameAction::CameraZoomIn | GameAction::CameraZoomOut => "Camera",
+            | GameAction::CameraZoomIn | GameAction::CameraZoomOut
+            | GameAction::FollowSlot1 | GameAction::FollowSlot2
+            | GameAction::FollowSlot3 | GameAction::FollowSlot4
+            | GameAction::FollowSlot5 | GameAction::FollowSlot6
+            | GameAction::JumpToBigEvent | GameAction::RecordCameraPath
+            | GameAction::PlayCameraPath => "Camera",
             GameAction::PausePlay | GameAction::SpeedSlow
             | GameAction::SpeedNormal | GameAction::SpeedFast
-            | GameAction::SpeedVeryFast => "Simulation",
-            GameAction::ToggleAuraIcons => "Display",
+            | GameAction::SpeedVeryFast | GameAction::ToggleWhatIfBranch
+            | GameAction::RestartSameSeed | GameAction::RestartNewSeed => "Simulation",
+            GameAction::ToggleAuraIcons | GameAction::ToggleSandboxConsole
+            | GameAction::ToggleDiagnostics | GameAction::ToggleAiPlans
+            | GameAction::TogglePresentationMode => "Display",
         }
     }
     
@@ -82,12 +122,28 @@ impl GameAction {
             GameAction::CameraMoveRight,
             GameAction::CameraZoomIn,
             GameAction::CameraZoomOut,
+            GameAction::FollowSlot1,
+            GameAction::FollowSlot2,
+            GameAction::FollowSlot3,
+            GameAction::FollowSlot4,
+            GameAction::FollowSlot5,
+            GameAction::FollowSlot6,
+            GameAction::JumpToBigEvent,
+            GameAction::RecordCameraPath,
+            GameAction::PlayCameraPath,
             GameAction::PausePlay,
             GameAction::SpeedSlow,
             GameAction::SpeedNormal,
             GameAction::SpeedFast,
             GameAction::SpeedVeryFast,
+            GameAction::ToggleWhatIfBranch,
+            GameAction::RestartSameSeed,
+            GameAction::RestartNewSeed,
             GameAction::ToggleAuraIcons,
+            GameAction::ToggleSandboxConsole,
+            GameAction::ToggleDiagnostics,
+            GameAction::ToggleAiPlans,
+            GameAction::TogglePresentationMode,
         ]
     }
 }
@@ -158,6 +214,7 @@ impl From<SerializableKeyCode> for KeyCode {
             "F7" => KeyCode::F7,
             "F8" => KeyCode::F8,
             "F9" => KeyCode::F9,
+            "Backquote" => KeyCode::Backquote,
             "F10" => KeyCode::F10,
             "F11" => KeyCode::F11,
             "F12" => KeyCode::F12,
@@ -288,6 +345,15 @@ impl Keybindings {
             KeyCode::Minus,
             KeyCode::NumpadSubtract
         ));
+        bindings.insert(GameAction::FollowSlot1, KeyBinding::new(KeyCode::F1));
+        bindings.insert(GameAction::FollowSlot2, KeyBinding::new(KeyCode::F2));
+        bindings.insert(GameAction::FollowSlot3, KeyBinding::new(KeyCode::F3));
+        bindings.insert(GameAction::FollowSlot4, KeyBinding::new(KeyCode::F4));
+        bindings.insert(GameAction::FollowSlot5, KeyBinding::new(KeyCode::F5));
+        bindings.insert(GameAction::FollowSlot6, KeyBinding::new(KeyCode::F6));
+        bindings.insert(GameAction::JumpToBigEvent, KeyBinding::new(KeyCode::KeyE));
+        bindings.insert(GameAction::RecordCameraPath, KeyBinding::new(KeyCode::F7));
+        bindings.insert(GameAction::PlayCameraPath, KeyBinding::new(KeyCode::KeyP));
 
         // Simulation
         bindings.insert(GameAction::PausePlay, KeyBinding::new(KeyCode::Space));
@@ -295,9 +361,16 @@ impl Keybindings {
         bindings.insert(GameAction::SpeedNormal, KeyBinding::new(KeyCode::Digit2));
         bindings.insert(GameAction::SpeedFast, KeyBinding::new(KeyCode::Digit3));
         bindings.insert(GameAction::SpeedVeryFast, KeyBinding::new(KeyCode::Digit4));
+        bindings.insert(GameAction::ToggleWhatIfBranch, KeyBinding::new(KeyCode::KeyB));
+        bindings.insert(GameAction::RestartSameSeed, KeyBinding::new(KeyCode::KeyR));
+        bindings.insert(GameAction::RestartNewSeed, KeyBinding::new(KeyCode::KeyN));
 
         // Display
         bindings.insert(GameAction::ToggleAuraIcons, KeyBinding::new(KeyCode::KeyV));
+        bindings.insert(GameAction::ToggleSandboxConsole, KeyBinding::new(KeyCode::Backquote));
+        bindings.insert(GameAction::ToggleDiagnostics, KeyBinding::new(KeyCode::F9));
+        bindings.insert(GameAction::ToggleAiPlans, KeyBinding::new(KeyCode::F10));
+        bindings.insert(GameAction::TogglePresentationMode, KeyBinding::new(KeyCode::F8));
 
         Self { bindings }
     }
@@ -418,6 +491,7 @@ impl Keybindings {
             KeyCode::F7 => "F7",
             KeyCode::F8 => "F8",
             KeyCode::F9 => "F9",
+            KeyCode::Backquote => "`",
             KeyCode::F10 => "F10",
             KeyCode::F11 => "F11",
             KeyCode::F12 => "F12",
@@ -3,70 +3,177 @@
 //! A prototype implementation of an autobattler where players configure teams
 //! of combatants and watch them battle CPU vs CPU.
 
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
 use bevy::prelude::*;
 use bevy::window::PresentMode;
 use bevy_egui::{egui, EguiContexts, EguiPlugin};
 
 use arenasim::camera::CameraPlugin;
-use arenasim::cli;
+use arenasim::cli::{self, CliError, ErrorFormat, ExitStatus};
 use arenasim::combat::CombatPlugin;
-use arenasim::headless;
+use arenasim::headless::{self, ModeError};
 use arenasim::settings::{GameSettings, SettingsPlugin};
-use arenasim::states::play_match::{AbilityConfigPlugin, MovementConfigPlugin};
+use arenasim::states::play_match::{
+    AbilityConfigPlugin, CameraPath, CameraPathPlayer, MatchRestart, MovementConfigPlugin, PresentationMode,
+};
 use arenasim::states::play_match::equipment::EquipmentPlugin;
-use arenasim::states::{GameState, StatesPlugin};
+use arenasim::states::{GameState, MatchConfig, StatesPlugin};
 use arenasim::ui::UiPlugin;
 
+/// First panic message of the run, for the `Panic` exit report. The first one
+/// wins: a batch worker's panic is re-raised on the main thread by `join`,
+/// and the original message is the useful one.
+static FIRST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
 fn main() {
     let args = cli::parse_args();
 
-    if let Some(batch_path) = args.batch {
-        // Parallel in-process batch runner for sweeps (2v2/3v3/strategy vars).
-        let out = args.out.unwrap_or_else(|| {
-            let ts = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_secs())
-                .unwrap_or(0);
-            format!("match_logs/batch_{}.csv", ts).into()
-        });
-        if let Err(e) = headless::run_batch(batch_path, out, args.jobs) {
-            eprintln!("Batch run failed: {}", e);
-            std::process::exit(1);
+    let headless_mode = args.command.is_some()
+        || args.batch.is_some()
+        || args.matrix.is_some()
+        || args.fuzz.is_some()
+        || args.calibrate.is_some()
+        || args.headless.is_some();
+    if !headless_mode {
+        // Normal graphical mode
+        let camera_path = match args.camera_path.as_deref().map(CameraPath::load).transpose() {
+            Ok(path) => path,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(ExitStatus::ConfigError.code());
+            }
+        };
+        let shared_match = match args
+            .code
+            .as_deref()
+            .map(|code| headless::load_share_code(code, args.allow_sim_version_mismatch))
+            .transpose()
+        {
+            Ok(shared) => shared,
+            Err(e) => {
+                eprintln!("Error: --code: {}", e);
+                std::process::exit(ExitStatus::ConfigError.code());
+            }
+        };
+        run_graphical_mode(args.presentation, camera_path, shared_match);
+        return;
+    }
+
+    // Headless modes exit with a structured code (see `cli::ExitStatus`),
+    // panics included.
+    let error_format = args.error_format;
+    install_panic_hook(error_format);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| run_headless_cli(args))).unwrap_or_else(|_| {
+        let message = FIRST_PANIC
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(CliError::new(ExitStatus::Panic, format!("Simulation panicked: {}", message)))
+    });
+    if let Err(error) = outcome {
+        eprintln!("{}", error.render(error_format));
+        std::process::exit(error.status.code());
+    }
+}
+
+/// Record panic messages for the exit report. With `--error-format json` the
+/// default panic output is suppressed so stderr stays machine-readable.
+fn install_panic_hook(error_format: ErrorFormat) {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let payload = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string());
+        let message = match info.location() {
+            Some(location) => format!("{} at {}:{}", payload, location.file(), location.line()),
+            None => payload,
+        };
+        FIRST_PANIC.lock().unwrap_or_else(|e| e.into_inner()).get_or_insert(message);
+        if error_format == ErrorFormat::Text {
+            default_hook(info);
         }
+    }));
+}
+
+/// Dispatch the headless modes (subcommands, `--batch`, `--matrix`,
+/// `--fuzz`, `--calibrate`, `--headless`).
+fn run_headless_cli(args: cli::Args) -> Result<(), CliError> {
+    if let Some(cli::Command::Optimize(opts)) = args.command {
+        // Counter-comp search: parallel in-process matches per candidate.
+        headless::run_optimize(opts).map_err(failed("Optimize run"))
+    } else if let Some(cli::Command::Tune(opts)) = args.command {
+        // Experimental ability auto-balancer (binary search on one parameter).
+        headless::run_tune(opts).map_err(failed("Tune run"))
+    } else if let Some(batch_path) = args.batch {
+        // Parallel in-process batch runner for sweeps (2v2/3v3/strategy vars).
+        let configs = headless::load_batch_configs(&batch_path, args.allow_sim_version_mismatch)
+            .map_err(|e| CliError::new(ExitStatus::ConfigError, format!("Batch run failed: {}", e)))?;
+        let out = args.out.unwrap_or_else(|| headless::report::default_csv_path("batch"));
+        headless::run_batch(batch_path, configs, out, args.jobs, args.db).map_err(failed("Batch run"))
     } else if let Some(n) = args.matrix {
         // 7×7 matchup matrix mode — defaults to trace `on` so every cell's
         // trace is on disk when an anomaly surfaces; explicit `off` opts out.
         let trace_mode = args.trace_mode.unwrap_or(cli::TraceMode::On);
-        if let Err(e) = headless::run_matrix(n, args.seed_base, args.save_logs, trace_mode) {
-            eprintln!("Matrix run failed: {}", e);
-            std::process::exit(1);
-        }
+        headless::run_matrix(n, args.seed_base, args.save_logs, trace_mode).map_err(failed("Matrix run"))
+    } else if let Some(n) = args.fuzz {
+        // Seeded fuzz mode — random comps / overrides / scripted CC; exits
+        // non-zero if any case panicked or violated an invariant.
+        headless::run_fuzz(n, args.seed_base).map_err(failed("Fuzz run"))
+    } else if let Some(n) = args.calibrate {
+        // Throughput benchmark — warmup, then N timed runs of one match.
+        headless::run_calibrate(n).map_err(failed("Calibration"))
     } else if let Some(config_path) = args.headless {
         // Single headless match — defaults to trace `off`; opt in via
         // `--trace-mode on` (or `verbose`).
         let trace_mode = args.trace_mode.unwrap_or(cli::TraceMode::Off);
-        run_headless_mode(config_path, args.output, args.max_duration, trace_mode);
+        run_headless_mode(
+            config_path,
+            args.output,
+            args.max_duration,
+            trace_mode,
+            args.allow_sim_version_mismatch,
+            args.results_json,
+            args.replay,
+        )
     } else {
-        // Normal graphical mode
-        run_graphical_mode();
+        Ok(())
+    }
+}
+
+/// Wrap a mode's error: bad input as `ExitStatus::ConfigError`, anything
+/// else as `ExitStatus::Failure`.
+fn failed(what: &'static str) -> impl Fn(ModeError) -> CliError {
+    move |e| {
+        let status = match e {
+            ModeError::Config(_) => ExitStatus::ConfigError,
+            ModeError::Run(_) => ExitStatus::Failure,
+        };
+        CliError::new(status, format!("{} failed: {}", what, e))
     }
 }
 
+/// Run one match. A draw is reported as an error too, so scripts can tell a
+/// stalemate (`ExitStatus::Stalemate`) from a capped match
+/// (`ExitStatus::Timeout`) by exit code alone.
 fn run_headless_mode(
     config_path: std::path::PathBuf,
     output: Option<std::path::PathBuf>,
     max_duration: Option<f32>,
     trace_mode: cli::TraceMode,
-) {
+    allow_sim_version_mismatch: bool,
+    results_json: Option<std::path::PathBuf>,
+    replay: Option<std::path::PathBuf>,
+) -> Result<(), CliError> {
     println!("Running in headless mode with config: {:?}", config_path);
 
-    let mut config = match headless::HeadlessMatchConfig::load_from_file(&config_path) {
-        Ok(c) => c,
-        Err(e) => {
-            eprintln!("Error loading config: {}", e);
-            std::process::exit(1);
-        }
-    };
+    let mut config = headless::HeadlessMatchConfig::load_from_file(&config_path, allow_sim_version_mismatch)
+        .map_err(|e| CliError::new(ExitStatus::ConfigError, format!("Error loading config: {}", e)))?;
 
     // Override from CLI args if provided
     if let Some(path) = output {
@@ -75,6 +182,11 @@ fn run_headless_mode(
     if let Some(duration) = max_duration {
         config.max_duration_secs = duration;
     }
+    // A replay has to reproduce this run, so it can't be left unseeded
+    if replay.is_some() {
+        let seed = headless::export::pin_seed(&mut config);
+        println!("Seed: {}", seed);
+    }
 
     // Build trace config when enabled. Single-match writes alongside the .txt
     // log with the same timestamp suffix.
@@ -90,23 +202,45 @@ fn run_headless_mode(
         None
     };
 
-    match headless::run_headless_match_with(config, false, trace_config) {
-        Ok(result) => {
-            // Brief stdout summary; full details live in the saved log file.
-            let winner = match result.winner {
-                None => "DRAW".to_string(),
-                Some(t) => format!("Team {}", t),
-            };
-            println!("Result: {} ({:.2}s)", winner, result.match_time);
-        }
-        Err(e) => {
-            eprintln!("Error running match: {}", e);
-            std::process::exit(1);
-        }
+    let result = headless::run_headless_match_with(config.clone(), false, trace_config)
+        .map_err(|e| CliError::new(ExitStatus::Failure, format!("Error running match: {}", e)))?;
+
+    // Brief stdout summary; full details live in the saved log file.
+    let winner = match result.winner {
+        None => "DRAW".to_string(),
+        Some(t) => format!("Team {}", t),
+    };
+    println!("Result: {} ({:.2}s)", winner, result.match_time);
+
+    let written = [
+        results_json.map(|path| (headless::export::write_results_json(&path, &config, &result), path)),
+        replay.map(|path| (headless::export::write_replay(&path, &config), path)),
+    ];
+    for (outcome, path) in written.into_iter().flatten() {
+        outcome.map_err(|e| CliError::new(ExitStatus::Failure, format!("Error writing output: {}", e)))?;
+        println!("Wrote {}", path.display());
+    }
+
+    match result.end_reason {
+        headless::EndReason::Kill => Ok(()),
+        headless::EndReason::Stalemate => Err(CliError::new(
+            ExitStatus::Stalemate,
+            format!("Match ended in a stalemate after {:.2}s", result.match_time),
+        )),
+        headless::EndReason::CapDraw => Err(CliError::new(
+            ExitStatus::Timeout,
+            format!("Match hit the {:.0}s duration cap", config.max_duration_secs),
+        )),
     }
 }
 
-fn run_graphical_mode() {
+/// `shared_match` is a setup and seed from `--code`; the game starts straight
+/// into that match instead of the main menu.
+fn run_graphical_mode(
+    presentation: bool,
+    camera_path: Option<CameraPath>,
+    shared_match: Option<(MatchConfig, Option<u64>)>,
+) {
     // Load settings first to apply them to window configuration
     let settings = GameSettings::load();
     let (width, height) = settings.resolution.dimensions();
@@ -117,7 +251,8 @@ fn run_graphical_mode() {
         PresentMode::AutoNoVsync
     };
 
-    App::new()
+    let mut app = App::new();
+    app
         // Bevy default plugins with settings-based window configuration
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -142,11 +277,23 @@ fn run_graphical_mode() {
             CombatPlugin,
             UiPlugin,
         ))
+        .insert_resource(PresentationMode { enabled: presentation })
+        .insert_resource(CameraPathPlayer::with_path(camera_path))
         // Start in the main menu state
         .init_state::<GameState>()
         // Setup custom font
-        .add_systems(Startup, setup_custom_font)
-        .run();
+        .add_systems(Startup, setup_custom_font);
+
+    if let Some((config, seed)) = shared_match {
+        // setup_play_match takes the seed from MatchRestart, as on a same-seed restart
+        app.insert_resource(config)
+            .insert_resource(MatchRestart { seed })
+            .add_systems(Startup, |mut next_state: ResMut<NextState<GameState>>| {
+                next_state.set(GameState::PlayMatch);
+            });
+    }
+
+    app.run();
 }
 
 fn setup_custom_font(
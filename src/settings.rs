@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode};
+use bevy_egui::egui;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -19,12 +20,233 @@ pub struct GameSettings {
     /// Whether to show aura icons below combatant health bars (default: true)
     #[serde(default = "default_show_aura_icons")]
     pub show_aura_icons: bool,
+    /// Merge runs of DoT/HoT ticks into one combat log row (default: true)
+    #[serde(default = "default_merge_periodic_ticks")]
+    pub merge_periodic_ticks: bool,
+    /// Show the live win-odds bar during matches (default: true)
+    #[serde(default = "default_show_win_odds")]
+    pub show_win_odds: bool,
+    /// Spoken commentary (only used when built with the `tts` feature)
+    #[serde(default)]
+    pub commentary_speech: CommentarySpeechSettings,
+    /// Low-health / healer-CC / kill alerts during matches
+    #[serde(default)]
+    pub alerts: AlertSettings,
+    /// Plain arena: no seeded floor tint, banners or props (for benchmarking)
+    #[serde(default)]
+    pub plain_arena: bool,
+    /// Ignore per-map lighting/weather presets (always plain daylight, no fog
+    /// or torch lights) for performance
+    #[serde(default)]
+    pub ignore_lighting_presets: bool,
+    /// Team colors used across the arena, HUD, timeline and Results
+    #[serde(default)]
+    pub team_colors: TeamColorSettings,
+    /// Large-print plain-sentence feed of major match events
+    #[serde(default)]
+    pub event_feed: EventFeedSettings,
 }
 
 fn default_show_aura_icons() -> bool {
     true
 }
 
+fn default_merge_periodic_ticks() -> bool {
+    true
+}
+
+fn default_show_win_odds() -> bool {
+    true
+}
+
+/// Text-to-speech settings for match commentary. Stored regardless of the
+/// `tts` feature so settings.ron round-trips between builds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommentarySpeechSettings {
+    /// Speak commentary lines as they appear on the ticker
+    pub enabled: bool,
+    /// Speech volume, 0.0 to 1.0
+    pub volume: f32,
+    /// Minimum real-time seconds between the start of two spoken lines
+    pub min_interval_secs: f32,
+    /// External TTS command instead of the OS-native voice, e.g.
+    /// `"espeak-ng -a {volume} {text}"`. Split on whitespace; `{text}` is the
+    /// line and `{volume}` is 0-100. Without `{text}` the line is appended.
+    pub command: Option<String>,
+}
+
+/// Spectator alerts for decisive moments: a screen-edge vignette and a tone
+/// when a combatant on the followed team drops low, their healer is CC'd, or
+/// one of them dies. With no combatant followed or selected, both teams count.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertSettings {
+    /// Flash a colored vignette around the screen edge
+    pub vignette: bool,
+    /// Play an alert tone
+    pub sound: bool,
+    /// Alert tone volume, 0.0 to 1.0
+    pub volume: f32,
+    /// Health fraction below which the low-health alert fires
+    pub low_health_threshold: f32,
+    /// Alert when a healer is crowd controlled
+    pub healer_cc: bool,
+    /// Alert when a combatant dies
+    pub kills: bool,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        Self {
+            vignette: true,
+            sound: true,
+            volume: 0.5,
+            low_health_threshold: 0.2,
+            healer_cc: true,
+            kills: true,
+        }
+    }
+}
+
+/// Accessible event feed: major match events as plain sentences in large,
+/// high-contrast text, paced for reading.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventFeedSettings {
+    pub enabled: bool,
+    /// Text size in points
+    pub text_size: f32,
+    /// Minimum real-time seconds between two new lines (reading speed)
+    pub seconds_per_line: f32,
+}
+
+impl Default for EventFeedSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            text_size: 28.0,
+            seconds_per_line: 3.0,
+        }
+    }
+}
+
+impl Default for CommentarySpeechSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            volume: 0.8,
+            min_interval_secs: 2.5,
+            command: None,
+        }
+    }
+}
+
+/// Team color palettes. The default avoids the red/green axis so both teams
+/// stay distinct with protanopia or deuteranopia.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TeamPalette {
+    /// Sky blue vs orange (Okabe-Ito), safe for red-green color blindness
+    #[default]
+    ColorblindSafe,
+    /// The original blue vs red
+    Classic,
+    /// Gold vs purple
+    GoldPurple,
+    /// User-picked colors (`TeamColorSettings::custom`)
+    Custom,
+}
+
+impl TeamPalette {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamPalette::ColorblindSafe => "Colorblind Safe",
+            TeamPalette::Classic => "Classic",
+            TeamPalette::GoldPurple => "Gold / Purple",
+            TeamPalette::Custom => "Custom",
+        }
+    }
+
+    pub fn all() -> [TeamPalette; 4] {
+        [
+            TeamPalette::ColorblindSafe,
+            TeamPalette::Classic,
+            TeamPalette::GoldPurple,
+            TeamPalette::Custom,
+        ]
+    }
+
+    /// Team 1 and team 2 colors (sRGB) of a preset; None for `Custom`
+    pub fn preset_colors(&self) -> Option<[[u8; 3]; 2]> {
+        match self {
+            TeamPalette::ColorblindSafe => Some([[86, 180, 233], [230, 159, 0]]),
+            TeamPalette::Classic => Some([[100, 150, 255], [255, 100, 100]]),
+            TeamPalette::GoldPurple => Some([[240, 200, 70], [175, 115, 255]]),
+            TeamPalette::Custom => None,
+        }
+    }
+}
+
+/// Chosen team palette plus the colors used when it is `Custom`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamColorSettings {
+    pub palette: TeamPalette,
+    /// Team 1 and team 2 colors (sRGB) for the `Custom` palette
+    pub custom: [[u8; 3]; 2],
+}
+
+impl Default for TeamColorSettings {
+    fn default() -> Self {
+        Self {
+            palette: TeamPalette::default(),
+            custom: [[100, 150, 255], [255, 100, 100]],
+        }
+    }
+}
+
+/// The resolved team colors, kept in sync with `GameSettings::team_colors`
+/// (like `Keybindings`) so every team-colored element reads one resource.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct TeamColors {
+    colors: [[u8; 3]; 2],
+}
+
+impl Default for TeamColors {
+    fn default() -> Self {
+        Self::from_settings(&TeamColorSettings::default())
+    }
+}
+
+impl TeamColors {
+    pub fn from_settings(settings: &TeamColorSettings) -> Self {
+        Self {
+            colors: settings.palette.preset_colors().unwrap_or(settings.custom),
+        }
+    }
+
+    fn rgb(&self, team: u8) -> [u8; 3] {
+        self.colors[if team == 2 { 1 } else { 0 }]
+    }
+
+    /// Team color for egui (HUD, timeline, Results)
+    pub fn egui(&self, team: u8) -> egui::Color32 {
+        let [r, g, b] = self.rgb(team);
+        egui::Color32::from_rgb(r, g, b)
+    }
+
+    /// Team color for meshes
+    pub fn bevy(&self, team: u8) -> Color {
+        let [r, g, b] = self.rgb(team);
+        Color::srgb_u8(r, g, b)
+    }
+
+    /// Team color for a combat log ID ("Team 2 Mage" → team 2)
+    pub fn for_combatant_id(&self, combatant_id: &str) -> egui::Color32 {
+        self.egui(if combatant_id.starts_with("Team 2") { 2 } else { 1 })
+    }
+}
+
 /// Tracks whether settings have changed and require application restart
 #[derive(Resource)]
 pub struct PendingSettingsRestart {
@@ -65,6 +287,14 @@ impl Default for GameSettings {
             vsync: true,
             keybindings: Keybindings::default(),
             show_aura_icons: true,
+            merge_periodic_ticks: true,
+            show_win_odds: true,
+            commentary_speech: CommentarySpeechSettings::default(),
+            alerts: AlertSettings::default(),
+            plain_arena: false,
+            ignore_lighting_presets: false,
+            team_colors: TeamColorSettings::default(),
+            event_feed: EventFeedSettings::default(),
         }
     }
 }
@@ -184,16 +414,18 @@ impl Plugin for SettingsPlugin {
         // Load settings from file
         let settings = GameSettings::load();
         
-        // Also insert keybindings as a separate resource for easy access
+        // Also insert keybindings and team colors as separate resources for easy access
         let keybindings = settings.keybindings.clone();
+        let team_colors = TeamColors::from_settings(&settings.team_colors);
         
         app.insert_resource(settings.clone())
             .insert_resource(keybindings)
+            .insert_resource(team_colors)
             .insert_resource(PendingSettingsRestart {
                 restart_required: false,
                 previous_settings: settings,
             })
-            .add_systems(Update, (save_settings_on_change, apply_runtime_settings, sync_keybindings));
+            .add_systems(Update, (save_settings_on_change, apply_runtime_settings, sync_keybindings, sync_team_colors));
     }
 }
 
@@ -257,3 +489,13 @@ fn sync_keybindings(
     }
 }
 
+/// System to keep the TeamColors resource in sync with GameSettings
+fn sync_team_colors(
+    settings: Res<GameSettings>,
+    mut team_colors: ResMut<TeamColors>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        // Options UI marks settings changed every frame; only flag a real change
+        team_colors.set_if_neq(TeamColors::from_settings(&settings.team_colors));
+    }
+}
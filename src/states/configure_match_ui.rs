@@ -1,9 +1,9 @@
 //! Configure Match UI - Team Setup and Map Selection
 //!
 //! This module handles the match configuration screen where players:
-//! - Select team sizes (1-3 combatants per team)
+//! - Select team sizes (1-5 combatants per team; locked to 1v1 in Duel mode)
 //! - Choose character classes for each team slot
-//! - Select the arena map
+//! - Select the arena map (fixed to the Duel Ring in Duel mode)
 //! - Start the match when ready
 //!
 //! ## UI Structure
@@ -21,7 +21,7 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use std::collections::HashMap;
-use super::{GameState, match_config::{self, MatchConfig}};
+use super::{GameState, match_config::{self, MatchConfig, MAX_TEAM_SIZE}};
 use super::view_combatant_ui::ViewCombatantState;
 
 /// Resource storing loaded class icon textures for egui rendering.
@@ -105,12 +105,27 @@ pub struct CharacterPickerState {
     pub slot: usize,
 }
 
+/// State for the "Load from code" dialog and the share-code row under the
+/// Start button.
+#[derive(Default)]
+pub struct ShareCodeDialog {
+    /// Whether the load dialog is open
+    pub open: bool,
+    /// Code pasted into the dialog
+    pub input: String,
+    /// Why the last load failed
+    pub error: Option<String>,
+    /// Set after a successful copy, shown next to the buttons
+    pub copied: bool,
+}
+
 /// Main UI system for the Configure Match screen.
 /// 
 /// Renders:
 /// - Header with back button and title
 /// - Three-column layout (Team 1, Map, Team 2)
 /// - Start Match button (enabled when config is valid)
+/// - Share code copy/load row and the "Load from code" dialog
 /// - Character picker modal (when active)
 pub fn configure_match_ui(
     mut contexts: EguiContexts,
@@ -121,6 +136,8 @@ pub fn configure_match_ui(
     keybindings: Res<crate::keybindings::Keybindings>,
     keyboard: Res<ButtonInput<KeyCode>>,
     class_icons: Res<ClassIcons>,
+    team_colors: Res<crate::settings::TeamColors>,
+    mut share_dialog: Local<ShareCodeDialog>,
 ) {
     use crate::keybindings::GameAction;
 
@@ -142,7 +159,9 @@ pub fn configure_match_ui(
 
     // Handle Back key - close modal if open, otherwise return to main menu
     if keybindings.action_just_pressed(GameAction::Back, &keyboard) {
-        if let Some(ref mut picker) = picker_state {
+        if share_dialog.open {
+            share_dialog.open = false;
+        } else if let Some(ref mut picker) = picker_state {
             if picker.active {
                 picker.active = false;
             } else {
@@ -196,31 +215,39 @@ pub fn configure_match_ui(
             let content_width = screen_width - margins_and_spacing;
             let col_width = content_width / 3.0;
             
-            ui.horizontal(|ui| {
-                ui.spacing_mut().item_spacing.x = 20.0;
-                let panel_width = col_width - 10.0; // Account for borders/padding
-
-                // Team 1 column
-                ui.vertical(|ui| {
-                    ui.set_width(col_width);
-                    ui.add_space(5.0);
-                    render_team_panel(ui, &mut config, 1, &mut picker_state, panel_width, &class_icons, &mut commands, &mut next_state);
-                });
-
-                // Map column
-                ui.vertical(|ui| {
-                    ui.set_width(col_width);
-                    ui.add_space(5.0);
-                    render_map_panel(ui, &mut config, panel_width);
-                });
-
-                // Team 2 column
-                ui.vertical(|ui| {
-                    ui.set_width(col_width);
-                    ui.add_space(5.0);
-                    render_team_panel(ui, &mut config, 2, &mut picker_state, panel_width, &class_icons, &mut commands, &mut next_state);
+            // Scroll the columns (not the START button) if a 5v5 setup is
+            // taller than the window.
+            let columns_max_height = (ui.available_height() - 120.0).max(200.0);
+            egui::ScrollArea::vertical()
+                .max_height(columns_max_height)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 20.0;
+                        let panel_width = col_width - 10.0; // Account for borders/padding
+
+                        // Team 1 column
+                        ui.vertical(|ui| {
+                            ui.set_width(col_width);
+                            ui.add_space(5.0);
+                            render_team_panel(ui, &mut config, 1, team_colors.egui(1), &mut picker_state, panel_width, &class_icons, &mut commands, &mut next_state);
+                        });
+
+                        // Map column
+                        ui.vertical(|ui| {
+                            ui.set_width(col_width);
+                            ui.add_space(5.0);
+                            render_map_panel(ui, &mut config, panel_width);
+                        });
+
+                        // Team 2 column
+                        ui.vertical(|ui| {
+                            ui.set_width(col_width);
+                            ui.add_space(5.0);
+                            render_team_panel(ui, &mut config, 2, team_colors.egui(2), &mut picker_state, panel_width, &class_icons, &mut commands, &mut next_state);
+                        });
+                    });
                 });
-            });
 
             ui.add_space(30.0);
 
@@ -246,13 +273,24 @@ pub fn configure_match_ui(
 
                 if ui.add_enabled(is_valid, button).clicked() {
                     info!("Starting match with config: {:?}", *config);
+                    // A gauntlet run starts fresh from round 1 with this roster
+                    if config.mode == match_config::MatchMode::Gauntlet {
+                        commands.insert_resource(super::gauntlet::GauntletState::start(&config));
+                    }
                     next_state.set(GameState::PlayMatch);
                 }
+
+                ui.add_space(8.0);
+                render_share_code_row(ui, &config, &mut share_dialog);
             });
 
             ui.add_space(20.0);
         });
 
+    if share_dialog.open {
+        render_load_code_dialog(ctx, &mut config, &mut share_dialog, &mut commands, &mut next_state);
+    }
+
     // Character picker modal - shown when active
     if let Some(ref mut picker) = picker_state {
         if picker.active {
@@ -261,6 +299,92 @@ pub fn configure_match_ui(
     }
 }
 
+/// "Copy share code" and "Load from code…" buttons under the Start button.
+/// The copied code carries the setup only; the Results screen copies it with
+/// the match's seed.
+fn render_share_code_row(ui: &mut egui::Ui, config: &MatchConfig, dialog: &mut ShareCodeDialog) {
+    // A fixed-width row so vertical_centered centers it (ui.horizontal would
+    // start at the left edge)
+    ui.allocate_ui_with_layout(
+        egui::vec2(290.0, 24.0),
+        egui::Layout::left_to_right(egui::Align::Center),
+        |ui| {
+            let can_share = config.is_valid() && config.mode != match_config::MatchMode::Gauntlet;
+            let copy = ui
+                .add_enabled(can_share, egui::Button::new(egui::RichText::new("Copy share code").size(14.0)))
+                .on_disabled_hover_text("Fill every slot first (gauntlet runs can't be shared)");
+            if copy.clicked() {
+                match crate::headless::encode_share_code(config, None) {
+                    Ok(code) => {
+                        ui.ctx().copy_text(code);
+                        dialog.copied = true;
+                    }
+                    Err(e) => warn!("Failed to build share code: {}", e),
+                }
+            }
+            if ui.button(egui::RichText::new("Load from code…").size(14.0)).clicked() {
+                dialog.open = true;
+                dialog.error = None;
+                dialog.copied = false;
+            }
+        },
+    );
+    if dialog.copied {
+        ui.label(
+            egui::RichText::new("Share code copied to clipboard")
+                .size(12.0)
+                .color(egui::Color32::from_rgb(150, 150, 150)),
+        );
+    }
+}
+
+/// "Load from code" dialog. A code with a seed starts the match right away
+/// (through `MatchRestart`, like a same-seed restart); a setup-only code just
+/// fills in the configuration.
+fn render_load_code_dialog(
+    ctx: &egui::Context,
+    config: &mut MatchConfig,
+    dialog: &mut ShareCodeDialog,
+    commands: &mut Commands,
+    next_state: &mut NextState<GameState>,
+) {
+    let mut open = dialog.open;
+    egui::Window::new("Load from code")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.label("Paste a share code:");
+            ui.add(
+                egui::TextEdit::singleline(&mut dialog.input)
+                    .desired_width(420.0)
+                    .hint_text(crate::headless::share_code::SHARE_CODE_PREFIX),
+            );
+            if let Some(error) = &dialog.error {
+                ui.label(egui::RichText::new(error).size(12.0).color(egui::Color32::from_rgb(230, 90, 90)));
+            }
+            ui.add_space(6.0);
+            if ui.button("Load").clicked() {
+                match crate::headless::load_share_code(&dialog.input, false) {
+                    Ok((loaded, seed)) => {
+                        info!("Loaded match setup from share code: {:?}", loaded);
+                        *config = loaded;
+                        dialog.open = false;
+                        dialog.error = None;
+                        dialog.input.clear();
+                        if seed.is_some() {
+                            commands.insert_resource(super::play_match::MatchRestart { seed });
+                            next_state.set(GameState::PlayMatch);
+                        }
+                    }
+                    Err(e) => dialog.error = Some(e),
+                }
+            }
+        });
+    dialog.open &= open;
+}
+
 /// Render the character picker modal window.
 ///
 /// Displays all available character classes with:
@@ -390,17 +514,13 @@ fn render_team_panel(
     ui: &mut egui::Ui,
     config: &mut MatchConfig,
     team: u8,
+    team_color: egui::Color32,
     picker_state: &mut Option<ResMut<CharacterPickerState>>,
     max_width: f32,
     class_icons: &ClassIcons,
     commands: &mut Commands,
     next_state: &mut ResMut<NextState<GameState>>,
 ) {
-    let team_color = if team == 1 {
-        egui::Color32::from_rgb(51, 102, 204)
-    } else {
-        egui::Color32::from_rgb(204, 51, 51)
-    };
 
     // Get current team data
     let team_size = if team == 1 {
@@ -420,8 +540,14 @@ fn render_team_panel(
         ui.heading(egui::RichText::new(format!("TEAM {}", team)).size(20.0).color(team_color));
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            // Duel mode is locked to 1v1 — no size controls
+            if config.mode == match_config::MatchMode::Duel {
+                ui.label(egui::RichText::new("1v1").size(18.0).color(egui::Color32::from_rgb(153, 153, 153)));
+                return;
+            }
+
             // Plus button - increase team size
-            if ui.add(egui::Button::new("+").min_size(egui::vec2(25.0, 25.0))).clicked() && team_size < 3 {
+            if ui.add(egui::Button::new("+").min_size(egui::vec2(25.0, 25.0))).clicked() && team_size < MAX_TEAM_SIZE {
                 if team == 1 {
                     config.set_team1_size(team_size + 1);
                 } else {
@@ -444,15 +570,19 @@ fn render_team_panel(
 
     ui.add_space(20.0);
 
-    // Character slots (always show 3, but some may be inactive)
-    for slot in 0..3 {
+    // Character slots: always at least 3 (some may be inactive), and as many
+    // as the larger team so both columns line up. 4v4/5v5 switch to compact
+    // rows so five slots plus the kill-target list still fit on screen.
+    let visible_slots = config.team1_size.max(config.team2_size).max(3);
+    let compact = visible_slots > 3;
+    for slot in 0..visible_slots {
         let character = team_slots.get(slot).and_then(|c| *c);
         let is_active = slot < team_size;
 
-        render_character_slot(ui, config, team, slot, character, is_active, team_color, picker_state, max_width, class_icons, commands, next_state);
+        render_character_slot(ui, config, team, slot, character, is_active, compact, team_color, picker_state, max_width, class_icons, commands, next_state);
 
-        if slot < 2 {
-            ui.add_space(12.0);
+        if slot + 1 < visible_slots {
+            ui.add_space(if compact { 6.0 } else { 12.0 });
         }
     }
     
@@ -537,6 +667,8 @@ fn render_team_panel(
 /// - **Active + Filled**: Shows class icon and name (click to view details)
 /// - **Active + Empty**: Shows "Click to select" prompt (click to open picker)
 /// - **Inactive**: Shows grayed-out dash
+///
+/// `compact` rows (used for 4v4/5v5) are shorter and drop the class description.
 fn render_character_slot(
     ui: &mut egui::Ui,
     _config: &mut MatchConfig,
@@ -544,6 +676,7 @@ fn render_character_slot(
     slot: usize,
     character: Option<match_config::CharacterClass>,
     is_active: bool,
+    compact: bool,
     team_color: egui::Color32,
     picker_state: &mut Option<ResMut<CharacterPickerState>>,
     max_width: f32,
@@ -565,8 +698,9 @@ fn render_character_slot(
 
     // Allocate space for the slot
     let slot_width = max_width.max(50.0);
+    let slot_height = if compact { 42.0 } else { 60.0 };
     let (rect, response) = ui.allocate_exact_size(
-        egui::vec2(slot_width, 60.0),
+        egui::vec2(slot_width, slot_height),
         if is_active { egui::Sense::click() } else { egui::Sense::hover() }
     );
 
@@ -587,7 +721,7 @@ fn render_character_slot(
     );
 
     // Draw content based on slot state
-    let content_rect = rect.shrink(12.0);
+    let content_rect = rect.shrink(if compact { 6.0 } else { 12.0 });
     let mut content_pos = content_rect.left_top();
     content_pos.x += 12.0;
     content_pos.y = content_rect.center().y;
@@ -602,7 +736,7 @@ fn render_character_slot(
         );
 
         // Class icon
-        let icon_size = 46.0;
+        let icon_size = if compact { 30.0 } else { 46.0 };
         let icon_rect = egui::Rect::from_min_size(
             egui::pos2(content_pos.x, content_pos.y - icon_size / 2.0),
             egui::vec2(icon_size, icon_size),
@@ -624,28 +758,38 @@ fn render_character_slot(
             ui.painter().rect_stroke(icon_rect, 6.0, egui::Stroke::new(2.0, color32), egui::StrokeKind::Outside);
         }
 
-        // Class text
-        let text_pos = egui::pos2(content_pos.x + icon_size + 15.0, content_pos.y - 20.0);
-
-        ui.painter().text(
-            text_pos,
-            egui::Align2::LEFT_TOP,
-            class.name(),
-            egui::FontId::proportional(20.0),
-            color32,
-        );
+        // Class text (compact rows show the name only, vertically centred)
+        if compact {
+            ui.painter().text(
+                egui::pos2(content_pos.x + icon_size + 15.0, content_pos.y),
+                egui::Align2::LEFT_CENTER,
+                class.name(),
+                egui::FontId::proportional(18.0),
+                color32,
+            );
+        } else {
+            let text_pos = egui::pos2(content_pos.x + icon_size + 15.0, content_pos.y - 20.0);
+
+            ui.painter().text(
+                text_pos,
+                egui::Align2::LEFT_TOP,
+                class.name(),
+                egui::FontId::proportional(20.0),
+                color32,
+            );
 
-        ui.painter().text(
-            egui::pos2(text_pos.x, text_pos.y + 24.0),
-            egui::Align2::LEFT_TOP,
-            class.description(),
-            egui::FontId::proportional(14.0),
-            egui::Color32::from_rgb(153, 153, 153),
-        );
+            ui.painter().text(
+                egui::pos2(text_pos.x, text_pos.y + 24.0),
+                egui::Align2::LEFT_TOP,
+                class.description(),
+                egui::FontId::proportional(14.0),
+                egui::Color32::from_rgb(153, 153, 153),
+            );
+        }
 
         // Add X button in top-right corner to change selection
-        let btn_size = 20.0;
-        let btn_margin = 8.0;
+        let btn_size = if compact { 16.0 } else { 20.0 };
+        let btn_margin = if compact { 4.0 } else { 8.0 };
         let btn_rect = egui::Rect::from_min_size(
             egui::pos2(rect.right() - btn_size - btn_margin, rect.top() + btn_margin),
             egui::vec2(btn_size, btn_size),
@@ -732,9 +876,14 @@ fn render_character_slot(
 /// - Map description
 /// - VS text separator
 fn render_map_panel(ui: &mut egui::Ui, config: &mut MatchConfig, max_width: f32) {
+    let is_duel = config.mode == match_config::MatchMode::Duel;
     ui.vertical_centered(|ui| {
         ui.heading(
-            egui::RichText::new("ARENA")
+            egui::RichText::new(match config.mode {
+                match_config::MatchMode::Duel => "DUEL",
+                match_config::MatchMode::Gauntlet => "GAUNTLET",
+                match_config::MatchMode::Standard => "ARENA",
+            })
                 .size(20.0)
                 .color(egui::Color32::from_rgb(230, 204, 153)),
         );
@@ -771,8 +920,8 @@ fn render_map_panel(ui: &mut egui::Ui, config: &mut MatchConfig, max_width: f32)
             
             ui.add_space(padding);
             
-            // Previous map button
-            if ui.button("◀").clicked() {
+            // Previous map button (the Duel preset's ring is fixed)
+            if ui.add_enabled(!is_duel, egui::Button::new("◀")).clicked() {
                 let maps = match_config::ArenaMap::all();
                 let current_idx = maps.iter().position(|m| *m == config.map).unwrap_or(0);
                 let new_idx = if current_idx == 0 {
@@ -797,7 +946,7 @@ fn render_map_panel(ui: &mut egui::Ui, config: &mut MatchConfig, max_width: f32)
             ui.add_space(spacing);
 
             // Next map button
-            if ui.button("▶").clicked() {
+            if ui.add_enabled(!is_duel, egui::Button::new("▶")).clicked() {
                 let maps = match_config::ArenaMap::all();
                 let current_idx = maps.iter().position(|m| *m == config.map).unwrap_or(0);
                 let new_idx = (current_idx + 1) % maps.len();
@@ -814,6 +963,23 @@ fn render_map_panel(ui: &mut egui::Ui, config: &mut MatchConfig, max_width: f32)
                 .color(egui::Color32::from_rgb(153, 153, 153)),
         );
 
+        ui.add_space(8.0);
+
+        // Lighting/weather: the map's default or an override
+        let default_lighting = config.map.default_lighting();
+        let lighting_label = |lighting: Option<match_config::ArenaLighting>| match lighting {
+            None => format!("Map default ({})", default_lighting.name()),
+            Some(lighting) => lighting.name().to_string(),
+        };
+        egui::ComboBox::from_id_salt("arena_lighting")
+            .selected_text(lighting_label(config.lighting))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut config.lighting, None, lighting_label(None));
+                for &lighting in match_config::ArenaLighting::all() {
+                    ui.selectable_value(&mut config.lighting, Some(lighting), lighting.name());
+                }
+            });
+
         ui.add_space(30.0);
         
         // VS separator
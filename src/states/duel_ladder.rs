@@ -0,0 +1,245 @@
+//! Duel ladder - per-class Elo ratings from 1v1 Duel mode
+//!
+//! Every finished Duel-mode match updates the two classes' ratings (Elo,
+//! K = 32, everyone starts at 1500) and their win/loss/draw record. Ratings
+//! are per class rather than per composition, so the ladder doubles as a quick
+//! read on individual class tuning. Mirror matches are not rated.
+//!
+//! The ladder persists to `duel_ladder.ron` next to the executable, following
+//! the same load/save pattern as `settings.ron`.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::match_config::{CharacterClass, MatchConfig, MatchMode};
+use super::play_match::MatchResults;
+
+/// Rating every class starts at.
+pub const STARTING_RATING: f32 = 1500.0;
+
+/// Elo K-factor: maximum rating swing from a single duel.
+pub const ELO_K: f32 = 32.0;
+
+/// One class's standing on the duel ladder.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClassRating {
+    pub rating: f32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl Default for ClassRating {
+    fn default() -> Self {
+        Self {
+            rating: STARTING_RATING,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+        }
+    }
+}
+
+impl ClassRating {
+    /// Total rated duels played
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+}
+
+/// Per-class duel ladder, persisted between sessions.
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DuelLadder {
+    pub ratings: BTreeMap<CharacterClass, ClassRating>,
+}
+
+/// Rating change from the most recent duel, shown on the Results screen.
+/// Inserted by `record_duel_result`, removed when leaving Results.
+#[derive(Resource, Clone, Debug)]
+pub struct DuelLadderResult {
+    pub team1_class: CharacterClass,
+    pub team2_class: CharacterClass,
+    /// Rating after the duel
+    pub team1_rating: f32,
+    pub team2_rating: f32,
+    /// Rating change from the duel (positive = gained)
+    pub team1_delta: f32,
+    pub team2_delta: f32,
+}
+
+/// Elo expected score for a player rated `rating` against `opponent`.
+pub fn expected_score(rating: f32, opponent: f32) -> f32 {
+    1.0 / (1.0 + 10f32.powf((opponent - rating) / 400.0))
+}
+
+impl DuelLadder {
+    /// Get a class's standing (starting rating if it has never duelled)
+    pub fn get(&self, class: CharacterClass) -> ClassRating {
+        self.ratings.get(&class).copied().unwrap_or_default()
+    }
+
+    /// Record a duel between two classes and return the rating deltas
+    /// `(team1_delta, team2_delta)`. `winner` uses the `MatchResults`
+    /// convention: None = draw, Some(1) / Some(2) = that team won.
+    ///
+    /// Mirror matches leave the ladder untouched and return zero deltas.
+    pub fn record(&mut self, team1: CharacterClass, team2: CharacterClass, winner: Option<u8>) -> (f32, f32) {
+        if team1 == team2 {
+            return (0.0, 0.0);
+        }
+
+        let a = self.get(team1);
+        let b = self.get(team2);
+        let score_a = match winner {
+            Some(1) => 1.0,
+            Some(2) => 0.0,
+            _ => 0.5,
+        };
+        let delta_a = ELO_K * (score_a - expected_score(a.rating, b.rating));
+        let delta_b = ELO_K * ((1.0 - score_a) - expected_score(b.rating, a.rating));
+
+        let entry_a = self.ratings.entry(team1).or_default();
+        entry_a.rating += delta_a;
+        match winner {
+            Some(1) => entry_a.wins += 1,
+            Some(2) => entry_a.losses += 1,
+            _ => entry_a.draws += 1,
+        }
+
+        let entry_b = self.ratings.entry(team2).or_default();
+        entry_b.rating += delta_b;
+        match winner {
+            Some(1) => entry_b.losses += 1,
+            Some(2) => entry_b.wins += 1,
+            _ => entry_b.draws += 1,
+        }
+
+        (delta_a, delta_b)
+    }
+
+    /// Classes that have played at least one rated duel, highest rating first
+    pub fn standings(&self) -> Vec<(CharacterClass, ClassRating)> {
+        let mut rows: Vec<_> = self
+            .ratings
+            .iter()
+            .filter(|(_, r)| r.games() > 0)
+            .map(|(class, r)| (*class, *r))
+            .collect();
+        rows.sort_by(|a, b| b.1.rating.total_cmp(&a.1.rating).then(a.0.cmp(&b.0)));
+        rows
+    }
+
+    /// Get the path to the ladder file
+    fn ladder_path() -> PathBuf {
+        // Same directory as settings.ron
+        PathBuf::from("duel_ladder.ron")
+    }
+
+    /// Load the ladder from file, or return an empty ladder if it doesn't exist
+    pub fn load() -> Self {
+        let path = Self::ladder_path();
+        if !path.exists() {
+            return Self::default();
+        }
+        match fs::read_to_string(&path) {
+            Ok(contents) => match ron::from_str::<DuelLadder>(&contents) {
+                Ok(ladder) => {
+                    info!("Loaded duel ladder from {:?}", path);
+                    ladder
+                }
+                Err(e) => {
+                    warn!("Failed to parse duel ladder file: {}", e);
+                    Self::default()
+                }
+            },
+            Err(e) => {
+                warn!("Failed to read duel ladder file: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save the ladder to file
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::ladder_path();
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        fs::write(&path, contents)?;
+        info!("Saved duel ladder to {:?}", path);
+        Ok(())
+    }
+}
+
+/// On entering Results after a Duel-mode match, rate the duel, persist the
+/// ladder, and hand the rating change to the Results screen.
+pub fn record_duel_result(
+    mut commands: Commands,
+    config: Res<MatchConfig>,
+    results: Option<Res<MatchResults>>,
+    mut ladder: ResMut<DuelLadder>,
+) {
+    if config.mode != MatchMode::Duel {
+        return;
+    }
+    let Some(results) = results else { return; };
+    let (Some(team1), Some(team2)) = (results.team1_combatants.first(), results.team2_combatants.first()) else {
+        return;
+    };
+
+    let (team1_delta, team2_delta) = ladder.record(team1.class, team2.class, results.winner);
+    if let Err(e) = ladder.save() {
+        warn!("Failed to save duel ladder: {}", e);
+    }
+
+    commands.insert_resource(DuelLadderResult {
+        team1_class: team1.class,
+        team2_class: team2.class,
+        team1_rating: ladder.get(team1.class).rating,
+        team2_rating: ladder.get(team2.class).rating,
+        team1_delta,
+        team2_delta,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_duel_moves_half_k() {
+        let mut ladder = DuelLadder::default();
+        let (d1, d2) = ladder.record(CharacterClass::Warrior, CharacterClass::Mage, Some(1));
+        assert!((d1 - ELO_K / 2.0).abs() < 1e-4);
+        assert!((d2 + ELO_K / 2.0).abs() < 1e-4);
+        assert_eq!(ladder.get(CharacterClass::Warrior).wins, 1);
+        assert_eq!(ladder.get(CharacterClass::Mage).losses, 1);
+    }
+
+    #[test]
+    fn draw_between_equals_changes_nothing_but_record() {
+        let mut ladder = DuelLadder::default();
+        let (d1, d2) = ladder.record(CharacterClass::Rogue, CharacterClass::Priest, None);
+        assert!(d1.abs() < 1e-4 && d2.abs() < 1e-4);
+        assert_eq!(ladder.get(CharacterClass::Rogue).draws, 1);
+        assert_eq!(ladder.get(CharacterClass::Priest).draws, 1);
+    }
+
+    #[test]
+    fn mirror_matches_are_unrated() {
+        let mut ladder = DuelLadder::default();
+        assert_eq!(ladder.record(CharacterClass::Hunter, CharacterClass::Hunter, Some(2)), (0.0, 0.0));
+        assert!(ladder.standings().is_empty());
+    }
+
+    #[test]
+    fn upset_pays_more_than_expected_win() {
+        let mut ladder = DuelLadder::default();
+        ladder.ratings.insert(CharacterClass::Warlock, ClassRating { rating: 1700.0, ..Default::default() });
+        // Underdog Paladin (1500) beats the 1700 Warlock
+        let (underdog_gain, _) = ladder.record(CharacterClass::Paladin, CharacterClass::Warlock, Some(1));
+        assert!(underdog_gain > ELO_K / 2.0);
+        assert_eq!(ladder.standings()[0].0, CharacterClass::Warlock);
+    }
+}
@@ -0,0 +1,301 @@
+//! Gauntlet mode - cross-match carryover
+//!
+//! Team 1 fights a chain of rounds. After each round it wins, its surviving
+//! members keep their current HP, mana (or rage/energy) and ability cooldowns
+//! into the next round against a fresh, randomly rolled enemy team of the
+//! configured team 2 size. Fallen members stay out for the rest of the run.
+//! The run ends on the first loss or draw; the Results screen shows the streak.
+//!
+//! Flow:
+//! 1. ConfigureMatch START (mode = Gauntlet) inserts a fresh `GauntletState`
+//! 2. `capture_gauntlet_round` (OnExit PlayMatch, before cleanup) records the
+//!    round outcome and snapshots team 1's survivors
+//! 3. `apply_gauntlet_carryover` (OnEnter PlayMatch, after setup) writes the
+//!    snapshot back onto the newly spawned team 1 combatants
+//! 4. `gauntlet_results_panel` draws the streak strip and NEXT ROUND button
+//! 5. `end_gauntlet_run` (OnEnter MainMenu) restores the original roster
+//!
+//! Graphical mode only — headless runs are single matches.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use rand::Rng;
+use std::collections::HashMap;
+
+use super::match_config::{CharacterClass, MatchConfig, MatchMode};
+use super::play_match::abilities::AbilityType;
+use super::play_match::components::{Combatant, Pet};
+use super::play_match::{MatchRestart, MatchResults};
+use super::GameState;
+
+/// Team 1 member state carried from one gauntlet round into the next.
+#[derive(Clone, Debug)]
+pub struct CarriedCombatant {
+    pub slot: u8,
+    pub class: CharacterClass,
+    pub current_health: f32,
+    /// Mana, rage or energy depending on the class's resource type
+    pub current_mana: f32,
+    pub ability_cooldowns: HashMap<AbilityType, f32>,
+}
+
+/// Progress of the current gauntlet run.
+#[derive(Resource, Clone, Debug)]
+pub struct GauntletState {
+    /// Round currently being (or last) played, 1-based
+    pub round: u32,
+    /// Consecutive rounds won
+    pub streak: u32,
+    /// Team 1 survivors at the end of the last won round
+    pub survivors: Vec<CarriedCombatant>,
+    /// Team 1 lost or drew the last round; no further rounds
+    pub finished: bool,
+    /// Team 1 slots as configured, restored when the run ends
+    pub roster: Vec<Option<CharacterClass>>,
+}
+
+impl GauntletState {
+    /// Start a run from the configured match (round 1, no carryover)
+    pub fn start(config: &MatchConfig) -> Self {
+        Self {
+            round: 1,
+            streak: 0,
+            survivors: Vec::new(),
+            finished: false,
+            roster: config.team1.clone(),
+        }
+    }
+
+    /// Record a finished round. `winner` uses the `MatchResults` convention
+    /// (None = draw). Only a team 1 win extends the run.
+    pub fn record_round(&mut self, winner: Option<u8>, survivors: Vec<CarriedCombatant>) {
+        if winner == Some(1) && !survivors.is_empty() {
+            self.streak += 1;
+            self.survivors = survivors;
+        } else {
+            self.finished = true;
+            self.survivors.clear();
+        }
+    }
+
+    /// Look up the carried state for a team 1 slot
+    pub fn carried(&self, slot: u8) -> Option<&CarriedCombatant> {
+        self.survivors.iter().find(|c| c.slot == slot)
+    }
+
+    /// Build the next round's config: team 1 keeps only surviving slots
+    /// (fallen slots become empty and are skipped at spawn), team 2 is replaced
+    /// by `enemy`. Per-slot strategy and gear preferences are left untouched so
+    /// survivors keep their loadouts.
+    pub fn next_round_config(&self, base: &MatchConfig, enemy: &[CharacterClass]) -> MatchConfig {
+        let mut config = base.clone();
+        config.team1 = self
+            .roster
+            .iter()
+            .enumerate()
+            .map(|(slot, class)| class.filter(|_| self.carried(slot as u8).is_some()))
+            .collect();
+        config.team2 = enemy.iter().copied().map(Some).collect();
+        config
+    }
+}
+
+/// Roll a random enemy team of `size` classes
+fn roll_enemy_team(size: usize) -> Vec<CharacterClass> {
+    let classes = CharacterClass::all();
+    let mut rng = rand::rng();
+    (0..size)
+        .map(|_| classes[rng.random_range(0..classes.len())])
+        .collect()
+}
+
+/// Record the round outcome and snapshot team 1 survivors before the
+/// PlayMatch entities are despawned.
+pub fn capture_gauntlet_round(
+    config: Res<MatchConfig>,
+    results: Option<Res<MatchResults>>,
+    gauntlet: Option<ResMut<GauntletState>>,
+    combatants: Query<&Combatant, Without<Pet>>,
+    restart: Option<Res<MatchRestart>>,
+) {
+    // A restarted round replays from the same carried-over state
+    if config.mode != MatchMode::Gauntlet || restart.is_some() {
+        return;
+    }
+    let Some(mut gauntlet) = gauntlet else { return; };
+
+    // No results means the match was abandoned mid-round
+    let winner = results.and_then(|r| r.winner);
+    let survivors = combatants
+        .iter()
+        .filter(|c| c.team == 1 && c.is_alive())
+        .map(|c| CarriedCombatant {
+            slot: c.slot,
+            class: c.class,
+            current_health: c.current_health,
+            current_mana: c.current_mana,
+            ability_cooldowns: c.ability_cooldowns.clone(),
+        })
+        .collect();
+    gauntlet.record_round(winner, survivors);
+    info!(
+        "Gauntlet round {} over: streak {}, {}",
+        gauntlet.round,
+        gauntlet.streak,
+        if gauntlet.finished { "run ended" } else { "advancing" }
+    );
+}
+
+/// Write carried HP/mana/cooldowns onto the freshly spawned team 1 combatants.
+/// Round 1 has no survivors recorded, so this is a no-op there.
+pub fn apply_gauntlet_carryover(
+    config: Res<MatchConfig>,
+    gauntlet: Option<Res<GauntletState>>,
+    mut combatants: Query<&mut Combatant, Without<Pet>>,
+) {
+    if config.mode != MatchMode::Gauntlet {
+        return;
+    }
+    let Some(gauntlet) = gauntlet else { return; };
+
+    for mut combatant in combatants.iter_mut().filter(|c| c.team == 1) {
+        if let Some(carried) = gauntlet.carried(combatant.slot) {
+            combatant.current_health = carried.current_health.min(combatant.max_health);
+            combatant.current_mana = carried.current_mana.min(combatant.max_mana);
+            combatant.ability_cooldowns = carried.ability_cooldowns.clone();
+        }
+    }
+}
+
+/// Gauntlet strip along the bottom of the Results screen: streak so far and,
+/// while the run is alive, the NEXT ROUND button. Runs before `results_ui` so
+/// the bottom panel is laid out ahead of the Results CentralPanel.
+pub fn gauntlet_results_panel(
+    mut contexts: EguiContexts,
+    mut config: ResMut<MatchConfig>,
+    gauntlet: Option<ResMut<GauntletState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+) {
+    if config.mode != MatchMode::Gauntlet {
+        return;
+    }
+    let Some(mut gauntlet) = gauntlet else { return; };
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let mut next_round = false;
+    egui::TopBottomPanel::bottom("gauntlet_panel")
+        .frame(egui::Frame::new().fill(egui::Color32::from_rgb(28, 28, 40)).inner_margin(egui::Margin::same(12)))
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                let (headline, color) = if gauntlet.finished {
+                    (
+                        format!("GAUNTLET OVER — streak {}", gauntlet.streak),
+                        egui::Color32::from_rgb(205, 110, 110),
+                    )
+                } else {
+                    (
+                        format!("GAUNTLET — round {} won, streak {}", gauntlet.round, gauntlet.streak),
+                        egui::Color32::from_rgb(255, 205, 90),
+                    )
+                };
+                ui.label(egui::RichText::new(headline).size(20.0).strong().color(color));
+
+                if !gauntlet.finished {
+                    ui.add_space(4.0);
+                    let carried: Vec<String> = gauntlet
+                        .survivors
+                        .iter()
+                        .map(|c| format!("{} {:.0} HP", c.class.name(), c.current_health))
+                        .collect();
+                    ui.label(
+                        egui::RichText::new(format!("Carrying: {}", carried.join(", ")))
+                            .size(13.0)
+                            .color(egui::Color32::from_rgb(140, 140, 158)),
+                    );
+                    ui.add_space(6.0);
+                    next_round = ui
+                        .add_sized(
+                            egui::vec2(200.0, 36.0),
+                            egui::Button::new(egui::RichText::new("NEXT ROUND").size(18.0)),
+                        )
+                        .clicked();
+                }
+            });
+        });
+
+    if next_round {
+        let enemy = roll_enemy_team(config.team2_size);
+        *config = gauntlet.next_round_config(&config, &enemy);
+        gauntlet.round += 1;
+        commands.remove_resource::<MatchResults>();
+        next_state.set(GameState::PlayMatch);
+    }
+}
+
+/// Back on the main menu: end any gauntlet run and restore team 1's
+/// configured roster (fallen slots were emptied between rounds).
+pub fn end_gauntlet_run(
+    mut config: ResMut<MatchConfig>,
+    gauntlet: Option<Res<GauntletState>>,
+    mut commands: Commands,
+) {
+    let Some(gauntlet) = gauntlet else { return; };
+    if config.mode == MatchMode::Gauntlet {
+        config.team1 = gauntlet.roster.clone();
+    }
+    commands.remove_resource::<GauntletState>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn carried(slot: u8, class: CharacterClass, hp: f32) -> CarriedCombatant {
+        CarriedCombatant {
+            slot,
+            class,
+            current_health: hp,
+            current_mana: 50.0,
+            ability_cooldowns: HashMap::new(),
+        }
+    }
+
+    fn three_man_config() -> MatchConfig {
+        let mut config = MatchConfig::gauntlet();
+        config.set_team1_size(3);
+        config.set_team2_size(2);
+        config.team1 = vec![Some(CharacterClass::Warrior), Some(CharacterClass::Priest), Some(CharacterClass::Mage)];
+        config.team2 = vec![Some(CharacterClass::Rogue), Some(CharacterClass::Shaman)];
+        config
+    }
+
+    #[test]
+    fn win_extends_streak_and_next_round_drops_fallen_slots() {
+        let config = three_man_config();
+        let mut gauntlet = GauntletState::start(&config);
+        gauntlet.record_round(
+            Some(1),
+            vec![carried(0, CharacterClass::Warrior, 120.0), carried(2, CharacterClass::Mage, 40.0)],
+        );
+        assert_eq!(gauntlet.streak, 1);
+        assert!(!gauntlet.finished);
+
+        let next = gauntlet.next_round_config(&config, &[CharacterClass::Hunter, CharacterClass::Paladin]);
+        assert_eq!(next.team1, vec![Some(CharacterClass::Warrior), None, Some(CharacterClass::Mage)]);
+        assert_eq!(next.team2, vec![Some(CharacterClass::Hunter), Some(CharacterClass::Paladin)]);
+        assert_eq!(gauntlet.carried(2).map(|c| c.current_health), Some(40.0));
+        assert!(gauntlet.carried(1).is_none());
+    }
+
+    #[test]
+    fn loss_or_draw_ends_the_run() {
+        let config = three_man_config();
+        let mut gauntlet = GauntletState::start(&config);
+        gauntlet.record_round(Some(1), vec![carried(0, CharacterClass::Warrior, 10.0)]);
+        gauntlet.record_round(None, vec![carried(0, CharacterClass::Warrior, 5.0)]);
+        assert!(gauntlet.finished);
+        assert_eq!(gauntlet.streak, 1);
+        assert!(gauntlet.survivors.is_empty());
+    }
+}
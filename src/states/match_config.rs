@@ -7,6 +7,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::states::play_match::constants::DUEL_RING_RADIUS;
 use crate::states::play_match::equipment::{ItemId, ItemSlot};
 
 /// Rogue stealth opener choice
@@ -226,8 +227,58 @@ impl PaladinAura {
     }
 }
 
+/// AI personality — biases a combatant's decision weights without changing
+/// its rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Personality {
+    /// Balanced — the stock class AI
+    #[default]
+    Balanced,
+    /// Aggressive — tunnels the kill target instead of swapping to nearby enemies
+    Aggressive,
+    /// Cautious — holds extra range from enemies
+    Cautious,
+    /// Kill-hungry — swaps to whichever enemy is closest to dying
+    KillHungry,
+    /// Peel-focused — turns on enemies attacking its healer
+    PeelFocused,
+}
+
+impl Personality {
+    /// All personalities, in display order
+    pub const ALL: [Personality; 5] = [
+        Personality::Balanced,
+        Personality::Aggressive,
+        Personality::Cautious,
+        Personality::KillHungry,
+        Personality::PeelFocused,
+    ];
+
+    /// Get the display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Personality::Balanced => "Balanced",
+            Personality::Aggressive => "Aggressive",
+            Personality::Cautious => "Cautious",
+            Personality::KillHungry => "Greedy for Kills",
+            Personality::PeelFocused => "Peel-Focused",
+        }
+    }
+
+    /// Get a short description
+    pub fn description(&self) -> &'static str {
+        match self {
+            Personality::Balanced => "Default decision making",
+            Personality::Aggressive => "Tunnels the kill target, ignoring closer enemies",
+            Personality::Cautious => "Keeps extra distance from enemies",
+            Personality::KillHungry => "Swaps to enemies in execute range",
+            Personality::PeelFocused => "Attacks whoever is pressuring the team's healer",
+        }
+    }
+}
+
 /// Available character classes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CharacterClass {
     Warrior,
     Mage,
@@ -364,12 +415,14 @@ pub enum ArenaMap {
     #[default]
     BasicArena,
     PillaredArena,
+    /// Small circular ring used by the Duel preset
+    DuelRing,
 }
 
 impl ArenaMap {
     /// Get all available maps
     pub fn all() -> &'static [ArenaMap] {
-        &[ArenaMap::BasicArena, ArenaMap::PillaredArena]
+        &[ArenaMap::BasicArena, ArenaMap::PillaredArena, ArenaMap::DuelRing]
     }
 
     /// Get the display name
@@ -377,6 +430,7 @@ impl ArenaMap {
         match self {
             ArenaMap::BasicArena => "Basic Arena",
             ArenaMap::PillaredArena => "Pillared Arena",
+            ArenaMap::DuelRing => "Duel Ring",
         }
     }
 
@@ -385,16 +439,113 @@ impl ArenaMap {
         match self {
             ArenaMap::BasicArena => "Simple rectangular arena",
             ArenaMap::PillaredArena => "Arena with pillars for cover",
+            ArenaMap::DuelRing => "Small circular ring for 1v1 duels",
+        }
+    }
+
+    /// Radius of the circular play area, or None for the full octagonal arena.
+    /// Combatants are confined to this radius after movement each frame.
+    pub fn ring_radius(&self) -> Option<f32> {
+        match self {
+            ArenaMap::DuelRing => Some(DUEL_RING_RADIUS),
+            ArenaMap::BasicArena | ArenaMap::PillaredArena => None,
+        }
+    }
+
+    /// Lighting/weather used unless `MatchConfig::lighting` overrides it
+    pub fn default_lighting(&self) -> ArenaLighting {
+        match self {
+            ArenaMap::BasicArena => ArenaLighting::Day,
+            ArenaMap::PillaredArena => ArenaLighting::Dusk,
+            ArenaMap::DuelRing => ArenaLighting::NightTorches,
+        }
+    }
+
+    /// Distance from the arena centre (along X) at which each team spawns.
+    /// Team 1 spawns at `-spawn_x`, team 2 at `+spawn_x`.
+    pub fn spawn_x(&self) -> f32 {
+        match self.ring_radius() {
+            // Inside the ring, leaving room for the gate bars in front
+            Some(radius) => radius - 3.0,
+            None => 35.0,
+        }
+    }
+}
+
+/// Lighting and weather preset for the arena. Purely visual; see
+/// `play_match::arena_lighting` for the light and fog values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArenaLighting {
+    /// Warm midday sun
+    #[default]
+    Day,
+    /// Low orange sun and long shadows
+    Dusk,
+    /// Dim moonlight with torches along the walls
+    NightTorches,
+    /// Hazy sun and sandy fog
+    Sandstorm,
+}
+
+impl ArenaLighting {
+    /// Get all presets
+    pub fn all() -> &'static [ArenaLighting] {
+        &[ArenaLighting::Day, ArenaLighting::Dusk, ArenaLighting::NightTorches, ArenaLighting::Sandstorm]
+    }
+
+    /// Get the display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArenaLighting::Day => "Day",
+            ArenaLighting::Dusk => "Dusk",
+            ArenaLighting::NightTorches => "Night (Torches)",
+            ArenaLighting::Sandstorm => "Sandstorm",
+        }
+    }
+}
+
+/// Match mode preset: the regular team arena, a 1v1 duel, or a gauntlet run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MatchMode {
+    #[default]
+    Standard,
+    /// 1v1 in the Duel Ring with a shorter countdown. Results feed the
+    /// per-class duel ladder instead of being discarded.
+    Duel,
+    /// Team 1 fights round after round against fresh random enemy teams,
+    /// carrying HP, mana and cooldowns between rounds until it loses.
+    Gauntlet,
+}
+
+impl MatchMode {
+    /// Get the display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            MatchMode::Standard => "Standard",
+            MatchMode::Duel => "Duel",
+            MatchMode::Gauntlet => "Gauntlet",
+        }
+    }
+
+    /// Pre-combat countdown length in seconds
+    pub fn countdown_secs(&self) -> f32 {
+        match self {
+            MatchMode::Standard | MatchMode::Gauntlet => 10.0,
+            MatchMode::Duel => 5.0,
         }
     }
 }
 
+/// Largest supported team size (5v5). Pet slots start at `PET_SLOT_BASE`
+/// (10), so this must stay below it.
+pub const MAX_TEAM_SIZE: usize = 5;
+
 /// The match configuration resource
 #[derive(Resource, Debug, Clone)]
 pub struct MatchConfig {
-    /// Team 1 size (1-3)
+    /// Team 1 size (1-MAX_TEAM_SIZE)
     pub team1_size: usize,
-    /// Team 2 size (1-3)
+    /// Team 2 size (1-MAX_TEAM_SIZE)
     pub team2_size: usize,
     /// Characters assigned to Team 1 slots
     pub team1: Vec<Option<CharacterClass>>,
@@ -402,6 +553,8 @@ pub struct MatchConfig {
     pub team2: Vec<Option<CharacterClass>>,
     /// Selected map
     pub map: ArenaMap,
+    /// Standard team match or 1v1 duel
+    pub mode: MatchMode,
     /// Team 1's kill target priority (index into enemy team, None = no priority)
     pub team1_kill_target: Option<usize>,
     /// Team 2's kill target priority (index into enemy team, None = no priority)
@@ -439,10 +592,19 @@ pub struct MatchConfig {
     pub team1_paladin_auras: Vec<PaladinAura>,
     /// Team 2's paladin aura preferences (one per slot, defaults to DevotionAura)
     pub team2_paladin_auras: Vec<PaladinAura>,
+    /// Team 1's AI personalities (one per slot, defaults to Balanced)
+    pub team1_personalities: Vec<Personality>,
+    /// Team 2's AI personalities (one per slot, defaults to Balanced)
+    pub team2_personalities: Vec<Personality>,
     /// Team 1's equipment overrides per slot (one HashMap per team slot)
     pub team1_equipment: Vec<HashMap<ItemSlot, ItemId>>,
     /// Team 2's equipment overrides per slot (one HashMap per team slot)
     pub team2_equipment: Vec<HashMap<ItemSlot, ItemId>>,
+    /// Seed for the arena's cosmetic dressing (floor tint, banners, props).
+    /// None rolls a fresh look each match. Never affects simulation.
+    pub arena_seed: Option<u64>,
+    /// Lighting/weather override; None uses the map's default lighting
+    pub lighting: Option<ArenaLighting>,
 }
 
 impl Default for MatchConfig {
@@ -453,6 +615,7 @@ impl Default for MatchConfig {
             team1: vec![None],
             team2: vec![None],
             map: ArenaMap::BasicArena,
+            mode: MatchMode::Standard,
             team1_kill_target: Some(0), // Default to enemy slot 0 — opt out by clicking again
             team2_kill_target: Some(0), // Default to enemy slot 0 — opt out by clicking again
             team1_cc_target: None,   // Use heuristics by default
@@ -472,16 +635,68 @@ impl Default for MatchConfig {
             team2_mage_armors: vec![MageArmor::default()],
             team1_paladin_auras: vec![PaladinAura::default()],
             team2_paladin_auras: vec![PaladinAura::default()],
+            team1_personalities: vec![Personality::default()],
+            team2_personalities: vec![Personality::default()],
             team1_equipment: vec![HashMap::new()],
             team2_equipment: vec![HashMap::new()],
+            arena_seed: None,
+            lighting: None,
         }
     }
 }
 
 impl MatchConfig {
+    /// Stable fingerprint of the match setup for `MatchMetadata`. Equipment
+    /// overrides are listed in sorted order so HashMap iteration order
+    /// doesn't change the hash.
+    pub fn fingerprint(&self) -> String {
+        let sorted_equipment = |teams: &[HashMap<ItemSlot, ItemId>]| -> Vec<Vec<String>> {
+            teams
+                .iter()
+                .map(|slot| {
+                    let mut overrides: Vec<String> =
+                        slot.iter().map(|(item_slot, item)| format!("{:?}={:?}", item_slot, item)).collect();
+                    overrides.sort();
+                    overrides
+                })
+                .collect()
+        };
+        let setup = MatchConfig {
+            team1_equipment: Vec::new(),
+            team2_equipment: Vec::new(),
+            ..self.clone()
+        };
+        let canonical = format!(
+            "{:?} {:?} {:?}",
+            setup,
+            sorted_equipment(&self.team1_equipment),
+            sorted_equipment(&self.team2_equipment)
+        );
+        crate::combat::metadata::fingerprint(canonical.as_bytes())
+    }
+
+    /// Duel preset: 1v1 in the Duel Ring with the shorter duel countdown.
+    /// Class slots start empty, like the default config.
+    pub fn duel() -> Self {
+        Self {
+            map: ArenaMap::DuelRing,
+            mode: MatchMode::Duel,
+            ..Self::default()
+        }
+    }
+
+    /// Gauntlet preset: a standard arena setup whose team 2 is only the
+    /// round-1 opponent; later rounds roll fresh enemy teams of the same size.
+    pub fn gauntlet() -> Self {
+        Self {
+            mode: MatchMode::Gauntlet,
+            ..Self::default()
+        }
+    }
+
     /// Set team 1 size, adjusting the slots vector
     pub fn set_team1_size(&mut self, size: usize) {
-        let size = size.clamp(1, 3);
+        let size = size.clamp(1, MAX_TEAM_SIZE);
         self.team1_size = size;
         self.team1.resize(size, None);
         // Clamp opponent's kill_target if the index is now out of bounds for our team.
@@ -501,12 +716,13 @@ impl MatchConfig {
         self.team1_warrior_shouts.resize(size, WarriorShout::default());
         self.team1_mage_armors.resize(size, MageArmor::default());
         self.team1_paladin_auras.resize(size, PaladinAura::default());
+        self.team1_personalities.resize(size, Personality::default());
         self.team1_equipment.resize(size, HashMap::new());
     }
 
     /// Set team 2 size, adjusting the slots vector
     pub fn set_team2_size(&mut self, size: usize) {
-        let size = size.clamp(1, 3);
+        let size = size.clamp(1, MAX_TEAM_SIZE);
         self.team2_size = size;
         self.team2.resize(size, None);
         // Clamp opponent's kill_target if the index is now out of bounds for our team.
@@ -530,6 +746,7 @@ impl MatchConfig {
         self.team2_warrior_shouts.resize(size, WarriorShout::default());
         self.team2_mage_armors.resize(size, MageArmor::default());
         self.team2_paladin_auras.resize(size, PaladinAura::default());
+        self.team2_personalities.resize(size, Personality::default());
         self.team2_equipment.resize(size, HashMap::new());
     }
 
@@ -11,6 +11,9 @@ pub mod play_match;
 pub mod results_ui;
 pub mod view_combatant_ui;
 pub mod armory_ui;
+pub mod duel_ladder;
+pub mod gauntlet;
+pub mod spectator_queue;
 
 pub use match_config::MatchConfig;
 
@@ -30,6 +33,9 @@ pub enum GameState {
     ViewCombatant,
     /// Active match - the autobattle simulation
     PlayMatch,
+    /// Transient: passed through for one frame when a match restarts, so
+    /// PlayMatch's OnExit/OnEnter run (Bevy skips them for PlayMatch → PlayMatch)
+    RestartMatch,
     /// Post-match results - statistics and breakdown
     Results,
     /// Armory - browse all equipment in the game
@@ -44,11 +50,38 @@ pub struct StatesPlugin;
 impl Plugin for StatesPlugin {
     fn build(&self, app: &mut App) {
         app
+            // Occluded-combatant silhouettes; no prepass or shadows needed
+            .add_plugins(MaterialPlugin::<play_match::OccludedSilhouetteMaterial> {
+                prepass_enabled: false,
+                shadows_enabled: false,
+                ..default()
+            })
             // Initialize match config resource
             .init_resource::<MatchConfig>()
+            .init_resource::<spectator_queue::SpectatorQueue>()
+            .init_resource::<results_ui::ResultsShareCode>()
+            // Per-class duel ladder, persisted to duel_ladder.ron
+            .insert_resource(duel_ladder::DuelLadder::load())
             // Initialize class icon resources
             .init_resource::<configure_match_ui::ClassIcons>()
             .init_resource::<configure_match_ui::ClassIconHandles>()
+            .init_resource::<play_match::ClassPortraits>()
+            .init_resource::<play_match::SandboxConsole>()
+            .init_resource::<play_match::MatchBranch>()
+            .init_resource::<play_match::DiagnosticsOverlay>()
+            .init_resource::<play_match::OverlayCullStats>()
+            .init_resource::<play_match::AiPlanOverlay>()
+            .init_resource::<play_match::PresentationMode>()
+            .init_resource::<play_match::CameraPathPlayer>()
+            // Match commentary: templates from commentary.ron, per-match ticker feed
+            .insert_resource(
+                play_match::load_commentary_templates()
+                    .unwrap_or_else(|e| panic!("Failed to load commentary templates: {}", e)),
+            )
+            .init_resource::<play_match::CommentaryFeed>()
+            .init_resource::<play_match::EventFeed>()
+            .init_resource::<play_match::WinProbability>()
+            .init_resource::<play_match::AlertState>()
             // Initialize ability icon resources for view combatant screen
             .init_resource::<view_combatant_ui::AbilityIcons>()
             .init_resource::<view_combatant_ui::AbilityIconHandles>()
@@ -81,6 +114,8 @@ impl Plugin for StatesPlugin {
                 Update,
                 (
                     configure_match_ui::load_class_icons,
+                    // Queue strip is a bottom panel: lay it out before the CentralPanel
+                    spectator_queue::spectator_queue_setup_panel,
                     configure_match_ui::configure_match_ui,
                 )
                     .chain()
@@ -111,7 +146,32 @@ impl Plugin for StatesPlugin {
                     .run_if(in_state(GameState::Armory)),
             )
             // Play match systems (defined in play_match module)
-            .add_systems(OnEnter(GameState::PlayMatch), play_match::setup_play_match);
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::setup_play_match)
+            // Gauntlet: carried HP/mana/cooldowns overwrite the fresh spawn
+            .add_systems(
+                OnEnter(GameState::PlayMatch),
+                gauntlet::apply_gauntlet_carryover.after(play_match::setup_play_match),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), gauntlet::end_gauntlet_run)
+            // Spectator queue: queued matches run at the chosen speed
+            .add_systems(
+                OnEnter(GameState::PlayMatch),
+                spectator_queue::apply_queue_speed.after(play_match::setup_play_match),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), spectator_queue::end_spectator_queue)
+            // Class portraits: rendered once per class, stages retire in any state
+            .add_systems(
+                OnEnter(GameState::PlayMatch),
+                play_match::spawn_class_portraits.after(play_match::setup_play_match),
+            )
+            .add_systems(Update, play_match::finish_class_portraits)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_diagnostics_overlay)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_ai_plan_overlay)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_commentary_feed)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_event_feed)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_win_probability)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::reset_alert_state)
+            .add_systems(OnEnter(GameState::PlayMatch), play_match::start_camera_path);
 
         // Configure combat system phase ordering and add core combat systems
         // These are shared between graphical and headless modes
@@ -124,7 +184,10 @@ impl Plugin for StatesPlugin {
                 Update,
                 (
                     play_match::handle_time_controls,
+                    play_match::handle_branch_hotkey,
+                    play_match::handle_restart_hotkeys,
                     play_match::handle_camera_input,
+                    play_match::handle_observer_hotkeys,
                     // pick_selected_combatant consumes the pending_pick flag set
                     // by handle_camera_input on click-release; must run after it.
                     play_match::pick_selected_combatant,
@@ -132,6 +195,11 @@ impl Plugin for StatesPlugin {
                     // Selection resource changes — runs after picking.
                     play_match::sync_selection_ring,
                     play_match::update_camera_position,
+                    // Camera path playback overrides the camera mode, so it
+                    // runs after update_camera_position.
+                    play_match::handle_camera_path_hotkeys,
+                    play_match::apply_camera_path,
+                    play_match::record_camera_path,
                     play_match::animate_gate_bars,
                     play_match::update_play_match,
                 )
@@ -149,6 +217,15 @@ impl Plugin for StatesPlugin {
                     .before(play_match::move_projectiles)
                     .run_if(in_state(GameState::PlayMatch)),
             )
+            // Graphical-only: the AI plan overlay reads this frame's decision
+            // events before the trace flush drains them
+            .add_systems(
+                Update,
+                play_match::record_ai_plans
+                    .in_set(CombatSystemPhase::CombatResolution)
+                    .before(play_match::decision_trace::flush_decision_trace_system)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
             // Combat resolution, death, and visual effects (after core combat)
             .add_systems(
                 Update,
@@ -283,6 +360,54 @@ impl Plugin for StatesPlugin {
                     .after(CombatSystemPhase::CombatResolution)
                     .run_if(in_state(GameState::PlayMatch)),
             )
+            // Ability ground decals (e.g. Immolate scorch), from the ability's
+            // `visuals.ground_decal` descriptor; marker spawned in core like FCT.
+            .add_systems(
+                Update,
+                (
+                    play_match::spawn_ground_decal_visuals,   // Flat disc for new decals
+                    play_match::update_ground_decals,         // Fade over lifetime
+                    play_match::cleanup_expired_ground_decals, // Despawn when expired
+                )
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            // Commentary: narrate this frame's combat log entries for the ticker
+            .add_systems(
+                Update,
+                play_match::update_commentary_feed
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            // Accessible event feed: plain sentences for major events, paced for reading
+            .add_systems(
+                Update,
+                play_match::update_event_feed
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            .add_systems(
+                Update,
+                play_match::render_event_feed.run_if(in_state(GameState::PlayMatch)),
+            )
+            // Live win probability: odds bar in-match, momentum graph on Results
+            .add_systems(
+                Update,
+                play_match::update_win_probability
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            .add_systems(
+                Update,
+                play_match::render_win_odds_bar.run_if(in_state(GameState::PlayMatch)),
+            )
+            // Spectator alerts: low health, healer CC and deaths on the followed team
+            .add_systems(
+                Update,
+                play_match::detect_match_alerts
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
             // Drain Life beam visual effects (separate group to avoid tuple size limits)
             .add_systems(
                 Update,
@@ -290,6 +415,7 @@ impl Plugin for StatesPlugin {
                     play_match::spawn_drain_life_beams,     // Spawn beam when Drain Life starts
                     play_match::update_drain_life_beams,    // Update beam position/rotation
                     play_match::spawn_drain_particles,      // Spawn particles along beam
+                    play_match::spawn_drain_visuals,        // Shared meshes for new particles
                     play_match::update_drain_particles,     // Move particles toward caster
                     play_match::cleanup_drain_life_beams,   // Remove beam when channel ends
                 )
@@ -348,11 +474,22 @@ impl Plugin for StatesPlugin {
             .add_systems(
                 Update,
                 (
+                    play_match::toggle_presentation_mode,
+                    play_match::render_presentation_labels,
                     play_match::render_time_controls,
                     play_match::render_camera_controls,
+                    play_match::render_camera_path_status,
                     play_match::render_countdown,
                     play_match::render_victory_celebration,
                     play_match::render_health_bars,
+                    play_match::render_kill_feed,
+                    play_match::render_commentary_ticker,
+                    play_match::render_alert_vignette,
+                    play_match::render_sandbox_console,
+                    play_match::render_branch_banner,
+                    play_match::render_pause_menu,
+                    play_match::render_diagnostics_overlay,
+                    play_match::render_ai_plan_overlay,
                     play_match::render_floating_combat_text,
                     play_match::render_speech_bubbles,
                     play_match::render_combat_panel,
@@ -369,16 +506,81 @@ impl Plugin for StatesPlugin {
                     .after(CombatSystemPhase::CombatResolution)
                     .run_if(in_state(GameState::PlayMatch)),
             )
+            // Silhouettes follow the combatant's mesh (Polymorph) and death
+            .add_systems(
+                Update,
+                (
+                    play_match::attach_occluded_silhouettes,
+                    play_match::sync_occluded_silhouettes,
+                )
+                    .chain()
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            // Combat log click-to-jump: camera move + participant rings
+            .add_systems(
+                Update,
+                (
+                    play_match::apply_combat_log_focus,
+                    play_match::update_log_focus_rings,
+                )
+                    .chain()
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            // Aura source ring/beam: same timing as the selection ring
+            .add_systems(
+                Update,
+                (
+                    play_match::sync_aura_source_highlight,
+                    play_match::follow_aura_source_highlight,
+                )
+                    .chain()
+                    .after(CombatSystemPhase::CombatResolution)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
             .add_systems(
                 OnExit(GameState::PlayMatch),
                 play_match::reset_selection_on_exit,
             )
+            // Gauntlet survivors must be captured before cleanup despawns them
+            .add_systems(
+                OnExit(GameState::PlayMatch),
+                gauntlet::capture_gauntlet_round.before(play_match::cleanup_play_match),
+            )
             .add_systems(OnExit(GameState::PlayMatch), play_match::cleanup_play_match)
+            .add_systems(OnExit(GameState::PlayMatch), play_match::close_sandbox_console)
+            .add_systems(OnExit(GameState::PlayMatch), play_match::discard_match_branch)
+            .add_systems(OnExit(GameState::PlayMatch), play_match::finish_camera_path_recording)
+            // Restarts bounce through RestartMatch so OnExit/OnEnter(PlayMatch) run
+            .add_systems(OnEnter(GameState::RestartMatch), play_match::resume_restarted_match)
+            // Rate finished Duel-mode matches before the Results screen draws
+            .add_systems(OnEnter(GameState::Results), duel_ladder::record_duel_result)
+            .add_systems(OnEnter(GameState::Results), spectator_queue::record_queue_result)
+            .add_systems(OnEnter(GameState::Results), results_ui::capture_results_share_code)
             // Results systems (defined in results_ui module)
             .add_systems(
                 Update,
-                results_ui::results_ui.run_if(in_state(GameState::Results)),
+                (
+                    // Bottom panel must be laid out before the Results CentralPanel
+                    gauntlet::gauntlet_results_panel,
+                    spectator_queue::spectator_queue_results_panel,
+                    results_ui::results_ui,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Results)),
             );
+
+        // Spoken commentary (optional `tts` feature)
+        #[cfg(feature = "tts")]
+        app.init_resource::<play_match::CommentarySpeech>()
+            .add_systems(
+                Update,
+                play_match::speak_commentary
+                    .after(play_match::update_commentary_feed)
+                    .run_if(in_state(GameState::PlayMatch)),
+            )
+            .add_systems(OnExit(GameState::PlayMatch), play_match::stop_commentary_speech);
     }
 }
 
@@ -389,6 +591,7 @@ impl Plugin for StatesPlugin {
 fn main_menu_ui(
     mut contexts: EguiContexts,
     mut next_state: ResMut<NextState<GameState>>,
+    mut config: ResMut<MatchConfig>,
     mut commands: Commands,
     primary_window: Query<Entity, With<bevy::window::PrimaryWindow>>,
 ) {
@@ -406,7 +609,7 @@ fn main_menu_ui(
         .frame(egui::Frame::none().fill(egui::Color32::from_rgb(20, 20, 30)))
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
-                ui.add_space(150.0);
+                ui.add_space(90.0); // Leaves room for six buttons at 720p
 
                 // Title
                 ui.heading(
@@ -424,7 +627,7 @@ fn main_menu_ui(
                         .color(egui::Color32::from_rgb(153, 140, 128)),
                 );
 
-                ui.add_space(60.0);
+                ui.add_space(40.0);
 
                 // Menu buttons
                 let button_size = egui::vec2(280.0, 60.0);
@@ -441,6 +644,51 @@ fn main_menu_ui(
                     .clicked()
                 {
                     info!("Match button pressed - transitioning to ConfigureMatch");
+                    // Leaving a duel/gauntlet setup: start from a fresh standard match
+                    if config.mode != match_config::MatchMode::Standard {
+                        *config = MatchConfig::default();
+                    }
+                    next_state.set(GameState::ConfigureMatch);
+                }
+
+                ui.add_space(10.0);
+
+                if ui
+                    .add_sized(
+                        button_size,
+                        egui::Button::new(
+                            egui::RichText::new("DUEL")
+                                .size(28.0)
+                                .color(egui::Color32::from_rgb(230, 217, 191)),
+                        ),
+                    )
+                    .clicked()
+                {
+                    info!("Duel button pressed - transitioning to ConfigureMatch (duel preset)");
+                    // Keep the previous duel's picks for quick rematches
+                    if config.mode != match_config::MatchMode::Duel {
+                        *config = MatchConfig::duel();
+                    }
+                    next_state.set(GameState::ConfigureMatch);
+                }
+
+                ui.add_space(10.0);
+
+                if ui
+                    .add_sized(
+                        button_size,
+                        egui::Button::new(
+                            egui::RichText::new("GAUNTLET")
+                                .size(28.0)
+                                .color(egui::Color32::from_rgb(230, 217, 191)),
+                        ),
+                    )
+                    .clicked()
+                {
+                    info!("Gauntlet button pressed - transitioning to ConfigureMatch (gauntlet preset)");
+                    if config.mode != match_config::MatchMode::Gauntlet {
+                        *config = MatchConfig::gauntlet();
+                    }
                     next_state.set(GameState::ConfigureMatch);
                 }
 
@@ -765,6 +1013,326 @@ fn options_ui(
 
                         ui.add_space(20.0);
 
+                        // Combat log tick merging
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Merge Periodic Ticks")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                let merge_label = if settings.merge_periodic_ticks { "On" } else { "Off" };
+                                if ui.checkbox(&mut settings.merge_periodic_ticks, egui::RichText::new(merge_label).size(18.0)).changed() {
+                                    info!("Merge Periodic Ticks toggled to: {}", settings.merge_periodic_ticks);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("One combat log row per DoT/HoT run (count and total) • Toggle in the Combat Log tab")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Live win odds bar
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Win Odds Bar")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                let odds_label = if settings.show_win_odds { "On" } else { "Off" };
+                                if ui.checkbox(&mut settings.show_win_odds, egui::RichText::new(odds_label).size(18.0)).changed() {
+                                    info!("Win Odds Bar toggled to: {}", settings.show_win_odds);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("Live win chance and betting odds at the top of the match • Toggle in-match in Time Controls")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Plain arena (benchmarking)
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Plain Arena")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                let plain_label = if settings.plain_arena { "On" } else { "Off" };
+                                if ui.checkbox(&mut settings.plain_arena, egui::RichText::new(plain_label).size(18.0)).changed() {
+                                    info!("Plain Arena toggled to: {}", settings.plain_arena);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("No seeded floor tint, banners or props • Fixed look for benchmarking")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Per-map lighting/weather presets
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Lighting Presets")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                let mut presets = !settings.ignore_lighting_presets;
+                                let presets_label = if presets { "On" } else { "Off" };
+                                if ui.checkbox(&mut presets, egui::RichText::new(presets_label).size(18.0)).changed() {
+                                    settings.ignore_lighting_presets = !presets;
+                                    info!("Lighting Presets toggled to: {}", presets);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("Dusk, night torches and sandstorm fog per map • Off uses plain daylight (faster)")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Team colors
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            let team_colors = &mut settings.team_colors;
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Team Colors")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                egui::ComboBox::from_id_salt("team_palette")
+                                    .selected_text(team_colors.palette.as_str())
+                                    .show_ui(ui, |ui| {
+                                        for palette in crate::settings::TeamPalette::all() {
+                                            ui.selectable_value(&mut team_colors.palette, palette, palette.as_str());
+                                        }
+                                    });
+                            });
+
+                            ui.add_space(5.0);
+
+                            // Preview swatches; editable for the Custom palette
+                            let preview = crate::settings::TeamColors::from_settings(team_colors);
+                            ui.horizontal(|ui| {
+                                for team in [1, 2] {
+                                    ui.label(egui::RichText::new(format!("Team {}", team)).size(18.0).color(preview.egui(team)));
+                                    if team_colors.palette == crate::settings::TeamPalette::Custom {
+                                        ui.color_edit_button_srgb(&mut team_colors.custom[team as usize - 1]);
+                                    }
+                                    ui.add_space(20.0);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("Arena banners, nameplates, HUD, timeline and Results • Colorblind Safe avoids red/green")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Spectator alerts
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            let alerts = &mut settings.alerts;
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Match Alerts")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                ui.checkbox(&mut alerts.vignette, egui::RichText::new("Vignette").size(18.0));
+                                ui.checkbox(&mut alerts.sound, egui::RichText::new("Sound").size(18.0));
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("Volume").size(18.0));
+                                ui.add(egui::Slider::new(&mut alerts.volume, 0.0..=1.0).show_value(false));
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("Low health, healer CC and deaths on the followed team (both teams if none)")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Accessible event feed
+                        ui.group(|ui| {
+                            ui.set_min_width(580.0);
+                            ui.add_space(10.0);
+
+                            let feed = &mut settings.event_feed;
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new("Event Feed")
+                                        .size(24.0)
+                                        .color(egui::Color32::from_rgb(230, 204, 153)),
+                                );
+
+                                ui.add_space(20.0);
+
+                                let feed_label = if feed.enabled { "On" } else { "Off" };
+                                if ui.checkbox(&mut feed.enabled, egui::RichText::new(feed_label).size(18.0)).changed() {
+                                    info!("Event feed toggled to: {}", feed.enabled);
+                                }
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("Text size").size(18.0));
+                                ui.add(egui::Slider::new(&mut feed.text_size, 18.0..=48.0).suffix(" pt"));
+                            });
+
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new("Seconds per line").size(18.0));
+                                ui.add(egui::Slider::new(&mut feed.seconds_per_line, 1.0..=8.0).step_by(0.5));
+                            });
+
+                            ui.add_space(5.0);
+
+                            ui.label(
+                                egui::RichText::new("Major events as plain sentences in large, high-contrast text")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(150, 150, 150)),
+                            );
+
+                            ui.add_space(10.0);
+                        });
+
+                        ui.add_space(20.0);
+
+                        // Spoken commentary (tts builds only)
+                        #[cfg(feature = "tts")]
+                        {
+                            ui.group(|ui| {
+                                ui.set_min_width(580.0);
+                                ui.add_space(10.0);
+
+                                let speech = &mut settings.commentary_speech;
+                                ui.horizontal(|ui| {
+                                    ui.label(
+                                        egui::RichText::new("Spoken Commentary")
+                                            .size(24.0)
+                                            .color(egui::Color32::from_rgb(230, 204, 153)),
+                                    );
+
+                                    ui.add_space(20.0);
+
+                                    let speech_label = if speech.enabled { "On" } else { "Off" };
+                                    if ui.add(
+                                        egui::widgets::Checkbox::new(
+                                            &mut speech.enabled,
+                                            egui::RichText::new(speech_label)
+                                                .size(18.0)
+                                        )
+                                    ).changed() {
+                                        info!("Spoken commentary toggled to: {}", speech.enabled);
+                                    }
+                                });
+
+                                ui.add_space(5.0);
+
+                                ui.horizontal(|ui| {
+                                    ui.label(egui::RichText::new("Volume").size(18.0));
+                                    ui.add(egui::Slider::new(&mut speech.volume, 0.0..=1.0).show_value(false));
+                                });
+
+                                ui.add_space(5.0);
+
+                                ui.label(
+                                    egui::RichText::new("Reads the commentary ticker aloud • Custom TTS command in settings.ron")
+                                        .size(14.0)
+                                        .color(egui::Color32::from_rgb(150, 150, 150)),
+                                );
+
+                                ui.add_space(10.0);
+                            });
+
+                            ui.add_space(20.0);
+                        }
+
                         // Controls / Keybindings button
                         ui.group(|ui| {
                             ui.set_min_width(580.0);
@@ -93,8 +93,14 @@ pub enum AbilityType {
     Rend,
     MortalStrike, // Warrior damage + healing reduction
     Pummel,    // Warrior interrupt
+    Intercept, // Warrior in-combat charge + short stun (shares Charge's cooldown group)
+    Intervene, // Warrior dash to an ally, redirects the next attack on them
+    Execute,   // Warrior finisher - only usable on targets in execute range
     Kick,      // Rogue interrupt
     CripplingPoison, // Rogue weapon poison: on-hit chance to slow (passive, not cast)
+    Evasion,   // Rogue defensive: +dodge chance vs melee
+    Sprint,    // Rogue movement speed burst
+    Vanish,    // Rogue: restealth, shed roots/slows, drop enemy targeting
     // Warlock abilities
     Corruption,     // Shadow DoT
     Shadowbolt,     // Shadow projectile
@@ -106,6 +112,9 @@ pub enum AbilityType {
     CurseOfTongues, // Shadow debuff - increases target cast time
     UnstableAffliction, // Shadow DoT - dispel backlash applies Silence + Shadow damage
     DeathCoil,      // Shadow instant - 3s horror (never breaks) + damage + self-heal, peel cooldown
+    DrainSoul,      // Shadow channel - low damage, ticks on a low-HP target yield Soul Shards
+    Shadowburn,     // Shadow instant execute - costs a Soul Shard
+    Soulstone,      // Self-buff - costs a Soul Shard, resurrects the Warlock once after death
     // Buff abilities
     ArcaneIntellect, // Mage buff - increases max mana
     BattleShout,     // Warrior buff - increases attack power
@@ -146,6 +155,8 @@ pub enum AbilityType {
     FrostArmor,         // Mage self-buff - procs slow on melee attackers
     MageArmorSpell,     // Mage self-buff - increases mana regen
     MoltenArmor,        // Mage self-buff - increases crit chance
+    IceBlock,           // Mage self-immunity - encased (can't move/act), cancelable early
+    ColdSnap,           // Mage - resets all Frost cooldowns (once per match)
     ShadowResistanceAura, // Paladin team aura - shadow resistance
     ConcentrationAura,  // Paladin team aura - reduces interrupt lockout duration
     // Shaman abilities
@@ -67,15 +67,104 @@ pub struct AuraEffect {
     pub dispel_type: DispelType,
 }
 
-/// Projectile visual configuration.
-///
-/// Defines the colors for projectile spells.
+/// Mesh shape of a projectile.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ProjectileShape {
+    /// Glowing sphere of the given radius (caster bolts)
+    Orb(f32),
+    /// Elongated cuboid of the given (width, length), long axis along the
+    /// flight direction (arrows, webs)
+    Bolt(f32, f32),
+}
+
+impl Default for ProjectileShape {
+    fn default() -> Self {
+        ProjectileShape::Orb(0.3)
+    }
+}
+
+/// Projectile look: colors and mesh shape.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProjectileVisuals {
     /// Base RGB color (0.0-1.0 range)
     pub color: [f32; 3],
     /// Emissive/glow RGB color (can exceed 1.0 for glow effect)
     pub emissive: [f32; 3],
+    /// Mesh shape (default: 0.3 radius orb)
+    #[serde(default)]
+    pub shape: ProjectileShape,
+}
+
+/// Effect shown at the target when the ability lands.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ImpactVisual {
+    /// Expanding, fading sphere (Mind Blast)
+    Burst {
+        color: [f32; 3],
+        emissive: [f32; 3],
+        /// Seconds until fully faded
+        duration: f32,
+        /// Scale at spawn and at the end of the effect
+        start_scale: f32,
+        end_scale: f32,
+    },
+    /// Burst in the ability's spell school colors with default timing
+    Generic,
+    /// Rising flame particles around the target (Immolate). The count is
+    /// rolled from the match RNG, so it is part of the deterministic sim.
+    Flames { count_min: u32, count_max: u32 },
+}
+
+/// Beam drawn from caster to target while the ability is channeled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BeamVisual {
+    pub color: [f32; 3],
+    pub emissive: [f32; 3],
+    /// Beam radius in world units
+    #[serde(default = "default_beam_width")]
+    pub width: f32,
+    /// Stream particles along the beam from target to caster (Drain Life)
+    #[serde(default)]
+    pub particles: bool,
+}
+
+/// Flat disc on the ground under the target when the ability lands.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GroundDecalVisual {
+    pub color: [f32; 3],
+    /// Disc radius in world units
+    pub radius: f32,
+    /// Seconds until fully faded
+    pub duration: f32,
+}
+
+/// Declarative visuals for an ability. Every part is optional: abilities
+/// without a projectile look get a spell-school colored orb, and impacts,
+/// beams and decals only appear when configured.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AbilityVisuals {
+    #[serde(default)]
+    pub projectile: Option<ProjectileVisuals>,
+    #[serde(default)]
+    pub impact: Option<ImpactVisual>,
+    #[serde(default)]
+    pub beam: Option<BeamVisual>,
+    #[serde(default)]
+    pub ground_decal: Option<GroundDecalVisual>,
+}
+
+/// Fallback (color, emissive) for a spell school, used for unconfigured
+/// projectiles and `ImpactVisual::Generic`.
+pub fn school_visual_colors(school: SpellSchool) -> ([f32; 3], [f32; 3]) {
+    match school {
+        SpellSchool::Frost => ([0.4, 0.7, 1.0], [0.6, 0.9, 1.5]),
+        SpellSchool::Shadow => ([0.6, 0.3, 0.8], [0.8, 0.4, 1.2]),
+        SpellSchool::Fire => ([1.0, 0.45, 0.1], [2.0, 0.8, 0.1]),
+        SpellSchool::Nature => ([0.4, 0.9, 0.3], [0.6, 1.6, 0.4]),
+        SpellSchool::Arcane => ([0.8, 0.5, 1.0], [1.2, 0.8, 1.6]),
+        SpellSchool::Holy => ([1.0, 0.95, 0.7], [1.8, 1.6, 0.9]),
+        _ => ([1.0, 0.8, 0.3], [1.2, 1.0, 0.5]),
+    }
 }
 
 /// Complete ability configuration loaded from RON.
@@ -83,7 +172,7 @@ pub struct ProjectileVisuals {
 /// This struct mirrors `AbilityDefinition` but with:
 /// - Named struct for aura effects instead of tuple
 /// - Additional fields for special behavior flags
-/// - Projectile visual configuration
+/// - Visual descriptor (projectile, impact, beam, ground decal)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AbilityConfig {
     /// Display name of the ability
@@ -112,6 +201,10 @@ pub struct AbilityConfig {
     /// Cooldown after cast in seconds
     #[serde(default)]
     pub cooldown: f32,
+    /// Shared cooldown group (e.g. "Charge" for Charge/Intercept). Using any
+    /// ability in a group puts every member on that ability's cooldown.
+    #[serde(default)]
+    pub cooldown_group: Option<String>,
 
     // === Damage ===
     /// Base minimum damage (before stat scaling)
@@ -150,9 +243,10 @@ pub struct AbilityConfig {
     /// Projectile travel speed in units/second (None = instant effect)
     #[serde(default)]
     pub projectile_speed: Option<f32>,
-    /// Projectile visual colors (if projectile_speed is Some)
+    /// Visual descriptor: projectile look, impact effect, channel beam and
+    /// ground decal
     #[serde(default)]
-    pub projectile_visuals: Option<ProjectileVisuals>,
+    pub visuals: AbilityVisuals,
 
     // === Spell School & Interrupts ===
     /// Spell school (determines lockout when interrupted)
@@ -172,9 +266,10 @@ pub struct AbilityConfig {
     /// This is a charge/gap-closer ability (Charge)
     #[serde(default)]
     pub is_charge: bool,
-    /// Spawn visual impact effect on hit (Mind Blast)
+    /// Only usable on targets in execute range, below `EXECUTE_HP_THRESHOLD`
+    /// (Execute, Shadowburn)
     #[serde(default)]
-    pub spawn_impact_effect: bool,
+    pub is_execute: bool,
 
     // === Channeling ===
     /// Duration of channel in seconds (None = not a channeled spell)
@@ -234,6 +329,10 @@ fn default_channel_tick_interval() -> f32 {
     1.0
 }
 
+fn default_beam_width() -> f32 {
+    0.15
+}
+
 impl AbilityConfig {
     /// Returns true if this is a damage ability
     pub fn is_damage(&self) -> bool {
@@ -249,6 +348,14 @@ impl AbilityConfig {
     pub fn is_channel(&self) -> bool {
         self.channel_duration.is_some()
     }
+
+    /// Projectile look, falling back to a spell-school colored orb
+    pub fn projectile_visuals(&self) -> ProjectileVisuals {
+        self.visuals.projectile.clone().unwrap_or_else(|| {
+            let (color, emissive) = school_visual_colors(self.spell_school);
+            ProjectileVisuals { color, emissive, shape: ProjectileShape::default() }
+        })
+    }
 }
 
 /// Root structure for the abilities.ron file
@@ -288,6 +395,12 @@ impl AbilityDefinitions {
         self.definitions.get(ability)
     }
 
+    /// Mutable access to an ability's configuration. Only for tooling that
+    /// runs matches against modified numbers (the `tune` auto-balancer).
+    pub fn get_mut(&mut self, ability: &AbilityType) -> Option<&mut AbilityConfig> {
+        self.definitions.get_mut(ability)
+    }
+
     /// Get the configuration for an ability type, panicking if not found.
     /// Use this when you know the ability must exist (validated at startup).
     pub fn get_unchecked(&self, ability: &AbilityType) -> &AbilityConfig {
@@ -313,8 +426,14 @@ impl AbilityDefinitions {
             AbilityType::Rend,
             AbilityType::MortalStrike,
             AbilityType::Pummel,
+            AbilityType::Intercept,
+            AbilityType::Intervene,
+            AbilityType::Execute,
             AbilityType::Kick,
             AbilityType::CripplingPoison,
+            AbilityType::Evasion,
+            AbilityType::Sprint,
+            AbilityType::Vanish,
             AbilityType::Corruption,
             AbilityType::Shadowbolt,
             AbilityType::Fear,
@@ -325,6 +444,9 @@ impl AbilityDefinitions {
             AbilityType::CurseOfTongues,
             AbilityType::UnstableAffliction,
             AbilityType::DeathCoil,
+            AbilityType::DrainSoul,
+            AbilityType::Shadowburn,
+            AbilityType::Soulstone,
             AbilityType::ArcaneIntellect,
             AbilityType::BattleShout,
             AbilityType::IceBarrier,
@@ -360,6 +482,8 @@ impl AbilityDefinitions {
             AbilityType::FrostArmor,
             AbilityType::MageArmorSpell,
             AbilityType::MoltenArmor,
+            AbilityType::IceBlock,
+            AbilityType::ColdSnap,
             AbilityType::ShadowResistanceAura,
             AbilityType::ConcentrationAura,
             // Shaman abilities
@@ -386,6 +510,23 @@ impl AbilityDefinitions {
         }
     }
 
+    /// Every ability sharing `ability`'s cooldown group, `ability` itself
+    /// included, in `AbilityType` order. Ungrouped abilities return just
+    /// themselves.
+    pub fn cooldown_group_members(&self, ability: &AbilityType) -> Vec<AbilityType> {
+        let Some(group) = self.get(ability).and_then(|c| c.cooldown_group.as_ref()) else {
+            return vec![*ability];
+        };
+        let mut members: Vec<AbilityType> = self
+            .definitions
+            .iter()
+            .filter(|(_, c)| c.cooldown_group.as_ref() == Some(group))
+            .map(|(a, _)| *a)
+            .collect();
+        members.sort();
+        members
+    }
+
     /// Get all ability types that are defined
     pub fn ability_types(&self) -> impl Iterator<Item = &AbilityType> {
         self.definitions.keys()
@@ -455,6 +596,7 @@ mod tests {
             min_range: None,
             mana_cost: 0.0,
             cooldown: 0.0,
+            cooldown_group: None,
             damage_base_min: 10.0,
             damage_base_max: 20.0,
             damage_coefficient: 0.5,
@@ -465,13 +607,13 @@ mod tests {
             applies_aura: None,
             application_chance: None,
             projectile_speed: None,
-            projectile_visuals: None,
+            visuals: AbilityVisuals::default(),
             spell_school: SpellSchool::Frost,
             is_interrupt: false,
             lockout_duration: 0.0,
             requires_stealth: false,
             is_charge: false,
-            spawn_impact_effect: false,
+            is_execute: false,
             channel_duration: None,
             channel_tick_interval: 1.0,
             channel_healing_per_tick: 0.0,
@@ -494,6 +636,7 @@ mod tests {
             min_range: None,
             mana_cost: 25.0,
             cooldown: 0.0,
+            cooldown_group: None,
             damage_base_min: 0.0,
             damage_base_max: 0.0,
             damage_coefficient: 0.0,
@@ -504,13 +647,13 @@ mod tests {
             applies_aura: None,
             application_chance: None,
             projectile_speed: None,
-            projectile_visuals: None,
+            visuals: AbilityVisuals::default(),
             spell_school: SpellSchool::Holy,
             is_interrupt: false,
             lockout_duration: 0.0,
             requires_stealth: false,
             is_charge: false,
-            spawn_impact_effect: false,
+            is_execute: false,
             channel_duration: None,
             channel_tick_interval: 1.0,
             channel_healing_per_tick: 0.0,
@@ -522,6 +665,36 @@ mod tests {
         assert!(config.is_heal());
     }
 
+    #[test]
+    fn charge_and_intercept_share_a_cooldown_group() {
+        let ability_defs = load_ability_definitions().expect("abilities.ron must load");
+        let group = vec![AbilityType::Charge, AbilityType::Intercept];
+        assert_eq!(ability_defs.cooldown_group_members(&AbilityType::Charge), group);
+        assert_eq!(ability_defs.cooldown_group_members(&AbilityType::Intercept), group);
+        assert_eq!(
+            ability_defs.cooldown_group_members(&AbilityType::Pummel),
+            vec![AbilityType::Pummel]
+        );
+    }
+
+    #[test]
+    fn visual_descriptors_load_from_config() {
+        let ability_defs = load_ability_definitions().expect("abilities.ron must load");
+
+        // Arrows are bolts, unconfigured projectiles fall back to a school orb
+        let aimed = ability_defs.get_unchecked(&AbilityType::AimedShot).projectile_visuals();
+        assert!(matches!(aimed.shape, ProjectileShape::Bolt(..)));
+        let frostbolt = ability_defs.get_unchecked(&AbilityType::Frostbolt).projectile_visuals();
+        assert!(matches!(frostbolt.shape, ProjectileShape::Orb(_)));
+        assert_eq!(frostbolt.color, school_visual_colors(SpellSchool::Frost).0);
+
+        let immolate = &ability_defs.get_unchecked(&AbilityType::Immolate).visuals;
+        assert!(matches!(immolate.impact, Some(ImpactVisual::Flames { .. })));
+        assert!(immolate.ground_decal.is_some());
+        assert!(ability_defs.get_unchecked(&AbilityType::DrainSoul).visuals.beam.is_some());
+        assert!(ability_defs.get_unchecked(&AbilityType::Frostbolt).visuals.beam.is_none());
+    }
+
     #[test]
     fn all_abilities_have_icons() {
         let ability_defs = load_ability_definitions().expect("abilities.ron must load");
@@ -0,0 +1,243 @@
+//! Arena Dressing
+//!
+//! Seeded cosmetic variation so recorded matches look distinct: a floor tint,
+//! team-colored banners on each team's end wall, and props (crates, barrels,
+//! rocks) scattered outside the walls.
+//!
+//! Purely visual. The layout comes from its own RNG seeded with
+//! `MatchConfig::arena_seed` (a fresh seed each match when unset) and never
+//! touches `GameRng`. Dressing entities carry no gameplay components, and
+//! props are placed outside the walls, so none of it can affect simulation.
+//! Only the graphical setup spawns it; `GameSettings::plain_arena` turns it off
+//! (the fixed default look) for benchmarking.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::settings::TeamColors;
+use super::components::PlayMatchEntity;
+use super::constants::{ARENA_FLOOR_HALF_X, ARENA_FLOOR_HALF_Z};
+
+/// The default sandy floor color, baked into the floor texture
+pub const BASE_FLOOR_COLOR: [f32; 3] = [0.79, 0.66, 0.46];
+/// Maximum per-channel floor tint deviation (fraction of the base color)
+const FLOOR_TINT_VARIATION: f32 = 0.08;
+/// Props keep at least this far outside the wall centerlines
+const PROP_MIN_WALL_CLEARANCE: f32 = 2.5;
+/// Props stay within this far of the wall centerlines
+const PROP_MAX_WALL_DISTANCE: f32 = 14.0;
+/// Range of prop counts
+const PROP_COUNT: std::ops::RangeInclusive<usize> = 24..=36;
+/// Range of banners per team wall
+const BANNERS_PER_WALL: std::ops::RangeInclusive<usize> = 2..=3;
+
+/// Marker for dressing entities (banners and props)
+#[derive(Component)]
+pub struct ArenaProp;
+
+/// Kinds of scattered prop
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropKind {
+    Crate,
+    Barrel,
+    Rock,
+}
+
+impl PropKind {
+    fn color(self) -> Color {
+        match self {
+            PropKind::Crate => Color::srgb(0.55, 0.38, 0.22),
+            PropKind::Barrel => Color::srgb(0.45, 0.28, 0.16),
+            PropKind::Rock => Color::srgb(0.48, 0.45, 0.42),
+        }
+    }
+}
+
+/// One prop outside the walls
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropPlacement {
+    pub kind: PropKind,
+    /// Ground position (x, z)
+    pub position: Vec2,
+    pub yaw: f32,
+    pub scale: f32,
+}
+
+/// One banner hanging on a team's end wall
+#[derive(Clone, Debug, PartialEq)]
+pub struct BannerPlacement {
+    pub team: u8,
+    /// Position along the wall (z)
+    pub offset: f32,
+    /// Cloth length
+    pub length: f32,
+    /// Brightness multiplier on the team color
+    pub shade: f32,
+}
+
+/// The full cosmetic layout for one seed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArenaDressing {
+    pub seed: u64,
+    /// Floor color (replaces `BASE_FLOOR_COLOR` in the floor texture)
+    pub floor_color: [f32; 3],
+    pub banners: Vec<BannerPlacement>,
+    pub props: Vec<PropPlacement>,
+}
+
+impl ArenaDressing {
+    /// Lay out the dressing for `seed`. The same seed always gives the same layout.
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let floor_color = BASE_FLOOR_COLOR.map(|channel| {
+            channel * (1.0 + rng.random_range(-FLOOR_TINT_VARIATION..=FLOOR_TINT_VARIATION))
+        });
+
+        let mut banners = Vec::new();
+        for team in [1, 2] {
+            let count = rng.random_range(BANNERS_PER_WALL);
+            // Spread evenly along the (z) wall, with a little jitter
+            let spacing = 16.0 / count as f32;
+            for i in 0..count {
+                let center = -8.0 + spacing * (i as f32 + 0.5);
+                banners.push(BannerPlacement {
+                    team,
+                    offset: center + rng.random_range(-1.0..=1.0),
+                    length: rng.random_range(1.8..=2.8),
+                    shade: rng.random_range(0.8..=1.0),
+                });
+            }
+        }
+
+        let count = rng.random_range(PROP_COUNT);
+        let props = (0..count)
+            .map(|_| {
+                let kind = match rng.random_range(0..3) {
+                    0 => PropKind::Crate,
+                    1 => PropKind::Barrel,
+                    _ => PropKind::Rock,
+                };
+                PropPlacement {
+                    kind,
+                    position: prop_position(&mut rng),
+                    yaw: rng.random_range(0.0..std::f32::consts::TAU),
+                    scale: rng.random_range(0.7..=1.4),
+                }
+            })
+            .collect();
+
+        Self { seed, floor_color, banners, props }
+    }
+}
+
+/// Whether a ground point is clear of the walls (with prop clearance)
+pub fn outside_playfield(position: Vec2) -> bool {
+    position.x.abs() > ARENA_FLOOR_HALF_X + PROP_MIN_WALL_CLEARANCE
+        || position.y.abs() > ARENA_FLOOR_HALF_Z + PROP_MIN_WALL_CLEARANCE
+}
+
+/// A random ground point in the band outside the walls: pick a side, then a
+/// point along it.
+fn prop_position(rng: &mut StdRng) -> Vec2 {
+    let depth = rng.random_range(PROP_MIN_WALL_CLEARANCE + 0.5..PROP_MAX_WALL_DISTANCE);
+    let sign = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
+    if rng.random_bool(0.5) {
+        // Long (north/south) side
+        let along = rng.random_range(-ARENA_FLOOR_HALF_X - PROP_MAX_WALL_DISTANCE..ARENA_FLOOR_HALF_X + PROP_MAX_WALL_DISTANCE);
+        Vec2::new(along, sign * (ARENA_FLOOR_HALF_Z + depth))
+    } else {
+        // Short (east/west) side
+        let along = rng.random_range(-ARENA_FLOOR_HALF_Z - PROP_MAX_WALL_DISTANCE..ARENA_FLOOR_HALF_Z + PROP_MAX_WALL_DISTANCE);
+        Vec2::new(sign * (ARENA_FLOOR_HALF_X + depth), along)
+    }
+}
+
+/// Team banner color: the team color, darkened by `shade`
+fn team_banner_color(team_colors: &TeamColors, team: u8, shade: f32) -> Color {
+    let color = team_colors.bevy(team).to_srgba();
+    Color::srgb(color.red * shade, color.green * shade, color.blue * shade)
+}
+
+/// Spawn the banners and props. Team 1 starts on the west (-x) end, team 2 on
+/// the east (+x) end; each team's banners hang on the inside of its end wall.
+pub fn spawn_arena_dressing(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    dressing: &ArenaDressing,
+    team_colors: &TeamColors,
+    wall_height: f32,
+    wall_thickness: f32,
+) {
+    for banner in &dressing.banners {
+        let side = if banner.team == 1 { -1.0 } else { 1.0 };
+        let x = side * (ARENA_FLOOR_HALF_X - wall_thickness / 2.0 - 0.05);
+        commands.spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.06, banner.length, 1.4))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: team_banner_color(team_colors, banner.team, banner.shade),
+                perceptual_roughness: 0.85,
+                ..default()
+            })),
+            Transform::from_xyz(x, wall_height - banner.length / 2.0 - 0.2, banner.offset),
+            ArenaProp,
+            PlayMatchEntity,
+        ));
+    }
+
+    let crate_mesh = meshes.add(Cuboid::new(1.0, 1.0, 1.0));
+    let barrel_mesh = meshes.add(Cylinder::new(0.45, 1.1));
+    let rock_mesh = meshes.add(Sphere::new(0.7));
+    let material = |kind: PropKind, materials: &mut Assets<StandardMaterial>| {
+        materials.add(StandardMaterial {
+            base_color: kind.color(),
+            perceptual_roughness: 0.9,
+            ..default()
+        })
+    };
+    let crate_material = material(PropKind::Crate, materials);
+    let barrel_material = material(PropKind::Barrel, materials);
+    let rock_material = material(PropKind::Rock, materials);
+
+    for prop in &dressing.props {
+        let (mesh, material, height, squash) = match prop.kind {
+            PropKind::Crate => (crate_mesh.clone(), crate_material.clone(), 1.0, 1.0),
+            PropKind::Barrel => (barrel_mesh.clone(), barrel_material.clone(), 1.1, 1.0),
+            PropKind::Rock => (rock_mesh.clone(), rock_material.clone(), 1.4, 0.6),
+        };
+        let scale = Vec3::new(prop.scale, prop.scale * squash, prop.scale);
+        commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_xyz(prop.position.x, height * scale.y / 2.0, prop.position.y)
+                .with_rotation(Quat::from_rotation_y(prop.yaw))
+                .with_scale(scale),
+            ArenaProp,
+            PlayMatchEntity,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_dressing() {
+        assert_eq!(ArenaDressing::generate(42), ArenaDressing::generate(42));
+        assert_ne!(ArenaDressing::generate(42).props, ArenaDressing::generate(43).props);
+    }
+
+    #[test]
+    fn props_stay_outside_the_walls() {
+        for seed in 0..50 {
+            let dressing = ArenaDressing::generate(seed);
+            assert!(PROP_COUNT.contains(&dressing.props.len()));
+            for prop in &dressing.props {
+                assert!(outside_playfield(prop.position), "seed {seed}: prop at {:?}", prop.position);
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Arena Lighting
+//!
+//! Light and fog values for each `ArenaLighting` preset, applied once at match
+//! setup: the sun (directional light), ambient light, clear color, optional
+//! distance fog on the arena camera, and optional torches along the walls.
+//!
+//! Each map has a default preset (`ArenaMap::default_lighting`) that
+//! `MatchConfig::lighting` can override. `GameSettings::ignore_lighting_presets`
+//! forces `Day` (no fog, no torch lights) for performance.
+
+use bevy::pbr::{DistanceFog, FogFalloff};
+use bevy::prelude::*;
+
+use super::components::PlayMatchEntity;
+use super::constants::{ARENA_FLOOR_HALF_X, ARENA_FLOOR_HALF_Z};
+use super::match_config::ArenaLighting;
+
+/// Height of a torch's flame above the floor (walls are 4 units tall)
+const TORCH_HEIGHT: f32 = 3.2;
+/// Distance of torches in from the wall centerlines
+const TORCH_WALL_INSET: f32 = 1.0;
+
+/// Linear distance fog
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FogPreset {
+    pub color: Color,
+    /// Distance from the camera where fog starts
+    pub start: f32,
+    /// Distance where fog is fully opaque
+    pub end: f32,
+}
+
+/// The light and fog settings of one preset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LightingPreset {
+    pub sun_color: Color,
+    pub sun_illuminance: f32,
+    /// The sun shines from here toward the arena center
+    pub sun_position: Vec3,
+    pub ambient_color: Color,
+    pub ambient_brightness: f32,
+    pub clear_color: Color,
+    pub fog: Option<FogPreset>,
+    /// Spawn torches (flame + point light) along the walls
+    pub torches: bool,
+}
+
+/// Light and fog values for a preset.
+pub fn lighting_preset(lighting: ArenaLighting) -> LightingPreset {
+    match lighting {
+        // Warm golden sunlight over a deep cool backdrop. Ambient is kept low
+        // so the directional light + shadows carry the contrast and emissive
+        // effects pop under bloom.
+        ArenaLighting::Day => LightingPreset {
+            sun_color: Color::srgb(1.0, 0.95, 0.85),
+            sun_illuminance: 25000.0,
+            sun_position: Vec3::new(4.0, 8.0, 4.0),
+            ambient_color: Color::srgb(0.9, 0.85, 0.7),
+            ambient_brightness: 250.0,
+            clear_color: Color::srgb(0.05, 0.06, 0.09),
+            fog: None,
+            torches: false,
+        },
+        // Low orange sun raking across the arena
+        ArenaLighting::Dusk => LightingPreset {
+            sun_color: Color::srgb(1.0, 0.62, 0.38),
+            sun_illuminance: 11000.0,
+            sun_position: Vec3::new(30.0, 7.0, 10.0),
+            ambient_color: Color::srgb(0.65, 0.5, 0.6),
+            ambient_brightness: 180.0,
+            clear_color: Color::srgb(0.16, 0.09, 0.11),
+            fog: Some(FogPreset {
+                color: Color::srgb(0.3, 0.18, 0.18),
+                start: 70.0,
+                end: 160.0,
+            }),
+            torches: false,
+        },
+        // Cool moonlight; the torches light the play area
+        ArenaLighting::NightTorches => LightingPreset {
+            sun_color: Color::srgb(0.55, 0.65, 1.0),
+            sun_illuminance: 2500.0,
+            sun_position: Vec3::new(-4.0, 8.0, -3.0),
+            ambient_color: Color::srgb(0.35, 0.4, 0.65),
+            ambient_brightness: 110.0,
+            clear_color: Color::srgb(0.01, 0.015, 0.04),
+            fog: None,
+            torches: true,
+        },
+        // Hazy sun behind thick sandy fog
+        ArenaLighting::Sandstorm => LightingPreset {
+            sun_color: Color::srgb(1.0, 0.85, 0.62),
+            sun_illuminance: 12000.0,
+            sun_position: Vec3::new(6.0, 8.0, 2.0),
+            ambient_color: Color::srgb(0.95, 0.8, 0.55),
+            ambient_brightness: 420.0,
+            clear_color: Color::srgb(0.62, 0.5, 0.34),
+            fog: Some(FogPreset {
+                color: Color::srgb(0.62, 0.5, 0.34),
+                start: 35.0,
+                end: 120.0,
+            }),
+            torches: false,
+        },
+    }
+}
+
+/// Distance fog component for a fog preset.
+pub fn distance_fog(fog: &FogPreset) -> DistanceFog {
+    DistanceFog {
+        color: fog.color,
+        falloff: FogFalloff::Linear {
+            start: fog.start,
+            end: fog.end,
+        },
+        ..default()
+    }
+}
+
+/// Spawn torches along the inside of the walls: three on each long wall and
+/// one on each short wall.
+pub fn spawn_torches(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) {
+    let post_mesh = meshes.add(Cylinder::new(0.08, TORCH_HEIGHT));
+    let flame_mesh = meshes.add(Sphere::new(0.22));
+    let post_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.2, 0.14, 0.1),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    let flame_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.6, 0.2),
+        emissive: LinearRgba::rgb(6.0, 2.6, 0.6),
+        unlit: true,
+        ..default()
+    });
+
+    let long_z = ARENA_FLOOR_HALF_Z - TORCH_WALL_INSET;
+    let short_x = ARENA_FLOOR_HALF_X - TORCH_WALL_INSET;
+    let positions = [-20.0, 0.0, 20.0]
+        .into_iter()
+        .flat_map(|x| [Vec2::new(x, long_z), Vec2::new(x, -long_z)])
+        .chain([Vec2::new(short_x, 0.0), Vec2::new(-short_x, 0.0)]);
+
+    for position in positions {
+        commands.spawn((
+            Mesh3d(post_mesh.clone()),
+            MeshMaterial3d(post_material.clone()),
+            Transform::from_xyz(position.x, TORCH_HEIGHT / 2.0, position.y),
+            PlayMatchEntity,
+        ));
+        commands.spawn((
+            Mesh3d(flame_mesh.clone()),
+            MeshMaterial3d(flame_material.clone()),
+            PointLight {
+                color: Color::srgb(1.0, 0.65, 0.3),
+                intensity: 600_000.0,
+                range: 28.0,
+                shadows_enabled: false,
+                ..default()
+            },
+            Transform::from_xyz(position.x, TORCH_HEIGHT + 0.2, position.y),
+            PlayMatchEntity,
+        ));
+    }
+}
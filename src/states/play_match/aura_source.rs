@@ -0,0 +1,190 @@
+//! Aura source highlighting — hover an aura icon to see who applied it.
+//!
+//! Hovering an aura icon under a health bar records the aura in
+//! [`AuraSourceHighlight`]. While it is set:
+//! - an amber ring sits at the caster's feet ([`AuraSourceRing`]) and a thin
+//!   beam runs from the caster to the aura's target ([`AuraSourceBeam`])
+//! - the timeline flashes the caster's column header and the cast that
+//!   applied the aura
+//!
+//! Mostly useful in mirror matchups, where two Mages' Polymorphs are otherwise
+//! hard to tell apart. Self-applied auras (the caster is the target) only get
+//! the ring.
+//!
+//! All systems here are graphical-only. Registration lives in
+//! `src/states/mod.rs` (`StatesPlugin::build()`).
+
+use bevy::color::LinearRgba;
+use bevy::prelude::*;
+
+use super::components::{AuraSourceBeam, AuraSourceRing, Combatant, PlayMatchEntity, WalkAnim};
+
+/// Inner radius of the caster ring torus mesh.
+const RING_INNER_RADIUS: f32 = 0.8;
+/// Outer radius of the caster ring torus mesh.
+const RING_OUTER_RADIUS: f32 = 1.0;
+/// Same floor offset as the selection ring.
+const RING_GROUND_OFFSET_Y: f32 = -0.95 + 0.05;
+/// Beam radius — thinner than a Drain Life beam so the two don't get confused.
+const BEAM_RADIUS: f32 = 0.04;
+
+type HighlightFilter = Or<(With<AuraSourceRing>, With<AuraSourceBeam>)>;
+type NotHighlight = (Without<AuraSourceRing>, Without<AuraSourceBeam>);
+
+/// The aura icon under the cursor this frame, if any.
+///
+/// Written every frame by `render_health_bars`; read by
+/// [`sync_aura_source_highlight`] and the timeline.
+#[derive(Resource, Default, Clone, Debug, PartialEq)]
+pub struct AuraSourceHighlight {
+    pub hovered: Option<HoveredAura>,
+}
+
+/// A hovered aura and where it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HoveredAura {
+    /// The combatant wearing the aura
+    pub target: Entity,
+    /// The combatant that applied it
+    pub caster: Entity,
+    /// The caster's combat log ID (timeline column)
+    pub caster_id: String,
+    /// Ability that applied the aura (timeline entry)
+    pub ability_name: String,
+}
+
+impl AuraSourceHighlight {
+    /// Caster and target of the hovered aura.
+    pub fn link(&self) -> Option<(Entity, Entity)> {
+        self.hovered.as_ref().map(|h| (h.caster, h.target))
+    }
+
+    /// Whether the timeline cast `ability_name` by `combatant_id` should flash.
+    pub fn matches_cast(&self, combatant_id: &str, ability_name: &str) -> bool {
+        self.hovered
+            .as_ref()
+            .is_some_and(|h| h.caster_id == combatant_id && h.ability_name == ability_name)
+    }
+}
+
+/// Index of the last cast at or before `now` in a column's `(timestamp, name,
+/// interrupted)` list — the one that applied the hovered aura.
+pub fn applying_cast_index(casts: &[(f32, &str, bool)], ability_name: &str, now: f32) -> Option<usize> {
+    casts
+        .iter()
+        .rposition(|&(timestamp, name, interrupted)| name == ability_name && !interrupted && timestamp <= now)
+}
+
+/// Respawns the ring and beam when the hovered caster/target pair changes and
+/// despawns them when nothing is hovered.
+pub fn sync_aura_source_highlight(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    highlight: Res<AuraSourceHighlight>,
+    mut shown: Local<Option<(Entity, Entity)>>,
+    existing: Query<Entity, HighlightFilter>,
+) {
+    let link = highlight.link();
+    if link == *shown && (link.is_none() || !existing.is_empty()) {
+        return;
+    }
+    *shown = link;
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some((caster, target)) = link else {
+        return;
+    };
+
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.75, 0.2, 0.6),
+        emissive: LinearRgba::new(1.2, 0.8, 0.2, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
+
+    commands.spawn((
+        Mesh3d(meshes.add(Torus::new(RING_INNER_RADIUS, RING_OUTER_RADIUS))),
+        MeshMaterial3d(material.clone()),
+        Transform::default(),
+        AuraSourceRing { caster },
+        PlayMatchEntity,
+    ));
+
+    if caster != target {
+        commands.spawn((
+            Mesh3d(meshes.add(Cylinder::new(BEAM_RADIUS, 1.0))),
+            MeshMaterial3d(material),
+            Transform::default(),
+            AuraSourceBeam { caster, target },
+            PlayMatchEntity,
+        ));
+    }
+}
+
+/// Keeps the ring at the caster's feet and the beam between caster and target.
+/// Hides either one when an end is gone (a despawned pet, a dead caster).
+pub fn follow_aura_source_highlight(
+    time: Res<Time<Real>>,
+    combatants: Query<(&Transform, &Combatant, Option<&WalkAnim>), NotHighlight>,
+    mut rings: Query<(&AuraSourceRing, &mut Transform, &mut Visibility), Without<AuraSourceBeam>>,
+    mut beams: Query<(&AuraSourceBeam, &mut Transform, &mut Visibility), Without<AuraSourceRing>>,
+) {
+    for (ring, mut transform, mut visibility) in rings.iter_mut() {
+        let Ok((caster_transform, combatant, walk)) = combatants.get(ring.caster) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = if combatant.is_alive() { Visibility::Inherited } else { Visibility::Hidden };
+        let ground_y = walk.map(|w| w.ground_y).unwrap_or(caster_transform.translation.y);
+        transform.translation = Vec3::new(
+            caster_transform.translation.x,
+            ground_y + RING_GROUND_OFFSET_Y,
+            caster_transform.translation.z,
+        );
+        // Real time so the pulse keeps going while paused
+        transform.scale = Vec3::splat(1.0 + 0.08 * (time.elapsed_secs() * 6.0).sin());
+    }
+
+    for (beam, mut transform, mut visibility) in beams.iter_mut() {
+        let (Ok((from, ..)), Ok((to, ..))) = (combatants.get(beam.caster), combatants.get(beam.target)) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        // Chest height, as for channel beams
+        let from = from.translation + Vec3::Y * 0.5;
+        let to = to.translation + Vec3::Y * 0.5;
+        let direction = to - from;
+        let distance = direction.length();
+        if distance < 0.01 {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        *visibility = Visibility::Inherited;
+        transform.translation = (from + to) / 2.0;
+        transform.scale = Vec3::new(1.0, distance, 1.0);
+        transform.rotation = Quat::from_rotation_arc(Vec3::Y, direction / distance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applying_cast_is_the_latest_completed_one() {
+        let casts = vec![
+            (2.0, "Polymorph", false),
+            (5.0, "Frostbolt", false),
+            (9.0, "Polymorph", true),
+            (12.0, "Polymorph", false),
+        ];
+        assert_eq!(applying_cast_index(&casts, "Polymorph", 10.0), Some(0));
+        assert_eq!(applying_cast_index(&casts, "Polymorph", 12.5), Some(3));
+        assert_eq!(applying_cast_index(&casts, "Fear", 12.5), None);
+    }
+}
@@ -14,7 +14,10 @@ use bevy_egui::egui;
 use crate::combat::log::{CombatLog, CombatLogEventType};
 use super::match_config;
 use super::components::*;
-use super::utils::{combatant_id, get_next_fct_offset};
+use super::effect_pool::EffectCommandsExt;
+use super::utils::{
+    check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset, stable_combatant_order,
+};
 
 /// Update all active auras - tick down durations and remove expired ones.
 ///
@@ -31,7 +34,8 @@ pub fn update_auras(
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mut auras, mut combatant, dr_tracker) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, _, c, _)| (e, c))) {
+        let Ok((entity, mut auras, mut combatant, dr_tracker)) = combatants.get_mut(entity) else { continue; };
         // Tick DR timers (resets DR level when 15s expires)
         if let Some(mut tracker) = dr_tracker {
             tracker.tick_timers(dt);
@@ -51,7 +55,7 @@ pub fn update_auras(
                 if aura.fear_direction_timer <= 0.0 {
                     // Generate random angle (0 to 2*PI) using seeded RNG
                     let angle = game_rng.random_f32() * std::f32::consts::TAU;
-                    aura.fear_direction = (angle.cos(), angle.sin());
+                    aura.fear_direction = (ops::cos(angle), ops::sin(angle));
 
                     // Reset timer: change direction every 1-2 seconds (WoW-style)
                     // Polymorph changes direction slightly less frequently (sheep wander lazily)
@@ -218,17 +222,16 @@ pub fn apply_pending_auras(
             pending.aura.tick_interval
         );
 
-        // Get target combatant
-        let Ok((mut target_combatant, mut active_auras, target_transform, mut dr_tracker)) = combatants.get_mut(pending.target) else {
-            commands.entity(pending_entity).despawn();
-            continue;
-        };
-
-        // Don't apply auras to dead combatants
-        if !target_combatant.is_alive() {
-            commands.entity(pending_entity).despawn();
+        // Get target combatant. Despawned or dead targets (a DoT/CC queued on
+        // the kill-shot frame) resolve as logged no-ops.
+        let target_lookup = combatants.get_mut(pending.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(c, _, _, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, &pending.aura.ability_name, "target", reason);
             continue;
         }
+        let Some((mut target_combatant, mut active_auras, target_transform, mut dr_tracker)) = target_lookup else {
+            continue;
+        };
 
         // Check for CC immunity: Charging combatants are immune to crowd control
         let is_cc_aura = matches!(
@@ -247,17 +250,14 @@ pub fn apply_pending_auras(
                 (0.0, 0.0)
             };
 
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: "Immune".to_string(),
-                    color: egui::Color32::YELLOW,
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit: false,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: "Immune".to_string(),
+                color: egui::Color32::YELLOW,
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: false,
+            });
 
             // Log to combat log
             let cc_name = match pending.aura.effect_type {
@@ -309,17 +309,14 @@ pub fn apply_pending_auras(
                 (0.0, 0.0)
             };
 
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: "Immune".to_string(),
-                    color: egui::Color32::YELLOW,
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit: false,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: "Immune".to_string(),
+                color: egui::Color32::YELLOW,
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: false,
+            });
 
             commands.entity(pending_entity).despawn();
             continue;
@@ -339,17 +336,14 @@ pub fn apply_pending_auras(
                         (0.0, 0.0)
                     };
 
-                    commands.spawn((
-                        FloatingCombatText {
-                            world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                            text: "IMMUNE".to_string(),
-                            color: egui::Color32::YELLOW,
-                            lifetime: 1.5,
-                            vertical_offset: offset_y,
-                            is_crit: false,
-                        },
-                        PlayMatchEntity,
-                    ));
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                        text: "IMMUNE".to_string(),
+                        color: egui::Color32::YELLOW,
+                        lifetime: 1.5,
+                        vertical_offset: offset_y,
+                        is_crit: false,
+                    });
 
                     let display_name = if let Ok(pet) = pet_query.get(pending.target) {
                         pet.pet_type.name().to_string()
@@ -377,17 +371,7 @@ pub fn apply_pending_auras(
         // Check if target already has this buff type (prevent stacking for buff auras)
         // Also includes Absorb shields and WeakenedSoul to prevent same-frame double-application
         // Note: Different Absorb abilities (Ice Barrier vs PW:S) CAN coexist - only same ability is blocked
-        let is_buff_aura = matches!(
-            pending.aura.effect_type,
-            AuraType::MaxHealthIncrease | AuraType::MaxManaIncrease | AuraType::AttackPowerIncrease
-            | AuraType::Absorb | AuraType::WeakenedSoul | AuraType::DamageTakenReduction
-            | AuraType::DamageImmunity | AuraType::AttackPowerReduction
-            | AuraType::CritChanceIncrease | AuraType::ManaRegenIncrease
-            | AuraType::FrostArmorBuff | AuraType::LockoutDurationReduction
-            | AuraType::SpellResistanceBuff | AuraType::AttackSpeedSlow
-            | AuraType::SpellPowerIncrease | AuraType::HealingOverTime
-            | AuraType::WindfuryBuff
-        );
+        let is_buff_aura = pending.aura.effect_type.refuses_stacking();
         if is_buff_aura {
             // For Absorb shields, use ability_name as the key to allow different absorbs to coexist
             // For other buffs, use the aura type
@@ -694,7 +678,8 @@ pub fn process_aura_breaks(
     mut combat_log: ResMut<CombatLog>,
     mut combatants: Query<(Entity, &Combatant, &mut ActiveAuras, Option<&DamageTakenThisFrame>)>,
 ) {
-    for (entity, combatant, mut active_auras, damage_taken) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, combatant, mut active_auras, damage_taken)) = combatants.get_mut(entity) else { continue; };
         let Some(damage_taken) = damage_taken else {
             continue; // No damage this frame
         };
@@ -819,7 +804,8 @@ pub fn process_dot_ticks(
     let mut dot_damage_to_apply: Vec<(Entity, Entity, f32, Vec3, u8, match_config::CharacterClass, String, super::abilities::SpellSchool)> = Vec::new();
     
     // First pass: tick down DoT timers and queue damage
-    for (entity, combatant, _transform, mut active_auras) in combatants_with_auras.iter_mut() {
+    for entity in stable_combatant_order(combatants_with_auras.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, combatant, _transform, mut active_auras)) = combatants_with_auras.get_mut(entity) else { continue; };
         if !combatant.is_alive() {
             continue;
         }
@@ -915,17 +901,14 @@ pub fn process_dot_ticks(
         } else {
             (0.0, 0.0)
         };
-        commands.spawn((
-            FloatingCombatText {
-                world_position: target_pos + Vec3::new(offset_x, super::FCT_HEIGHT + offset_y, 0.0),
-                text: format!("{:.0}", actual_damage),
-                color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
-                lifetime: 1.5,
-                vertical_offset: offset_y,
-                is_crit: false, // DoT ticks never crit
-            },
-            PlayMatchEntity,
-        ));
+        commands.spawn_effect(FloatingCombatText {
+            world_position: target_pos + Vec3::new(offset_x, super::FCT_HEIGHT + offset_y, 0.0),
+            text: format!("{:.0}", actual_damage),
+            color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
+            lifetime: 1.5,
+            vertical_offset: offset_y,
+            is_crit: false, // DoT ticks never crit
+        });
 
         // Spawn light blue floating combat text for absorbed damage
         if absorbed > 0.0 {
@@ -934,17 +917,14 @@ pub fn process_dot_ticks(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: target_pos + Vec3::new(absorb_offset_x, super::FCT_HEIGHT + absorb_offset_y, 0.0),
-                    text: format!("{:.0} absorbed", absorbed),
-                    color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                    lifetime: 1.5,
-                    vertical_offset: absorb_offset_y,
-                    is_crit: false,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: target_pos + Vec3::new(absorb_offset_x, super::FCT_HEIGHT + absorb_offset_y, 0.0),
+                text: format!("{:.0} absorbed", absorbed),
+                color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                lifetime: 1.5,
+                vertical_offset: absorb_offset_y,
+                is_crit: false,
+            });
         }
 
         // Log to combat log with structured data
@@ -975,13 +955,12 @@ pub fn process_dot_ticks(
                 target_class.name()
             )
         };
-        combat_log.log_damage(
+        combat_log.log_periodic_damage(
             combatant_id(caster_team, caster_class),
             combatant_id(target_team, target_class),
             ability_name.clone(),
             actual_damage,
             is_killing_blow,
-            false, // is_crit - DoT ticks never crit
             message,
         );
 
@@ -1001,6 +980,7 @@ pub fn process_dot_ticks(
                 Some(combatant_id(caster_team, caster_class)),
                 death_message,
             );
+            commands.spawn(SoulShardPending { killer: caster_entity });
         }
     }
 
@@ -1064,7 +1044,8 @@ pub fn process_hot_ticks(
     let mut hot_healing_to_apply: Vec<(Entity, Entity, f32, Vec3, u8, match_config::CharacterClass, String)> = Vec::new();
 
     // First pass: tick down HoT timers and queue healing
-    for (entity, combatant, _transform, mut active_auras) in combatants_with_auras.iter_mut() {
+    for entity in stable_combatant_order(combatants_with_auras.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, combatant, _transform, mut active_auras)) = combatants_with_auras.get_mut(entity) else { continue; };
         if !combatant.is_alive() {
             continue;
         }
@@ -1141,17 +1122,14 @@ pub fn process_hot_ticks(
         } else {
             (0.0, 0.0)
         };
-        commands.spawn((
-            FloatingCombatText {
-                world_position: target_pos + Vec3::new(offset_x, super::FCT_HEIGHT + offset_y, 0.0),
-                text: format!("+{:.0}", actual_healing),
-                color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
-                lifetime: 1.5,
-                vertical_offset: offset_y,
-                is_crit: false, // HoT ticks never crit
-            },
-            PlayMatchEntity,
-        ));
+        commands.spawn_effect(FloatingCombatText {
+            world_position: target_pos + Vec3::new(offset_x, super::FCT_HEIGHT + offset_y, 0.0),
+            text: format!("+{:.0}", actual_healing),
+            color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
+            lifetime: 1.5,
+            vertical_offset: offset_y,
+            is_crit: false, // HoT ticks never crit
+        });
 
         // Log to combat log with structured data
         let message = format!(
@@ -1163,12 +1141,11 @@ pub fn process_hot_ticks(
             target_class.name(),
             actual_healing
         );
-        combat_log.log_healing(
+        combat_log.log_periodic_healing(
             combatant_id(caster_team, caster_class),
             combatant_id(target_team, target_class),
             ability_name.clone(),
             actual_healing,
-            false, // is_crit - HoT ticks never crit
             message,
         );
     }
@@ -0,0 +1,395 @@
+//! What-if branches
+//!
+//! While a match is paused, the viewer can fork it into a branch, change
+//! something through the sandbox console (e.g. `aura 2 0 Polymorph 8` to make
+//! the Mage's sheep land on a different target), watch the alternative play
+//! out, and then return to the exact moment the match was forked.
+//!
+//! Forking snapshots the simulation in place:
+//! - Every branch-scoped entity (combatants, pets, projectiles, traps, slow
+//!   zones, totems, Shadow Sight orbs and pending effects) is cloned into a
+//!   parked copy carrying Bevy's `Disabled` marker, so no system sees it.
+//!   Only components that implement `Clone` are copied, which is why the
+//!   simulation components all derive it.
+//! - The simulation resources (combat log, RNG, countdown, Shadow Sight
+//!   timer) are cloned, along with the commentary feed, event feed and alert
+//!   state that read the log through a cursor, and the win probability graph.
+//!
+//! Returning writes each parked copy back onto its original entity, strips
+//! components the original gained in the branch (a cast bar, a death
+//! animation), despawns branch-scoped entities the branch spawned, and brings
+//! back the ones it consumed (a projectile that landed, a pet that died with
+//! its owner) from their parked copy. A consumed entity comes back under a new
+//! entity id, so references to the old one are dropped like any other stale
+//! reference. The match is left paused at the fork point.
+//!
+//! Visual-only entities (floating text, impacts, beams) are not snapshotted;
+//! the ones a branch spawned fade out on their own. A branch that ends the
+//! match shows the victory celebration but never saves a log or moves on to
+//! the Results screen.
+//!
+//! Entry points:
+//! - In-process: `fork_match_branch(world)` / `return_from_branch(world)`
+//! - Graphical: the what-if hotkey (B by default) while paused
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::entity_disabling::Disabled;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use std::collections::HashSet;
+
+use crate::combat::log::CombatLog;
+use super::commentary::CommentaryFeed;
+use super::event_feed::EventFeed;
+use super::components::*;
+use super::effects::backlash::BacklashPending;
+use super::rendering::AlertState;
+use super::win_probability::WinProbability;
+
+/// Entities whose state belongs to the simulation and is rewound on return.
+/// Split in two `Or`s to stay within Bevy's tuple arity.
+type BranchScoped = Or<(
+    Or<(
+        With<Combatant>,
+        With<Projectile>,
+        With<Trap>,
+        With<TrapLaunchProjectile>,
+        With<SlowZone>,
+        With<Totem>,
+        With<ShadowSightOrb>,
+    )>,
+    Or<(
+        With<AuraPending>,
+        With<InterruptPending>,
+        With<HolyShockHealPending>,
+        With<HolyShockDamagePending>,
+        With<SandboxDamagePending>,
+        With<SandboxHealPending>,
+        With<DivineShieldPending>,
+        With<IceBlockPending>,
+        With<VanishPending>,
+        With<DispelPending>,
+        With<BacklashPending>,
+        With<SoulShardPending>,
+    )>,
+)>;
+
+/// The active what-if branch, if any.
+#[derive(Resource, Default)]
+pub struct MatchBranch {
+    snapshot: Option<BranchSnapshot>,
+}
+
+impl MatchBranch {
+    /// Whether the match is currently running a what-if branch
+    pub fn is_active(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Match time the current branch was forked at
+    pub fn forked_at(&self) -> Option<f32> {
+        self.snapshot.as_ref().map(|s| s.forked_at)
+    }
+}
+
+/// Everything needed to put the match back where it was forked.
+struct BranchSnapshot {
+    /// Combat log clock at the fork
+    forked_at: f32,
+    parked: Vec<ParkedEntity>,
+    combat_log: Option<CombatLog>,
+    rng: Option<GameRng>,
+    countdown: Option<MatchCountdown>,
+    shadow_sight: Option<ShadowSightState>,
+    commentary: Option<CommentaryFeed>,
+    event_feed: Option<EventFeed>,
+    alerts: Option<AlertState>,
+    win_probability: Option<WinProbability>,
+}
+
+/// A branch-scoped entity and its disabled copy taken at the fork.
+struct ParkedEntity {
+    original: Entity,
+    copy: Entity,
+    /// Components the original had at the fork
+    components: Vec<ComponentId>,
+}
+
+/// Fork the running match into a what-if branch. Returns the match time of
+/// the fork. Fails if a branch is already active or the match is over.
+pub fn fork_match_branch(world: &mut World) -> Result<f32, String> {
+    if world.get_resource::<MatchBranch>().is_some_and(MatchBranch::is_active) {
+        return Err("already in a what-if branch".to_string());
+    }
+    if world.contains_resource::<VictoryCelebration>() {
+        return Err("the match is already over".to_string());
+    }
+
+    let originals: Vec<Entity> = world
+        .query_filtered::<Entity, BranchScoped>()
+        .iter(world)
+        .collect();
+    let mut parked = Vec::with_capacity(originals.len());
+    for original in originals {
+        let components = world.entity(original).archetype().components().collect();
+        let copy = world.entity_mut(original).clone_and_spawn();
+        world.entity_mut(copy).insert(Disabled);
+        parked.push(ParkedEntity { original, copy, components });
+    }
+
+    let combat_log = world.get_resource::<CombatLog>().cloned();
+    let forked_at = combat_log.as_ref().map_or(0.0, |log| log.match_time);
+    let snapshot = BranchSnapshot {
+        forked_at,
+        parked,
+        combat_log,
+        rng: world.get_resource::<GameRng>().cloned(),
+        countdown: world.get_resource::<MatchCountdown>().cloned(),
+        shadow_sight: world.get_resource::<ShadowSightState>().cloned(),
+        commentary: world.get_resource::<CommentaryFeed>().cloned(),
+        event_feed: world.get_resource::<EventFeed>().cloned(),
+        alerts: world.get_resource::<AlertState>().cloned(),
+        win_probability: world.get_resource::<WinProbability>().cloned(),
+    };
+    world.get_resource_or_init::<MatchBranch>().snapshot = Some(snapshot);
+    Ok(forked_at)
+}
+
+/// Leave the active branch and restore the match to the fork point. Returns
+/// the match time restored to. Fails if no branch is active.
+pub fn return_from_branch(world: &mut World) -> Result<f32, String> {
+    let snapshot = world
+        .get_resource_mut::<MatchBranch>()
+        .and_then(|mut branch| branch.snapshot.take())
+        .ok_or_else(|| "not in a what-if branch".to_string())?;
+
+    // Despawn whatever the branch spawned
+    let forked: HashSet<Entity> = snapshot.parked.iter().map(|p| p.original).collect();
+    let spawned: Vec<Entity> = world
+        .query_filtered::<Entity, BranchScoped>()
+        .iter(world)
+        .filter(|entity| !forked.contains(entity))
+        .collect();
+    for entity in spawned {
+        world.despawn(entity);
+    }
+
+    for parked in snapshot.parked {
+        if world.get_entity(parked.original).is_err() {
+            // Consumed in the branch: the parked copy takes its place
+            world.entity_mut(parked.copy).remove::<Disabled>();
+            continue;
+        }
+        let gained: Vec<ComponentId> = world
+            .entity(parked.original)
+            .archetype()
+            .components()
+            .filter(|id| !parked.components.contains(id))
+            .collect();
+        world.entity_mut(parked.original).remove_by_ids(&gained);
+        world.entity_mut(parked.copy).clone_with(parked.original, |builder| {
+            builder.deny::<Disabled>();
+        });
+        world.despawn(parked.copy);
+    }
+
+    restore_resource(world, snapshot.combat_log);
+    restore_resource(world, snapshot.rng);
+    restore_resource(world, snapshot.countdown);
+    restore_resource(world, snapshot.shadow_sight);
+    restore_resource(world, snapshot.commentary);
+    restore_resource(world, snapshot.event_feed);
+    restore_resource(world, snapshot.alerts);
+    restore_resource(world, snapshot.win_probability);
+    // The fork was taken before the match ended
+    world.remove_resource::<VictoryCelebration>();
+
+    Ok(snapshot.forked_at)
+}
+
+fn restore_resource<R: Resource>(world: &mut World, saved: Option<R>) {
+    if let Some(resource) = saved {
+        world.insert_resource(resource);
+    }
+}
+
+/// Drop the parked copies of an unfinished branch. Parked copies are
+/// disabled, so `cleanup_play_match` never sees them.
+pub fn discard_match_branch(mut commands: Commands, mut branch: ResMut<MatchBranch>) {
+    if let Some(snapshot) = branch.snapshot.take() {
+        for parked in snapshot.parked {
+            commands.entity(parked.copy).despawn();
+        }
+    }
+}
+
+// ============================================================================
+// Graphical controls
+// ============================================================================
+
+/// What-if hotkey: fork while paused, or return from the active branch.
+/// Returning leaves the match paused at the fork point.
+pub fn handle_branch_hotkey(
+    mut commands: Commands,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    sim_speed: Res<SimulationSpeed>,
+    branch: Res<MatchBranch>,
+) {
+    use crate::keybindings::GameAction;
+
+    if !keybindings.action_just_pressed(GameAction::ToggleWhatIfBranch, &keyboard) {
+        return;
+    }
+
+    if branch.is_active() {
+        commands.queue(|world: &mut World| match return_from_branch(world) {
+            Ok(at) => {
+                world.resource_mut::<SimulationSpeed>().multiplier = 0.0;
+                world.resource_mut::<Time<Virtual>>().set_relative_speed(0.0);
+                info!("Returned from what-if branch to {:.1}s", at);
+            }
+            Err(e) => warn!("Cannot return from what-if branch: {}", e),
+        });
+    } else if sim_speed.is_paused() {
+        commands.queue(|world: &mut World| match fork_match_branch(world) {
+            Ok(at) => info!("Forked what-if branch at {:.1}s", at),
+            Err(e) => warn!("Cannot fork what-if branch: {}", e),
+        });
+    } else {
+        info!("Pause the match before forking a what-if branch");
+    }
+}
+
+/// Banner across the top of the match view while a branch is running.
+pub fn render_branch_banner(
+    mut contexts: EguiContexts,
+    branch: Res<MatchBranch>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+) {
+    use crate::keybindings::GameAction;
+
+    let Some(forked_at) = branch.forked_at() else { return; };
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    egui::Area::new(egui::Id::new("what_if_branch_banner"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 100.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_rgba_unmultiplied(60, 30, 90, 220))
+                .inner_margin(egui::Margin::symmetric(12, 6))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "WHAT-IF BRANCH · forked at {:.1}s · {} to return",
+                            forked_at,
+                            keybindings.binding_display(GameAction::ToggleWhatIfBranch),
+                        ))
+                        .size(16.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(220, 190, 255)),
+                    );
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::log::CombatLogEventType;
+    use crate::states::play_match::abilities::AbilityType;
+    use crate::states::match_config::CharacterClass;
+
+    fn spawn_mage(world: &mut World) -> Entity {
+        world
+            .spawn((Combatant::new(1, 0, CharacterClass::Mage), Transform::default(), PlayMatchEntity))
+            .id()
+    }
+
+    #[test]
+    fn return_restores_the_fork_point() {
+        let mut world = World::new();
+        world.init_resource::<CombatLog>();
+        let mage = spawn_mage(&mut world);
+        let pending = world
+            .spawn(SandboxHealPending { target: mage, amount: 100.0 })
+            .id();
+        world.resource_mut::<CombatLog>().log(CombatLogEventType::MatchEvent, "before".into());
+
+        fork_match_branch(&mut world).unwrap();
+        assert!(fork_match_branch(&mut world).is_err());
+
+        // The branch damages the mage, starts a cast, consumes the pending
+        // heal and spawns a projectile
+        world.get_mut::<Combatant>(mage).unwrap().current_health = 1.0;
+        world.entity_mut(mage).insert(CastingState::new(AbilityType::Frostbolt, mage, 2.5));
+        world.despawn(pending);
+        world.spawn(Projectile {
+            caster: mage,
+            target: mage,
+            ability: AbilityType::Frostbolt,
+            speed: 20.0,
+            caster_team: 1,
+            caster_class: CharacterClass::Mage,
+        });
+        world.resource_mut::<CombatLog>().log(CombatLogEventType::MatchEvent, "in branch".into());
+
+        return_from_branch(&mut world).unwrap();
+        assert!(!world.resource::<MatchBranch>().is_active());
+
+        let combatant = world.get::<Combatant>(mage).unwrap();
+        assert_eq!(combatant.current_health, combatant.max_health);
+        assert!(world.get::<CastingState>(mage).is_none());
+        assert_eq!(world.query::<&Projectile>().iter(&world).count(), 0);
+        // The consumed heal is back, and no parked copy is left behind
+        assert_eq!(world.query::<&SandboxHealPending>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&Combatant>().iter(&world).count(), 1);
+        assert_eq!(world.query_filtered::<Entity, With<Disabled>>().iter(&world).count(), 0);
+        assert_eq!(world.resource::<CombatLog>().entries.len(), 1);
+
+        assert!(return_from_branch(&mut world).is_err());
+    }
+    #[test]
+    fn return_rewinds_a_branch_that_ended_the_match() {
+        use bevy::ecs::system::RunSystemOnce;
+        use super::super::win_probability::update_win_probability;
+
+        let mut world = World::new();
+        world.init_resource::<CombatLog>();
+        world.init_resource::<Time>();
+        world.init_resource::<WinProbability>();
+        spawn_mage(&mut world);
+        world.spawn((Combatant::new(2, 0, CharacterClass::Mage), Transform::default(), PlayMatchEntity));
+        world.run_system_once(update_win_probability).unwrap();
+        let history_at_fork = world.resource::<WinProbability>().history.clone();
+
+        // The branch plays on to a team 1 win
+        fork_match_branch(&mut world).unwrap();
+        world.insert_resource(VictoryCelebration {
+            winner: Some(1),
+            time_remaining: 5.0,
+            match_results: MatchResults {
+                winner: Some(1),
+                duration_secs: 0.0,
+                team1_combatants: Vec::new(),
+                team2_combatants: Vec::new(),
+            },
+        });
+        world.run_system_once(update_win_probability).unwrap();
+        assert_eq!(world.resource::<WinProbability>().team1, 1.0);
+
+        return_from_branch(&mut world).unwrap();
+        assert!(!world.contains_resource::<VictoryCelebration>());
+        let win_probability = world.resource::<WinProbability>();
+        assert_eq!(win_probability.history, history_at_fork, "the branch's samples are dropped");
+        assert!(win_probability.team1 < 1.0);
+
+        // Not finished: the restored match keeps estimating
+        world.resource_mut::<CombatLog>().match_time = 10.0;
+        world.run_system_once(update_win_probability).unwrap();
+        assert_eq!(world.resource::<WinProbability>().history.len(), history_at_fork.len() + 1);
+    }
+}
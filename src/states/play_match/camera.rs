@@ -5,7 +5,13 @@
 use bevy::prelude::*;
 use bevy::time::Real;
 use bevy_egui::{egui, EguiContexts};
-use super::components::{CameraController, CameraMode, ArenaCamera, Combatant};
+use super::components::{CameraController, CameraMode, ArenaCamera, Combatant, Pet};
+use super::utils::combatant_id;
+use crate::combat::log::{CombatLog, CombatantId, StructuredEventData};
+
+/// Critical hits at or above this much damage count as a big event for the
+/// jump-to-event hotkey.
+pub const BIG_CRIT_DAMAGE: f32 = 100.0;
 
 /// Handle camera input for mode switching, zoom, rotation, and drag
 pub fn handle_camera_input(
@@ -98,7 +104,7 @@ pub fn handle_camera_input(
     // Reset camera
     if keybindings.action_just_pressed(GameAction::ResetCamera, &keyboard) {
         camera_controller.mode = CameraMode::FollowCenter;
-        camera_controller.zoom_distance = 60.0;
+        camera_controller.zoom_distance = camera_controller.default_zoom;
         camera_controller.pitch = 38.7f32.to_radians();
         camera_controller.yaw = 0.0;
     }
@@ -275,21 +281,97 @@ pub fn update_camera_position(
     camera_transform.look_at(target_point, Vec3::Y);
 }
 
+/// Entities in observer order: team 1 by slot, then team 2 by slot. The
+/// follow-slot hotkeys (F1-F6 by default) index into this list, so in a 3v3
+/// F1-F3 are team 1 and F4-F6 are team 2.
+pub fn observer_order(mut combatants: Vec<(Entity, u8, u8)>) -> Vec<Entity> {
+    combatants.sort_by_key(|&(_, team, slot)| (team, slot));
+    combatants.into_iter().map(|(entity, _, _)| entity).collect()
+}
+
+/// Find the most recent big event in the log and return the combatant the
+/// camera should follow for it:
+/// - a kill follows the killer (or the victim if the killer is unknown)
+/// - a crit of at least `BIG_CRIT_DAMAGE` follows the target
+/// - crowd control on a healer follows the healer
+pub fn latest_big_event(log: &CombatLog, is_healer: impl Fn(&str) -> bool) -> Option<CombatantId> {
+    log.entries.iter().rev().find_map(|entry| match entry.structured_data.as_ref()? {
+        StructuredEventData::Death { victim, killer } => {
+            Some(killer.clone().unwrap_or_else(|| victim.clone()))
+        }
+        StructuredEventData::Damage { target, amount, is_crit: true, .. } if *amount >= BIG_CRIT_DAMAGE => {
+            Some(target.clone())
+        }
+        StructuredEventData::CrowdControl { target, .. } if is_healer(target) => Some(target.clone()),
+        _ => None,
+    })
+}
+
+/// Observer hotkeys: follow a combatant by observer slot, or jump to whoever
+/// was involved in the most recent big event (kill, big crit, CC on a healer).
+pub fn handle_observer_hotkeys(
+    mut camera_controller: ResMut<CameraController>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    combat_log: Res<CombatLog>,
+    combatants: Query<(Entity, &Combatant), Without<Pet>>,
+) {
+    use crate::keybindings::GameAction;
+
+    const FOLLOW_SLOTS: [GameAction; 6] = [
+        GameAction::FollowSlot1,
+        GameAction::FollowSlot2,
+        GameAction::FollowSlot3,
+        GameAction::FollowSlot4,
+        GameAction::FollowSlot5,
+        GameAction::FollowSlot6,
+    ];
+
+    if let Some(index) = FOLLOW_SLOTS
+        .iter()
+        .position(|&action| keybindings.action_just_pressed(action, &keyboard))
+    {
+        let order = observer_order(combatants.iter().map(|(e, c)| (e, c.team, c.slot)).collect());
+        if let Some(&entity) = order.get(index) {
+            camera_controller.mode = CameraMode::FollowCombatant(entity);
+        }
+    }
+
+    if keybindings.action_just_pressed(GameAction::JumpToBigEvent, &keyboard) {
+        let find = |id: &str| {
+            combatants
+                .iter()
+                .find(|(_, c)| combatant_id(c.team, c.class) == id)
+        };
+        let focus = latest_big_event(&combat_log, |id| {
+            find(id).is_some_and(|(_, c)| c.class.is_healer())
+        });
+        if let Some((entity, _)) = focus.as_deref().and_then(find) {
+            camera_controller.mode = CameraMode::FollowCombatant(entity);
+        }
+    }
+}
+
 /// Render camera controls help overlay
 pub fn render_camera_controls(
     mut contexts: EguiContexts,
     camera_controller: Res<CameraController>,
+    presentation: Res<super::PresentationMode>,
     keybindings: Res<crate::keybindings::Keybindings>,
 ) {
     use crate::keybindings::GameAction;
 
+    if presentation.enabled {
+        return;
+    }
+
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
 
     // Position in bottom-right corner (to avoid overlapping with timeline panel on left)
     let panel_width = 260.0;
     egui::Window::new("Camera Controls")
-        .fixed_pos(egui::pos2(ctx.screen_rect().width() - panel_width - 10.0, ctx.screen_rect().height() - 160.0))
+        .fixed_pos(egui::pos2(ctx.screen_rect().width() - panel_width - 10.0, ctx.screen_rect().height() - 214.0))
         .resizable(false)
         .collapsible(false)
         .title_bar(false)
@@ -332,6 +414,32 @@ pub fn render_camera_controls(
                 .size(11.0)
                 .color(egui::Color32::from_rgb(200, 200, 200))
             );
+            ui.label(
+                egui::RichText::new(format!(
+                    "{}-{} - Follow combatant",
+                    keybindings.binding_display(GameAction::FollowSlot1),
+                    keybindings.binding_display(GameAction::FollowSlot6)
+                ))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(200, 200, 200))
+            );
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} - Jump to last big event",
+                    keybindings.binding_display(GameAction::JumpToBigEvent)
+                ))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(200, 200, 200))
+            );
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} / {} - Record / play camera path",
+                    keybindings.binding_display(GameAction::RecordCameraPath),
+                    keybindings.binding_display(GameAction::PlayCameraPath)
+                ))
+                .size(11.0)
+                .color(egui::Color32::from_rgb(200, 200, 200))
+            );
             ui.label(
                 egui::RichText::new("Mouse Wheel - Zoom")
                     .size(11.0)
@@ -345,3 +453,30 @@ pub fn render_camera_controls(
         });
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observer_order_is_team_then_slot() {
+        let (a, b, c) = (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3));
+        assert_eq!(observer_order(vec![(a, 2, 0), (b, 1, 1), (c, 1, 0)]), vec![c, b, a]);
+    }
+
+    #[test]
+    fn latest_big_event_skips_small_hits_and_non_healer_cc() {
+        let mut log = CombatLog::default();
+        log.log_death("Team 2 Mage".into(), Some("Team 1 Rogue".into()), String::new());
+        log.log_crowd_control("Team 1 Rogue".into(), "Team 2 Priest".into(), "Stun".into(), 4.0, String::new());
+        log.log_crowd_control("Team 2 Priest".into(), "Team 1 Rogue".into(), "Fear".into(), 4.0, String::new());
+        let healer = |id: &str| id.ends_with("Priest");
+        assert_eq!(latest_big_event(&log, healer).as_deref(), Some("Team 2 Priest"));
+
+        log.log_damage("Team 2 Warrior".into(), "Team 1 Rogue".into(), "Mortal Strike".into(), 140.0, false, true, String::new());
+        assert_eq!(latest_big_event(&log, healer).as_deref(), Some("Team 1 Rogue"));
+
+        log.log_damage("Team 2 Warrior".into(), "Team 1 Mage".into(), "Auto Attack".into(), 30.0, false, true, String::new());
+        assert_eq!(latest_big_event(&log, healer).as_deref(), Some("Team 1 Rogue"));
+    }
+}
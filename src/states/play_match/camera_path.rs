@@ -0,0 +1,291 @@
+//! Scriptable camera paths for cinematic replays
+//!
+//! A camera path is a list of keyframes — camera position and look-at target
+//! at a match time — stored as a small RON file:
+//!
+//! ```ron
+//! (
+//!     keyframes: [
+//!         (time: 0.0, position: (0.0, 45.0, 60.0), target: (0.0, 0.0, 0.0)),
+//!         (time: 6.0, position: (-25.0, 12.0, 20.0), target: (0.0, 1.0, 0.0)),
+//!     ],
+//! )
+//! ```
+//!
+//! Between keyframes the camera follows a Catmull-Rom spline through the
+//! positions and targets; before the first and after the last it holds. The
+//! clock is `CombatLog::match_time`, so playback pauses and changes speed with
+//! the simulation, and a same-seed restart (R) replays the fight under the same
+//! shot.
+//!
+//! Paths come from `--camera-path <file>` or are recorded live: the record
+//! hotkey (F7) samples the camera every [`RECORD_INTERVAL`] of match time until
+//! pressed again, saves the path to `camera_paths/path_<timestamp>.ron`, and
+//! makes it the active path. The play hotkey (P) toggles playback of the
+//! active path, which starts automatically at every match start while a path
+//! is loaded.
+//!
+//! All systems here are graphical-only. Registration lives in
+//! `src/states/mod.rs` (`StatesPlugin::build()`).
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use serde::{Deserialize, Serialize};
+
+use crate::combat::log::CombatLog;
+use super::components::{ArenaCamera, CameraController};
+
+/// Match seconds between live-recorded keyframes
+pub const RECORD_INTERVAL: f32 = 0.5;
+/// Directory recorded paths are saved to
+const CAMERA_PATH_DIR: &str = "camera_paths";
+
+/// One camera pose at a match time.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CameraKeyframe {
+    /// Match time, in seconds
+    pub time: f32,
+    pub position: (f32, f32, f32),
+    pub target: (f32, f32, f32),
+}
+
+/// Keyframed camera path, ordered by time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CameraPath {
+    pub keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraPath {
+    /// Parse a path from RON. Keyframes are sorted by time.
+    pub fn from_ron(contents: &str) -> Result<Self, String> {
+        let mut path: CameraPath = ron::from_str(contents).map_err(|e| e.to_string())?;
+        if path.keyframes.is_empty() {
+            return Err("camera path has no keyframes".to_string());
+        }
+        path.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Ok(path)
+    }
+
+    /// Load a path from a RON file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| format!("read {}: {}", path.display(), e))?;
+        Self::from_ron(&contents).map_err(|e| format!("parse {}: {}", path.display(), e))
+    }
+
+    /// Save the path as pretty RON, creating the parent directory.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("create {}: {}", parent.display(), e))?;
+            }
+        }
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("serialize {}: {}", path.display(), e))?;
+        std::fs::write(path, contents).map_err(|e| format!("write {}: {}", path.display(), e))
+    }
+
+    /// Camera position and look-at target at `time`, or `None` for an empty path.
+    pub fn sample(&self, time: f32) -> Option<(Vec3, Vec3)> {
+        let frames = &self.keyframes;
+        let first = frames.first()?;
+        let last = frames.last()?;
+        if time <= first.time {
+            return Some((first.position.into(), first.target.into()));
+        }
+        if time >= last.time {
+            return Some((last.position.into(), last.target.into()));
+        }
+
+        // Segment i runs from keyframe i to i + 1
+        let i = frames.partition_point(|k| k.time <= time) - 1;
+        let (k1, k2) = (&frames[i], &frames[i + 1]);
+        let span = k2.time - k1.time;
+        let t = if span > 0.0 { (time - k1.time) / span } else { 1.0 };
+
+        // Past either end, mirror the neighbouring keyframe so the end
+        // segments don't ease in or out
+        let spline = |pose: fn(&CameraKeyframe) -> Vec3| {
+            let (p1, p2) = (pose(k1), pose(k2));
+            let p0 = if i > 0 { pose(&frames[i - 1]) } else { 2.0 * p1 - p2 };
+            let p3 = frames.get(i + 2).map(pose).unwrap_or(2.0 * p2 - p1);
+            catmull_rom(p0, p1, p2, p3, t)
+        };
+        Some((spline(|k| k.position.into()), spline(|k| k.target.into())))
+    }
+}
+
+/// Uniform Catmull-Rom spline through p1 (t = 0) and p2 (t = 1).
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Active camera path and recording state. Lives for the whole app, so a
+/// loaded or recorded path carries over to restarts and later matches.
+#[derive(Resource, Default)]
+pub struct CameraPathPlayer {
+    /// Path played back by the play hotkey and at match start
+    pub path: Option<CameraPath>,
+    /// Whether the active path is driving the camera
+    pub playing: bool,
+    /// Keyframes captured so far, while recording
+    pub recording: Option<CameraPath>,
+}
+
+impl CameraPathPlayer {
+    /// A player with `path` loaded, or an empty one.
+    pub fn with_path(path: Option<CameraPath>) -> Self {
+        Self { path, ..Default::default() }
+    }
+
+    /// Stop recording and save what was captured, making it the active path.
+    fn finish_recording(&mut self) {
+        let Some(recorded) = self.recording.take() else { return; };
+        if recorded.keyframes.is_empty() {
+            info!("Camera path recording stopped with no keyframes");
+            return;
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let file = PathBuf::from(CAMERA_PATH_DIR).join(format!("path_{}.ron", timestamp));
+        match recorded.save(&file) {
+            Ok(()) => info!("Saved camera path ({} keyframes) to {:?}", recorded.keyframes.len(), file),
+            Err(e) => warn!("Failed to save camera path: {}", e),
+        }
+        self.path = Some(recorded);
+    }
+}
+
+/// At match start, play the active path from its beginning.
+pub fn start_camera_path(mut player: ResMut<CameraPathPlayer>) {
+    player.playing = player.path.is_some();
+}
+
+/// Leaving the match ends a recording in progress.
+pub fn finish_camera_path_recording(mut player: ResMut<CameraPathPlayer>) {
+    player.finish_recording();
+}
+
+/// Camera path hotkeys: start/stop recording, play/stop the active path.
+pub fn handle_camera_path_hotkeys(
+    mut player: ResMut<CameraPathPlayer>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if keybindings.action_just_pressed(GameAction::RecordCameraPath, &keyboard) {
+        if player.recording.is_some() {
+            player.finish_recording();
+        } else {
+            info!("Recording camera path");
+            player.playing = false;
+            player.recording = Some(CameraPath::default());
+        }
+    }
+
+    if keybindings.action_just_pressed(GameAction::PlayCameraPath, &keyboard) && player.recording.is_none() {
+        player.playing = !player.playing && player.path.is_some();
+        info!("Camera path playback: {}", player.playing);
+    }
+}
+
+/// While recording, capture the camera pose every `RECORD_INTERVAL` of match time.
+pub fn record_camera_path(
+    mut player: ResMut<CameraPathPlayer>,
+    combat_log: Res<CombatLog>,
+    camera_controller: Res<CameraController>,
+    camera_query: Query<&Transform, With<ArenaCamera>>,
+) {
+    let Some(recording) = player.recording.as_mut() else { return; };
+    let Ok(transform) = camera_query.single() else { return; };
+
+    let time = combat_log.match_time;
+    if recording.keyframes.last().is_some_and(|k| time < k.time + RECORD_INTERVAL) {
+        return;
+    }
+    // The camera orbits its target at zoom_distance
+    let target = transform.translation + transform.forward() * camera_controller.zoom_distance;
+    recording.keyframes.push(CameraKeyframe {
+        time,
+        position: transform.translation.into(),
+        target: target.into(),
+    });
+}
+
+/// While playing, pose the camera from the active path. Runs after
+/// `update_camera_position` so the path wins over the camera mode.
+pub fn apply_camera_path(
+    player: Res<CameraPathPlayer>,
+    combat_log: Res<CombatLog>,
+    mut camera_query: Query<&mut Transform, With<ArenaCamera>>,
+) {
+    if !player.playing {
+        return;
+    }
+    let Some((position, target)) = player.path.as_ref().and_then(|path| path.sample(combat_log.match_time)) else {
+        return;
+    };
+    let Ok(mut transform) = camera_query.single_mut() else { return; };
+    transform.translation = position;
+    transform.look_at(target, Vec3::Y);
+}
+
+/// Small "REC" badge at the top centre, under the win odds bar, while recording.
+pub fn render_camera_path_status(mut contexts: EguiContexts, player: Res<CameraPathPlayer>) {
+    let Some(recording) = player.recording.as_ref() else { return; };
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    egui::Area::new(egui::Id::new("camera_path_rec"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 70.0))
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(170))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("● REC camera path ({} keyframes)", recording.keyframes.len()))
+                            .size(13.0)
+                            .color(egui::Color32::from_rgb(255, 80, 80))
+                            .strong(),
+                    );
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_passes_through_keyframes_and_holds_at_the_ends() {
+        let path = CameraPath::from_ron(
+            "(keyframes: [
+                (time: 4.0, position: (10.0, 5.0, 0.0), target: (0.0, 0.0, 0.0)),
+                (time: 0.0, position: (0.0, 5.0, 0.0), target: (0.0, 0.0, 0.0)),
+                (time: 8.0, position: (20.0, 5.0, 0.0), target: (0.0, 0.0, 5.0)),
+            ])",
+        )
+        .unwrap();
+
+        assert_eq!(path.sample(-1.0).unwrap().0, Vec3::new(0.0, 5.0, 0.0));
+        assert_eq!(path.sample(4.0).unwrap().0, Vec3::new(10.0, 5.0, 0.0));
+        assert_eq!(path.sample(99.0).unwrap(), (Vec3::new(20.0, 5.0, 0.0), Vec3::new(0.0, 0.0, 5.0)));
+        // Evenly spaced collinear keyframes: the spline stays on the line
+        assert!((path.sample(2.0).unwrap().0 - Vec3::new(5.0, 5.0, 0.0)).length() < 1e-4);
+
+        let round_trip = CameraPath::from_ron(&ron::to_string(&path).unwrap()).unwrap();
+        assert_eq!(round_trip, path);
+        assert!(CameraPath::from_ron("(keyframes: [])").is_err());
+    }
+}
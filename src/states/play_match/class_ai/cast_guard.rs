@@ -17,10 +17,11 @@ use crate::states::play_match::components::{ActiveAuras, Combatant};
 
 /// Opt-in guards layered on top of the universal pre-cast checks.
 ///
-/// The universal checks (always applied) are: spell-school lockout, silence
-/// (gated on `mana_cost > 0` and caster's resource type via [`is_silenced`]),
-/// per-ability cooldown, and `can_cast_config` (mana / range / min-range /
-/// stealth) for targeted casts or a bare mana check for self-targeted casts.
+/// The universal checks (always applied) are: execute range (for `is_execute`
+/// abilities), spell-school lockout, silence (gated on `mana_cost > 0` and
+/// caster's resource type via [`is_silenced`]), per-ability cooldown, and
+/// `can_cast_config` (mana / range / min-range / stealth) for targeted casts or
+/// a bare mana check for self-targeted casts.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PreCastOpts {
     /// Skip the cast if the target is currently CC'd by an aura on our team
@@ -59,11 +60,12 @@ pub struct PreCastOpts {
 /// 1. friendly-CC (opt-in)
 /// 2. friendly-DoTs (opt-in)
 /// 3. target damage immunity (opt-in)
-/// 4. spell-school lockout
-/// 5. silence (skipped when `bypass_silence`; otherwise auto-gated on
+/// 4. execute range (`is_execute` abilities only)
+/// 5. spell-school lockout
+/// 6. silence (skipped when `bypass_silence`; otherwise auto-gated on
 ///    `mana_cost > 0` and caster resource type)
-/// 6. per-ability cooldown
-/// 7. mana / range / min-range / stealth (via `can_cast_config` for
+/// 7. per-ability cooldown
+/// 8. mana / range / min-range / stealth (via `can_cast_config` for
 ///    targeted casts; mana-only for self-targeted)
 pub fn pre_cast_ok(
     ability: AbilityType,
@@ -85,6 +87,9 @@ pub fn pre_cast_ok(
         if opts.check_target_immune && ctx.entity_is_immune(target_entity) {
             return false;
         }
+        if def.is_execute && !ctx.in_execute_range(target_entity) {
+            return false;
+        }
     }
 
     if is_spell_school_locked(def.spell_school, auras) {
@@ -139,6 +144,9 @@ pub fn classify_pre_cast_failure(
         if opts.check_target_immune && ctx.entity_is_immune(target_entity) {
             return RejectionReason::TargetImmune;
         }
+        if def.is_execute && !ctx.in_execute_range(target_entity) {
+            return RejectionReason::PreconditionUnmet { note: "target above execute range".into() };
+        }
     }
     if is_spell_school_locked(def.spell_school, auras) {
         return RejectionReason::SilencedOrLocked { school: def.spell_school };
@@ -237,9 +237,10 @@ pub fn decide_hunter_action(
     // someone other than that healer (bursting the healer would break its own
     // breakable trap). Outside the window the rotation below is the unchanged
     // order, so non-CC frames are untouched. This is the complement to the trap
-    // rework: the trap creates the healer-down window, this converts it.
-    let burst_window =
-        ctx.enemy_healer_is_cced() && ctx.enemy_healer() != Some(target_entity);
+    // rework: the trap creates the healer-down window, this converts it. A kill
+    // target in execute range opens the same window: one Aimed Shot finishes it.
+    let burst_window = (ctx.enemy_healer_is_cced() && ctx.enemy_healer() != Some(target_entity))
+        || ctx.in_execute_range(target_entity);
     if burst_window && distance_to_target >= 20.0 {
         if try_aimed_shot(
             commands, combat_log, abilities, entity, combatant, my_pos,
@@ -502,7 +503,7 @@ fn try_place_trap_at(
         let landing = Vec3::new(position.x, 0.0, position.z);
         let direction = (landing - origin).normalize_or_zero();
         let rotation = if direction != Vec3::ZERO {
-            Quat::from_rotation_y(direction.x.atan2(direction.z))
+            Quat::from_rotation_y(ops::atan2(direction.x, direction.z))
         } else {
             Quat::IDENTITY
         };
@@ -3,6 +3,11 @@
 //! Handles AI decision-making for the Mage class.
 //!
 //! ## Priority Order
+//! 0. Ice Block / Cold Snap (lethal burst: HP < 25% while targeted, or < 40%
+//!    while two or more enemies are on the Mage). With Ice Block on cooldown,
+//!    Cold Snap resets it so the next tick can block again. An encased Mage
+//!    cancels the block early once healed above 60% (see `try_cancel_ice_block`,
+//!    called from `combat_ai.rs` since the encasement gates normal dispatch).
 //! 1. Ice Barrier (self-shield when no shield or HP < 80%)
 //! 2. Mage Armor (self-buff based on preference: Frost Armor / Mage Armor / Molten Armor)
 //! 3. Arcane Intellect (buff mana-using allies pre-combat)
@@ -17,8 +22,10 @@ use crate::states::match_config::{CharacterClass, MageArmor};
 use crate::states::play_match::abilities::AbilityType;
 use crate::states::play_match::ability_config::AbilityDefinitions;
 use crate::states::play_match::components::*;
+use crate::states::play_match::abilities::SpellSchool;
 use crate::states::play_match::constants::{
-    CRIT_DAMAGE_MULTIPLIER, DEFENSIVE_HP_THRESHOLD, GCD, MELEE_RANGE, SAFE_KITING_DISTANCE,
+    CRIT_DAMAGE_MULTIPLIER, DEFENSIVE_HP_THRESHOLD, GCD, ICE_BLOCK_CANCEL_HP_THRESHOLD,
+    ICE_BLOCK_FOCUSED_HP_THRESHOLD, ICE_BLOCK_HP_THRESHOLD, MELEE_RANGE, SAFE_KITING_DISTANCE,
 };
 use crate::states::play_match::combat_core::{calculate_cast_time, roll_crit, get_attack_power_bonus_from_slice, get_spell_power_bonus_from_slice, get_crit_chance_bonus_from_slice};
 use crate::states::play_match::decision_trace::{
@@ -56,6 +63,12 @@ pub fn decide_mage_action(
         return false;
     };
 
+    // Priority 0: Ice Block, chained through Cold Snap (lethal burst)
+    if try_ice_block(commands, combat_log, abilities, entity, combatant, my_pos, auras, ctx, &mut builder) {
+        builder.finish();
+        return true;
+    }
+
     // Priority 1: Ice Barrier (self-shield)
     if try_ice_barrier(commands, combat_log, abilities, entity, combatant, ctx, &mut builder) {
         builder.finish();
@@ -215,6 +228,163 @@ fn try_ice_barrier(
     true
 }
 
+/// Whether the Mage is facing lethal burst: below `ICE_BLOCK_HP_THRESHOLD`
+/// while any visible enemy targets it, or below `ICE_BLOCK_FOCUSED_HP_THRESHOLD`
+/// while two or more do.
+pub fn facing_lethal_burst(health_pct: f32, attackers: usize) -> bool {
+    (attackers >= 1 && health_pct < ICE_BLOCK_HP_THRESHOLD)
+        || (attackers >= 2 && health_pct < ICE_BLOCK_FOCUSED_HP_THRESHOLD)
+}
+
+/// Try to Ice Block out of lethal burst. If Ice Block is on cooldown, Cold
+/// Snap resets it (and every other Frost cooldown) instead, so the block
+/// follows on the next tick — the defensive chain.
+/// Returns true if either ability was used.
+fn try_ice_block(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let ice_block = AbilityType::IceBlock;
+    let def = abilities.get_unchecked(&ice_block);
+
+    let health_pct = combatant.current_health / combatant.max_health;
+    if !facing_lethal_burst(health_pct, ctx.enemies_targeting(entity).len()) {
+        builder.reject(
+            ice_block,
+            RejectionReason::PreconditionUnmet { note: "not facing lethal burst".into() },
+        );
+        return false;
+    }
+
+    // Divine Shield or an earlier block already covers us.
+    if ctx.has_aura(AuraType::DamageImmunity) {
+        builder.reject(ice_block, RejectionReason::AlreadyApplied);
+        return false;
+    }
+
+    if combatant.ability_cooldowns.contains_key(&ice_block) {
+        return try_cold_snap(commands, combat_log, abilities, entity, combatant, my_pos, auras, ctx, builder);
+    }
+
+    if !pre_cast_ok(ice_block, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()) {
+        builder.reject(
+            ice_block,
+            classify_pre_cast_failure(ice_block, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()),
+        );
+        return false;
+    }
+
+    builder.choose(ice_block, Some(entity), true);
+
+    spawn_speech_bubble(commands, entity, "Ice Block");
+    combatant.current_mana -= def.mana_cost;
+    combatant.start_cooldown(ice_block, abilities);
+    combatant.global_cooldown = GCD;
+
+    log_ability_use(combat_log, combatant.team, combatant.class, "Ice Block", None, "casts");
+
+    commands.spawn(IceBlockPending {
+        caster: entity,
+        caster_team: combatant.team,
+        caster_class: combatant.class,
+        cancel: false,
+    });
+
+    info!(
+        "Team {} {} casts Ice Block ({:.0}% HP)",
+        combatant.team,
+        combatant.class.name(),
+        health_pct * 100.0
+    );
+
+    true
+}
+
+/// Cold Snap: finish every other Frost cooldown. Only called by `try_ice_block`
+/// when Ice Block is the cooldown standing between the Mage and death.
+/// Off the GCD, so the freshly reset Ice Block can follow on the next tick.
+fn try_cold_snap(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let cold_snap = AbilityType::ColdSnap;
+    let def = abilities.get_unchecked(&cold_snap);
+
+    if !pre_cast_ok(cold_snap, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()) {
+        builder.reject(
+            cold_snap,
+            classify_pre_cast_failure(cold_snap, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()),
+        );
+        return false;
+    }
+
+    builder.choose(cold_snap, Some(entity), true);
+
+    spawn_speech_bubble(commands, entity, "Cold Snap");
+    combatant.current_mana -= def.mana_cost;
+    combatant.start_cooldown(cold_snap, abilities);
+    let reset = combatant.reset_school_cooldowns(SpellSchool::Frost, cold_snap, abilities);
+
+    log_ability_use(combat_log, combatant.team, combatant.class, "Cold Snap", None, "casts");
+
+    info!(
+        "Team {} {} casts Cold Snap (reset {:?})",
+        combatant.team,
+        combatant.class.name(),
+        reset
+    );
+
+    true
+}
+
+/// Cancel an active Ice Block once the Mage has been healed through it.
+///
+/// Called from `combat_ai.rs` for an encased Mage, before the incapacitation
+/// gate (the encasement blocks normal dispatch, like CC does for Divine
+/// Shield). The caller owns the builder lifecycle.
+pub fn try_cancel_ice_block(
+    commands: &mut Commands,
+    entity: Entity,
+    combatant: &Combatant,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let ice_block = AbilityType::IceBlock;
+
+    let health_pct = combatant.current_health / combatant.max_health;
+    if health_pct < ICE_BLOCK_CANCEL_HP_THRESHOLD {
+        builder.reject(
+            ice_block,
+            RejectionReason::PreconditionUnmet { note: "Ice Block held: HP below cancel threshold".into() },
+        );
+        return false;
+    }
+
+    builder.choose(ice_block, Some(entity), true);
+
+    commands.spawn(IceBlockPending {
+        caster: entity,
+        caster_team: combatant.team,
+        caster_class: combatant.class,
+        cancel: true,
+    });
+
+    true
+}
+
 /// Try to cast the chosen Mage Armor on self (Frost Armor, Mage Armor, or Molten Armor).
 /// Returns true if the ability was used.
 fn try_mage_armor(
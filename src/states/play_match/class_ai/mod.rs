@@ -38,7 +38,7 @@ use super::match_config::CharacterClass;
 use super::abilities::AbilityType;
 use super::ability_config::AbilityDefinitions;
 use super::components::{Aura, ActiveAuras, Combatant, AuraType, DispelPending, PetType, DRCategory, DRTracker};
-use super::constants::GCD;
+use super::constants::{EXECUTE_HP_THRESHOLD, GCD};
 use super::{is_spell_school_locked, is_silenced};
 use super::utils::log_ability_use;
 
@@ -200,6 +200,7 @@ impl<'a> CombatContext<'a> {
     /// CombatContext can't delegate because it stores auras as `&[Aura]`, not `&ActiveAuras`.
     pub fn is_incapacitated(&self) -> bool {
         self.has_aura(AuraType::Stun) || self.has_aura(AuraType::Fear) || self.has_aura(AuraType::Polymorph) || self.has_aura(AuraType::Incapacitate)
+            || self.has_aura(AuraType::IceBlock)
     }
 
     /// Check if an entity is currently CC'd (Stun, Fear, Root, or Polymorph).
@@ -232,6 +233,22 @@ impl<'a> CombatContext<'a> {
             .map(|(entity, _)| *entity)
     }
 
+    /// First living allied healer (excluding self and pets) in entity order —
+    /// the teammate a Warrior peels for with Intercept / Intervene.
+    pub fn ally_healer(&self) -> Option<Entity> {
+        let my_team = self.self_info().map(|i| i.team).unwrap_or(0);
+        self.combatants
+            .iter()
+            .find(|(entity, info)| {
+                **entity != self.self_entity
+                    && info.team == my_team
+                    && info.is_alive
+                    && !info.is_pet
+                    && info.class.is_healer()
+            })
+            .map(|(entity, _)| *entity)
+    }
+
     /// True when a living enemy healer exists AND is currently unable to cast
     /// a heal — the bucket-A burst window. This is the CAST-PREVENTING CC
     /// subset (Stun / Fear / Polymorph / Incapacitate), NOT [`is_ccd`]: a
@@ -318,6 +335,19 @@ impl<'a> CombatContext<'a> {
             || self.entity_has_aura(enemy.entity, AuraType::ShadowSight)
     }
 
+    /// Whether Vanish would actually hide `me`: false while `me` holds Shadow
+    /// Sight (the holder is revealed) or any living enemy holds it (they see
+    /// through stealth). Mirrors `visible_to` from the enemies' side.
+    pub fn vanish_would_hide(&self, me: Entity) -> bool {
+        let Some(my_team) = self.combatants.get(&me).map(|i| i.team) else {
+            return false;
+        };
+        !self.entity_has_aura(me, AuraType::ShadowSight)
+            && !self.combatants.values().any(|c| {
+                c.team != my_team && c.is_alive && self.entity_has_aura(c.entity, AuraType::ShadowSight)
+            })
+    }
+
     // ------------------------------------------------------------------
     // Threat predicates (healer postures — R6/R7 trigger and window inputs)
     // ------------------------------------------------------------------
@@ -378,6 +408,38 @@ impl<'a> CombatContext<'a> {
             })
     }
 
+    /// The ally (self included, pets excluded) the enemy team is most likely
+    /// to burst next: the one with the most visible enemies targeting it.
+    /// Ties go to the lower HP fraction, then the lowest `Entity`. `None`
+    /// while no ally is being targeted — there is nothing to predict yet.
+    pub fn predicted_kill_target(&self, me: Entity) -> Option<&CombatantInfo> {
+        let my_team = self.combatants.get(&me)?.team;
+        self.combatants
+            .values()
+            .filter(|c| c.team == my_team && c.is_alive && !c.is_pet)
+            .map(|c| (c, self.enemies_targeting(c.entity).len()))
+            .filter(|(_, attackers)| *attackers > 0)
+            .min_by(|(a, a_count), (b, b_count)| {
+                b_count
+                    .cmp(a_count)
+                    .then(a.health_pct().partial_cmp(&b.health_pct()).unwrap())
+            })
+            .map(|(c, _)| c)
+    }
+
+    /// Remaining Weakened Soul duration on `entity`, or `None` if it can
+    /// receive Power Word: Shield. Readable for any combatant, not just the
+    /// caster's current target.
+    pub fn weakened_soul_remaining(&self, entity: Entity) -> Option<f32> {
+        self.active_auras.get(&entity).and_then(|auras| {
+            auras
+                .iter()
+                .filter(|a| a.effect_type == AuraType::WeakenedSoul)
+                .map(|a| a.duration)
+                .max_by(|a, b| a.partial_cmp(b).unwrap())
+        })
+    }
+
     /// Remaining movement-impairment window on `attacker`: the longest
     /// remaining Root/Stun/Incapacitate duration, or `None` if the attacker
     /// is free to move. Fear is deliberately excluded — a feared attacker
@@ -398,8 +460,9 @@ impl<'a> CombatContext<'a> {
     }
 
     /// Product of `MovementSpeedSlow` magnitudes currently on `entity`
-    /// (`1.0` = unslowed; `0.5` = moving at half speed). Mirrors the
-    /// executor's slow handling in `move_to_target`, so the ESCAPE window
+    /// (`1.0` = unslowed; `0.5` = moving at half speed), times any
+    /// `MovementSpeedIncrease` (Sprint). Mirrors the executor's
+    /// `effective_movement_speed` in `move_to_target`, so the ESCAPE window
     /// math (R7) predicts the same effective speed the directive will
     /// actually move at.
     pub fn movement_slow_multiplier(&self, entity: Entity) -> f32 {
@@ -408,8 +471,11 @@ impl<'a> CombatContext<'a> {
             .map(|auras| {
                 auras
                     .iter()
-                    .filter(|a| a.effect_type == AuraType::MovementSpeedSlow)
-                    .map(|a| a.magnitude)
+                    .map(|a| match a.effect_type {
+                        AuraType::MovementSpeedSlow => a.magnitude,
+                        AuraType::MovementSpeedIncrease => 1.0 + a.magnitude,
+                        _ => 1.0,
+                    })
                     .product()
             })
             .unwrap_or(1.0)
@@ -474,6 +540,15 @@ impl<'a> CombatContext<'a> {
             .unwrap_or(false)
     }
 
+    /// True when a living combatant is in execute range (below
+    /// `EXECUTE_HP_THRESHOLD`): execute abilities unlock and DPS AI treats
+    /// the target as a burst window.
+    pub fn in_execute_range(&self, entity: Entity) -> bool {
+        self.combatants
+            .get(&entity)
+            .is_some_and(|info| info.is_alive && info.health_pct() < EXECUTE_HP_THRESHOLD)
+    }
+
     /// Check if an entity has damage immunity (Divine Shield).
     pub fn entity_is_immune(&self, entity: Entity) -> bool {
         self.active_auras
@@ -548,6 +623,38 @@ where
         .map(|(entity, _, _)| entity)
 }
 
+/// Kill-hungry personalities swap to an enemy at or below this HP fraction.
+pub const KILL_HUNGRY_EXECUTE_PCT: f32 = 0.3;
+
+/// Kill-hungry target chooser (pure). Given eligible candidates
+/// `(entity, health_fraction)`, returns the one closest to dying if it is at
+/// or below `execute_pct`. Deterministic tie-break by entity. Same caller
+/// contract as [`select_softer_melee_target`].
+pub fn select_kill_hungry_target<I>(candidates: I, execute_pct: f32) -> Option<Entity>
+where
+    I: IntoIterator<Item = (Entity, f32)>,
+{
+    candidates
+        .into_iter()
+        .filter(|(_, pct)| *pct <= execute_pct)
+        .min_by(|(ea, pa), (eb, pb)| pa.partial_cmp(pb).unwrap().then(ea.cmp(eb)))
+        .map(|(entity, _)| entity)
+}
+
+/// Peel target chooser (pure). Given eligible candidates
+/// `(entity, distance, current_target)`, returns the nearest one targeting any
+/// of `protected` (the team's healers). Deterministic tie-break by entity.
+pub fn select_peel_target<I>(candidates: I, protected: &[Entity]) -> Option<Entity>
+where
+    I: IntoIterator<Item = (Entity, f32, Option<Entity>)>,
+{
+    candidates
+        .into_iter()
+        .filter(|(_, _, target)| target.is_some_and(|t| protected.contains(&t)))
+        .min_by(|(ea, da, _), (eb, db, _)| da.partial_cmp(db).unwrap().then(ea.cmp(eb)))
+        .map(|(entity, _, _)| entity)
+}
+
 // ============================================================================
 // Shared Healer Utilities
 // ============================================================================
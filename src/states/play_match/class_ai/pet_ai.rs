@@ -13,7 +13,7 @@ use crate::states::play_match::components::*;
 use crate::states::play_match::decision_trace::{
     ActorView, DecisionEventBuilder, DecisionTrace, RejectionReason, TargetView,
 };
-use crate::states::play_match::utils::spawn_speech_bubble;
+use crate::states::play_match::utils::{spawn_speech_bubble, stable_combatant_order};
 use crate::states::match_config::CharacterClass;
 use super::CombatContext;
 
@@ -121,7 +121,8 @@ pub fn pet_ai_system(
             })
             .collect();
 
-    for (entity, mut combatant, transform, pet, auras, pet_command) in pets.iter_mut() {
+    for entity in stable_combatant_order(pets.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, mut combatant, transform, pet, auras, pet_command)) = pets.get_mut(entity) else { continue; };
         if !combatant.is_alive() {
             continue;
         }
@@ -666,7 +667,7 @@ fn execute_boar_charge(
     target: Entity,
 ) {
     let ability = AbilityType::BoarCharge;
-    commands.entity(entity).try_insert(ChargingState { target });
+    commands.entity(entity).try_insert(ChargingState { target, on_arrival: None });
 
     if let Some(aura_pending) = AuraPending::from_ability(target, entity, def) {
         commands.spawn((aura_pending, PlayMatchEntity));
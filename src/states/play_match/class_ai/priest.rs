@@ -5,7 +5,7 @@
 //! ## Priority Order
 //! 1. Power Word: Fortitude (buff all allies pre-combat)
 //! 2. Dispel Magic - Urgent (Polymorph, Fear - complete loss of control)
-//! 3. Power Word: Shield (shield low-health allies)
+//! 3. Power Word: Shield (pre-shield the predicted kill target, else low-health allies)
 //! 4. Flash Heal (heal injured allies)
 //! 5. Dispel Magic - Maintenance (Roots, DoTs when team HP is stable)
 //! 6. Mind Blast (damage when allies are healthy)
@@ -36,6 +36,10 @@ use super::healer_postures::{
 
 use super::CombatContext;
 
+/// Enemies that must be targeting the predicted kill target before the
+/// Priest spends Power Word: Shield on it ahead of any damage.
+const PRE_SHIELD_MIN_ATTACKERS: usize = 2;
+
 /// Per-tick output of [`evaluate_priest_posture`], threaded into
 /// [`decide_priest_action`] (mirrors the Paladin's `PaladinMovementPlan`):
 /// the escape-defer urgency input plus the Psychic Scream dip gate.
@@ -513,6 +517,12 @@ fn try_fortitude(
 }
 
 /// Try to cast Power Word: Shield on an ally.
+///
+/// The predicted kill target (`CombatContext::predicted_kill_target`) is
+/// shielded first, at any HP, once `PRE_SHIELD_MIN_ATTACKERS` enemies are on
+/// it; otherwise the lowest ally that is either full
+/// HP (pre-combat) or below 70% is chosen. Allies with Weakened Soul are
+/// never candidates.
 fn try_power_word_shield(
     commands: &mut Commands,
     combat_log: &mut CombatLog,
@@ -539,37 +549,51 @@ fn try_power_word_shield(
         return false;
     }
 
-    let mut best_candidate: Option<(Entity, Vec3, f32)> = None;
-
-    for (ally_entity, info) in ctx.combatants.iter() {
-        if info.team != combatant.team || info.current_health <= 0.0 || info.is_pet {
-            continue;
-        }
-        let ally_auras = ctx.active_auras.get(ally_entity);
-        let has_weakened_soul = ally_auras
-            .map_or(false, |auras| auras.iter().any(|a| a.effect_type == AuraType::WeakenedSoul));
-        let has_pw_shield = ally_auras.map_or(false, |auras| {
+    // Weakened Soul is read for every ally, not just our target: an ally that
+    // can't take a shield is skipped so the GCD goes to one that can.
+    let can_shield = |ally: Entity| {
+        let has_pw_shield = ctx.active_auras.get(&ally).is_some_and(|auras| {
             auras
                 .iter()
                 .any(|a| a.effect_type == AuraType::Absorb && a.ability_name == "Power Word: Shield")
         });
-        let shielded_this_frame_check = shielded_this_frame.contains(ally_entity);
-
-        if has_weakened_soul || has_pw_shield || shielded_this_frame_check {
-            continue;
-        }
+        ctx.weakened_soul_remaining(ally).is_none()
+            && !has_pw_shield
+            && !shielded_this_frame.contains(&ally)
+    };
 
-        let hp_percent = info.current_health / info.max_health;
-        let is_full_hp = hp_percent >= 1.0;
-        let is_below_threshold = hp_percent < 0.7;
+    // Pre-shield the predicted kill target at any HP once the enemy team has
+    // converged on it, so the shield is up before the burst lands rather than
+    // after it drops below the reactive threshold.
+    let predicted = ctx
+        .predicted_kill_target(entity)
+        .filter(|info| ctx.enemies_targeting(info.entity).len() >= PRE_SHIELD_MIN_ATTACKERS)
+        .filter(|info| can_shield(info.entity) && my_pos.distance(info.position) <= pw_shield_def.range)
+        .map(|info| (info.entity, info.position, info.health_pct()));
+
+    let mut best_candidate: Option<(Entity, Vec3, f32)> = predicted;
+
+    if best_candidate.is_none() {
+        for (ally_entity, info) in ctx.combatants.iter() {
+            if info.team != combatant.team || info.current_health <= 0.0 || info.is_pet {
+                continue;
+            }
+            if !can_shield(*ally_entity) {
+                continue;
+            }
 
-        if is_full_hp || is_below_threshold {
-            match best_candidate {
-                None => best_candidate = Some((*ally_entity, info.position, hp_percent)),
-                Some((_, _, best_percent)) if hp_percent < best_percent => {
-                    best_candidate = Some((*ally_entity, info.position, hp_percent));
+            let hp_percent = info.current_health / info.max_health;
+            let is_full_hp = hp_percent >= 1.0;
+            let is_below_threshold = hp_percent < 0.7;
+
+            if is_full_hp || is_below_threshold {
+                match best_candidate {
+                    None => best_candidate = Some((*ally_entity, info.position, hp_percent)),
+                    Some((_, _, best_percent)) if hp_percent < best_percent => {
+                        best_candidate = Some((*ally_entity, info.position, hp_percent));
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
         }
     }
@@ -2,6 +2,12 @@
 //!
 //! Handles AI decision-making for the Rogue class.
 //!
+//! ## Escape (Off-GCD, Checked First)
+//! 1. Vanish when focused below 35% HP — skipped if Shadow Sight would reveal
+//!    the Rogue (held by the Rogue itself or any living enemy)
+//! 2. Evasion when focused below 70% HP by a melee attacker in range
+//! 3. Sprint once engaged, when focused or restealthed and the target is far
+//!
 //! ## Priority Order (Stealthed)
 //! 1. Ambush (opener from stealth)
 //!
@@ -64,6 +70,14 @@ pub fn decide_rogue_action(
         return false;
     };
 
+    // Defensive kit (off the GCD, usable in or out of stealth): runs before the
+    // immune-target check so a focused Rogue still escapes while its own target
+    // is bubbled.
+    if try_escape(commands, combat_log, abilities, entity, combatant, my_pos, target_pos, ctx, &mut builder) {
+        builder.finish();
+        return true;
+    }
+
     // Don't waste abilities on immune targets (Divine Shield).
     if ctx.entity_is_immune(target_entity) {
         builder.finish_no_action(NoActionReason::TargetImmune);
@@ -190,6 +204,146 @@ pub fn decide_rogue_action(
     acted
 }
 
+/// Health fraction below which a focused Rogue pops Evasion against melee.
+const EVASION_HEALTH_THRESHOLD: f32 = 0.7;
+
+/// Health fraction below which a focused Rogue Vanishes.
+const VANISH_HEALTH_THRESHOLD: f32 = 0.35;
+
+/// Distance to the target beyond which an engaged Rogue Sprints to close
+/// (a kiting target while focused, or the reopen after a Vanish).
+const SPRINT_MIN_DISTANCE: f32 = 12.0;
+
+/// Escape logic for a focused Rogue, in priority order:
+/// 1. Vanish below `VANISH_HEALTH_THRESHOLD` — only when it would actually hide
+///    (no Shadow Sight on the Rogue or any living enemy).
+/// 2. Evasion below `EVASION_HEALTH_THRESHOLD` with a melee attacker in range.
+/// 3. Sprint once engaged when the target is beyond `SPRINT_MIN_DISTANCE`.
+///
+/// Returns true if one of them was used.
+fn try_escape(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    target_pos: Vec3,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let attackers = ctx.enemies_targeting(entity);
+    let focused = !attackers.is_empty();
+    let health_pct = combatant.current_health / combatant.max_health;
+
+    if focused && !combatant.stealthed && health_pct < VANISH_HEALTH_THRESHOLD {
+        if !ctx.vanish_would_hide(entity) {
+            builder.reject(
+                AbilityType::Vanish,
+                hold_reason("Vanish held: Shadow Sight would reveal the Rogue"),
+            );
+        } else if try_vanish(commands, combat_log, abilities, entity, combatant, builder) {
+            return true;
+        }
+    }
+
+    let melee_attacker_in_range = attackers.iter().any(|a| {
+        a.pet_type.map_or(a.class.is_melee(), |pet| pet.is_melee())
+            && a.position.distance(my_pos) <= MELEE_RANGE
+    });
+    if melee_attacker_in_range
+        && health_pct < EVASION_HEALTH_THRESHOLD
+        && try_self_buff(commands, combat_log, abilities, AbilityType::Evasion, entity, combatant, ctx, builder)
+    {
+        return true;
+    }
+
+    if combatant.has_engaged()
+        && (focused || combatant.stealthed)
+        && my_pos.distance(target_pos) > SPRINT_MIN_DISTANCE
+        && try_self_buff(commands, combat_log, abilities, AbilityType::Sprint, entity, combatant, ctx, builder)
+    {
+        return true;
+    }
+
+    false
+}
+
+/// Try to use an off-GCD self-buff (Evasion, Sprint): applies the ability's
+/// aura to the Rogue and starts its cooldown.
+fn try_self_buff(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    ability: AbilityType,
+    entity: Entity,
+    combatant: &mut Combatant,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let def = abilities.get_unchecked(&ability);
+
+    if let Some(remaining) = combatant.ability_cooldowns.get(&ability) {
+        builder.reject(ability, RejectionReason::OnCooldown { remaining: *remaining });
+        return false;
+    }
+    let already_active = def.applies_aura.as_ref().is_some_and(|aura| {
+        ctx.self_auras().is_some_and(|auras| auras.iter().any(|a| a.effect_type == aura.aura_type))
+    });
+    if already_active {
+        builder.reject(ability, RejectionReason::AlreadyApplied);
+        return false;
+    }
+
+    builder.choose(ability, Some(entity), true);
+
+    combatant.start_cooldown(ability, abilities);
+    if let Some(aura_pending) = AuraPending::from_ability(entity, entity, def) {
+        commands.spawn(aura_pending);
+    }
+
+    log_ability_use(combat_log, combatant.team, combatant.class, &def.name, None, "uses");
+    spawn_speech_bubble(commands, entity, &def.name);
+
+    info!("Team {} {} uses {}", combatant.team, combatant.class.name(), def.name);
+
+    true
+}
+
+/// Try to use Vanish: restealth and shed every root and movement slow. Enemies
+/// lose their lock on the Rogue on their next target acquisition, and casts
+/// aimed at it fizzle (see `process_casting`), unless they hold Shadow Sight.
+fn try_vanish(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let ability = AbilityType::Vanish;
+    let def = abilities.get_unchecked(&ability);
+
+    if let Some(remaining) = combatant.ability_cooldowns.get(&ability) {
+        builder.reject(ability, RejectionReason::OnCooldown { remaining: *remaining });
+        return false;
+    }
+
+    builder.choose(ability, Some(entity), true);
+
+    combatant.start_cooldown(ability, abilities);
+    combatant.stealthed = true;
+
+    commands.spawn(VanishPending { caster: entity });
+
+    log_ability_use(combat_log, combatant.team, combatant.class, &def.name, None, "uses");
+    spawn_speech_bubble(commands, entity, &def.name);
+
+    info!("Team {} {} vanishes into stealth!", combatant.team, combatant.class.name());
+
+    true
+}
+
 /// Buffer (seconds) before a control window expires at which the Rogue chains
 /// the next stun. Firing this early trades ~0.5s of the lockdown for safety
 /// against the target dying / going immune / leaving melee in the final moment.
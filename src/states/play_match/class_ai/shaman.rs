@@ -485,7 +485,7 @@ fn totem_spec(element: TotemElement) -> (AbilityType, AuraType, f32, SpellSchool
 /// element index). No RNG — required for seeded-replay determinism.
 fn totem_spacing_offset(element: TotemElement) -> Vec3 {
     let angle = element.index() as f32 * std::f32::consts::FRAC_PI_2;
-    Vec3::new(angle.cos(), 0.0, angle.sin()) * TOTEM_SPACING_OFFSET
+    Vec3::new(ops::cos(angle), 0.0, ops::sin(angle)) * TOTEM_SPACING_OFFSET
 }
 
 /// Cast-and-drop a single totem. Modeled on the Hunter's Frost Trap cast but
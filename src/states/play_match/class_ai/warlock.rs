@@ -3,6 +3,8 @@
 //! Handles AI decision-making for the Warlock class.
 //!
 //! ## Priority Order
+//! 0. Death Coil peel, Soulstone (costs a Soul Shard), then the execute:
+//!    Shadowburn below 20% HP (costs a shard), else Drain Soul to refill shards
 //! 1. Corruption (instant Shadow DoT)
 //! 2. Spread curses to enemies (per-target preferences)
 //! 3. Immolate (2s cast Fire DoT) - skipped when being kited
@@ -26,7 +28,9 @@ use crate::states::play_match::components::{
     DRCategory, PlayMatchEntity, Projectile,
 };
 use crate::states::play_match::combat_core::calculate_cast_time;
-use crate::states::play_match::constants::GCD;
+use crate::states::play_match::constants::{
+    DRAIN_SOUL_SHARD_HP_THRESHOLD, GCD, MAX_SOUL_SHARDS,
+};
 use crate::states::play_match::decision_trace::{
     DecisionEventBuilder, DecisionTrace, RejectionReason,
 };
@@ -189,6 +193,41 @@ pub fn decide_warlock_action(
         }
     }
 
+    // Priority 0.25: Soulstone. Banked early so a later death isn't final.
+    if try_soulstone(commands, combat_log, abilities, entity, combatant, my_pos, auras, ctx, &mut builder) {
+        builder.finish();
+        return true;
+    }
+
+    // Priority 0.5: Execute. Shadowburn spends a shard to finish the kill
+    // target; with no shard left, Drain Soul pulls one out of it instead.
+    let target_hp = target_info.health_pct();
+    if target_immune {
+        builder.reject(AbilityType::Shadowburn, RejectionReason::TargetImmune);
+    } else if try_shadowburn(
+        commands, combat_log, abilities, entity, combatant, my_pos, auras,
+        target_entity, target_pos, target_hp, ctx, &mut builder,
+    ) {
+        builder.finish();
+        return true;
+    }
+    if target_immune {
+        builder.reject(AbilityType::DrainSoul, RejectionReason::TargetImmune);
+    } else if being_kited {
+        builder.reject(
+            AbilityType::DrainSoul,
+            RejectionReason::PreconditionUnmet {
+                note: "being kited — channel would be interrupted".into(),
+            },
+        );
+    } else if try_drain_soul(
+        commands, combat_log, abilities, entity, combatant, my_pos, auras,
+        target_entity, target_pos, target_hp, ctx, &mut builder,
+    ) {
+        builder.finish();
+        return true;
+    }
+
     let enemy_has_dispeller = ctx.alive_enemies().iter().any(|e| matches!(
         e.class,
         CharacterClass::Priest | CharacterClass::Paladin
@@ -696,6 +735,227 @@ fn try_death_coil(
     true
 }
 
+/// Consume a Soul Shard to Soulstone the Warlock.
+///
+/// Skipped when the Warlock is its team's last combatant: its death would end
+/// the match before the revival, so the shard is better kept for Shadowburn.
+fn try_soulstone(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let soulstone = AbilityType::Soulstone;
+    let def = abilities.get_unchecked(&soulstone);
+
+    if ctx.has_aura(AuraType::SoulstoneResurrection) {
+        builder.reject(soulstone, RejectionReason::AlreadyApplied);
+        return false;
+    }
+    if combatant.soul_shards == 0 {
+        builder.reject(
+            soulstone,
+            RejectionReason::PreconditionUnmet { note: "no Soul Shard".into() },
+        );
+        return false;
+    }
+    if ctx.alive_allies().len() < 2 {
+        builder.reject(
+            soulstone,
+            RejectionReason::PreconditionUnmet { note: "no living teammate to outlast a death".into() },
+        );
+        return false;
+    }
+
+    if !pre_cast_ok(soulstone, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()) {
+        builder.reject(
+            soulstone,
+            classify_pre_cast_failure(soulstone, def, combatant, my_pos, auras, None, ctx, PreCastOpts::default()),
+        );
+        return false;
+    }
+
+    builder.choose(soulstone, Some(entity), true);
+
+    combatant.spend_soul_shard();
+    combatant.current_mana -= def.mana_cost;
+    combatant.start_cooldown(soulstone, abilities);
+    combatant.global_cooldown = GCD;
+
+    log_ability_use(combat_log, combatant.team, combatant.class, "Soulstone", None, "uses");
+
+    if let Some(aura_pending) = AuraPending::from_ability(entity, entity, def) {
+        commands.spawn(aura_pending);
+    }
+
+    info!(
+        "Team {} {} uses Soulstone ({} shards left)",
+        combatant.team,
+        combatant.class.name(),
+        combatant.soul_shards
+    );
+
+    true
+}
+
+/// Spend a Soul Shard on Shadowburn to execute a low-HP target.
+/// Instant: resolves through a zero-length `CastingState` like Frost Shock,
+/// so damage and mana are handled by the casting system.
+fn try_shadowburn(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    target_entity: Entity,
+    target_pos: Vec3,
+    target_hp: f32,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let shadowburn = AbilityType::Shadowburn;
+    let def = abilities.get_unchecked(&shadowburn);
+
+    // Execute range is enforced by `pre_cast_ok` (Shadowburn is `is_execute`).
+    let opts = PreCastOpts { check_friendly_cc: true, ..Default::default() };
+    if !pre_cast_ok(
+        shadowburn, def, combatant, my_pos, auras,
+        Some((target_entity, target_pos)), ctx, opts,
+    ) {
+        builder.reject(
+            shadowburn,
+            classify_pre_cast_failure(
+                shadowburn, def, combatant, my_pos, auras,
+                Some((target_entity, target_pos)), ctx, opts,
+            ),
+        );
+        return false;
+    }
+    if combatant.soul_shards == 0 {
+        builder.reject(
+            shadowburn,
+            RejectionReason::PreconditionUnmet { note: "no Soul Shard".into() },
+        );
+        return false;
+    }
+
+    builder.choose(shadowburn, Some(target_entity), true);
+
+    combatant.spend_soul_shard();
+    combatant.start_cooldown(shadowburn, abilities);
+    combatant.global_cooldown = GCD;
+    let cast_time = calculate_cast_time(def.cast_time, auras); // 0.0 — completes immediately
+    commands.entity(entity).insert(CastingState::new(shadowburn, target_entity, cast_time));
+
+    let target_tuple = ctx.combatants
+        .get(&target_entity)
+        .map(|info| (info.team, info.class));
+    log_ability_use(combat_log, combatant.team, combatant.class, "Shadowburn", target_tuple, "casts");
+
+    info!(
+        "Team {} {} Shadowburns enemy at {:.0}% HP ({} shards left)",
+        combatant.team,
+        combatant.class.name(),
+        target_hp * 100.0,
+        combatant.soul_shards
+    );
+
+    true
+}
+
+/// Channel Drain Soul on a low-HP target to refill an empty shard pool.
+/// Each tick on a target at or below `DRAIN_SOUL_SHARD_HP_THRESHOLD` yields a
+/// shard (see `process_channeling`); at full shards the channel is pointless.
+fn try_drain_soul(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    target_entity: Entity,
+    target_pos: Vec3,
+    target_hp: f32,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let drain_soul = AbilityType::DrainSoul;
+    let def = abilities.get_unchecked(&drain_soul);
+
+    if target_hp > DRAIN_SOUL_SHARD_HP_THRESHOLD {
+        builder.reject(
+            drain_soul,
+            RejectionReason::PreconditionUnmet { note: "target too healthy to yield a shard".into() },
+        );
+        return false;
+    }
+    if combatant.soul_shards > 0 {
+        let note = if combatant.soul_shards >= MAX_SOUL_SHARDS {
+            "Soul Shards full"
+        } else {
+            "already holding a Soul Shard"
+        };
+        builder.reject(drain_soul, RejectionReason::PreconditionUnmet { note: note.into() });
+        return false;
+    }
+
+    let opts = PreCastOpts { check_friendly_cc: true, ..Default::default() };
+    if !pre_cast_ok(
+        drain_soul, def, combatant, my_pos, auras,
+        Some((target_entity, target_pos)), ctx, opts,
+    ) {
+        builder.reject(
+            drain_soul,
+            classify_pre_cast_failure(
+                drain_soul, def, combatant, my_pos, auras,
+                Some((target_entity, target_pos)), ctx, opts,
+            ),
+        );
+        return false;
+    }
+
+    builder.choose(drain_soul, Some(target_entity), false);
+
+    combatant.current_mana -= def.mana_cost;
+    combatant.global_cooldown = GCD;
+
+    let channel_duration = def.channel_duration.unwrap_or(5.0);
+    let tick_interval = def.channel_tick_interval;
+
+    commands.entity(entity).insert(ChannelingState {
+        ability: drain_soul,
+        duration_remaining: channel_duration,
+        time_until_next_tick: tick_interval,
+        tick_interval,
+        target: target_entity,
+        interrupted: false,
+        interrupted_display_time: 0.0,
+        ticks_applied: 0,
+    });
+
+    let target_tuple = ctx.combatants
+        .get(&target_entity)
+        .map(|info| (info.team, info.class));
+    log_ability_use(combat_log, combatant.team, combatant.class, "Drain Soul", target_tuple, "begins channeling");
+
+    info!(
+        "Team {} {} starts channeling Drain Soul on enemy at {:.0}% HP",
+        combatant.team,
+        combatant.class.name(),
+        target_hp * 100.0
+    );
+
+    true
+}
+
 /// Try to cast Shadow Bolt on target.
 fn try_shadowbolt(
     commands: &mut Commands,
@@ -4,10 +4,18 @@
 //!
 //! ## Priority Order
 //! 1. Shout (buff allies or debuff enemies based on warrior_shout preference)
-//! 2. Charge (gap closer when out of melee range)
-//! 3. Rend (bleed DoT on target)
-//! 4. Mortal Strike (main damage, healing reduction)
-//! 5. Heroic Strike (rage dump)
+//! 2. Peel for the healer (Intercept their attacker, else Intervene to them)
+//! 3. Charge (opener, until the Warrior first lands damage) / Intercept (after)
+//! 4. Execute (target in execute range, below 20% HP)
+//! 5. Rend (bleed DoT on target)
+//! 6. Mortal Strike (main damage, healing reduction)
+//! 7. Heroic Strike (rage dump)
+//!
+//! Execute range also opens a burst window (like a CC'd enemy healer), which
+//! moves Mortal Strike ahead of Rend.
+//!
+//! Charge and Intercept share the "Charge" cooldown group, so the opener
+//! locks Intercept out for Charge's cooldown and vice versa.
 #![allow(clippy::too_many_arguments)]
 
 use bevy::prelude::*;
@@ -76,6 +84,24 @@ pub fn decide_warrior_action(
         return true;
     }
 
+    // Priority 2: Peel for the healer
+    let mut intercept_tried = false;
+    if try_peel_for_healer(
+        commands,
+        combat_log,
+        abilities,
+        entity,
+        combatant,
+        my_pos,
+        auras,
+        ctx,
+        &mut intercept_tried,
+        &mut builder,
+    ) {
+        builder.finish();
+        return true;
+    }
+
     // Get target for combat abilities
     let Some(target_entity) = combatant.target else {
         builder.finish_no_action(NoActionReason::NoValidTarget);
@@ -94,31 +120,62 @@ pub fn decide_warrior_action(
         return false;
     }
 
-    // Priority 2: Charge (gap closer)
-    if try_charge(
-        commands,
-        combat_log,
-        abilities,
-        entity,
-        combatant,
-        my_pos,
-        auras,
-        target_entity,
-        target_pos,
-        ctx,
-        &mut builder,
-    ) {
+    // Priority 3: Charge (opener) or Intercept (in-combat gap closer)
+    let gap_closed = if combatant.has_engaged() {
+        !intercept_tried
+            && try_intercept(
+                commands,
+                combat_log,
+                abilities,
+                entity,
+                combatant,
+                my_pos,
+                auras,
+                target_entity,
+                target_pos,
+                ctx,
+                &mut builder,
+            )
+    } else {
+        try_charge(
+            commands,
+            combat_log,
+            abilities,
+            entity,
+            combatant,
+            my_pos,
+            auras,
+            target_entity,
+            target_pos,
+            ctx,
+            &mut builder,
+        )
+    };
+    if gap_closed {
         builder.finish();
         return true;
     }
 
-    // Burst-during-CC (bucket A): while the enemy healer is hard-CC'd and
-    // can't react, land Mortal Strike (the hard hit + Mortal Wounds healing
-    // debuff) BEFORE spending the GCD refreshing the Rend DoT. Outside the
-    // window the normal order holds (Rend, then Mortal Strike). Each ability
-    // is still attempted at most once per tick (the `!burst_window` guard on
-    // the trailing Mortal Strike), keeping the decision trace clean.
-    let burst_window = ctx.enemy_healer_is_cced();
+    // Burst window: while the enemy healer is hard-CC'd and can't react (bucket
+    // A), or the target is in execute range, land Mortal Strike (the hard hit +
+    // Mortal Wounds healing debuff) BEFORE spending the GCD refreshing the Rend
+    // DoT. Outside the window the normal order holds (Rend, then Mortal Strike).
+    // Each ability is still attempted at most once per tick (the
+    // `!burst_window` guard on the trailing Mortal Strike), keeping the
+    // decision trace clean.
+    let in_execute_range = ctx.in_execute_range(target_entity);
+    let burst_window = ctx.enemy_healer_is_cced() || in_execute_range;
+
+    // Priority 3.5: Execute (only once the target is in execute range)
+    if in_execute_range
+        && try_execute(
+            combat_log, game_rng, abilities, entity, combatant, my_pos, auras,
+            target_entity, target_pos, ctx, instant_attacks, &mut builder,
+        )
+    {
+        builder.finish();
+        return true;
+    }
 
     if burst_window
         && try_mortal_strike(
@@ -130,7 +187,7 @@ pub fn decide_warrior_action(
         return true;
     }
 
-    // Priority 3: Rend (DoT)
+    // Priority 5: Rend (DoT)
     if try_rend(
         commands,
         combat_log,
@@ -148,7 +205,7 @@ pub fn decide_warrior_action(
         return true;
     }
 
-    // Priority 4: Mortal Strike (normal-order attempt — skipped when the burst
+    // Priority 6: Mortal Strike (normal-order attempt — skipped when the burst
     // window already attempted it above).
     if !burst_window
         && try_mortal_strike(
@@ -171,7 +228,7 @@ pub fn decide_warrior_action(
         return true;
     }
 
-    // Priority 5: Heroic Strike (rage dump)
+    // Priority 7: Heroic Strike (rage dump)
     try_heroic_strike(abilities, combatant, target_pos, my_pos, &mut builder);
 
     // No GCD-consuming ability used this tick. Heroic Strike may have queued
@@ -436,7 +493,8 @@ fn try_commanding_shout(
     true
 }
 
-/// Try to use Charge to close distance.
+/// Try to use Charge to close distance. Charge is the opener; once the
+/// Warrior has landed damage the caller tries Intercept instead.
 /// Returns true if Charge was used.
 fn try_charge(
     commands: &mut Commands,
@@ -464,12 +522,7 @@ fn try_charge(
         return false;
     }
 
-    // Check if rooted
-    let is_rooted = auras
-        .map(|a| a.auras.iter().any(|aura| matches!(aura.effect_type, AuraType::Root)))
-        .unwrap_or(false);
-
-    if is_rooted {
+    if is_rooted(auras) {
         builder.reject(charge, RejectionReason::Rooted);
         return false;
     }
@@ -477,45 +530,137 @@ fn try_charge(
     let distance_to_target = my_pos.distance(target_pos);
 
     // Must be within charge range
-    if distance_to_target < CHARGE_MIN_RANGE {
+    if !charge_distance_ok(charge, charge_def.range, distance_to_target, builder) {
+        return false;
+    }
+
+    builder.choose(charge, Some(target_entity), true);
+
+    // Execute Charge
+    combatant.start_cooldown(charge, abilities);
+    combatant.global_cooldown = GCD;
+
+    commands.entity(entity).insert(ChargingState {
+        target: target_entity,
+        on_arrival: None,
+    });
+
+    // Log
+    let target_tuple = ctx.combatants
+        .get(&target_entity)
+        .map(|info| (info.team, info.class));
+    log_ability_use(combat_log, combatant.team, combatant.class, "Charge", target_tuple, "uses");
+
+    info!(
+        "Team {} {} uses Charge on enemy (distance: {:.1} units)",
+        combatant.team,
+        combatant.class.name(),
+        distance_to_target
+    );
+
+    true
+}
+
+/// Whether a Root aura pins the Warrior in place (blocks every charge-type move).
+fn is_rooted(auras: Option<&ActiveAuras>) -> bool {
+    auras.is_some_and(|a| a.auras.iter().any(|aura| aura.effect_type == AuraType::Root))
+}
+
+/// Charge-type abilities need at least `CHARGE_MIN_RANGE` to the destination
+/// and at most the ability's range. Records the rejection when out of bounds.
+fn charge_distance_ok(
+    ability: AbilityType,
+    range: f32,
+    distance: f32,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    if distance < CHARGE_MIN_RANGE {
         builder.reject(
-            charge,
+            ability,
             RejectionReason::WithinDeadZone {
-                distance: distance_to_target,
+                distance,
                 min: CHARGE_MIN_RANGE,
             },
         );
         return false;
     }
-    if distance_to_target > charge_def.range {
+    if distance > range {
+        builder.reject(ability, RejectionReason::OutOfRange { distance, max: range });
+        return false;
+    }
+    true
+}
+
+/// Try to Intercept `target_entity`: the in-combat Charge, which also stuns
+/// the target once it connects. Shares Charge's cooldown group.
+/// Returns true if Intercept was used.
+fn try_intercept(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    target_entity: Entity,
+    target_pos: Vec3,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let intercept = AbilityType::Intercept;
+    let def = abilities.get_unchecked(&intercept);
+
+    if ctx.has_friendly_breakable_cc(target_entity) {
+        builder.reject(intercept, RejectionReason::FriendlyBreakableCC);
+        return false;
+    }
+
+    if let Some(remaining) = combatant.ability_cooldowns.get(&intercept) {
+        builder.reject(intercept, RejectionReason::OnCooldown { remaining: *remaining });
+        return false;
+    }
+
+    if is_rooted(auras) {
+        builder.reject(intercept, RejectionReason::Rooted);
+        return false;
+    }
+
+    if combatant.current_mana < def.mana_cost {
         builder.reject(
-            charge,
-            RejectionReason::OutOfRange {
-                distance: distance_to_target,
-                max: charge_def.range,
+            intercept,
+            RejectionReason::InsufficientResource {
+                resource: ResourceKind::Rage,
+                have: combatant.current_mana,
+                need: def.mana_cost,
             },
         );
         return false;
     }
 
-    builder.choose(charge, Some(target_entity), true);
+    let distance_to_target = my_pos.distance(target_pos);
+    if !charge_distance_ok(intercept, def.range, distance_to_target, builder) {
+        return false;
+    }
 
-    // Execute Charge
-    combatant.ability_cooldowns.insert(charge, charge_def.cooldown);
+    builder.choose(intercept, Some(target_entity), true);
+
+    combatant.current_mana -= def.mana_cost;
+    combatant.start_cooldown(intercept, abilities);
     combatant.global_cooldown = GCD;
 
+    // The stun lands when the charge connects (see `move_to_target`)
     commands.entity(entity).insert(ChargingState {
         target: target_entity,
+        on_arrival: AuraPending::from_ability(target_entity, entity, def),
     });
 
-    // Log
     let target_tuple = ctx.combatants
         .get(&target_entity)
         .map(|info| (info.team, info.class));
-    log_ability_use(combat_log, combatant.team, combatant.class, "Charge", target_tuple, "uses");
+    log_ability_use(combat_log, combatant.team, combatant.class, "Intercept", target_tuple, "uses");
 
     info!(
-        "Team {} {} uses Charge on enemy (distance: {:.1} units)",
+        "Team {} {} uses Intercept (distance: {:.1} units)",
         combatant.team,
         combatant.class.name(),
         distance_to_target
@@ -524,6 +669,154 @@ fn try_charge(
     true
 }
 
+/// Try to Intervene to `ally`: dash to them and take the next attack aimed
+/// at them (the Intervene aura is consumed by the first redirect).
+/// Returns true if Intervene was used.
+fn try_intervene(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    ally: Entity,
+    ally_pos: Vec3,
+    ctx: &CombatContext,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let intervene = AbilityType::Intervene;
+    let def = abilities.get_unchecked(&intervene);
+
+    if let Some(remaining) = combatant.ability_cooldowns.get(&intervene) {
+        builder.reject(intervene, RejectionReason::OnCooldown { remaining: *remaining });
+        return false;
+    }
+
+    let already_protected = ctx.active_auras
+        .get(&ally)
+        .is_some_and(|auras| auras.iter().any(|a| a.effect_type == AuraType::Intervene));
+    if already_protected {
+        builder.reject(intervene, RejectionReason::AlreadyApplied);
+        return false;
+    }
+
+    if is_rooted(auras) {
+        builder.reject(intervene, RejectionReason::Rooted);
+        return false;
+    }
+
+    if combatant.current_mana < def.mana_cost {
+        builder.reject(
+            intervene,
+            RejectionReason::InsufficientResource {
+                resource: ResourceKind::Rage,
+                have: combatant.current_mana,
+                need: def.mana_cost,
+            },
+        );
+        return false;
+    }
+
+    let distance_to_ally = my_pos.distance(ally_pos);
+    if !charge_distance_ok(intervene, def.range, distance_to_ally, builder) {
+        return false;
+    }
+
+    builder.choose(intervene, Some(ally), true);
+
+    combatant.current_mana -= def.mana_cost;
+    combatant.start_cooldown(intervene, abilities);
+    combatant.global_cooldown = GCD;
+
+    commands.entity(entity).insert(ChargingState { target: ally, on_arrival: None });
+    if let Some(aura_pending) = AuraPending::from_ability(ally, entity, def) {
+        commands.spawn(aura_pending);
+    }
+
+    let ally_tuple = ctx.combatants.get(&ally).map(|info| (info.team, info.class));
+    log_ability_use(combat_log, combatant.team, combatant.class, "Intervene", ally_tuple, "uses");
+
+    info!(
+        "Team {} {} uses Intervene (distance: {:.1} units)",
+        combatant.team,
+        combatant.class.name(),
+        distance_to_ally
+    );
+
+    true
+}
+
+/// Peel for the team's healer once the Warrior has engaged. The healer's
+/// nearest (non-pet) attacker gets Intercepted — the stun buys the healer
+/// room; if that isn't possible the Warrior Intervenes to the healer instead.
+/// Skipped when that attacker is already the Warrior's own target, since the
+/// normal gap-closer handles it. Sets `intercept_tried` when Intercept was
+/// evaluated so the caller doesn't attempt it twice in one tick.
+/// Returns true if either ability was used.
+fn try_peel_for_healer(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    ctx: &CombatContext,
+    intercept_tried: &mut bool,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    if !combatant.has_engaged() {
+        return false;
+    }
+    let Some(healer) = ctx.ally_healer() else {
+        return false;
+    };
+    let Some(healer_pos) = ctx.combatants.get(&healer).map(|info| info.position) else {
+        return false;
+    };
+    let attacker = ctx
+        .enemies_targeting(healer)
+        .into_iter()
+        .filter(|c| !c.is_pet)
+        .min_by(|a, b| {
+            healer_pos
+                .distance(a.position)
+                .partial_cmp(&healer_pos.distance(b.position))
+                .unwrap()
+        });
+    let Some(attacker) = attacker else {
+        return false;
+    };
+    if combatant.target == Some(attacker.entity) {
+        return false;
+    }
+
+    if !ctx.entity_is_immune(attacker.entity) {
+        *intercept_tried = true;
+        if try_intercept(
+            commands,
+            combat_log,
+            abilities,
+            entity,
+            combatant,
+            my_pos,
+            auras,
+            attacker.entity,
+            attacker.position,
+            ctx,
+            builder,
+        ) {
+            return true;
+        }
+    }
+
+    try_intervene(
+        commands, combat_log, abilities, entity, combatant, my_pos, auras, healer, healer_pos,
+        ctx, builder,
+    )
+}
+
 /// Try to apply Rend DoT to target.
 /// Returns true if Rend was used.
 fn try_rend(
@@ -713,6 +1006,93 @@ fn try_mortal_strike(
     true
 }
 
+/// Try to use Execute on a target in execute range (gated by `pre_cast_ok`,
+/// since Execute is `is_execute`). Resolves through the instant-attack queue
+/// like Mortal Strike.
+/// Returns true if Execute was used.
+fn try_execute(
+    combat_log: &mut CombatLog,
+    game_rng: &mut GameRng,
+    abilities: &AbilityDefinitions,
+    entity: Entity,
+    combatant: &mut Combatant,
+    my_pos: Vec3,
+    auras: Option<&ActiveAuras>,
+    target_entity: Entity,
+    target_pos: Vec3,
+    ctx: &CombatContext,
+    instant_attacks: &mut Vec<super::QueuedInstantAttack>,
+    builder: &mut DecisionEventBuilder<'_>,
+) -> bool {
+    let execute = AbilityType::Execute;
+    let def = abilities.get_unchecked(&execute);
+
+    let opts = PreCastOpts { check_friendly_cc: true, ..Default::default() };
+    if !pre_cast_ok(
+        execute,
+        def,
+        combatant,
+        my_pos,
+        auras,
+        Some((target_entity, target_pos)),
+        ctx,
+        opts,
+    ) {
+        builder.reject(
+            execute,
+            classify_pre_cast_failure(
+                execute,
+                def,
+                combatant,
+                my_pos,
+                auras,
+                Some((target_entity, target_pos)),
+                ctx,
+                opts,
+            ),
+        );
+        return false;
+    }
+
+    let Some(target_info) = ctx.combatants.get(&target_entity) else {
+        builder.reject(execute, RejectionReason::NoValidTarget);
+        return false;
+    };
+
+    builder.choose(execute, Some(target_entity), true);
+
+    combatant.current_mana -= def.mana_cost;
+    combatant.global_cooldown = GCD;
+
+    log_ability_use(combat_log, combatant.team, combatant.class, "Execute", Some((target_info.team, target_info.class)), "uses");
+
+    let self_auras = ctx.active_auras.get(&entity).map(|v| v.as_slice()).unwrap_or(&[]);
+    let ap_bonus = get_attack_power_bonus_from_slice(self_auras);
+    let crit_bonus = get_crit_chance_bonus_from_slice(self_auras);
+    let mut damage = combatant.calculate_ability_damage_config(def, game_rng, ap_bonus, 0.0);
+    let is_crit = roll_crit(combatant.crit_chance + crit_bonus, game_rng);
+    if is_crit { damage *= CRIT_DAMAGE_MULTIPLIER; }
+    instant_attacks.push(super::QueuedInstantAttack {
+        attacker: entity,
+        target: target_entity,
+        damage,
+        attacker_team: combatant.team,
+        attacker_class: combatant.class,
+        ability: execute,
+        is_crit,
+    });
+
+    info!(
+        "Team {} {} Executes enemy at {:.0}% HP for {:.0} damage!",
+        combatant.team,
+        combatant.class.name(),
+        target_info.health_pct() * 100.0,
+        damage
+    );
+
+    true
+}
+
 /// Try to queue Heroic Strike for next auto-attack.
 /// This doesn't consume a GCD, just queues bonus damage.
 fn try_heroic_strike(
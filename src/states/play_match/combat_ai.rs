@@ -8,12 +8,13 @@
 use bevy::prelude::*;
 use bevy::ecs::system::SystemParam;
 use bevy_egui::egui;
-use crate::combat::log::CombatLog;
+use crate::combat::log::{CombatLog, CombatLogEventType};
 use super::match_config;
 use super::components::*;
 use super::abilities::AbilityType;
 use super::ability_config::AbilityDefinitions;
-use super::utils::{combatant_id, get_next_fct_offset, log_ability_use};
+use super::effect_pool::EffectCommandsExt;
+use super::utils::{combatant_id, get_next_fct_offset, log_ability_use, stable_combatant_order};
 use super::class_ai;
 
 // Re-export spawn_speech_bubble for backward compatibility (used by other modules)
@@ -68,10 +69,16 @@ pub fn acquire_targets(
         })
         .collect();
 
+    // HP fraction and current target of every living combatant, for the
+    // personality target overrides below (lookups only)
+    let mut personality_view: std::collections::HashMap<Entity, (f32, Option<Entity>)> =
+        std::collections::HashMap::new();
+
     for (entity, c, transform, _) in combatants.iter() {
         if !c.is_alive() {
             continue;
         }
+        personality_view.insert(entity, (c.current_health / c.max_health.max(1.0), c.target));
 
         let has_shadow_sight = shadow_sight_holders.contains(&entity);
         let is_immune = active_auras_map
@@ -113,7 +120,8 @@ pub fn acquire_targets(
         .filter(|(_, _, _, _, _, _, _, is_pet)| !is_pet).collect();
 
     // For each combatant, ensure they have a valid target
-    for (entity, mut combatant, transform, _) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, mut combatant, transform, _)) = combatants.get_mut(entity) else { continue; };
         if !combatant.is_alive() {
             combatant.target = None;
             combatant.cc_target = None;
@@ -228,7 +236,8 @@ pub fn acquire_targets(
         // in the same tick, so for the configured case the kill target's
         // intermediate write is never observed; the `swap_sticky` gate on that
         // re-force holds the swap across the hysteresis window (no ping-pong).
-        if combatant.class.is_melee() {
+        // Aggressive personalities skip it and tunnel their target.
+        if combatant.class.is_melee() && combatant.personality != match_config::Personality::Aggressive {
             if let Some((cur_entity, cur_pos, _, _, _, cur_health, _, _)) = combatant
                 .target
                 .and_then(|t| enemy_combatants.iter().find(|(e, ..)| *e == t))
@@ -276,6 +285,44 @@ pub fn acquire_targets(
             }
         }
 
+        // ===== Personality target overrides =====
+        // Kill-hungry combatants drop their focus for any enemy in execute
+        // range; peel-focused ones turn on whoever is attacking their healer.
+        // Runs last, so it wins over the kill-target re-force every tick; a
+        // change restarts the melee chase clock like a bucket A swap.
+        let my_pos = transform.translation;
+        let eligible = || {
+            enemy_combatants.iter().filter(|(_, _, st, ss, _, hp, imm, is_pet)| {
+                !is_pet && !imm && *hp > 0.0 && can_see(*st, *ss)
+            })
+        };
+        let override_target = match combatant.personality {
+            match_config::Personality::KillHungry => class_ai::select_kill_hungry_target(
+                eligible().filter_map(|(e, ..)| personality_view.get(e).map(|(pct, _)| (*e, *pct))),
+                class_ai::KILL_HUNGRY_EXECUTE_PCT,
+            ),
+            match_config::Personality::PeelFocused => {
+                let own_team = if combatant.team == 1 { &team1_combatants } else { &team2_combatants };
+                let healers: Vec<Entity> = own_team
+                    .iter()
+                    .filter(|(e, _, _, _, class, _, _, is_pet)| *e != entity && !is_pet && class.is_healer())
+                    .map(|(e, ..)| *e)
+                    .collect();
+                class_ai::select_peel_target(
+                    eligible().map(|(e, pos, ..)| {
+                        (*e, my_pos.distance(*pos), personality_view.get(e).and_then(|(_, t)| *t))
+                    }),
+                    &healers,
+                )
+            }
+            _ => None,
+        };
+        if let Some(new_target) = override_target.filter(|t| combatant.target != Some(*t)) {
+            combatant.target = Some(new_target);
+            combatant.last_kill_target = Some(new_target);
+            combatant.last_target_swap_time = decision_trace.current_sim_time;
+        }
+
         // ===== CC Target Acquisition =====
         // Separate from kill target - use for CC abilities to create outnumbering situations
 
@@ -584,7 +631,8 @@ pub fn decide_abilities(
     // the "Cheap Shot then Kick on same target same frame" wasted-interrupt gap.
     let mut same_frame_cc_queue: Vec<(Entity, Aura)> = Vec::new();
 
-    for (entity, mut combatant, transform, auras) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, mut combatant, transform, auras)) = combatants.get_mut(entity) else { continue; };
         // Flush pending same-frame CCs into the per-frame snapshot BEFORE any checks
         // or decisions for this combatant, so that stuns/roots landed earlier this frame
         // are visible to this combatant's incapacitation check and its CombatContext.
@@ -643,14 +691,35 @@ pub fn decide_abilities(
             continue; // Still incapacitated, can't do anything else
         }
 
+        // Mage-specific: an Ice Block encasement gates dispatch like CC, but the
+        // Mage may still cancel it early. Same builder ownership as above.
+        let encased = snapshot.active_auras
+            .get(&entity)
+            .is_some_and(|auras_slice| auras_slice.iter().any(|a| a.effect_type == AuraType::IceBlock));
+        if encased {
+            let cc_ctx = snapshot.context_for(entity);
+            if let Some(av) = cc_ctx
+                .self_info()
+                .map(crate::states::play_match::decision_trace::ActorView::from_info)
+            {
+                let mut builder = decision_trace.start_ability_decision(av, None);
+                class_ai::mage::try_cancel_ice_block(&mut commands, entity, &combatant, &mut builder);
+                builder.finish();
+            }
+            continue; // Still encased this frame either way
+        }
+
         if is_incapacitated {
             continue;
         }
-        
+
         let my_pos = transform.translation;
 
         let ctx = snapshot.context_for(entity);
 
+        // Cautious personalities see wider threat radii (see `for_personality`)
+        let movement_config = movement_config.for_personality(combatant.personality);
+
         // Class AI dispatch. Match is exhaustive over `CharacterClass` —
         // adding a new class fails the build here until a dispatch arm is
         // added, replacing the previous silent no-op when a class was
@@ -986,8 +1055,66 @@ pub fn decide_abilities(
         };
         let damage = (damage * ds_penalty).max(0.0);
 
+        // Intervene: redirect this strike onto the Warrior protecting the target.
+        let target_entity = match combatants.get_mut(target_entity) {
+            Ok((_, _, _, mut target_auras)) => super::combat_core::take_intervene(
+                target_auras.as_deref_mut(),
+                |e| snapshot.combatants.get(&e).is_some_and(|info| info.is_alive),
+            )
+            .map_or(target_entity, |protector| {
+                combat_log.log(
+                    CombatLogEventType::AuraRemoved,
+                    format!(
+                        "Team {} Warrior intervenes: Team {} {}'s {} hits the Warrior instead",
+                        snapshot.combatants.get(&protector).map_or(0, |info| info.team),
+                        attacker_team,
+                        attacker_class.name(),
+                        ability_name
+                    ),
+                );
+                protector
+            }),
+            Err(_) => target_entity,
+        };
+
         if let Ok((_, mut target, target_transform, mut target_auras)) = combatants.get_mut(target_entity) {
             if target.is_alive() {
+                // Melee attack table: physical melee strikes can be dodged (Evasion).
+                if attacker_class.is_melee()
+                    && ability_spell_school == super::abilities::SpellSchool::Physical
+                    && super::combat_core::roll_dodge(
+                        super::combat_core::get_dodge_chance(target_auras.as_deref()),
+                        &mut game_rng,
+                    )
+                {
+                    combat_log.log(
+                        CombatLogEventType::Damage,
+                        format!(
+                            "Team {} {}'s {} is dodged by Team {} {}",
+                            attacker_team,
+                            attacker_class.name(),
+                            ability_name,
+                            target.team,
+                            target.class.name()
+                        ),
+                    );
+                    let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(target_entity) {
+                        get_next_fct_offset(&mut fct_state)
+                    } else {
+                        (0.0, 0.0)
+                    };
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: target_transform.translation
+                            + Vec3::new(offset_x, super::FCT_HEIGHT + offset_y, 0.0),
+                        text: "Dodge".to_string(),
+                        color: egui::Color32::from_rgb(200, 200, 200),
+                        lifetime: 1.5,
+                        vertical_offset: offset_y,
+                        is_crit: false,
+                    });
+                    continue;
+                }
+
                 // Apply damage with absorb shield consideration
                 let (dmg, absorbed) = super::combat_core::apply_damage_with_absorb(
                     damage,
@@ -1028,17 +1155,14 @@ pub fn decide_abilities(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                        text: format!("{:.0}", actual_damage),
-                        color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
-                        lifetime: 1.5,
-                        vertical_offset: offset_y,
-                        is_crit,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                    text: format!("{:.0}", actual_damage),
+                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
+                    lifetime: 1.5,
+                    vertical_offset: offset_y,
+                    is_crit,
+                });
 
                 // Spawn light blue floating combat text for absorbed damage
                 if absorbed > 0.0 {
@@ -1047,17 +1171,14 @@ pub fn decide_abilities(
                     } else {
                         (0.0, 0.0)
                     };
-                    commands.spawn((
-                        FloatingCombatText {
-                            world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                            text: format!("{:.0} absorbed", absorbed),
-                            color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                            lifetime: 1.5,
-                            vertical_offset: absorb_offset_y,
-                            is_crit: false,
-                        },
-                        PlayMatchEntity,
-                    ));
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                        text: format!("{:.0} absorbed", absorbed),
+                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                        lifetime: 1.5,
+                        vertical_offset: absorb_offset_y,
+                        is_crit: false,
+                    });
                 }
 
                 // Log the instant attack with structured data
@@ -1117,6 +1238,7 @@ pub fn decide_abilities(
                         Some(combatant_id(attacker_team, attacker_class)),
                         death_message,
                     );
+                    commands.spawn(SoulShardPending { killer: attacker_entity });
                 }
             }
         }
@@ -1178,17 +1300,14 @@ pub fn decide_abilities(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                        text: format!("{:.0}", actual_damage),
-                        color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
-                        lifetime: 1.5,
-                        vertical_offset: offset_y,
-                        is_crit,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                    text: format!("{:.0}", actual_damage),
+                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
+                    lifetime: 1.5,
+                    vertical_offset: offset_y,
+                    is_crit,
+                });
 
                 // Spawn light blue floating combat text for absorbed damage
                 if absorbed > 0.0 {
@@ -1197,17 +1316,14 @@ pub fn decide_abilities(
                     } else {
                         (0.0, 0.0)
                     };
-                    commands.spawn((
-                        FloatingCombatText {
-                            world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                            text: format!("{:.0} absorbed", absorbed),
-                            color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                            lifetime: 1.5,
-                            vertical_offset: absorb_offset_y,
-                            is_crit: false,
-                        },
-                        PlayMatchEntity,
-                    ));
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                        text: format!("{:.0} absorbed", absorbed),
+                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                        lifetime: 1.5,
+                        vertical_offset: absorb_offset_y,
+                        is_crit: false,
+                    });
                 }
 
                 // Log the Frost Nova damage with structured data
@@ -1265,6 +1381,7 @@ pub fn decide_abilities(
                         Some(combatant_id(caster_team, caster_class)),
                         death_message,
                     );
+                    commands.spawn(SoulShardPending { killer: caster_entity });
                 }
             }
         }
@@ -1296,7 +1413,8 @@ pub fn check_interrupts(
         return;
     }
 
-    for (entity, mut combatant, transform) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, c, _)| (e, c))) {
+        let Ok((entity, mut combatant, transform)) = combatants.get_mut(entity) else { continue; };
         if !combatant.is_alive() {
             continue;
         }
@@ -8,9 +8,10 @@ use super::super::components::*;
 use super::super::abilities::{AbilityType, SpellSchool};
 use super::super::ability_config::AbilityDefinitions;
 use super::super::constants::CRIT_DAMAGE_MULTIPLIER;
-use super::super::utils::get_next_fct_offset;
+use super::super::effect_pool::EffectCommandsExt;
+use super::super::utils::{get_next_fct_offset, stable_combatant_order};
 use super::super::{MELEE_RANGE, WAND_RANGE, HUNTER_DEAD_ZONE, AUTO_SHOT_RANGE, FCT_HEIGHT};
-use super::damage::{roll_crit, apply_damage_with_absorb, get_physical_damage_reduction, get_divine_shield_damage_penalty};
+use super::damage::{roll_crit, apply_damage_with_absorb, get_physical_damage_reduction, get_divine_shield_damage_penalty, take_intervene, get_dodge_chance, roll_dodge};
 
 /// Auto-attack system: Process attacks based on attack speed timers.
 ///
@@ -148,7 +149,8 @@ pub fn combat_auto_attack(
     // whose order can ripple into downstream entity allocation.
     let mut damage_per_aura_break: std::collections::BTreeMap<Entity, f32> = std::collections::BTreeMap::new();
 
-    for (attacker_entity, transform, mut combatant, casting_state, channeling_state, auras) in combatants.iter_mut() {
+    for attacker_entity in stable_combatant_order(combatants.iter().map(|(e, _, c, ..)| (e, c))) {
+        let Ok((attacker_entity, transform, mut combatant, casting_state, channeling_state, auras)) = combatants.get_mut(attacker_entity) else { continue; };
         if !combatant.is_alive() {
             continue;
         }
@@ -314,6 +316,34 @@ pub fn combat_auto_attack(
         }
     }
 
+    // Intervene: the first swing at a protected ally lands on the Warrior
+    // instead. Resolved before damage so every downstream proc (rage, Frost
+    // Armor, poisons) sees the real victim.
+    for (attacker_entity, target_entity, _, _, _) in attacks.iter_mut() {
+        let Ok((_, _, _, _, _, mut target_auras)) = combatants.get_mut(*target_entity) else {
+            continue;
+        };
+        let protector = take_intervene(target_auras.as_deref_mut(), |e| {
+            combatant_info.get(&e).is_some_and(|info| info.4)
+        });
+        if let Some(protector) = protector {
+            if let (Some(attacker), Some(ally), Some(warrior)) = (
+                combatant_info.get(attacker_entity),
+                combatant_info.get(target_entity),
+                combatant_info.get(&protector),
+            ) {
+                combat_log.log(
+                    CombatLogEventType::AuraRemoved,
+                    format!(
+                        "Team {} {} intervenes: Team {} {}'s attack on {} hits the Warrior instead",
+                        warrior.0, warrior.2, attacker.0, attacker.2, ally.2
+                    ),
+                );
+            }
+            *target_entity = protector;
+        }
+    }
+
     // Apply damage to targets and track damage dealt.
     // The maps/sets below all use BTreeMap/BTreeSet rather than HashMap/HashSet
     // so iteration order is deterministic by Entity. `frost_armor_procs` in
@@ -378,6 +408,41 @@ pub fn combat_auto_attack(
 
         if let Ok((_, _, mut target, _, _, mut target_auras)) = combatants.get_mut(target_entity) {
             if target.is_alive() {
+                // Melee attack table: Evasion lets the target dodge swings
+                // outright (ranged Auto Shot / wands are never dodged).
+                let attacker_is_melee = combatant_info.get(&attacker_entity).is_some_and(|info| info.3);
+                if attacker_is_melee
+                    && roll_dodge(get_dodge_chance(target_auras.as_deref()), &mut game_rng)
+                {
+                    if let (Some(attacker), Some(victim)) =
+                        (combatant_info.get(&attacker_entity), combatant_info.get(&target_entity))
+                    {
+                        combat_log.log(
+                            CombatLogEventType::Damage,
+                            format!(
+                                "Team {} {}'s Auto Attack is dodged by Team {} {}",
+                                attacker.0, attacker.2, victim.0, victim.2
+                            ),
+                        );
+                    }
+                    if let Some(&target_pos) = positions.get(&target_entity) {
+                        let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(target_entity) {
+                            get_next_fct_offset(&mut fct_state)
+                        } else {
+                            (0.0, 0.0)
+                        };
+                        commands.spawn_effect(FloatingCombatText {
+                            world_position: target_pos + Vec3::new(offset_x, FCT_HEIGHT + offset_y, 0.0),
+                            text: "Dodge".to_string(),
+                            color: egui::Color32::from_rgb(200, 200, 200),
+                            lifetime: 1.5,
+                            vertical_offset: offset_y,
+                            is_crit: false,
+                        });
+                    }
+                    continue;
+                }
+
                 // Apply damage with absorb shield consideration
                 let (actual_damage, absorbed) = apply_damage_with_absorb(
                     damage,
@@ -521,6 +586,7 @@ pub fn combat_auto_attack(
                             Some(attacker_id),
                             death_message,
                         );
+                        commands.spawn(SoulShardPending { killer: attacker_entity });
                     }
                 }
             }
@@ -602,17 +668,14 @@ pub fn combat_auto_attack(
                 (0.0, 0.0)
             };
 
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("{:.0}", total_damage),
-                    color: egui::Color32::WHITE, // White for auto-attacks
-                    lifetime: 1.5, // Display for 1.5 seconds
-                    vertical_offset: offset_y,
-                    is_crit: target_was_crit,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("{:.0}", total_damage),
+                color: egui::Color32::WHITE, // White for auto-attacks
+                lifetime: 1.5, // Display for 1.5 seconds
+                vertical_offset: offset_y,
+                is_crit: target_was_crit,
+            });
 
             // Spawn light blue floating combat text for absorbed damage
             if let Some(&total_absorbed) = absorbed_per_target.get(&target_entity) {
@@ -622,17 +685,14 @@ pub fn combat_auto_attack(
                     } else {
                         (0.0, 0.0)
                     };
-                    commands.spawn((
-                        FloatingCombatText {
-                            world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                            text: format!("{:.0} absorbed", total_absorbed),
-                            color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                            lifetime: 1.5,
-                            vertical_offset: absorb_offset_y,
-                            is_crit: false,
-                        },
-                        PlayMatchEntity,
-                    ));
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                        text: format!("{:.0} absorbed", total_absorbed),
+                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                        lifetime: 1.5,
+                        vertical_offset: absorb_offset_y,
+                        is_crit: false,
+                    });
                 }
             }
         }
@@ -8,8 +8,9 @@ use super::super::components::*;
 use super::super::abilities::AbilityType;
 use super::super::abilities::SpellSchool;
 use super::super::ability_config::AbilityDefinitions;
-use super::super::constants::{CRIT_DAMAGE_MULTIPLIER, CRIT_HEALING_MULTIPLIER};
-use super::super::utils::{spawn_speech_bubble, get_next_fct_offset, combatant_id};
+use super::super::constants::{CRIT_DAMAGE_MULTIPLIER, CRIT_HEALING_MULTIPLIER, DRAIN_SOUL_SHARD_HP_THRESHOLD, MAX_SOUL_SHARDS};
+use super::super::effect_pool::EffectCommandsExt;
+use super::super::utils::{spawn_ability_impact_visuals, spawn_speech_bubble, get_next_fct_offset, combatant_id, stable_combatant_order};
 use super::super::FCT_HEIGHT;
 use super::damage::{roll_crit, apply_damage_with_absorb, get_physical_damage_reduction, get_divine_shield_damage_penalty};
 
@@ -124,11 +125,21 @@ pub fn process_casting(
 
     let dt = time.delta_secs();
 
+    // Stealth snapshot for Vanish: (team, stealthed, has Shadow Sight) per entity.
+    let stealth_info: std::collections::HashMap<Entity, (u8, bool, bool)> = combatants
+        .iter()
+        .map(|(entity, _, combatant, _, auras)| {
+            let shadow_sight = auras.is_some_and(|a| a.auras.iter().any(|aura| aura.effect_type == AuraType::ShadowSight));
+            (entity, (combatant.team, combatant.stealthed, shadow_sight))
+        })
+        .collect();
+
     // Track completed casts
     let mut completed_casts = Vec::new();
 
     // First pass: update cast timers and collect completed casts
-    for (caster_entity, caster_transform, mut caster, casting_state, caster_auras) in combatants.iter_mut() {
+    for caster_entity in stable_combatant_order(combatants.iter().map(|(e, _, c, ..)| (e, c))) {
+        let Ok((caster_entity, caster_transform, mut caster, casting_state, caster_auras)) = combatants.get_mut(caster_entity) else { continue; };
         let Some(mut casting) = casting_state else {
             continue;
         };
@@ -171,6 +182,27 @@ pub fn process_casting(
             continue;
         }
 
+        // Vanish: a cast aimed at an enemy who has slipped into stealth loses its
+        // target and fizzles (unless either side holds Shadow Sight).
+        if !casting.interrupted {
+            let my_shadow_sight = stealth_info.get(&caster_entity).is_some_and(|info| info.2);
+            let target_vanished = casting.target
+                .and_then(|target| stealth_info.get(&target))
+                .is_some_and(|&(team, stealthed, target_shadow_sight)| {
+                    team != caster.team && stealthed && !my_shadow_sight && !target_shadow_sight
+                });
+            if target_vanished {
+                let caster_id = format!("Team {} {}", caster.team, caster.class.name());
+                combat_log.mark_cast_interrupted(&caster_id, &ability_def.name);
+                combat_log.log(
+                    CombatLogEventType::CrowdControl,
+                    format!("{}'s {} lost its target to stealth", caster_id, ability_def.name),
+                );
+                commands.entity(caster_entity).remove::<CastingState>();
+                continue;
+            }
+        }
+
         // Handle interrupted casts
         if casting.interrupted {
             // Tick down the interrupted display timer
@@ -350,17 +382,14 @@ pub fn process_casting(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("{:.0}", actual_damage),
-                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit: is_crit_damage,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("{:.0}", actual_damage),
+                color: egui::Color32::from_rgb(255, 255, 0), // Yellow for abilities
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: is_crit_damage,
+            });
 
             // Spawn light blue floating combat text for absorbed damage
             if absorbed > 0.0 {
@@ -369,61 +398,19 @@ pub fn process_casting(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                        text: format!("{:.0} absorbed", absorbed),
-                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                        lifetime: 1.5,
-                        vertical_offset: absorb_offset_y,
-                        is_crit: false,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                    text: format!("{:.0} absorbed", absorbed),
+                    color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                    lifetime: 1.5,
+                    vertical_offset: absorb_offset_y,
+                    is_crit: false,
+                });
             }
 
-            // Spawn visual effect for Mind Blast (shadow impact)
-            if ability == AbilityType::MindBlast {
-                commands.spawn((
-                    SpellImpactEffect {
-                        position: target_pos,
-                        lifetime: 0.5,
-                        initial_lifetime: 0.5,
-                        initial_scale: 0.5,
-                        final_scale: 2.0,
-                    },
-                    PlayMatchEntity,
-                ));
-            }
-
-            // Spawn flame particles for Immolate (fire rising effect)
-            if ability == AbilityType::Immolate {
-                // Spawn 8-12 flame particles around target
-                let particle_count = 8 + (game_rng.random_f32() * 5.0) as i32;
-                for _ in 0..particle_count {
-                    // Randomize position slightly around target
-                    let offset = Vec3::new(
-                        (game_rng.random_f32() - 0.5) * 1.0,  // -0.5 to 0.5
-                        game_rng.random_f32() * 0.5,          // 0 to 0.5 (start near ground)
-                        (game_rng.random_f32() - 0.5) * 1.0,
-                    );
-                    let velocity = Vec3::new(
-                        (game_rng.random_f32() - 0.5) * 0.5,  // Slight horizontal drift
-                        2.0 + game_rng.random_f32() * 1.5,    // Upward: 2.0-3.5 units/sec
-                        (game_rng.random_f32() - 0.5) * 0.5,
-                    );
-                    let lifetime = 0.6 + game_rng.random_f32() * 0.4;  // 0.6-1.0 sec
-                    commands.spawn((
-                        FlameParticle {
-                            velocity,
-                            lifetime,
-                            initial_lifetime: lifetime,
-                        },
-                        Transform::from_translation(target_pos + offset),
-                        PlayMatchEntity,
-                    ));
-                }
-            }
+            // Impact visuals from the ability's descriptor (Mind Blast burst,
+            // Immolate flames + scorch)
+            spawn_ability_impact_visuals(&mut commands, def, target_pos, &mut game_rng);
 
             // Log the damage with structured data
             let is_killing_blow = !target.is_alive();
@@ -481,6 +468,7 @@ pub fn process_casting(
                     Some(combatant_id(caster_team, caster_class)),
                     death_message,
                 );
+                commands.spawn(SoulShardPending { killer: caster_entity });
             }
         }
         // Handle healing spells
@@ -518,17 +506,14 @@ pub fn process_casting(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("+{:.0}", actual_healing),
-                    color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit: is_crit_heal,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("+{:.0}", actual_healing),
+                color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: is_crit_heal,
+            });
 
             // Log the healing with structured data
             let verb = if is_crit_heal { "CRITICALLY heals" } else { "heals" };
@@ -690,6 +675,7 @@ pub fn process_casting(
                 Some(combatant_id(caster_team, caster_class)),
                 message,
             );
+            commands.spawn(SoulShardPending { killer: caster_entity });
         }
     }
 
@@ -769,6 +755,11 @@ pub fn process_channeling(
         .iter()
         .map(|(entity, _, combatant, _, _)| (entity, (combatant.is_alive(), combatant.team, combatant.class)))
         .collect();
+    // Snapshot health fractions for Drain Soul's low-HP shard gate
+    let health_pct: std::collections::HashMap<Entity, f32> = combatants
+        .iter()
+        .map(|(entity, _, combatant, _, _)| (entity, combatant.current_health / combatant.max_health))
+        .collect();
     // Snapshot target immunity status for Drain Life healing suppression
     let immunity_info: std::collections::HashSet<Entity> = combatants
         .iter()
@@ -778,7 +769,8 @@ pub fn process_channeling(
         .map(|(entity, _, _, _, _)| entity)
         .collect();
 
-    for (caster_entity, _caster_transform, caster, channeling_state, caster_auras) in combatants.iter_mut() {
+    for caster_entity in stable_combatant_order(combatants.iter().map(|(e, _, c, ..)| (e, c))) {
+        let Ok((caster_entity, _caster_transform, mut caster, channeling_state, caster_auras)) = combatants.get_mut(caster_entity) else { continue; };
         let Some(mut channeling) = channeling_state else {
             continue;
         };
@@ -871,13 +863,12 @@ pub fn process_channeling(
                     target_class.name(),
                     damage
                 );
-                combat_log.log_damage(
+                combat_log.log_periodic_damage(
                     combatant_id(caster.team, caster.class),
                     combatant_id(target_team, target_class),
                     format!("{} (tick)", ability_def.name),
                     damage,
                     false, // Not a killing blow check here - will be handled when applying damage
-                    false, // is_crit - channel ticks never crit
                     damage_message,
                 );
 
@@ -889,12 +880,11 @@ pub fn process_channeling(
                         ability_def.name,
                         healing
                     );
-                    combat_log.log_healing(
+                    combat_log.log_periodic_healing(
                         combatant_id(caster.team, caster.class),
                         combatant_id(caster.team, caster.class),
                         format!("{} (tick)", ability_def.name),
                         healing,
-                        false, // is_crit - channel ticks never crit
                         heal_message,
                     );
                 }
@@ -908,17 +898,32 @@ pub fn process_channeling(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                        text: format!("{:.0}", damage),
-                        color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
-                        lifetime: 1.5,
-                        vertical_offset: offset_y,
-                        is_crit: false,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                    text: format!("{:.0}", damage),
+                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
+                    lifetime: 1.5,
+                    vertical_offset: offset_y,
+                    is_crit: false,
+                });
+            }
+
+            // Drain Soul: a tick on a low-HP target rips out a Soul Shard
+            let target_low = health_pct
+                .get(&channeling.target)
+                .is_some_and(|&pct| pct <= DRAIN_SOUL_SHARD_HP_THRESHOLD);
+            if channeling.ability == AbilityType::DrainSoul && target_low && caster.gain_soul_shard() {
+                combat_log.log(
+                    CombatLogEventType::Buff,
+                    format!(
+                        "Team {} {} gains a Soul Shard from {} ({}/{})",
+                        caster.team,
+                        caster.class.name(),
+                        ability_def.name,
+                        caster.soul_shards,
+                        MAX_SOUL_SHARDS
+                    ),
+                );
             }
 
             // Reset tick timer
@@ -967,17 +972,14 @@ pub fn process_channeling(
                     } else {
                         (0.0, 0.0)
                     };
-                    commands.spawn((
-                        FloatingCombatText {
-                            world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                            text: format!("{:.0} absorbed", absorbed),
-                            color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                            lifetime: 1.5,
-                            vertical_offset: offset_y,
-                            is_crit: false,
-                        },
-                        PlayMatchEntity,
-                    ));
+                    commands.spawn_effect(FloatingCombatText {
+                        world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                        text: format!("{:.0} absorbed", absorbed),
+                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                        lifetime: 1.5,
+                        vertical_offset: offset_y,
+                        is_crit: false,
+                    });
                 }
 
                 // Warriors generate Rage from taking damage
@@ -1004,6 +1006,7 @@ pub fn process_channeling(
                         Some(combatant_id(caster_team, caster_class)),
                         death_message,
                     );
+                    commands.spawn(SoulShardPending { killer: caster_entity });
                 }
             }
         }
@@ -1040,17 +1043,14 @@ pub fn process_channeling(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("+{:.0}", effective_healing),
-                    color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit: false,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("+{:.0}", effective_healing),
+                color: egui::Color32::from_rgb(100, 255, 100), // Green for healing
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: false,
+            });
         }
     }
 
@@ -1,11 +1,12 @@
 //! Damage application, absorb shields, and interrupt processing.
 
 use bevy::prelude::*;
-use crate::combat::log::{CombatLog, CombatLogEventType};
+use crate::combat::log::CombatLog;
 use super::super::components::*;
 use super::super::abilities::SpellSchool;
 use super::super::ability_config::AbilityDefinitions;
 use super::super::constants::DIVINE_SHIELD_DAMAGE_PENALTY;
+use super::super::utils::{check_effect_reference, discard_stale_effect};
 use super::get_lockout_duration_reduction;
 
 /// Roll a critical strike check. Returns true if the roll is a crit.
@@ -13,6 +14,29 @@ pub fn roll_crit(crit_chance: f32, rng: &mut GameRng) -> bool {
     rng.random_f32() < crit_chance
 }
 
+/// Melee attack table: the target's chance to dodge a melee swing or strike.
+/// There is no base dodge — only `DodgeChanceIncrease` auras (Evasion)
+/// contribute — so combatants without one never dodge. Clamped to [0, 1].
+pub fn get_dodge_chance(auras: Option<&ActiveAuras>) -> f32 {
+    auras
+        .map(|a| {
+            a.auras
+                .iter()
+                .filter(|aura| aura.effect_type == AuraType::DodgeChanceIncrease)
+                .map(|aura| aura.magnitude)
+                .sum::<f32>()
+        })
+        .unwrap_or(0.0)
+        .clamp(0.0, 1.0)
+}
+
+/// Roll the melee attack table. Returns true if the attack is dodged. Draws
+/// from the RNG only when there is a dodge chance, so matches without
+/// Evasion keep their exact random sequence.
+pub fn roll_dodge(dodge_chance: f32, rng: &mut GameRng) -> bool {
+    dodge_chance > 0.0 && rng.random_f32() < dodge_chance
+}
+
 /// Map a magical `SpellSchool` to its slot in `Combatant::damage_mitigated_by_resistance`.
 /// Returns `None` for `SpellSchool::Physical` and `SpellSchool::None`, which have no resistance slot.
 pub(crate) fn resistance_school_index(school: SpellSchool) -> Option<usize> {
@@ -153,6 +177,20 @@ pub fn has_weakened_soul(auras: Option<&ActiveAuras>) -> bool {
     auras.map_or(false, |a| a.auras.iter().any(|aura| aura.effect_type == AuraType::WeakenedSoul))
 }
 
+/// Consume an Intervene on the attacked combatant and return the Warrior who
+/// takes the hit instead. The aura is spent even when its caster has since
+/// died (the redirect simply fizzles), so a dead protector can't soak a
+/// second attack. Returns `None` if there is no Intervene to redirect through.
+pub fn take_intervene(
+    auras: Option<&mut ActiveAuras>,
+    protector_alive: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    let auras = auras?;
+    let idx = auras.auras.iter().position(|a| a.effect_type == AuraType::Intervene)?;
+    let aura = auras.auras.remove(idx);
+    aura.caster.filter(|&protector| protector_alive(protector))
+}
+
 /// Get the physical damage reduction multiplier from DamageReduction auras on the attacker.
 /// Used by Curse of Weakness to reduce outgoing physical damage by a percentage.
 /// Returns the percentage reduction (0.2 = 20% less damage).
@@ -202,6 +240,14 @@ pub fn process_interrupts(
     }
 
     for (interrupt_entity, interrupt) in interrupts.iter() {
+        // A target that is not casting is a normal miss; a despawned or dead
+        // target is a stale reference and resolves as a logged no-op.
+        if let Err(reason) = check_effect_reference(combatants.get(interrupt.target).ok()) {
+            let name = &abilities.get_unchecked(&interrupt.ability).name;
+            discard_stale_effect(&mut commands, &mut combat_log, interrupt_entity, name, "target", reason);
+            continue;
+        }
+
         let mut interrupted = false;
 
         // Check if target is casting
@@ -362,7 +408,12 @@ fn apply_interrupt_lockout(
         school_name,
         effective_lockout
     );
-    combat_log.log(CombatLogEventType::AbilityUsed, message);
+    combat_log.log_interrupt(
+        format!("Team {} {}", caster_info.0, caster_info.1),
+        format!("Team {} {}", target_combatant.team, target_combatant.class.name()),
+        interrupted_spell_name.to_string(),
+        message,
+    );
 
     info!(
         "Team {} {} interrupted! {} school locked for {:.1}s",
@@ -1,8 +1,16 @@
-//! Death animation and pet despawn systems.
+//! Death animation, pet despawn, Soul Shard and Soulstone systems.
 
 use bevy::prelude::*;
+use bevy_egui::egui;
 use crate::combat::log::{CombatLog, CombatLogEventType};
+use crate::states::match_config::CharacterClass;
 use super::super::components::*;
+use super::super::constants::{MAX_SOUL_SHARDS, SOULSTONE_REVIVE_DELAY};
+use super::super::effect_pool::EffectCommandsExt;
+use super::super::utils::{
+    check_effect_reference, combatant_id, get_next_fct_offset, stable_combatant_order, StaleReference,
+};
+use super::super::FCT_HEIGHT;
 
 /// Trigger death animation when a combatant dies.
 /// Detects dead combatants without a DeathAnimation component and adds one.
@@ -110,6 +118,9 @@ pub fn ease_out_quad_for_test(t: f32) -> f32 {
 }
 
 /// Despawn pets whose owner has died by setting their HP to 0.
+///
+/// A pet whose owner entity no longer exists is treated the same way — an
+/// orphaned pet would otherwise keep fighting with a dangling owner link.
 pub fn despawn_pets_of_dead_owners(
     mut combat_log: ResMut<CombatLog>,
     mut pets: Query<(Entity, &Pet, &mut Combatant)>,
@@ -121,14 +132,148 @@ pub fn despawn_pets_of_dead_owners(
         if !pet_combatant.is_alive() {
             continue;
         }
-        if let Ok(owner) = owners.get(pet.owner) {
-            if !owner.is_alive() {
-                pet_combatant.current_health = 0.0;
-                combat_log.log(
-                    CombatLogEventType::Death,
-                    format!("[DEATH] Team {} {} despawns (owner died)", pet_combatant.team, pet.pet_type.name()),
-                );
+        let reason = match check_effect_reference(owners.get(pet.owner).ok()) {
+            Ok(()) => continue,
+            Err(StaleReference::Dead) => "owner died",
+            Err(StaleReference::Despawned) => "owner no longer exists",
+        };
+        pet_combatant.current_health = 0.0;
+        combat_log.log(
+            CombatLogEventType::Death,
+            format!("[DEATH] Team {} {} despawns ({})", pet_combatant.team, pet.pet_type.name(), reason),
+        );
+    }
+}
+
+/// Grant a Soul Shard to a Warlock for each killing blow it lands.
+///
+/// Every damage path that logs a death spawns a [`SoulShardPending`] naming the
+/// entity that landed the blow. A pet's kill is credited to its owner (the
+/// Felhunter fights for its Warlock); any other killer class is ignored.
+pub fn award_soul_shards(
+    mut commands: Commands,
+    mut combat_log: ResMut<CombatLog>,
+    pending: Query<(Entity, &SoulShardPending)>,
+    pets: Query<&Pet>,
+    mut warlocks: Query<&mut Combatant, Without<Pet>>,
+) {
+    for (pending_entity, shard) in pending.iter() {
+        commands.entity(pending_entity).despawn();
+
+        let killer = pets.get(shard.killer).map_or(shard.killer, |pet| pet.owner);
+        let Ok(mut warlock) = warlocks.get_mut(killer) else {
+            continue;
+        };
+        if warlock.class != CharacterClass::Warlock {
+            continue;
+        }
+        if warlock.gain_soul_shard() {
+            combat_log.log(
+                CombatLogEventType::Buff,
+                format!(
+                    "{} gains a Soul Shard ({}/{})",
+                    combatant_id(warlock.team, warlock.class),
+                    warlock.soul_shards,
+                    MAX_SOUL_SHARDS
+                ),
+            );
+        }
+    }
+}
+
+/// Resurrect Soulstoned combatants.
+///
+/// On death the SoulstoneResurrection aura is consumed (along with every other
+/// aura — death clears them) and a [`SoulstoneRevive`] countdown starts. When it
+/// expires the combatant stands back up at the stored fraction of its health
+/// and mana. A team that is wiped in the meantime has already lost, so the
+/// match-end check never waits on a pending revival.
+pub fn process_soulstone_revivals(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut combat_log: ResMut<CombatLog>,
+    mut combatants: Query<(Entity, &mut Combatant, &mut Transform, Option<&mut ActiveAuras>), Without<Pet>>,
+    mut revives: Query<&mut SoulstoneRevive>,
+    mut fct_states: Query<&mut FloatingTextState>,
+    celebration: Option<Res<VictoryCelebration>>,
+) {
+    if celebration.is_some() {
+        return;
+    }
+    let dt = time.delta_secs();
+
+    for entity in stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c))) {
+        let Ok((entity, mut combatant, mut transform, active_auras)) = combatants.get_mut(entity) else { continue; };
+        let id = combatant_id(combatant.team, combatant.class);
+
+        let Ok(mut revive) = revives.get_mut(entity) else {
+            // Just died holding a Soulstone? Consume it and start the countdown.
+            if !combatant.is_dead {
+                continue;
             }
+            let Some(mut active_auras) = active_auras else {
+                continue;
+            };
+            let Some(health_fraction) = active_auras
+                .auras
+                .iter()
+                .find(|a| a.effect_type == AuraType::SoulstoneResurrection)
+                .map(|a| a.magnitude)
+            else {
+                continue;
+            };
+            active_auras.auras.clear();
+            commands.entity(entity).insert(SoulstoneRevive {
+                remaining: SOULSTONE_REVIVE_DELAY,
+                health_fraction,
+            });
+            combat_log.log(
+                CombatLogEventType::Buff,
+                format!("{}'s Soulstone will resurrect them in {:.0}s", id, SOULSTONE_REVIVE_DELAY),
+            );
+            continue;
+        };
+
+        revive.remaining -= dt;
+        if revive.remaining > 0.0 {
+            continue;
+        }
+
+        combatant.is_dead = false;
+        combatant.current_health = (combatant.max_health * revive.health_fraction).max(1.0);
+        if combatant.resource_type == ResourceType::Mana {
+            combatant.current_mana = combatant.max_mana * revive.health_fraction;
         }
+        combatant.target = None;
+        combatant.global_cooldown = 0.0;
+
+        // Stand the capsule back up (undo the death animation)
+        transform.rotation = Quat::IDENTITY;
+        transform.translation.y = 1.0;
+        commands.entity(entity).remove::<(SoulstoneRevive, DeathAnimation)>();
+
+        combat_log.log(
+            CombatLogEventType::Buff,
+            format!(
+                "{} is resurrected by Soulstone ({:.0}/{:.0} HP)",
+                id, combatant.current_health, combatant.max_health
+            ),
+        );
+        info!("{} resurrected by Soulstone", id);
+
+        let text_position = transform.translation + Vec3::new(0.0, FCT_HEIGHT, 0.0);
+        let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(entity) {
+            get_next_fct_offset(&mut fct_state)
+        } else {
+            (0.0, 0.0)
+        };
+        commands.spawn_effect(FloatingCombatText {
+            world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+            text: "Soulstone".to_string(),
+            color: egui::Color32::from_rgb(180, 100, 255), // Soul purple
+            lifetime: 2.0,
+            vertical_offset: offset_y,
+            is_crit: false,
+        });
     }
 }
@@ -52,6 +52,19 @@ pub fn clamp_to_arena(mut pos: Vec3) -> Vec3 {
     pos
 }
 
+/// Clamp a position's XZ to a circle of `radius` around the arena centre.
+/// Used on top of `clamp_to_arena` for circular maps (the Duel Ring).
+pub fn clamp_to_ring(mut pos: Vec3, radius: f32) -> Vec3 {
+    let xz = Vec2::new(pos.x, pos.z);
+    let dist = xz.length();
+    if dist > radius {
+        let clamped = xz * (radius / dist);
+        pos.x = clamped.x;
+        pos.z = clamped.y;
+    }
+    pos
+}
+
 /// Get the total cast time increase from CastTimeIncrease auras on a combatant.
 /// Used by Curse of Tongues to slow casting.
 /// Returns the percentage increase (0.5 = 50% slower, so multiply cast time by 1.5).
@@ -162,6 +175,7 @@ mod tests {
     use super::*;
     use super::super::match_config;
     use super::super::abilities::SpellSchool;
+    use crate::combat::log::CombatLog;
 
     /// Helper to create a test combatant
     fn create_test_combatant(health: f32) -> Combatant {
@@ -726,6 +740,16 @@ mod tests {
         assert_eq!(once, twice, "Clamping twice should give the same result");
     }
 
+    #[test]
+    fn test_clamp_to_ring_projects_onto_circle() {
+        let inside = Vec3::new(3.0, 1.0, 4.0);
+        assert_eq!(clamp_to_ring(inside, 10.0), inside);
+
+        let clamped = clamp_to_ring(Vec3::new(30.0, 2.0, 40.0), 10.0);
+        assert!((clamped.x - 6.0).abs() < 0.001 && (clamped.z - 8.0).abs() < 0.001);
+        assert_eq!(clamped.y, 2.0, "Y should be unchanged");
+    }
+
     // =========================================================================
     // Dynamic Stat Bonus Helper Tests
     // =========================================================================
@@ -856,4 +880,202 @@ mod tests {
         let auras = ActiveAuras { auras: vec![] };
         assert_eq!(get_mana_regen_bonus(Some(&auras)), 0.0);
     }
+
+    // =========================================================================
+    // take_intervene Tests
+    // =========================================================================
+
+    #[test]
+    fn test_take_intervene_redirects_once_to_living_protector() {
+        let warrior = Entity::from_raw(7);
+        let mut auras = ActiveAuras {
+            auras: vec![Aura {
+                effect_type: AuraType::Intervene,
+                caster: Some(warrior),
+                ..Default::default()
+            }],
+        };
+        assert_eq!(take_intervene(Some(&mut auras), |_| true), Some(warrior));
+        assert!(auras.auras.is_empty(), "Intervene is consumed by the redirect");
+        assert_eq!(take_intervene(Some(&mut auras), |_| true), None);
+    }
+
+    #[test]
+    fn test_take_intervene_fizzles_when_protector_dead() {
+        let mut auras = ActiveAuras {
+            auras: vec![Aura {
+                effect_type: AuraType::Intervene,
+                caster: Some(Entity::from_raw(7)),
+                ..Default::default()
+            }],
+        };
+        assert_eq!(take_intervene(Some(&mut auras), |_| false), None);
+        assert!(auras.auras.is_empty());
+        assert_eq!(take_intervene(None, |_| true), None);
+    }
+
+    // =========================================================================
+    // Attack Table / Movement Speed Tests
+    // =========================================================================
+
+    #[test]
+    fn test_dodge_chance_sums_and_clamps() {
+        assert_eq!(get_dodge_chance(None), 0.0);
+        let auras = ActiveAuras {
+            auras: vec![
+                create_aura(AuraType::DodgeChanceIncrease, 0.5),
+                create_aura(AuraType::DodgeChanceIncrease, 0.75),
+                create_aura(AuraType::MovementSpeedIncrease, 0.7),
+            ],
+        };
+        assert_eq!(get_dodge_chance(Some(&auras)), 1.0);
+    }
+
+    #[test]
+    fn test_roll_dodge_without_chance_leaves_rng_untouched() {
+        let mut rng = GameRng::from_seed(42);
+        let mut reference = GameRng::from_seed(42);
+        assert!(!roll_dodge(0.0, &mut rng));
+        assert_eq!(rng.random_f32(), reference.random_f32());
+        assert!(roll_dodge(1.0, &mut rng));
+    }
+
+    #[test]
+    fn test_effective_movement_speed_stacks_sprint_with_slow() {
+        assert_eq!(effective_movement_speed(10.0, None), 10.0);
+        let auras = ActiveAuras {
+            auras: vec![
+                create_aura(AuraType::MovementSpeedIncrease, 0.7),
+                create_aura(AuraType::MovementSpeedSlow, 0.5),
+            ],
+        };
+        assert!((effective_movement_speed(10.0, Some(&auras)) - 8.5).abs() < 1e-5);
+    }
+
+    // =========================================================================
+    // Soul Shard / Soulstone Tests
+    // =========================================================================
+
+    fn soul_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CombatLog::default());
+        world.insert_resource(Time::<()>::default());
+        world
+    }
+
+    #[test]
+    fn test_soul_shards_cap_and_spend() {
+        use super::super::constants::{MAX_SOUL_SHARDS, STARTING_SOUL_SHARDS};
+        let mut warlock = Combatant::new(1, 0, match_config::CharacterClass::Warlock);
+        assert_eq!(warlock.soul_shards, STARTING_SOUL_SHARDS);
+        assert_eq!(Combatant::new(1, 0, match_config::CharacterClass::Mage).soul_shards, 0);
+
+        while warlock.gain_soul_shard() {}
+        assert_eq!(warlock.soul_shards, MAX_SOUL_SHARDS);
+
+        for _ in 0..MAX_SOUL_SHARDS {
+            assert!(warlock.spend_soul_shard());
+        }
+        assert!(!warlock.spend_soul_shard(), "nothing left to spend");
+        assert_eq!(warlock.soul_shards, 0);
+    }
+
+    #[test]
+    fn test_killing_blow_awards_soul_shard() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = soul_world();
+        let warlock = world.spawn(Combatant::new(1, 0, match_config::CharacterClass::Warlock)).id();
+        let start = world.get::<Combatant>(warlock).unwrap().soul_shards;
+        world.spawn(SoulShardPending { killer: warlock });
+
+        world.run_system_once(award_soul_shards).expect("award_soul_shards ran");
+
+        assert_eq!(world.get::<Combatant>(warlock).unwrap().soul_shards, start + 1);
+        let log = world.resource::<CombatLog>();
+        assert!(log.entries.iter().any(|e| e.message.contains("gains a Soul Shard")));
+        assert!(world.query::<&SoulShardPending>().iter(&world).next().is_none(), "pending consumed");
+    }
+
+    #[test]
+    fn test_soul_shard_credits_the_killing_warlock_not_its_teammate() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = soul_world();
+        let first = world.spawn(Combatant::new(1, 0, match_config::CharacterClass::Warlock)).id();
+        let second = world.spawn(Combatant::new(1, 1, match_config::CharacterClass::Warlock)).id();
+        let start = world.get::<Combatant>(first).unwrap().soul_shards;
+        world.spawn(SoulShardPending { killer: second });
+
+        world.run_system_once(award_soul_shards).expect("award_soul_shards ran");
+
+        assert_eq!(world.get::<Combatant>(first).unwrap().soul_shards, start);
+        assert_eq!(world.get::<Combatant>(second).unwrap().soul_shards, start + 1);
+    }
+
+    #[test]
+    fn test_felhunter_kill_credits_its_owner() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = soul_world();
+        let owner = Combatant::new(1, 0, match_config::CharacterClass::Warlock);
+        let start = owner.soul_shards;
+        let pet_combatant = Combatant::new_pet(1, 0, PetType::Felhunter, &owner);
+        let warlock = world.spawn(owner).id();
+        let felhunter = world
+            .spawn((pet_combatant, Pet { owner: warlock, pet_type: PetType::Felhunter }))
+            .id();
+        world.spawn(SoulShardPending { killer: felhunter });
+
+        world.run_system_once(award_soul_shards).expect("award_soul_shards ran");
+
+        assert_eq!(world.get::<Combatant>(warlock).unwrap().soul_shards, start + 1);
+    }
+
+    #[test]
+    fn test_soulstone_consumed_on_death_then_revives() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = soul_world();
+        let mut warlock = Combatant::new(1, 0, match_config::CharacterClass::Warlock);
+        warlock.current_health = 0.0;
+        warlock.is_dead = true;
+        let entity = world
+            .spawn((
+                warlock,
+                Transform::default(),
+                ActiveAuras {
+                    auras: vec![
+                        create_aura(AuraType::SoulstoneResurrection, 0.3),
+                        create_aura(AuraType::DamageOverTime, 10.0),
+                    ],
+                },
+            ))
+            .id();
+
+        // Death frame: the stone is consumed and the countdown starts.
+        world.run_system_once(process_soulstone_revivals).expect("revival system ran");
+        assert!(world.get::<ActiveAuras>(entity).unwrap().auras.is_empty());
+        assert!(world.get::<SoulstoneRevive>(entity).is_some());
+        assert!(!world.get::<Combatant>(entity).unwrap().is_alive());
+
+        // Countdown elapsed: back up at 30% health.
+        world.get_mut::<SoulstoneRevive>(entity).unwrap().remaining = 0.0;
+        world.run_system_once(process_soulstone_revivals).expect("revival system ran");
+        let revived = world.get::<Combatant>(entity).unwrap();
+        assert!(revived.is_alive());
+        assert!((revived.current_health - revived.max_health * 0.3).abs() < 1e-3);
+        assert!(world.get::<SoulstoneRevive>(entity).is_none());
+    }
+
+    #[test]
+    fn test_dead_without_soulstone_stays_dead() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = soul_world();
+        let mut warlock = Combatant::new(1, 0, match_config::CharacterClass::Warlock);
+        warlock.current_health = 0.0;
+        warlock.is_dead = true;
+        let entity = world
+            .spawn((warlock, Transform::default(), ActiveAuras::default()))
+            .id();
+
+        world.run_system_once(process_soulstone_revivals).expect("revival system ran");
+        assert!(world.get::<SoulstoneRevive>(entity).is_none());
+    }
 }
@@ -2,7 +2,9 @@
 
 use bevy::prelude::*;
 use super::super::components::*;
-use super::clamp_to_arena;
+use super::{clamp_to_arena, clamp_to_ring};
+use super::super::utils::stable_combatant_order;
+use crate::states::match_config::MatchConfig;
 use super::super::{MELEE_RANGE, DISENGAGE_SPEED};
 
 
@@ -10,6 +12,41 @@ use super::super::{MELEE_RANGE, DISENGAGE_SPEED};
 /// Prevents oscillation around the exact formation point.
 pub const DIRECTIVE_POINT_EPSILON: f32 = 0.25;
 
+/// Effective movement speed: base × every `MovementSpeedSlow` magnitude ×
+/// (1 + every `MovementSpeedIncrease` magnitude). Sprint and a slow multiply,
+/// so Sprint offsets a slow rather than cancelling it.
+pub fn effective_movement_speed(base: f32, auras: Option<&ActiveAuras>) -> f32 {
+    let Some(auras) = auras else {
+        return base;
+    };
+    auras.auras.iter().fold(base, |speed, aura| match aura.effect_type {
+        AuraType::MovementSpeedSlow => speed * aura.magnitude,
+        AuraType::MovementSpeedIncrease => speed * (1.0 + aura.magnitude),
+        _ => speed,
+    })
+}
+
+/// Keep combatants (and pets) inside the map's circular play area.
+///
+/// Runs right after `move_to_target`. Movement itself only knows the octagonal
+/// arena bounds, so on a ring map (the Duel Ring) anything that walked, kited,
+/// charged or was knocked past the ring edge is projected back onto it.
+/// No-op on maps without a ring radius.
+pub fn confine_to_ring_map(
+    config: Res<MatchConfig>,
+    mut combatants: Query<&mut Transform, With<Combatant>>,
+) {
+    let Some(radius) = config.map.ring_radius() else {
+        return;
+    };
+    for mut transform in combatants.iter_mut() {
+        let confined = clamp_to_ring(transform.translation, radius);
+        if confined != transform.translation {
+            transform.translation = confined;
+        }
+    }
+}
+
 pub fn move_to_target(
     countdown: Res<MatchCountdown>,
     time: Res<Time>,
@@ -41,7 +78,8 @@ pub fn move_to_target(
         .collect();
 
     // Move each combatant towards their target if needed
-    for (entity, mut transform, combatant, auras, casting_state, charging_state, channeling_state, disengaging_state, movement_directive) in combatants.iter_mut() {
+    for entity in stable_combatant_order(combatants.iter().map(|(e, _, c, ..)| (e, c))) {
+        let Ok((entity, mut transform, combatant, auras, casting_state, charging_state, channeling_state, disengaging_state, movement_directive)) = combatants.get_mut(entity) else { continue; };
         // MOVEMENT DIRECTIVE EXPIRY — checked before EVERY early-continue
         // below (death, casting, channeling, root/stun). `expires` is an
         // absolute sim-time deadline: a directive issued pre-stun must be
@@ -72,7 +110,7 @@ pub fn move_to_target(
 
         // Check for movement-preventing CC and wandering CC
         let (is_rooted_or_stunned, fear_direction, polymorph_direction) = if let Some(auras) = auras {
-            let rooted_or_stunned = auras.auras.iter().any(|a| matches!(a.effect_type, AuraType::Root | AuraType::Stun | AuraType::Incapacitate));
+            let rooted_or_stunned = auras.auras.iter().any(|a| matches!(a.effect_type, AuraType::Root | AuraType::Stun | AuraType::Incapacitate | AuraType::IceBlock));
             let fear_dir = auras.auras.iter()
                 .find(|a| a.effect_type == AuraType::Fear)
                 .map(|a| a.fear_direction);
@@ -106,7 +144,7 @@ pub fn move_to_target(
                 transform.translation = clamp_to_arena(transform.translation);
 
                 // Rotate to face direction of travel
-                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                 transform.rotation = target_rotation;
             }
 
@@ -128,7 +166,7 @@ pub fn move_to_target(
                 transform.translation = clamp_to_arena(transform.translation);
 
                 // Rotate to face direction of travel
-                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                 transform.rotation = target_rotation;
             }
 
@@ -145,8 +183,12 @@ pub fn move_to_target(
 
             let distance = my_pos.distance(target_pos);
 
-            // If we've reached melee range, end the charge
+            // If we've reached melee range, end the charge and land its
+            // impact (Intercept's stun)
             if distance <= MELEE_RANGE {
+                if let Some(aura_pending) = charge_state.on_arrival.clone() {
+                    commands.spawn(aura_pending);
+                }
                 commands.entity(entity).remove::<ChargingState>();
 
                 info!(
@@ -178,7 +220,7 @@ pub fn move_to_target(
                 transform.translation = clamp_to_arena(transform.translation);
 
                 // Rotate to face target
-                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                 transform.rotation = target_rotation;
             }
 
@@ -220,14 +262,7 @@ pub fn move_to_target(
         // Entities without the component fall through to normal pursuit.
         if let Some(directive) = movement_directive {
             // Effective movement speed: base × MovementSpeedSlow multipliers.
-            let mut movement_speed = combatant.base_movement_speed;
-            if let Some(auras) = auras {
-                for aura in &auras.auras {
-                    if aura.effect_type == AuraType::MovementSpeedSlow {
-                        movement_speed *= aura.magnitude;
-                    }
-                }
-            }
+            let movement_speed = effective_movement_speed(combatant.base_movement_speed, auras);
             let mut move_distance = movement_speed * dt;
 
             let direction = match directive.goal {
@@ -264,7 +299,7 @@ pub fn move_to_target(
                 transform.translation = clamp_to_arena(transform.translation);
 
                 // Rotate to face direction of travel
-                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                 transform.rotation = target_rotation;
             }
 
@@ -282,18 +317,11 @@ pub fn move_to_target(
                             owner_pos.x - my_pos.x, 0.0, owner_pos.z - my_pos.z,
                         ).normalize_or_zero();
                         if direction != Vec3::ZERO {
-                            let mut movement_speed = combatant.base_movement_speed;
-                            if let Some(auras) = auras {
-                                for aura in &auras.auras {
-                                    if aura.effect_type == AuraType::MovementSpeedSlow {
-                                        movement_speed *= aura.magnitude;
-                                    }
-                                }
-                            }
+                            let movement_speed = effective_movement_speed(combatant.base_movement_speed, auras);
                             let move_distance = movement_speed * dt;
                             transform.translation += direction * move_distance;
                             transform.translation = clamp_to_arena(transform.translation);
-                            let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                            let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                             transform.rotation = target_rotation;
                         }
                     }
@@ -329,14 +357,7 @@ pub fn move_to_target(
 
                 if direction != Vec3::ZERO {
                     // Calculate effective movement speed
-                    let mut movement_speed = combatant.base_movement_speed;
-                    if let Some(auras) = auras {
-                        for aura in &auras.auras {
-                            if aura.effect_type == AuraType::MovementSpeedSlow {
-                                movement_speed *= aura.magnitude;
-                            }
-                        }
-                    }
+                    let movement_speed = effective_movement_speed(combatant.base_movement_speed, auras);
 
                     // Move towards destination
                     let move_distance = movement_speed * dt;
@@ -346,7 +367,7 @@ pub fn move_to_target(
                     transform.translation = clamp_to_arena(transform.translation);
 
                     // Rotate to face destination
-                    let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                    let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                     transform.rotation = target_rotation;
                 }
             }
@@ -378,14 +399,7 @@ pub fn move_to_target(
 
             if direction != Vec3::ZERO {
                 // Calculate effective movement speed (base * aura modifiers)
-                let mut movement_speed = combatant.base_movement_speed;
-                if let Some(auras) = auras {
-                    for aura in &auras.auras {
-                        if aura.effect_type == AuraType::MovementSpeedSlow {
-                            movement_speed *= aura.magnitude;
-                        }
-                    }
-                }
+                let movement_speed = effective_movement_speed(combatant.base_movement_speed, auras);
 
                 // Move towards target
                 let move_distance = movement_speed * dt;
@@ -395,7 +409,7 @@ pub fn move_to_target(
                 transform.translation = clamp_to_arena(transform.translation);
 
                 // Rotate to face target
-                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                let target_rotation = Quat::from_rotation_y(ops::atan2(direction.x, direction.z));
                 transform.rotation = target_rotation;
             }
         }
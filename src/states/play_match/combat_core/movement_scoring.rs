@@ -66,11 +66,12 @@ pub const MASK_ANCHOR: u16 = 1 << 1;
 pub const CORNER_PENALTY_ONSET: f32 = ARENA_CORNER_SUM * 0.7;
 
 /// The 16 compass candidate directions (unit XZ vectors, TAU/16 apart). Index
-/// order is fixed, so argmax tie-breaks are deterministic.
+/// order is fixed, so argmax tie-breaks are deterministic, and the trig goes
+/// through `ops` so the vectors don't depend on the platform math library.
 pub fn compass_directions_16() -> [Vec2; 16] {
     std::array::from_fn(|i| {
         let angle = (i as f32) * std::f32::consts::TAU / 16.0;
-        Vec2::new(angle.cos(), angle.sin())
+        Vec2::new(ops::cos(angle), ops::sin(angle))
     })
 }
 
@@ -0,0 +1,419 @@
+//! Match Commentary
+//!
+//! Turns significant combat log events into natural-language lines ("Team 2's
+//! Rogue opens with Cheap Shot on the Priest!"), shown as a ticker during the
+//! match and compiled into a summary paragraph on the Results screen.
+//!
+//! All wording lives in `assets/config/commentary.ron`; this module only
+//! decides *which* events are worth a line and fills in the template
+//! placeholders, so the commentary can be localized by translating that file.
+//!
+//! Narrated events:
+//! - each team's first hostile cast (the opener)
+//! - crowd control landing
+//! - crits of at least `BIG_CRIT_DAMAGE`
+//! - deaths, with the killing-blow ability when known
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use serde::{Deserialize, Serialize};
+
+use crate::combat::log::{CombatLog, CombatLogEntry, CombatantId, StructuredEventData};
+use super::camera::BIG_CRIT_DAMAGE;
+use super::utils::team_from_combatant_id;
+
+/// Seconds of match time a line stays on the ticker
+const TICKER_LINE_DURATION: f32 = 6.0;
+/// Maximum lines shown at once (newest at the bottom)
+const TICKER_MAX_LINES: usize = 3;
+
+/// Commentary templates, loaded from `assets/config/commentary.ron`.
+/// See that file for the placeholders each template can use.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct CommentaryTemplates {
+    pub opener: String,
+    pub crowd_control: String,
+    pub big_crit: String,
+    pub kill: String,
+    pub death: String,
+    pub summary_victory: String,
+    pub summary_draw: String,
+    pub summary_first_blood: String,
+    pub summary_first_death: String,
+    pub summary_top_damage: String,
+    pub summary_top_healing: String,
+    pub summary_top_cc: String,
+    pub feed_match_start: String,
+    pub feed_gates_open: String,
+    pub feed_crowd_control: String,
+    pub feed_interrupt: String,
+    pub feed_dispel: String,
+    pub feed_big_crit: String,
+    pub feed_low_health: String,
+    pub feed_kill: String,
+    pub feed_death: String,
+    pub feed_victory: String,
+    pub feed_draw: String,
+}
+
+/// Load commentary templates from assets/config/commentary.ron
+pub fn load_commentary_templates() -> Result<CommentaryTemplates, String> {
+    let config_path = "assets/config/commentary.ron";
+
+    let contents = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read {}: {}", config_path, e))?;
+
+    let templates: CommentaryTemplates = ron::from_str(&contents)
+        .map_err(|e| format!("Failed to parse {}: {}", config_path, e))?;
+
+    info!("Loaded commentary templates from {}", config_path);
+    Ok(templates)
+}
+
+/// Fill `{key}` placeholders in a template. Unknown placeholders are left as-is.
+pub fn fill_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut text = template.to_string();
+    for (key, value) in values {
+        text = text.replace(&format!("{{{}}}", key), value);
+    }
+    text
+}
+
+/// Split a combat log ID ("Team 2 Rogue") into its team number and name.
+/// IDs that don't follow the pattern (e.g. "Sandbox") keep the whole ID as
+/// the name.
+fn split_combatant(id: &str) -> (String, String) {
+    id.strip_prefix("Team ")
+        .and_then(|rest| rest.split_once(' '))
+        .map(|(team, name)| (team.to_string(), name.to_string()))
+        .unwrap_or_else(|| (String::new(), id.to_string()))
+}
+
+/// Placeholder values for the `{source_team}` / `{source}` pair.
+fn source_values(id: &str) -> [(&'static str, String); 2] {
+    let (team, name) = split_combatant(id);
+    [("source_team", team), ("source", name)]
+}
+
+/// Placeholder values for the `{target_team}` / `{target}` pair.
+pub(super) fn target_values(id: &str) -> [(&'static str, String); 2] {
+    let (team, name) = split_combatant(id);
+    [("target_team", team), ("target", name)]
+}
+
+/// Format match seconds as m:ss.
+fn fmt_clock(secs: f32) -> String {
+    let total = secs.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+/// Incremental event-to-line converter. Feed it log entries in order; it
+/// remembers which teams have opened and the last ability to damage each
+/// combatant (for naming the killing blow).
+#[derive(Clone, Default)]
+pub struct Narrator {
+    opened: [bool; 2],
+    last_hit_by: HashMap<CombatantId, String>,
+}
+
+impl Narrator {
+    /// The commentary line for this entry, if it is significant.
+    pub fn narrate(&mut self, entry: &CombatLogEntry, templates: &CommentaryTemplates) -> Option<String> {
+        match entry.structured_data.as_ref()? {
+            StructuredEventData::AbilityCast { caster, ability, target: Some(target), .. } => {
+                let caster_team = team_from_combatant_id(caster)?;
+                if team_from_combatant_id(target)? == caster_team {
+                    return None;
+                }
+                let opened = self.opened.get_mut(caster_team.checked_sub(1)? as usize)?;
+                if *opened {
+                    return None;
+                }
+                *opened = true;
+                Some(fill_event_template(&templates.opener, caster, target, &[("ability", ability.clone())]))
+            }
+            StructuredEventData::CrowdControl { source, target, cc_type, duration_secs } => Some(fill_event_template(
+                &templates.crowd_control,
+                source,
+                target,
+                &[("cc", cc_type.clone()), ("duration", format!("{:.0}", duration_secs))],
+            )),
+            StructuredEventData::Damage { source, target, ability, amount, is_crit, .. } => {
+                self.last_hit_by.insert(target.clone(), ability.clone());
+                (*is_crit && *amount >= BIG_CRIT_DAMAGE).then(|| {
+                    fill_event_template(
+                        &templates.big_crit,
+                        source,
+                        target,
+                        &[("ability", ability.clone()), ("amount", format!("{:.0}", amount))],
+                    )
+                })
+            }
+            StructuredEventData::Death { victim, killer } => Some(match killer {
+                Some(killer) => {
+                    let ability = self.last_hit_by.get(victim).cloned().unwrap_or_default();
+                    fill_event_template(&templates.kill, killer, victim, &[("ability", ability)])
+                }
+                None => fill_template(&templates.death, &target_values(victim)),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Fill a template with the source and target placeholder pairs plus `extra`.
+pub fn fill_event_template(template: &str, source: &str, target: &str, extra: &[(&str, String)]) -> String {
+    let mut values: Vec<(&str, String)> = source_values(source).into_iter().collect();
+    values.extend(target_values(target));
+    values.extend(extra.iter().cloned());
+    fill_template(template, &values)
+}
+
+/// Compile the whole match into a summary paragraph for the Results screen:
+/// the result, first blood, and the top damage, healing and CC combatants.
+pub fn match_summary(
+    log: &CombatLog,
+    winner: Option<u8>,
+    duration_secs: f32,
+    templates: &CommentaryTemplates,
+) -> String {
+    let mut sentences = Vec::new();
+    let duration = fmt_clock(duration_secs);
+
+    sentences.push(match winner {
+        Some(winner) => fill_template(
+            &templates.summary_victory,
+            &[
+                ("winner", winner.to_string()),
+                ("loser", (3 - winner.clamp(1, 2)).to_string()),
+                ("duration", duration),
+            ],
+        ),
+        None => fill_template(&templates.summary_draw, &[("duration", duration)]),
+    });
+
+    // First blood, named by the last ability to hit the victim
+    let mut last_hit_by: HashMap<&str, &str> = HashMap::new();
+    for entry in &log.entries {
+        match &entry.structured_data {
+            Some(StructuredEventData::Damage { target, ability, .. }) => {
+                last_hit_by.insert(target, ability);
+            }
+            Some(StructuredEventData::Death { victim, killer }) => {
+                let time = ("time", fmt_clock(entry.timestamp));
+                let mut values: Vec<(&str, String)> = target_values(victim).into_iter().collect();
+                values.push(time);
+                sentences.push(match killer {
+                    Some(killer) => {
+                        values.extend(source_values(killer));
+                        let ability = last_hit_by.get(victim.as_str()).copied().unwrap_or_default();
+                        values.push(("ability", ability.to_string()));
+                        fill_template(&templates.summary_first_blood, &values)
+                    }
+                    None => fill_template(&templates.summary_first_death, &values),
+                });
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let combatants: Vec<String> = log
+        .all_combatants()
+        .into_iter()
+        .filter(|id| team_from_combatant_id(id).is_some())
+        .collect();
+    let leader = |stat: &dyn Fn(&str) -> f32| {
+        combatants
+            .iter()
+            .map(|id| (id, stat(id)))
+            .filter(|(_, value)| *value > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    };
+
+    if let Some((id, amount)) = leader(&|id| log.total_damage_dealt(id)) {
+        let mut values: Vec<(&str, String)> = source_values(id).into_iter().collect();
+        values.push(("amount", format!("{:.0}", amount)));
+        sentences.push(fill_template(&templates.summary_top_damage, &values));
+    }
+    if let Some((id, amount)) = leader(&|id| log.total_healing_done(id)) {
+        let mut values: Vec<(&str, String)> = source_values(id).into_iter().collect();
+        values.push(("amount", format!("{:.0}", amount)));
+        sentences.push(fill_template(&templates.summary_top_healing, &values));
+    }
+    if let Some((id, secs)) = leader(&|id| log.cc_done_seconds(id)) {
+        let mut values: Vec<(&str, String)> = source_values(id).into_iter().collect();
+        values.push(("duration", format!("{:.0}", secs)));
+        sentences.push(fill_template(&templates.summary_top_cc, &values));
+    }
+
+    sentences.join(" ")
+}
+
+// ============================================================================
+// Graphical ticker
+// ============================================================================
+
+/// A narrated line and the match time of the event behind it.
+#[derive(Clone)]
+pub struct CommentaryLine {
+    pub timestamp: f32,
+    pub text: String,
+}
+
+/// Commentary generated so far this match. `update_commentary_feed` narrates
+/// log entries as they arrive; the ticker shows the most recent lines.
+#[derive(Resource, Clone, Default)]
+pub struct CommentaryFeed {
+    pub lines: Vec<CommentaryLine>,
+    narrator: Narrator,
+    /// Index of the next combat log entry to narrate
+    cursor: usize,
+}
+
+/// Start each match with an empty feed.
+pub fn reset_commentary_feed(mut feed: ResMut<CommentaryFeed>) {
+    *feed = CommentaryFeed::default();
+}
+
+/// Narrate combat log entries added since the last frame.
+pub fn update_commentary_feed(
+    mut feed: ResMut<CommentaryFeed>,
+    combat_log: Res<CombatLog>,
+    templates: Res<CommentaryTemplates>,
+) {
+    // The log was cleared under us (new match before the reset ran)
+    if feed.cursor > combat_log.entries.len() {
+        *feed = CommentaryFeed::default();
+    }
+
+    let feed = &mut *feed;
+    for entry in &combat_log.entries[feed.cursor..] {
+        if let Some(text) = feed.narrator.narrate(entry, &templates) {
+            feed.lines.push(CommentaryLine { timestamp: entry.timestamp, text });
+        }
+    }
+    feed.cursor = combat_log.entries.len();
+}
+
+/// Commentary ticker: the latest lines along the bottom of the match view,
+/// fading out as they age.
+pub fn render_commentary_ticker(
+    mut contexts: EguiContexts,
+    feed: Res<CommentaryFeed>,
+    combat_log: Res<CombatLog>,
+) {
+    let recent: Vec<_> = feed
+        .lines
+        .iter()
+        .rev()
+        .take_while(|line| combat_log.match_time - line.timestamp <= TICKER_LINE_DURATION)
+        .take(TICKER_MAX_LINES)
+        .collect();
+    if recent.is_empty() {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    egui::Area::new(egui::Id::new("commentary_ticker"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -70.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(150))
+                .corner_radius(4.0)
+                .inner_margin(egui::Margin::symmetric(10, 6))
+                .show(ui, |ui| {
+                    for line in recent.iter().rev() {
+                        let age = combat_log.match_time - line.timestamp;
+                        // Fade over the last second on screen
+                        let alpha = ((TICKER_LINE_DURATION - age).clamp(0.0, 1.0) * 255.0) as u8;
+                        ui.label(
+                            egui::RichText::new(&line.text)
+                                .size(15.0)
+                                .color(egui::Color32::from_rgba_unmultiplied(235, 225, 200, alpha)),
+                        );
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn templates() -> CommentaryTemplates {
+        load_commentary_templates().expect("commentary.ron must load")
+    }
+
+    fn narrate_all(log: &CombatLog) -> Vec<String> {
+        let templates = templates();
+        let mut narrator = Narrator::default();
+        log.entries.iter().filter_map(|e| narrator.narrate(e, &templates)).collect()
+    }
+
+    #[test]
+    fn narrates_openers_once_per_team_and_kills() {
+        let mut log = CombatLog::default();
+        let rogue = "Team 2 Rogue".to_string();
+        let priest = "Team 1 Priest".to_string();
+        log.log_ability_cast(rogue.clone(), "Cheap Shot".into(), Some(priest.clone()), String::new());
+        log.log_ability_cast(rogue.clone(), "Sinister Strike".into(), Some(priest.clone()), String::new());
+        // Friendly casts are not openers
+        log.log_ability_cast(priest.clone(), "Power Word: Shield".into(), Some(priest.clone()), String::new());
+        log.log_damage(rogue.clone(), priest.clone(), "Eviscerate".into(), 40.0, true, false, String::new());
+        log.log_death(priest.clone(), Some(rogue.clone()), String::new());
+
+        assert_eq!(
+            narrate_all(&log),
+            vec![
+                "Team 2's Rogue opens with Cheap Shot on the Priest!".to_string(),
+                "Team 2's Rogue takes down Team 1's Priest with Eviscerate!".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_big_crits_are_narrated() {
+        let mut log = CombatLog::default();
+        let mage = "Team 1 Mage".to_string();
+        let warrior = "Team 2 Warrior".to_string();
+        log.log_damage(mage.clone(), warrior.clone(), "Frostbolt".into(), BIG_CRIT_DAMAGE - 1.0, false, true, String::new());
+        log.log_damage(mage.clone(), warrior.clone(), "Frostbolt".into(), BIG_CRIT_DAMAGE + 50.0, false, false, String::new());
+        log.log_damage(mage.clone(), warrior.clone(), "Frostbolt".into(), BIG_CRIT_DAMAGE + 50.0, false, true, String::new());
+
+        let lines = narrate_all(&log);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("Frostbolt") && lines[0].contains("150"), "{}", lines[0]);
+    }
+
+    #[test]
+    fn summary_covers_result_first_blood_and_leaders() {
+        let mut log = CombatLog::default();
+        let warlock = "Team 2 Warlock".to_string();
+        let mage = "Team 1 Mage".to_string();
+        log.match_time = 42.0;
+        log.log_damage(warlock.clone(), mage.clone(), "Shadow Bolt".into(), 900.0, true, false, String::new());
+        log.log_death(mage.clone(), Some(warlock.clone()), String::new());
+
+        let summary = match_summary(&log, Some(2), 107.0, &templates());
+        assert!(summary.starts_with("Team 2 defeated Team 1 in 1:47."), "{}", summary);
+        assert!(summary.contains("first blood at 0:42"), "{}", summary);
+        assert!(summary.contains("Shadow Bolt"), "{}", summary);
+        assert!(summary.contains("Team 2's Warlock led the damage meters with 900."), "{}", summary);
+        // Nobody healed or CC'd, so there are no sentences for them
+        assert!(!summary.contains("heals") && !summary.contains("control"), "{}", summary);
+    }
+
+    #[test]
+    fn fill_template_replaces_every_occurrence() {
+        assert_eq!(
+            fill_template("{a} and {a} but not {b}", &[("a", "x".to_string())]),
+            "x and x but not {b}"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Spoken Commentary (`tts` feature)
+//!
+//! Reads commentary ticker lines aloud for hands-off spectating. Each line is
+//! handed to a TTS process: the OS-native voice (`say` on macOS, `espeak-ng`
+//! on Linux, System.Speech via PowerShell on Windows) or the external command
+//! from `GameSettings::commentary_speech.command`.
+//!
+//! One line is spoken at a time, and a new line starts no sooner than
+//! `min_interval_secs` (real time) after the previous one. At high sim speeds
+//! lines arrive faster than they can be spoken, so only the newest few are
+//! kept waiting; older ones are dropped rather than lagging behind the match.
+
+use std::collections::VecDeque;
+use std::process::{Child, Command};
+
+use bevy::prelude::*;
+
+use crate::settings::{CommentarySpeechSettings, GameSettings};
+use super::commentary::CommentaryFeed;
+
+/// Lines waiting to be spoken; older lines are dropped beyond this
+const SPEECH_QUEUE_MAX: usize = 2;
+
+/// Spoken commentary state: the TTS process currently speaking (if any) and
+/// the lines waiting for it.
+#[derive(Resource, Default)]
+pub struct CommentarySpeech {
+    speaking: Option<Child>,
+    queue: VecDeque<String>,
+    /// Number of feed lines already queued or skipped
+    heard: usize,
+    /// Real time at which the last line started
+    last_started: Option<f32>,
+}
+
+/// Program and arguments that speak `line` with these settings.
+pub fn speech_command(settings: &CommentarySpeechSettings, line: &str) -> Option<(String, Vec<String>)> {
+    let volume = settings.volume.clamp(0.0, 1.0);
+
+    if let Some(command) = settings.command.as_deref() {
+        let mut words = command.split_whitespace();
+        let program = words.next()?.to_string();
+        let percent = format!("{:.0}", volume * 100.0);
+        let mut args: Vec<String> = words
+            .map(|word| word.replace("{volume}", &percent).replace("{text}", line))
+            .collect();
+        if !command.contains("{text}") {
+            args.push(line.to_string());
+        }
+        return Some((program, args));
+    }
+
+    native_speech_command(volume, line)
+}
+
+#[cfg(target_os = "macos")]
+fn native_speech_command(volume: f32, line: &str) -> Option<(String, Vec<String>)> {
+    // `say` takes volume as an embedded speech command
+    Some(("say".to_string(), vec![format!("[[volm {:.2}]] {}", volume, line)]))
+}
+
+#[cfg(target_os = "windows")]
+fn native_speech_command(volume: f32, line: &str) -> Option<(String, Vec<String>)> {
+    let script = format!(
+        "Add-Type -AssemblyName System.Speech; \
+         $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+         $s.Volume = {:.0}; $s.Speak('{}')",
+        volume * 100.0,
+        line.replace('\'', "''"),
+    );
+    Some((
+        "powershell".to_string(),
+        vec!["-NoProfile".to_string(), "-Command".to_string(), script],
+    ))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn native_speech_command(volume: f32, line: &str) -> Option<(String, Vec<String>)> {
+    // espeak-ng amplitude runs 0-200 with 100 as normal
+    Some((
+        "espeak-ng".to_string(),
+        vec!["-a".to_string(), format!("{:.0}", volume * 100.0), line.to_string()],
+    ))
+}
+
+/// Queue new ticker lines and start speaking the next one when the previous
+/// line has finished and the rate limit allows.
+pub fn speak_commentary(
+    mut speech: ResMut<CommentarySpeech>,
+    feed: Res<CommentaryFeed>,
+    settings: Res<GameSettings>,
+    time: Res<Time<Real>>,
+) {
+    let settings = &settings.commentary_speech;
+
+    // The feed was reset for a new match
+    if speech.heard > feed.lines.len() {
+        speech.heard = 0;
+        speech.queue.clear();
+    }
+    if !settings.enabled {
+        speech.heard = feed.lines.len();
+        speech.queue.clear();
+        return;
+    }
+
+    let speech = &mut *speech;
+    for line in &feed.lines[speech.heard..] {
+        speech.queue.push_back(line.text.clone());
+    }
+    speech.heard = feed.lines.len();
+    let overflow = speech.queue.len().saturating_sub(SPEECH_QUEUE_MAX);
+    speech.queue.drain(..overflow);
+
+    if let Some(child) = speech.speaking.as_mut() {
+        match child.try_wait() {
+            Ok(None) => return,
+            Ok(Some(_)) | Err(_) => speech.speaking = None,
+        }
+    }
+
+    let now = time.elapsed_secs();
+    if speech.last_started.is_some_and(|t| now - t < settings.min_interval_secs) {
+        return;
+    }
+    let Some(line) = speech.queue.pop_front() else { return; };
+    let Some((program, args)) = speech_command(settings, &line) else { return; };
+
+    match Command::new(&program).args(&args).spawn() {
+        Ok(child) => {
+            speech.speaking = Some(child);
+            speech.last_started = Some(now);
+        }
+        Err(e) => {
+            // Most likely no TTS program installed — don't retry every frame
+            warn!("Commentary speech: failed to run '{}': {}; disabling for this match", program, e);
+            speech.queue.clear();
+            speech.last_started = Some(f32::INFINITY);
+        }
+    }
+}
+
+/// Leaving the match stops any line still being spoken.
+pub fn stop_commentary_speech(mut speech: ResMut<CommentarySpeech>) {
+    if let Some(mut child) = speech.speaking.take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    *speech = CommentarySpeech::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn external_command_substitutes_text_and_volume() {
+        let settings = CommentarySpeechSettings {
+            volume: 0.5,
+            command: Some("espeak-ng -a {volume} {text}".to_string()),
+            ..default()
+        };
+        let (program, args) = speech_command(&settings, "First blood!").unwrap();
+        assert_eq!(program, "espeak-ng");
+        assert_eq!(args, vec!["-a", "50", "First blood!"]);
+    }
+
+    #[test]
+    fn external_command_without_text_placeholder_appends_line() {
+        let settings = CommentarySpeechSettings {
+            command: Some("my-tts --fast".to_string()),
+            ..default()
+        };
+        let (program, args) = speech_command(&settings, "Hello").unwrap();
+        assert_eq!(program, "my-tts");
+        assert_eq!(args, vec!["--fast", "Hello"]);
+    }
+}
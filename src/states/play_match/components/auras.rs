@@ -40,6 +40,18 @@ pub enum AuraType {
     Absorb,
     /// Weakened Soul - prevents receiving Power Word: Shield (applied by PW:S)
     WeakenedSoul,
+    /// Adds to the chance to dodge melee attacks (magnitude = dodge chance,
+    /// e.g. 0.5 = 50%). Read by the melee attack table (`roll_dodge`).
+    /// Used by the Rogue's Evasion.
+    DodgeChanceIncrease,
+    /// Increases movement speed by a percentage (magnitude = bonus, e.g.
+    /// 0.7 = 70% faster). Multiplies with any `MovementSpeedSlow`.
+    /// Used by the Rogue's Sprint.
+    MovementSpeedIncrease,
+    /// Intervene - the next attack against this combatant is redirected to the
+    /// aura's caster (the intervening Warrior), consuming the aura.
+    /// Magnitude unused (always 1.0 by convention).
+    Intervene,
     /// Polymorph - target wanders slowly, can't attack/cast, breaks on ANY damage.
     /// Separate from Stun for diminishing returns categories (incapacitates vs stuns).
     Polymorph,
@@ -55,6 +67,15 @@ pub enum AuraType {
     /// Complete damage immunity - all incoming damage is negated, all hostile auras are blocked.
     /// Used by Divine Shield. Magnitude unused (always 1.0 by convention).
     DamageImmunity,
+    /// Ice Block encasement - the holder cannot move, attack or cast.
+    /// Always paired with a `DamageImmunity` aura of the same name and duration
+    /// (the immunity half); both are removed together if the Mage cancels
+    /// the block early. Magnitude unused (always 1.0 by convention).
+    IceBlock,
+    /// Soulstone - when the holder dies, the aura is consumed and they are
+    /// resurrected a few seconds later (see `process_soulstone_revivals`).
+    /// Magnitude = fraction of max health restored on revival.
+    SoulstoneResurrection,
     /// Incapacitate - target is frozen in place, can't attack/cast, breaks on ANY damage.
     /// Unlike Polymorph (target wanders), incapacitated targets stand still.
     /// Shares DRCategory::Incapacitates with Polymorph.
@@ -139,6 +160,24 @@ impl AuraType {
                 | AuraType::Silence
         )
     }
+
+    /// Returns true for buffs that never stack: `apply_pending_auras` drops a
+    /// second application while one is active. Absorb shields refuse per
+    /// ability (Ice Barrier and Power Word: Shield coexist), the rest per type.
+    pub fn refuses_stacking(&self) -> bool {
+        matches!(
+            self,
+            AuraType::MaxHealthIncrease | AuraType::MaxManaIncrease | AuraType::AttackPowerIncrease
+            | AuraType::Absorb | AuraType::WeakenedSoul | AuraType::DamageTakenReduction
+            | AuraType::DamageImmunity | AuraType::IceBlock | AuraType::SoulstoneResurrection | AuraType::AttackPowerReduction
+            | AuraType::CritChanceIncrease | AuraType::ManaRegenIncrease
+            | AuraType::FrostArmorBuff | AuraType::LockoutDurationReduction
+            | AuraType::SpellResistanceBuff | AuraType::AttackSpeedSlow
+            | AuraType::SpellPowerIncrease | AuraType::HealingOverTime
+            | AuraType::WindfuryBuff | AuraType::Intervene
+            | AuraType::DodgeChanceIncrease | AuraType::MovementSpeedIncrease
+        )
+    }
 }
 
 // ============================================================================
@@ -245,7 +284,8 @@ impl Aura {
     ///
     /// Only beneficial auras qualify — Purge removes enemy buffs, never their
     /// debuffs/CC (those are the *target's* problem, not ours). Excludes:
-    /// `DamageImmunity` (Divine Shield is unpurgeable by design), `ShadowSight`
+    /// `DamageImmunity` / `IceBlock` (bubbles are unpurgeable by design),
+    /// `SoulstoneResurrection` (a Soulstone is not a magic buff), `ShadowSight`
     /// and `WeaponPoison` (mechanical markers, not real buffs), and every
     /// debuff/CC aura type.
     pub fn can_be_purged(&self) -> bool {
@@ -273,7 +313,7 @@ impl Aura {
 // ============================================================================
 
 /// Component tracking active auras/debuffs on a combatant.
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default)]
 pub struct ActiveAuras {
     pub auras: Vec<Aura>,
 }
@@ -284,7 +324,7 @@ pub struct ActiveAuras {
 
 /// Temporary component for pending auras to be applied.
 /// Used to avoid borrow checker issues when applying auras during casting.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct AuraPending {
     pub target: Entity,
     pub aura: Aura,
@@ -580,6 +620,8 @@ mod tests {
     fn can_be_purged_false_for_immunity_markers_and_debuffs() {
         for ty in [
             AuraType::DamageImmunity,
+            AuraType::IceBlock,
+            AuraType::SoulstoneResurrection,
             AuraType::ShadowSight,
             AuraType::WeaponPoison,
             AuraType::Stun,
@@ -1,12 +1,13 @@
 use bevy::prelude::*;
 use std::collections::HashMap;
-use super::super::match_config::{self, RogueOpener, RoguePoison, WarlockCurse, WarriorShout, MageArmor, PaladinAura};
+use super::super::match_config::{self, RogueOpener, RoguePoison, WarlockCurse, WarriorShout, MageArmor, PaladinAura, Personality};
 use super::super::abilities::{AbilityType, ScalingStat, SpellSchool};
-use super::super::ability_config::AbilityConfig;
+use super::super::ability_config::{AbilityConfig, AbilityDefinitions};
 use super::super::equipment::{ItemSlot, ItemId, ItemDefinitions};
-use super::auras::AuraType;
+use super::auras::{AuraPending, AuraType};
 use super::pets::PetType;
 use super::resources::GameRng;
+use super::super::constants::{MAX_SOUL_SHARDS, STARTING_SOUL_SHARDS};
 
 // ============================================================================
 // Enums
@@ -30,7 +31,7 @@ pub enum ResourceType {
 
 /// Marker component for all entities spawned in the Play Match scene.
 /// Used for cleanup when exiting the scene.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct PlayMatchEntity;
 
 /// Marker component for the arena camera
@@ -45,7 +46,7 @@ pub struct Celebrating {
 }
 
 /// Component tracking floating combat text pattern state for deterministic spreading
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct FloatingTextState {
     /// Pattern index for next text spawn: 0 (center), 1 (right), 2 (left), cycles
     pub next_pattern_index: u8,
@@ -73,7 +74,7 @@ pub struct SpeechBubble {
 
 /// Component marking a Shadow Sight orb entity.
 /// These orbs spawn after extended combat to break stealth stalemates.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct ShadowSightOrb {
     /// Which orb spawn point this is (0 or 1)
     pub spawn_index: u8,
@@ -195,6 +196,12 @@ pub struct Combatant {
     pub mage_armor: MageArmor,
     /// Paladin-specific: which aura to apply (Devotion Aura, Shadow Resistance Aura, or Concentration Aura)
     pub paladin_aura: PaladinAura,
+    /// AI personality biasing target selection and spacing (all classes)
+    pub personality: Personality,
+    /// Warlock-specific: Soul Shards held (0 for other classes). Earned from
+    /// killing blows and low-HP Drain Soul ticks, spent by Shadowburn and
+    /// Soulstone. Capped at `MAX_SOUL_SHARDS`.
+    pub soul_shards: u8,
 }
 
 impl Combatant {
@@ -231,6 +238,13 @@ impl Combatant {
         // Rogues start stealthed
         let stealthed = class == match_config::CharacterClass::Rogue;
 
+        // Warlocks bring a Soul Shard into the arena
+        let soul_shards = if class == match_config::CharacterClass::Warlock {
+            STARTING_SOUL_SHARDS
+        } else {
+            0
+        };
+
         Self {
             team,
             slot,
@@ -276,6 +290,8 @@ impl Combatant {
             warrior_shout: WarriorShout::default(),
             mage_armor: MageArmor::default(),
             paladin_aura: PaladinAura::default(),
+            personality: Personality::default(),
+            soul_shards,
         }
     }
 
@@ -374,6 +390,66 @@ impl Combatant {
         self.current_health > 0.0 && !self.is_dead
     }
 
+    /// Whether this combatant has landed damage yet — i.e. has engaged.
+    /// Opener-only abilities (Charge) are usable until this becomes true.
+    /// Taking damage doesn't count: a Warrior kited and shot on approach
+    /// still opens with Charge.
+    pub fn has_engaged(&self) -> bool {
+        self.damage_dealt > 0.0
+    }
+
+    /// Put `ability` on cooldown, along with every ability sharing its
+    /// cooldown group (Charge/Intercept). Each member gets `ability`'s
+    /// cooldown unless it already has a longer one remaining.
+    pub fn start_cooldown(&mut self, ability: AbilityType, abilities: &AbilityDefinitions) {
+        let cooldown = abilities.get_unchecked(&ability).cooldown;
+        for member in abilities.cooldown_group_members(&ability) {
+            let remaining = self.ability_cooldowns.entry(member).or_insert(0.0);
+            *remaining = remaining.max(cooldown);
+        }
+    }
+
+    /// Finish the cooldown of every `school` ability except `except` (Cold
+    /// Snap resets all Frost cooldowns but its own). Returns the abilities
+    /// that were reset, in `AbilityType` order.
+    pub fn reset_school_cooldowns(
+        &mut self,
+        school: SpellSchool,
+        except: AbilityType,
+        abilities: &AbilityDefinitions,
+    ) -> Vec<AbilityType> {
+        let mut reset: Vec<AbilityType> = self
+            .ability_cooldowns
+            .keys()
+            .filter(|a| **a != except && abilities.get(a).is_some_and(|def| def.spell_school == school))
+            .copied()
+            .collect();
+        reset.sort();
+        for ability in &reset {
+            self.ability_cooldowns.remove(ability);
+        }
+        reset
+    }
+
+    /// Add a Soul Shard if there's room. Returns false when already at
+    /// `MAX_SOUL_SHARDS` (the shard is lost, as in WoW with a full bag).
+    pub fn gain_soul_shard(&mut self) -> bool {
+        if self.soul_shards >= MAX_SOUL_SHARDS {
+            return false;
+        }
+        self.soul_shards += 1;
+        true
+    }
+
+    /// Spend a Soul Shard. Returns false (and spends nothing) if none are held.
+    pub fn spend_soul_shard(&mut self) -> bool {
+        if self.soul_shards == 0 {
+            return false;
+        }
+        self.soul_shards -= 1;
+        true
+    }
+
     /// Get the resistance value for a given spell school.
     pub fn get_resistance(&self, school: SpellSchool) -> f32 {
         match school {
@@ -478,8 +554,11 @@ impl Combatant {
             ItemSlot::Ranged
         };
 
-        for (slot, item_id) in loadout {
-            let Some(item) = items.get(item_id) else {
+        // Walk slots in their fixed order rather than HashMap order: the f32
+        // stat sums below are order-sensitive, and HashMap iteration order is
+        // randomized per process.
+        for slot in ItemSlot::all() {
+            let Some(item) = loadout.get(slot).and_then(|item_id| items.get(item_id)) else {
                 continue;
             };
 
@@ -515,7 +594,7 @@ impl Combatant {
 }
 
 /// Component tracking an active cast in progress.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct CastingState {
     /// The ability being cast
     pub ability: AbilityType,
@@ -545,7 +624,7 @@ impl CastingState {
 
 /// Component tracking an active channel in progress.
 /// Channeled spells deal their effects over time while the caster remains stationary.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct ChannelingState {
     /// The ability being channeled
     pub ability: AbilityType,
@@ -566,15 +645,18 @@ pub struct ChannelingState {
 }
 
 /// Component tracking an active Charge (Warrior gap closer).
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct ChargingState {
     /// Target entity being charged toward
     pub target: Entity,
+    /// Aura applied to the target when the charge connects (Intercept's stun);
+    /// dropped if the charge is cancelled first.
+    pub on_arrival: Option<AuraPending>,
 }
 
 /// Component for pending interrupt attempts.
 /// Spawned as a temporary entity to interrupt a target's cast.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct InterruptPending {
     /// The entity that cast the interrupt
     pub caster: Entity,
@@ -587,14 +669,14 @@ pub struct InterruptPending {
 }
 
 /// Component tracking damage taken this frame for aura breaking purposes.
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default)]
 pub struct DamageTakenThisFrame {
     pub amount: f32,
 }
 
 /// Component for spell projectiles that travel from caster to target.
 /// When the projectile reaches its target, damage/effects are applied.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Projectile {
     /// The entity that cast this projectile
     pub caster: Entity,
@@ -615,7 +697,7 @@ pub struct Projectile {
 // ============================================================================
 
 /// Pending Holy Shock heal to be processed.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct HolyShockHealPending {
     pub caster_spell_power: f32,
     pub caster_crit_chance: f32,
@@ -625,7 +707,7 @@ pub struct HolyShockHealPending {
 }
 
 /// Pending Holy Shock damage to be processed.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct HolyShockDamagePending {
     pub caster_spell_power: f32,
     pub caster_crit_chance: f32,
@@ -634,16 +716,66 @@ pub struct HolyShockDamagePending {
     pub target: Entity,
 }
 
+/// Pending sandbox damage injected through `SandboxQueue`.
+/// Resolved by `process_sandbox_effects` with the same absorb, mitigation and
+/// death handling as ability damage.
+#[derive(Component, Clone)]
+pub struct SandboxDamagePending {
+    pub target: Entity,
+    pub amount: f32,
+    pub school: SpellSchool,
+    pub is_crit: bool,
+}
+
+/// Pending sandbox heal injected through `SandboxQueue`.
+#[derive(Component, Clone)]
+pub struct SandboxHealPending {
+    pub target: Entity,
+    pub amount: f32,
+}
+
 /// Pending Divine Shield activation to be processed.
 /// Uses the deferred pending pattern because Paladin AI has immutable aura access.
 /// The process_divine_shield() system has mutable ActiveAuras and can purge debuffs + apply immunity.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct DivineShieldPending {
     pub caster: Entity,
     pub caster_team: u8,
     pub caster_class: match_config::CharacterClass,
 }
 
+/// Pending Ice Block activation (or early cancel) to be processed.
+/// Uses the deferred pending pattern, like Divine Shield: Mage AI has immutable
+/// aura access, while process_ice_block() can add or strip the paired
+/// DamageImmunity + IceBlock auras.
+#[derive(Component, Clone)]
+pub struct IceBlockPending {
+    pub caster: Entity,
+    pub caster_team: u8,
+    pub caster_class: match_config::CharacterClass,
+    /// True to break an active Ice Block early instead of entering one.
+    pub cancel: bool,
+}
+
+/// Pending Vanish to be processed. Uses the deferred pending pattern because
+/// Rogue AI has immutable aura access; process_vanish() strips every Root and
+/// MovementSpeedSlow from the Rogue at once (no random pick, unlike a dispel).
+#[derive(Component, Clone)]
+pub struct VanishPending {
+    pub caster: Entity,
+}
+
+/// A Soulstoned combatant waiting to resurrect. Inserted on death by
+/// `process_soulstone_revivals`, which consumes the SoulstoneResurrection aura,
+/// and removed when the combatant stands back up.
+#[derive(Component, Clone)]
+pub struct SoulstoneRevive {
+    /// Seconds until resurrection
+    pub remaining: f32,
+    /// Fraction of max health (and mana) restored
+    pub health_fraction: f32,
+}
+
 /// Pending dispel to be processed by the aura system.
 /// This allows dispels to be applied without holding mutable references
 /// to the aura map during AI decision making.
@@ -651,7 +783,7 @@ pub struct DivineShieldPending {
 ///
 /// Used by Priest (Dispel Magic), Paladin (Cleanse), Felhunter (Devour Magic),
 /// and Bird (Master's Call).
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct DispelPending {
     /// Target entity to dispel
     pub target: Entity,
@@ -671,3 +803,12 @@ pub struct DispelPending {
     /// Dispel Magic / Devour Magic leave poisons untouched (false).
     pub removes_poison: bool,
 }
+
+/// Pending Soul Shard credit for a killing blow.
+/// Spawned wherever a death is logged, keyed by the entity that landed the
+/// blow; `award_soul_shards` resolves pets to their owner and credits Warlocks.
+#[derive(Component, Clone)]
+pub struct SoulShardPending {
+    /// Entity that landed the killing blow (combatant or pet)
+    pub killer: Entity,
+}
@@ -113,7 +113,7 @@ impl TrapType {
 
 /// Component for Hunter traps placed on the ground.
 /// Traps have an arming delay, then trigger on enemy proximity.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Trap {
     /// Which type of trap this is
     pub trap_type: TrapType,
@@ -131,7 +131,7 @@ pub struct Trap {
 
 /// A trap that has been lobbed and is traveling through the air to its landing position.
 /// On arrival, despawns and spawns a regular Trap entity at the landing position.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct TrapLaunchProjectile {
     pub trap_type: TrapType,
     pub owner_team: u8,
@@ -148,7 +148,7 @@ pub struct TrapLaunchProjectile {
 
 /// Component for persistent slow zones created by Frost Trap.
 /// Enemies inside the zone receive a refreshing movement speed slow.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct SlowZone {
     /// Team of the hunter who created this zone
     pub owner_team: u8,
@@ -163,7 +163,7 @@ pub struct SlowZone {
 }
 
 /// Component tracking an active Disengage (Hunter backward leap).
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct DisengagingState {
     /// Direction of the leap (normalized, away from nearest enemy)
     pub direction: Vec3,
@@ -187,8 +187,9 @@ pub struct TrapBurst {
     pub initial_lifetime: f32,
 }
 
-/// Translucent ice cuboid around a Freezing Trap target.
-/// Follows the target's position and despawns when Incapacitate aura breaks.
+/// Translucent ice cuboid around a Freezing Trap target or an Ice Blocked Mage.
+/// Follows the target's position and despawns when the Incapacitate / IceBlock
+/// aura ends.
 #[derive(Component)]
 pub struct IceBlockVisual {
     /// The entity frozen inside the ice block
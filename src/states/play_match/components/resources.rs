@@ -12,7 +12,7 @@ use super::super::match_config;
 ///
 /// When a seed is provided (e.g., via headless config), the same seed will
 /// always produce the same match outcome. Without a seed, uses system entropy.
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct GameRng {
     rng: StdRng,
     /// The seed used to initialize this RNG (if deterministic)
@@ -64,6 +64,10 @@ pub struct SimulationSpeed {
 pub struct DisplaySettings {
     /// Whether to show aura icons below combatant health bars
     pub show_aura_icons: bool,
+    /// Whether the combat log merges runs of periodic ticks into one row
+    pub merge_periodic_ticks: bool,
+    /// Whether to show the live win-odds bar
+    pub show_win_odds: bool,
 }
 
 impl Default for SimulationSpeed {
@@ -119,6 +123,8 @@ pub enum CameraMode {
 pub struct CameraController {
     pub mode: CameraMode,
     pub zoom_distance: f32,      // Distance from target
+    /// Zoom the match starts at and Reset Camera returns to (scales with team size)
+    pub default_zoom: f32,
     pub pitch: f32,              // Rotation around X-axis (up/down)
     pub yaw: f32,                // Rotation around Y-axis (left/right)
     pub manual_target: Vec3,     // Look-at point for manual mode
@@ -140,6 +146,7 @@ impl Default for CameraController {
         Self {
             mode: CameraMode::FollowCenter,
             zoom_distance: 60.0,
+            default_zoom: 60.0,
             pitch: 38.7f32.to_radians(), // ~40 degrees
             yaw: 0.0,
             manual_target: Vec3::ZERO,
@@ -152,8 +159,22 @@ impl Default for CameraController {
     }
 }
 
+impl CameraController {
+    /// Default camera for a match whose larger team has `team_size` members.
+    /// Up to 3v3 keeps the classic 60-unit zoom; each extra member per side
+    /// pulls the camera back 6 units so a 5v5 spread still fits on screen.
+    pub fn for_team_size(team_size: usize) -> Self {
+        let zoom = 60.0 + team_size.saturating_sub(3) as f32 * 6.0;
+        Self {
+            zoom_distance: zoom,
+            default_zoom: zoom,
+            ..Default::default()
+        }
+    }
+}
+
 /// Match countdown state - tracks the pre-combat countdown phase
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct MatchCountdown {
     /// Time remaining in countdown (in seconds). When <= 0, gates open and combat starts.
     pub time_remaining: f32,
@@ -172,7 +193,7 @@ impl Default for MatchCountdown {
 
 /// Resource tracking Shadow Sight orb spawn state.
 /// Shadow Sight orbs spawn after extended combat to break stealth stalemates.
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct ShadowSightState {
     /// Time elapsed since gates opened
     pub combat_time: f32,
@@ -87,7 +87,7 @@ impl TotemElement {
 /// A grounded Shaman totem. Pulses `aura_type` (with `magnitude`) onto allied
 /// combatants (`team == owner_team`) within `radius`. `duration_remaining`
 /// ticks down each frame in `totem_pulse_system`; the totem despawns at 0.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Totem {
     /// Team of the Shaman who dropped this totem.
     pub owner_team: u8,
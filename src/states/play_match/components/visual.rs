@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use bevy_egui::egui;
-use super::super::abilities::SpellSchool;
+use super::super::abilities::{AbilityType, SpellSchool};
 use super::super::match_config::CharacterClass;
 
 // ============================================================================
@@ -26,11 +26,16 @@ pub struct FloatingCombatText {
 }
 
 /// Visual effect for spell impacts (Mind Blast, etc.)
-/// Displays as an expanding sphere that fades out
+/// Displays as an expanding sphere that fades out. Spawned from the ability's
+/// `ImpactVisual::Burst` / `Generic` descriptor.
 #[derive(Component)]
 pub struct SpellImpactEffect {
     /// World position where the effect should appear
     pub position: Vec3,
+    /// Base color (alpha is driven by the fade)
+    pub color: Color,
+    /// Emissive glow color
+    pub emissive: LinearRgba,
     /// Time remaining before effect disappears (in seconds)
     pub lifetime: f32,
     /// Initial lifetime for calculating fade/scale
@@ -82,7 +87,7 @@ pub struct ShieldBubble {
 
 /// Component that stores the original mesh handle for a combatant.
 /// Used to restore the mesh when polymorph ends.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct OriginalMesh(pub Handle<Mesh>);
 
 /// Marker component indicating the combatant is currently polymorphed.
@@ -102,14 +107,19 @@ pub struct FlameParticle {
     pub initial_lifetime: f32,
 }
 
-/// Drain Life beam effect connecting caster to target.
-/// Created when a Drain Life channel starts, despawned when it ends.
+/// Channel beam connecting caster to target (Drain Life, Drain Soul, or any
+/// ability with a `BeamVisual`). Created when the channel starts, despawned
+/// when it ends.
 #[derive(Component)]
 pub struct DrainLifeBeam {
-    /// The caster entity channeling Drain Life
+    /// The caster entity channeling
     pub caster: Entity,
     /// The target entity being drained
     pub target: Entity,
+    /// The channeled ability the beam belongs to
+    pub ability: AbilityType,
+    /// Whether particles stream along the beam
+    pub particles: bool,
     /// Timer for spawning particles along the beam
     pub particle_spawn_timer: f32,
 }
@@ -271,13 +281,31 @@ pub struct BacklashBurst {
 /// `ground_y` is captured at spawn; the bob is applied as an offset above it.
 /// `phase` advances by horizontal distance traveled, so slowed units bob slowly
 /// and stationary units do not bob at all.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct WalkAnim {
     pub ground_y: f32,
     pub phase: f32,
     pub previous_xz: Vec2,
 }
 
+/// Flat disc on the ground under an ability's target, from the ability's
+/// `GroundDecalVisual`. Spawned in core at the impact site (like
+/// `SpellImpactEffect`); the mesh is attached and faded in
+/// `rendering/effects.rs`.
+#[derive(Component)]
+pub struct GroundDecal {
+    /// Ground position of the disc center (y is ignored)
+    pub position: Vec3,
+    /// Disc radius in world units
+    pub radius: f32,
+    /// Base color (alpha is driven by the fade)
+    pub color: Color,
+    /// Seconds remaining before despawn
+    pub lifetime: f32,
+    /// Initial lifetime, for the fade
+    pub initial_lifetime: f32,
+}
+
 /// Marker component for the player's selection ring — a translucent torus
 /// laid flat at the selected combatant's feet. One ring exists at most.
 #[derive(Component)]
@@ -286,6 +314,31 @@ pub struct SelectionRing {
     pub target: Entity,
 }
 
+/// Amber ring at the feet of the caster of the hovered aura icon. One exists at
+/// most; see `aura_source`.
+#[derive(Component)]
+pub struct AuraSourceRing {
+    /// The caster entity this ring follows
+    pub caster: Entity,
+}
+
+/// Thin beam from the caster of the hovered aura icon to the aura's target.
+#[derive(Component)]
+pub struct AuraSourceBeam {
+    pub caster: Entity,
+    pub target: Entity,
+}
+
+/// Short-lived ring under a participant of the combat log line the viewer
+/// clicked; see `log_focus`.
+#[derive(Component)]
+pub struct LogFocusRing {
+    /// The participant this ring follows
+    pub target: Entity,
+    /// Seconds (real time) until the ring fades out
+    pub remaining: f32,
+}
+
 /// Transient Windfury Totem proc effect: a spinning wind funnel ("tornado") that
 /// swirls up around a melee ally the instant it lands a Windfury bonus swing.
 /// Spawned in core at the proc site (like FloatingCombatText); the
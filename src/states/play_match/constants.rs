@@ -62,6 +62,10 @@ pub const HEALTHY_HP_THRESHOLD: f32 = 0.7;
 /// HP threshold for safe long casts (Holy Light)
 pub const SAFE_HEAL_MAX_THRESHOLD: f32 = 0.85;
 
+/// Execute range: below this %, execute abilities (Execute, Shadowburn) unlock
+/// and DPS AI treats the target as a burst window.
+pub const EXECUTE_HP_THRESHOLD: f32 = 0.2;
+
 // ============================================================================
 // Damage Over Time
 // ============================================================================
@@ -101,6 +105,12 @@ pub const ARENA_FLOOR_HALF_Z: f32 = 23.0;
 /// Length cut off each corner along both axes to form the octagon.
 pub const ARENA_FLOOR_CORNER_CUT: f32 = 10.0;
 
+/// Gameplay radius of the Duel Ring (circular 1v1 map). Combatants are
+/// clamped inside it; the visual ring wall sits just outside at +1.5.
+/// Small enough that casters can't kite for the whole match, large enough
+/// that Charge/Intercept and ranged openers still have room to matter.
+pub const DUEL_RING_RADIUS: f32 = 18.0;
+
 // ============================================================================
 // Visual/UI
 // ============================================================================
@@ -132,6 +142,35 @@ pub const DIVINE_SHIELD_DAMAGE_PENALTY: f32 = 0.5;
 /// HP threshold for AI to activate Divine Shield (30% HP)
 pub const DIVINE_SHIELD_HP_THRESHOLD: f32 = 0.3;
 
+// ============================================================================
+// Ice Block
+// ============================================================================
+
+/// HP threshold for AI to Ice Block while any visible enemy targets the Mage
+pub const ICE_BLOCK_HP_THRESHOLD: f32 = 0.25;
+
+/// Higher HP threshold used when two or more enemies are bursting the Mage
+pub const ICE_BLOCK_FOCUSED_HP_THRESHOLD: f32 = 0.4;
+
+/// HP at which an encased Mage cancels Ice Block early (healed through it)
+pub const ICE_BLOCK_CANCEL_HP_THRESHOLD: f32 = 0.6;
+
+// ============================================================================
+// Soul Shards
+// ============================================================================
+
+/// Maximum Soul Shards a Warlock can hold
+pub const MAX_SOUL_SHARDS: u8 = 3;
+
+/// Soul Shards a Warlock brings into the arena
+pub const STARTING_SOUL_SHARDS: u8 = 1;
+
+/// Target HP at or below which a Drain Soul tick yields a Soul Shard
+pub const DRAIN_SOUL_SHARD_HP_THRESHOLD: f32 = 0.25;
+
+/// Seconds a Soulstoned combatant lies dead before resurrecting
+pub const SOULSTONE_REVIVE_DELAY: f32 = 3.0;
+
 // ============================================================================
 // Timing
 // ============================================================================
@@ -304,6 +343,15 @@ mod tests {
         assert!(EMERGENCY_HEAL_THRESHOLD > 0.0 && EMERGENCY_HEAL_THRESHOLD <= 1.0);
         assert!(SHIELD_HP_THRESHOLD > 0.0 && SHIELD_HP_THRESHOLD <= 1.0);
         assert!(LOW_HP_THRESHOLD > 0.0 && LOW_HP_THRESHOLD <= 1.0);
+        // Ice Block: the cancel threshold must sit above both entry thresholds,
+        // or a Mage would cancel the block the moment it entered it.
+        assert!(ICE_BLOCK_HP_THRESHOLD <= ICE_BLOCK_FOCUSED_HP_THRESHOLD);
+        assert!(ICE_BLOCK_FOCUSED_HP_THRESHOLD < ICE_BLOCK_CANCEL_HP_THRESHOLD);
+        assert!(ICE_BLOCK_CANCEL_HP_THRESHOLD <= 1.0);
+        // Shadowburn fires inside the Drain Soul window, so a Warlock with no
+        // shard can still drain one out of an execute-range target.
+        assert!(EXECUTE_HP_THRESHOLD > 0.0 && EXECUTE_HP_THRESHOLD < LOW_HP_THRESHOLD);
+        assert!(EXECUTE_HP_THRESHOLD <= DRAIN_SOUL_SHARD_HP_THRESHOLD);
     }
 
     #[test]
@@ -0,0 +1,239 @@
+//! Effect Entity Pooling
+//!
+//! Floating combat text, flame and drain particles and spell impacts are
+//! spawned and despawned constantly; at 3x speed in a big fight that is
+//! hundreds of entities (and, for the 3D ones, mesh and material assets) per
+//! second. Instead of despawning, expired effects are parked: their effect
+//! component is removed, they are hidden and tagged `Pooled`, and the next
+//! effect of the same kind reuses the entity together with the mesh and
+//! material it already carries.
+//!
+//! Spawn and release go through [`EffectCommandsExt`] on `Commands`, so the
+//! combat systems don't need an extra system parameter. Both are deferred
+//! commands that look up the `EffectPool` resource when applied; without it
+//! (headless runs) spawning falls back to a plain spawn, so headless matches
+//! are unaffected.
+
+use bevy::prelude::*;
+
+use super::components::{
+    DrainParticle, FlameParticle, FloatingCombatText, PlayMatchEntity, SpellImpactEffect,
+};
+
+/// Upper bound on parked entities per kind. Releases beyond this despawn,
+/// so one huge burst doesn't pin its peak entity count for the whole match.
+pub const MAX_POOLED_PER_KIND: usize = 256;
+
+/// The kinds of effect entity that are pooled. Each kind has its own free
+/// list, so a recycled entity always carries the right mesh and material.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EffectKind {
+    FloatingText,
+    FlameParticle,
+    DrainParticle,
+    SpellImpact,
+}
+
+impl EffectKind {
+    pub const COUNT: usize = 4;
+}
+
+/// An effect component whose entities can be recycled through the pool.
+pub trait PooledEffect: Component {
+    const KIND: EffectKind;
+}
+
+impl PooledEffect for FloatingCombatText {
+    const KIND: EffectKind = EffectKind::FloatingText;
+}
+
+impl PooledEffect for FlameParticle {
+    const KIND: EffectKind = EffectKind::FlameParticle;
+}
+
+impl PooledEffect for DrainParticle {
+    const KIND: EffectKind = EffectKind::DrainParticle;
+}
+
+impl PooledEffect for SpellImpactEffect {
+    const KIND: EffectKind = EffectKind::SpellImpact;
+}
+
+/// Marker for a parked effect entity waiting to be reused.
+#[derive(Component)]
+pub struct Pooled;
+
+/// Free lists of parked effect entities, one per `EffectKind`.
+/// Inserted by `setup_play_match` and removed by `cleanup_play_match`
+/// (which also despawns the parked entities, as they keep `PlayMatchEntity`).
+#[derive(Resource, Default)]
+pub struct EffectPool {
+    free: [Vec<Entity>; EffectKind::COUNT],
+    /// Effects spawned onto a recycled entity
+    pub reused: u64,
+    /// Effects that needed a fresh entity
+    pub spawned: u64,
+}
+
+impl EffectPool {
+    /// Take a parked entity of this kind, if any.
+    pub fn acquire(&mut self, kind: EffectKind) -> Option<Entity> {
+        self.free[kind as usize].pop()
+    }
+
+    /// Park an entity of this kind. Returns false if the free list is full
+    /// and the entity should be despawned instead.
+    pub fn release(&mut self, kind: EffectKind, entity: Entity) -> bool {
+        let free = &mut self.free[kind as usize];
+        if free.len() >= MAX_POOLED_PER_KIND {
+            return false;
+        }
+        free.push(entity);
+        true
+    }
+
+    /// Number of parked entities of this kind.
+    pub fn parked(&self, kind: EffectKind) -> usize {
+        self.free[kind as usize].len()
+    }
+}
+
+/// Pooled spawn/release of effect entities.
+pub trait EffectCommandsExt {
+    /// Spawn an effect, reusing a parked entity of the same kind if one is
+    /// available. The entity is tagged `PlayMatchEntity`.
+    fn spawn_effect<T: PooledEffect>(&mut self, effect: T);
+
+    /// Like `spawn_effect`, also placing the entity at `transform`.
+    fn spawn_effect_at<T: PooledEffect>(&mut self, effect: T, transform: Transform);
+
+    /// Retire an effect entity: park it in the pool, or despawn it when
+    /// there is no pool or the free list is full. Releasing an entity twice
+    /// (or one that is already gone) is a no-op.
+    fn release_effect<T: PooledEffect>(&mut self, entity: Entity);
+}
+
+impl EffectCommandsExt for Commands<'_, '_> {
+    fn spawn_effect<T: PooledEffect>(&mut self, effect: T) {
+        self.queue(move |world: &mut World| {
+            spawn_pooled(world, effect, None);
+        });
+    }
+
+    fn spawn_effect_at<T: PooledEffect>(&mut self, effect: T, transform: Transform) {
+        self.queue(move |world: &mut World| {
+            spawn_pooled(world, effect, Some(transform));
+        });
+    }
+
+    fn release_effect<T: PooledEffect>(&mut self, entity: Entity) {
+        self.queue(move |world: &mut World| {
+            release_pooled::<T>(world, entity);
+        });
+    }
+}
+
+fn spawn_pooled<T: PooledEffect>(world: &mut World, effect: T, transform: Option<Transform>) {
+    let recycled = world
+        .get_resource_mut::<EffectPool>()
+        .and_then(|mut pool| pool.acquire(T::KIND));
+
+    if let Some(entity) = recycled {
+        if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+            if entity_mut.contains::<Pooled>() {
+                entity_mut.remove::<Pooled>().insert(effect);
+                if let Some(transform) = transform {
+                    entity_mut.insert(transform);
+                }
+                if let Some(mut visibility) = entity_mut.get_mut::<Visibility>() {
+                    *visibility = Visibility::Inherited;
+                }
+                if let Some(mut pool) = world.get_resource_mut::<EffectPool>() {
+                    pool.reused += 1;
+                }
+                return;
+            }
+        }
+    }
+
+    let mut entity_mut = world.spawn((effect, PlayMatchEntity));
+    if let Some(transform) = transform {
+        entity_mut.insert(transform);
+    }
+    if let Some(mut pool) = world.get_resource_mut::<EffectPool>() {
+        pool.spawned += 1;
+    }
+}
+
+fn release_pooled<T: PooledEffect>(world: &mut World, entity: Entity) {
+    let Ok(entity_mut) = world.get_entity(entity) else {
+        return;
+    };
+    if entity_mut.contains::<Pooled>() || !entity_mut.contains::<T>() {
+        return;
+    }
+
+    let parked = world
+        .get_resource_mut::<EffectPool>()
+        .is_some_and(|mut pool| pool.release(T::KIND, entity));
+
+    let mut entity_mut = world.entity_mut(entity);
+    if parked {
+        entity_mut.remove::<T>().insert(Pooled);
+        if let Some(mut visibility) = entity_mut.get_mut::<Visibility>() {
+            *visibility = Visibility::Hidden;
+        }
+    } else {
+        entity_mut.despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flame() -> FlameParticle {
+        FlameParticle {
+            velocity: Vec3::Y,
+            lifetime: 1.0,
+            initial_lifetime: 1.0,
+        }
+    }
+
+    #[test]
+    fn released_entities_are_reused() {
+        let mut world = World::new();
+        world.init_resource::<EffectPool>();
+
+        spawn_pooled(&mut world, flame(), Some(Transform::default()));
+        let first = world.query_filtered::<Entity, With<FlameParticle>>().single(&world).unwrap();
+
+        release_pooled::<FlameParticle>(&mut world, first);
+        // A second release of the same entity must not park it twice
+        release_pooled::<FlameParticle>(&mut world, first);
+        assert!(world.get::<FlameParticle>(first).is_none());
+        assert!(world.get::<Pooled>(first).is_some());
+        assert_eq!(world.resource::<EffectPool>().parked(EffectKind::FlameParticle), 1);
+
+        // Other kinds don't take it
+        assert!(world.resource_mut::<EffectPool>().acquire(EffectKind::FloatingText).is_none());
+
+        spawn_pooled(&mut world, flame(), Some(Transform::from_xyz(1.0, 2.0, 3.0)));
+        assert!(world.get::<FlameParticle>(first).is_some());
+        assert!(world.get::<Pooled>(first).is_none());
+        assert_eq!(world.get::<Transform>(first).unwrap().translation, Vec3::new(1.0, 2.0, 3.0));
+
+        let pool = world.resource::<EffectPool>();
+        assert_eq!((pool.spawned, pool.reused), (1, 1));
+    }
+
+    #[test]
+    fn release_without_pool_despawns() {
+        let mut world = World::new();
+        spawn_pooled(&mut world, flame(), None);
+        let entity = world.query_filtered::<Entity, With<FlameParticle>>().single(&world).unwrap();
+
+        release_pooled::<FlameParticle>(&mut world, entity);
+        assert!(world.get_entity(entity).is_err());
+    }
+}
@@ -31,11 +31,12 @@ use crate::combat::log::{CombatLog, CombatLogEventType};
 use crate::states::play_match::abilities::SpellSchool;
 use crate::states::play_match::combat_core::apply_damage_with_absorb;
 use crate::states::play_match::components::*;
+use crate::states::play_match::utils::{check_effect_reference, discard_stale_effect};
 
 /// Pending backlash event spawned by `process_dispels` when an opposing-team
 /// combatant strips an Unstable Affliction aura. Consumed by `process_backlash`
 /// in the same Phase 1 tick.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct BacklashPending {
     /// The entity that performed the dispel — receives damage and silence.
     pub dispeller: Entity,
@@ -70,20 +71,19 @@ pub fn process_backlash(
     mut combatants: Query<(&mut Combatant, Option<&mut ActiveAuras>)>,
 ) {
     for (pending_entity, event) in pending.iter() {
-        // Despawn the event regardless of outcome.
-        commands.entity(pending_entity).despawn();
-
-        // Look up the dispeller. If they were already despawned (race on death this
-        // same frame from another source), skip silently.
-        let Ok((mut dispeller, dispeller_auras)) = combatants.get_mut(event.dispeller) else {
+        // Look up the dispeller. If they were already despawned or killed (race on
+        // death this same frame from another source), the backlash is a logged no-op.
+        let dispeller_lookup = combatants.get_mut(event.dispeller).ok();
+        if let Err(reason) = check_effect_reference(dispeller_lookup.as_ref().map(|(c, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Unstable Affliction backlash", "dispeller", reason);
+            continue;
+        }
+        let Some((mut dispeller, dispeller_auras)) = dispeller_lookup else {
             continue;
         };
 
-        if !dispeller.is_alive() {
-            // Dispeller already dead from another source this frame — no point
-            // applying damage or silence. Skip.
-            continue;
-        }
+        // Despawn the event regardless of outcome from here on.
+        commands.entity(pending_entity).despawn();
 
         // ----- Step 1: Apply backlash damage -----
         let (actual_damage, absorbed, dispeller_team, dispeller_class_name, still_alive) = {
@@ -9,6 +9,7 @@ use smallvec::SmallVec;
 use crate::combat::log::{CombatLog, CombatLogEventType};
 use crate::states::play_match::components::*;
 use crate::states::play_match::effects::backlash::BacklashPending;
+use crate::states::play_match::utils::{check_effect_reference, discard_stale_effect};
 
 /// Process pending dispels from Dispel Magic, Cleanse, or Devour Magic.
 ///
@@ -25,6 +26,7 @@ pub fn process_dispels(
     // with no debuffs on themselves). The Without<ActiveAuras> filter makes this
     // disjoint from the mutable query, satisfying Bevy's borrow checker.
     teams_no_auras: Query<&Combatant, Without<ActiveAuras>>,
+    pets: Query<&Pet>,
     mut game_rng: ResMut<GameRng>,
 ) {
     // Deferred heals to apply after aura processing (avoids borrow conflicts)
@@ -36,6 +38,30 @@ pub fn process_dispels(
     let mut deferred_backlashes: Vec<(Entity, Entity, f32)> = Vec::new();
 
     for (pending_entity, pending) in pending_dispels.iter() {
+        // A target with no ActiveAuras is valid (nothing to remove); only a
+        // despawned or dead target makes the dispel a logged no-op.
+        let target = combatants
+            .get(pending.target)
+            .ok()
+            .map(|(c, _)| c)
+            .or_else(|| teams_no_auras.get(pending.target).ok());
+        if let Err(reason) = check_effect_reference(target) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, pending.log_prefix, "target", reason);
+            continue;
+        }
+
+        // Dispeller's combat log ID, resolved before the target is borrowed mutably
+        let dispeller_id = combatants
+            .get(pending.dispeller)
+            .ok()
+            .map(|(c, _)| c)
+            .or_else(|| teams_no_auras.get(pending.dispeller).ok())
+            .map(|dispeller| match pets.get(pending.dispeller) {
+                Ok(pet) => format!("Team {} {}", dispeller.team, pet.pet_type.name()),
+                Err(_) => format!("Team {} {}", dispeller.team, dispeller.class.name()),
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+
         // Get target's auras
         if let Ok((combatant, mut active_auras)) = combatants.get_mut(pending.target) {
             // Find all dispellable aura indices (SmallVec avoids heap allocation for typical aura counts)
@@ -74,8 +100,14 @@ pub fn process_dispels(
                 let removed_aura = active_auras.auras.remove(idx_to_remove);
 
                 // Log the dispel using the provided log prefix
-                combat_log.log(
-                    CombatLogEventType::Buff,
+                let removed_crowd_control = removed_aura
+                    .dr_category()
+                    .is_some_and(|category| category != DRCategory::Slows);
+                combat_log.log_dispel(
+                    dispeller_id,
+                    format!("Team {} {}", combatant.team, combatant.class.name()),
+                    removed_aura.ability_name.clone(),
+                    removed_crowd_control,
                     format!(
                         "{} {} removed from Team {} {}",
                         pending.log_prefix,
@@ -10,7 +10,8 @@ use crate::combat::log::{CombatLog, CombatLogEventType};
 use crate::states::play_match::abilities::AbilityType;
 use crate::states::play_match::ability_config::AbilityDefinitions;
 use crate::states::play_match::components::*;
-use crate::states::play_match::utils::{combatant_id, get_next_fct_offset};
+use crate::states::play_match::effect_pool::EffectCommandsExt;
+use crate::states::play_match::utils::{check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset};
 
 /// Process pending Divine Shield activations.
 ///
@@ -35,12 +36,12 @@ pub fn process_divine_shield(
     let immunity_break = ds_aura.map(|a| a.break_on_damage).unwrap_or(-1.0);
 
     for (pending_entity, pending) in pending_shields.iter() {
-        if let Ok((combatant, transform, active_auras_opt)) = combatants.get_mut(pending.caster) {
-            if !combatant.is_alive() {
-                commands.entity(pending_entity).despawn();
-                continue;
-            }
-
+        let caster_lookup = combatants.get_mut(pending.caster).ok();
+        if let Err(reason) = check_effect_reference(caster_lookup.as_ref().map(|(c, _, _)| *c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Divine Shield", "caster", reason);
+            continue;
+        }
+        if let Some((_, transform, active_auras_opt)) = caster_lookup {
             let immunity_aura = Aura {
                 effect_type: AuraType::DamageImmunity,
                 duration: immunity_duration,
@@ -121,17 +122,14 @@ pub fn process_divine_shield(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: "Divine Shield".to_string(),
-                    color: egui::Color32::from_rgb(255, 215, 0), // Gold
-                    lifetime: 2.0,
-                    vertical_offset: offset_y,
-                    is_crit: false,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: "Divine Shield".to_string(),
+                color: egui::Color32::from_rgb(255, 215, 0), // Gold
+                lifetime: 2.0,
+                vertical_offset: offset_y,
+                is_crit: false,
+            });
         }
 
         // Remove the pending entity
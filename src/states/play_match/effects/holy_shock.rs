@@ -12,7 +12,8 @@ use crate::states::play_match::ability_config::AbilityDefinitions;
 use crate::states::play_match::components::*;
 use crate::states::play_match::combat_core::{apply_damage_with_absorb, roll_crit};
 use crate::states::play_match::constants::{CRIT_DAMAGE_MULTIPLIER, CRIT_HEALING_MULTIPLIER};
-use crate::states::play_match::utils::{combatant_id, get_next_fct_offset};
+use crate::states::play_match::effect_pool::EffectCommandsExt;
+use crate::states::play_match::utils::{check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset};
 
 /// Process pending Holy Shock heals.
 ///
@@ -31,12 +32,12 @@ pub fn process_holy_shock_heals(
 
     for (pending_entity, pending) in pending_heals.iter() {
         // Get target combatant
-        if let Ok((mut target, target_transform, target_auras)) = combatants.get_mut(pending.target) {
-            if !target.is_alive() {
-                commands.entity(pending_entity).despawn();
-                continue;
-            }
-
+        let target_lookup = combatants.get_mut(pending.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(c, _, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Holy Shock (heal)", "target", reason);
+            continue;
+        }
+        if let Some((mut target, target_transform, target_auras)) = target_lookup {
             // Calculate healing amount using ability config
             let base_heal = ability_def.healing_base_min
                 + game_rng.random_f32() * (ability_def.healing_base_max - ability_def.healing_base_min);
@@ -73,17 +74,14 @@ pub fn process_holy_shock_heals(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("+{:.0}", actual_heal),
-                    color: egui::Color32::from_rgb(0, 255, 0), // Green for healing
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("+{:.0}", actual_heal),
+                color: egui::Color32::from_rgb(0, 255, 0), // Green for healing
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit,
+            });
 
             // Log the heal with caster attribution
             let caster_id = combatant_id(pending.caster_team, pending.caster_class);
@@ -128,12 +126,12 @@ pub fn process_holy_shock_damage(
 
     for (pending_entity, pending) in pending_damage.iter() {
         // Get target combatant
-        if let Ok((mut target, target_transform, mut target_auras)) = combatants.get_mut(pending.target) {
-            if !target.is_alive() {
-                commands.entity(pending_entity).despawn();
-                continue;
-            }
-
+        let target_lookup = combatants.get_mut(pending.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(c, _, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Holy Shock (damage)", "target", reason);
+            continue;
+        }
+        if let Some((mut target, target_transform, mut target_auras)) = target_lookup {
             // Calculate damage amount using ability config
             let base_damage = ability_def.damage_base_min
                 + game_rng.random_f32() * (ability_def.damage_base_max - ability_def.damage_base_min);
@@ -175,17 +173,14 @@ pub fn process_holy_shock_damage(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("{:.0}", actual_damage),
-                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("{:.0}", actual_damage),
+                color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit,
+            });
 
             // Spawn absorbed text if applicable
             if absorbed > 0.0 {
@@ -194,17 +189,14 @@ pub fn process_holy_shock_damage(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                        text: format!("{:.0} absorbed", absorbed),
-                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                        lifetime: 1.5,
-                        vertical_offset: absorb_offset_y,
-                        is_crit: false,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                    text: format!("{:.0} absorbed", absorbed),
+                    color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                    lifetime: 1.5,
+                    vertical_offset: absorb_offset_y,
+                    is_crit: false,
+                });
             }
 
             // Log damage with caster attribution
@@ -0,0 +1,118 @@
+//! Ice Block Effect Processing
+//!
+//! Processes Ice Block activation and early cancellation. Activation applies a
+//! DamageImmunity aura paired with an IceBlock encasement aura (can't move,
+//! attack or cast); cancellation strips both. Uses the IceBlockPending deferred
+//! pattern because Mage AI has immutable aura access.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::combat::log::{CombatLog, CombatLogEventType};
+use crate::states::play_match::abilities::{AbilityType, SpellSchool};
+use crate::states::play_match::ability_config::AbilityDefinitions;
+use crate::states::play_match::components::*;
+use crate::states::play_match::effect_pool::EffectCommandsExt;
+use crate::states::play_match::utils::{check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset};
+
+/// Process pending Ice Block activations and cancels.
+///
+/// Activation pushes the immunity and encasement auras (same duration, both
+/// named "Ice Block") and spawns the ice cuboid visual. A cancel removes every
+/// aura named "Ice Block" of either type, freeing the Mage to act next frame.
+pub fn process_ice_block(
+    mut commands: Commands,
+    mut combat_log: ResMut<CombatLog>,
+    abilities: Res<AbilityDefinitions>,
+    pending_blocks: Query<(Entity, &IceBlockPending)>,
+    mut combatants: Query<(&Combatant, &Transform, Option<&mut ActiveAuras>)>,
+    mut fct_states: Query<&mut FloatingTextState>,
+) {
+    // Data-driven like Divine Shield: IceBlock's `applies_aura` carries the
+    // immunity duration. Fallback preserves the designed 10s if it's missing.
+    let def = abilities.get(&AbilityType::IceBlock);
+    let ability_name = def.map_or("Ice Block".to_string(), |d| d.name.clone());
+    let duration = def
+        .and_then(|d| d.applies_aura.as_ref())
+        .map_or(10.0, |a| a.duration);
+
+    for (pending_entity, pending) in pending_blocks.iter() {
+        let caster_lookup = combatants.get_mut(pending.caster).ok();
+        if let Err(reason) = check_effect_reference(caster_lookup.as_ref().map(|(c, _, _)| *c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Ice Block", "caster", reason);
+            continue;
+        }
+        let caster_id = combatant_id(pending.caster_team, pending.caster_class);
+
+        if let Some((_, transform, active_auras_opt)) = caster_lookup {
+            if pending.cancel {
+                if let Some(mut active_auras) = active_auras_opt {
+                    active_auras.auras.retain(|a| {
+                        !(a.ability_name == ability_name
+                            && matches!(a.effect_type, AuraType::DamageImmunity | AuraType::IceBlock))
+                    });
+                }
+                combat_log.log(
+                    CombatLogEventType::Buff,
+                    format!("{} cancels {}", caster_id, ability_name),
+                );
+                info!("{} cancels Ice Block early", caster_id);
+            } else {
+                let make_aura = |effect_type: AuraType| Aura {
+                    effect_type,
+                    duration,
+                    magnitude: 1.0,
+                    break_on_damage_threshold: -1.0,
+                    caster: Some(pending.caster),
+                    ability_name: ability_name.clone(),
+                    spell_school: Some(SpellSchool::Frost),
+                    ..Default::default()
+                };
+                let block_auras = [make_aura(AuraType::DamageImmunity), make_aura(AuraType::IceBlock)];
+
+                if let Some(mut active_auras) = active_auras_opt {
+                    active_auras.auras.extend(block_auras);
+                } else {
+                    // No auras yet — insert new ActiveAuras with the block pair
+                    commands.entity(pending.caster).insert(ActiveAuras {
+                        auras: block_auras.to_vec(),
+                    });
+                }
+
+                combat_log.log(
+                    CombatLogEventType::Buff,
+                    format!("{} uses {}", caster_id, ability_name),
+                );
+                info!("{} activates Ice Block", caster_id);
+
+                // Reuse the Freezing Trap cuboid around the encased Mage
+                commands.spawn((
+                    IceBlockVisual {
+                        target: pending.caster,
+                        grace_timer: 0.1,
+                    },
+                    PlayMatchEntity,
+                ));
+
+                // Spawn icy "Ice Block" FCT on the Mage
+                let text_position = transform.translation + Vec3::new(0.0, super::super::FCT_HEIGHT, 0.0);
+                let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(pending.caster) {
+                    get_next_fct_offset(&mut fct_state)
+                } else {
+                    (0.0, 0.0)
+                };
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                    text: ability_name.clone(),
+                    color: egui::Color32::from_rgb(135, 206, 250), // Light sky blue
+                    lifetime: 2.0,
+                    vertical_offset: offset_y,
+                    is_crit: false,
+                });
+            }
+        }
+
+        // Remove the pending entity
+        commands.entity(pending_entity).despawn();
+    }
+}
@@ -12,9 +12,13 @@
 pub mod holy_shock;
 pub mod dispels;
 pub mod divine_shield;
+pub mod ice_block;
+pub mod vanish;
 pub mod backlash;
 
 pub use holy_shock::{process_holy_shock_damage, process_holy_shock_heals};
 pub use dispels::process_dispels;
 pub use divine_shield::process_divine_shield;
+pub use ice_block::process_ice_block;
+pub use vanish::process_vanish;
 pub use backlash::*;
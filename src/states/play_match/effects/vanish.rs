@@ -0,0 +1,66 @@
+//! Vanish Effect Processing
+//!
+//! Strips every root and movement slow from a vanishing Rogue in one pass.
+//! Uses the VanishPending deferred pattern because Rogue AI has immutable
+//! aura access.
+
+use bevy::prelude::*;
+
+use crate::combat::log::CombatLog;
+use crate::states::play_match::components::*;
+use crate::states::play_match::utils::{check_effect_reference, combatant_id, discard_stale_effect};
+
+/// Process pending Vanishes.
+///
+/// Every Root and MovementSpeedSlow on the Rogue is removed and logged as a
+/// "[VANISH]" dispel, in aura order. Unlike `process_dispels` there is no
+/// random pick and no RNG draw: Vanish sheds all of them.
+pub fn process_vanish(
+    mut commands: Commands,
+    mut combat_log: ResMut<CombatLog>,
+    pending_vanishes: Query<(Entity, &VanishPending)>,
+    mut combatants: Query<(&Combatant, Option<&mut ActiveAuras>)>,
+) {
+    for (pending_entity, pending) in pending_vanishes.iter() {
+        let caster_lookup = combatants.get_mut(pending.caster).ok();
+        if let Err(reason) = check_effect_reference(caster_lookup.as_ref().map(|(c, _)| *c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "[VANISH]", "caster", reason);
+            continue;
+        }
+
+        if let Some((combatant, Some(mut active_auras))) = caster_lookup {
+            let caster_id = combatant_id(combatant.team, combatant.class);
+            let (removed, kept): (Vec<Aura>, Vec<Aura>) = std::mem::take(&mut active_auras.auras)
+                .into_iter()
+                .partition(|a| matches!(a.effect_type, AuraType::Root | AuraType::MovementSpeedSlow));
+            active_auras.auras = kept;
+
+            for aura in &removed {
+                combat_log.log_dispel(
+                    caster_id.clone(),
+                    caster_id.clone(),
+                    aura.ability_name.clone(),
+                    aura.dr_category().is_some_and(|category| category != DRCategory::Slows),
+                    format!("[VANISH] {} removed from {}", aura.ability_name, caster_id),
+                );
+                info!("[VANISH] {} removed from {}", aura.ability_name, caster_id);
+            }
+
+            if !removed.is_empty() {
+                commands.spawn((
+                    DispelRibbon {
+                        target: pending.caster,
+                        caster_class: combatant.class,
+                        lifetime: 1.2,
+                        initial_lifetime: 1.2,
+                        spin: 0.0,
+                    },
+                    PlayMatchEntity,
+                ));
+            }
+        }
+
+        // Remove the pending entity
+        commands.entity(pending_entity).despawn();
+    }
+}
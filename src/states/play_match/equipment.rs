@@ -450,7 +450,8 @@ pub fn validate_class_restrictions(
     loadout: &HashMap<ItemSlot, ItemId>,
     items: &ItemDefinitions,
 ) -> Result<(), String> {
-    for (slot, item_id) in loadout {
+    // Fixed slot order so the same loadout always reports the same error
+    for (slot, item_id) in ItemSlot::all().iter().filter_map(|slot| loadout.get(slot).map(|id| (slot, id))) {
         if let Some(item) = items.get(item_id) {
             if !can_equip(class, item) {
                 return Err(format!(
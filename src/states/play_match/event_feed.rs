@@ -0,0 +1,340 @@
+//! Accessible Event Feed
+//!
+//! An optional feed of the match's major events as plain sentences ("Team 2
+//! Rogue used Stun on Team 1 Priest for 4 seconds."), in large high-contrast
+//! text, for players who can't follow the arena or read the small combat
+//! text. Turned on and sized in Options (`GameSettings::event_feed`); the
+//! wording lives with the commentary templates in
+//! `assets/config/commentary.ron` (`feed_*`).
+//!
+//! Reported events:
+//! - match start (both rosters), gates opening and the result
+//! - crowd control, interrupts, and dispels that remove crowd control
+//! - crits of at least `BIG_CRIT_DAMAGE`
+//! - a combatant dropping below [`LOW_HEALTH_FRACTION`] health
+//! - deaths
+//!
+//! Lines are paced in real time: a new line appears at most every
+//! `seconds_per_line`, whatever the sim speed, so each sentence stays up long
+//! enough to read. When events arrive faster than that, the oldest waiting
+//! minor lines are skipped (match, low-health and death lines never are) and
+//! the feed counts how many.
+
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::combat::log::{CombatLog, CombatLogEntry, StructuredEventData};
+use crate::settings::GameSettings;
+use super::camera::BIG_CRIT_DAMAGE;
+use super::commentary::{fill_event_template, fill_template, target_values, CommentaryTemplates};
+use super::components::{Combatant, MatchCountdown, Pet, VictoryCelebration};
+use super::match_config::{CharacterClass, MatchConfig};
+use super::utils::combatant_id;
+
+/// Health fraction below which a combatant's low-health line is reported
+pub const LOW_HEALTH_FRACTION: f32 = 0.25;
+/// Health fraction a combatant must heal back above before it can be
+/// reported low again
+const LOW_HEALTH_REARM_FRACTION: f32 = 0.35;
+/// Lines kept on screen, newest at the bottom
+const FEED_VISIBLE_LINES: usize = 3;
+/// Lines allowed to wait; past this the oldest minor line is skipped
+const FEED_MAX_PENDING: usize = 6;
+
+/// Whether a line may be skipped when the feed falls behind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedImportance {
+    Minor,
+    Major,
+}
+
+/// Event feed state for the current match.
+#[derive(Resource, Default, Clone)]
+pub struct EventFeed {
+    /// Lines on screen, oldest first
+    pub shown: VecDeque<String>,
+    /// Lines waiting for their turn
+    pending: VecDeque<(String, FeedImportance)>,
+    /// Minor lines skipped this match because the feed fell behind
+    pub skipped: usize,
+    /// Index of the next combat log entry to describe
+    log_cursor: usize,
+    /// Real time the last line appeared
+    last_shown_at: Option<f32>,
+    /// Combatants currently reported as low on health
+    low_health: HashSet<Entity>,
+    gates_reported: bool,
+    result_reported: bool,
+}
+
+impl EventFeed {
+    /// Queue a line, skipping the oldest minor line if too many are waiting.
+    pub fn push(&mut self, text: String, importance: FeedImportance) {
+        self.pending.push_back((text, importance));
+        while self.pending.len() > FEED_MAX_PENDING {
+            let Some(oldest_minor) = self.pending.iter().position(|(_, i)| *i == FeedImportance::Minor) else {
+                break;
+            };
+            self.pending.remove(oldest_minor);
+            self.skipped += 1;
+        }
+    }
+
+    /// Show the next waiting line if `seconds_per_line` has passed since the
+    /// last one. Returns whether a line was shown.
+    pub fn advance(&mut self, now: f32, seconds_per_line: f32) -> bool {
+        if self.last_shown_at.is_some_and(|at| now - at < seconds_per_line) {
+            return false;
+        }
+        let Some((text, _)) = self.pending.pop_front() else { return false; };
+        self.shown.push_back(text);
+        while self.shown.len() > FEED_VISIBLE_LINES {
+            self.shown.pop_front();
+        }
+        self.last_shown_at = Some(now);
+        true
+    }
+}
+
+/// The feed sentence for a combat log entry, if it is a major event.
+pub fn describe_event(entry: &CombatLogEntry, templates: &CommentaryTemplates) -> Option<(String, FeedImportance)> {
+    let line = match entry.structured_data.as_ref()? {
+        StructuredEventData::CrowdControl { source, target, cc_type, duration_secs } => (
+            fill_event_template(
+                &templates.feed_crowd_control,
+                source,
+                target,
+                &[("cc", cc_type.clone()), ("duration", format!("{:.0}", duration_secs))],
+            ),
+            FeedImportance::Minor,
+        ),
+        StructuredEventData::Interrupt { source, target, ability } => (
+            fill_event_template(&templates.feed_interrupt, source, target, &[("ability", ability.clone())]),
+            FeedImportance::Minor,
+        ),
+        StructuredEventData::Dispel { source, target, aura, removed_crowd_control: true } => (
+            fill_event_template(&templates.feed_dispel, source, target, &[("aura", aura.clone())]),
+            FeedImportance::Minor,
+        ),
+        StructuredEventData::Damage { source, target, ability, amount, is_crit: true, .. }
+            if *amount >= BIG_CRIT_DAMAGE =>
+        {
+            (
+                fill_event_template(
+                    &templates.feed_big_crit,
+                    source,
+                    target,
+                    &[("ability", ability.clone()), ("amount", format!("{:.0}", amount))],
+                ),
+                FeedImportance::Minor,
+            )
+        }
+        StructuredEventData::Death { victim, killer } => (
+            match killer {
+                Some(killer) => fill_event_template(&templates.feed_kill, killer, victim, &[]),
+                None => fill_template(&templates.feed_death, &target_values(victim)),
+            },
+            FeedImportance::Major,
+        ),
+        _ => return None,
+    };
+    Some(line)
+}
+
+/// Class names of a team's filled slots, comma-separated.
+fn roster(team: &[Option<CharacterClass>]) -> String {
+    team.iter().flatten().map(|class| class.name()).collect::<Vec<_>>().join(", ")
+}
+
+/// Start each match with an empty feed that opens with both rosters.
+pub fn reset_event_feed(
+    mut feed: ResMut<EventFeed>,
+    templates: Res<CommentaryTemplates>,
+    config: Res<MatchConfig>,
+) {
+    *feed = EventFeed::default();
+    let line = fill_template(
+        &templates.feed_match_start,
+        &[("team1", roster(&config.team1)), ("team2", roster(&config.team2))],
+    );
+    feed.push(line, FeedImportance::Major);
+}
+
+/// Queue lines for this frame's events and show the next one when it's due.
+#[allow(clippy::too_many_arguments)]
+pub fn update_event_feed(
+    mut feed: ResMut<EventFeed>,
+    combat_log: Res<CombatLog>,
+    templates: Res<CommentaryTemplates>,
+    settings: Res<GameSettings>,
+    countdown: Option<Res<MatchCountdown>>,
+    celebration: Option<Res<VictoryCelebration>>,
+    time: Res<Time<Real>>,
+    combatants: Query<(Entity, &Combatant), Without<Pet>>,
+) {
+    // The log shrank under us (new match before the reset ran, or a rewound
+    // what-if branch): don't re-announce what's already in it
+    if feed.log_cursor > combat_log.entries.len() {
+        feed.log_cursor = combat_log.entries.len();
+    }
+    if !settings.event_feed.enabled {
+        feed.pending.clear();
+        feed.log_cursor = combat_log.entries.len();
+        return;
+    }
+
+    if !feed.gates_reported && countdown.is_some_and(|c| c.gates_opened) {
+        feed.gates_reported = true;
+        feed.push(templates.feed_gates_open.clone(), FeedImportance::Major);
+    }
+
+    let feed = &mut *feed;
+    for entry in &combat_log.entries[feed.log_cursor..] {
+        if let Some((text, importance)) = describe_event(entry, &templates) {
+            feed.push(text, importance);
+        }
+    }
+    feed.log_cursor = combat_log.entries.len();
+
+    for (entity, combatant) in combatants.iter() {
+        if !combatant.is_alive() {
+            continue;
+        }
+        let fraction = combatant.current_health / combatant.max_health.max(1.0);
+        if fraction < LOW_HEALTH_FRACTION {
+            if feed.low_health.insert(entity) {
+                let mut values: Vec<(&str, String)> =
+                    target_values(&combatant_id(combatant.team, combatant.class)).into_iter().collect();
+                values.push(("percent", format!("{:.0}", LOW_HEALTH_FRACTION * 100.0)));
+                feed.push(fill_template(&templates.feed_low_health, &values), FeedImportance::Major);
+            }
+        } else if fraction >= LOW_HEALTH_REARM_FRACTION {
+            feed.low_health.remove(&entity);
+        }
+    }
+
+    if let Some(celebration) = celebration.filter(|_| !feed.result_reported) {
+        feed.result_reported = true;
+        let line = match celebration.winner {
+            Some(winner) => fill_template(&templates.feed_victory, &[("winner", winner.to_string())]),
+            None => templates.feed_draw.clone(),
+        };
+        feed.push(line, FeedImportance::Major);
+    }
+
+    feed.advance(time.elapsed_secs(), settings.event_feed.seconds_per_line);
+}
+
+/// Event feed panel above the commentary ticker: opaque black with white
+/// text at the configured size, the newest line brightest.
+pub fn render_event_feed(mut contexts: EguiContexts, feed: Res<EventFeed>, settings: Res<GameSettings>) {
+    let settings = &settings.event_feed;
+    if !settings.enabled || feed.shown.is_empty() {
+        return;
+    }
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+    let max_width = ctx.screen_rect().width() * 0.7;
+
+    egui::Area::new(egui::Id::new("event_feed"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -150.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.set_max_width(max_width);
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(235))
+                .stroke(egui::Stroke::new(2.0, egui::Color32::WHITE))
+                .corner_radius(6.0)
+                .inner_margin(egui::Margin::symmetric(16, 10))
+                .show(ui, |ui| {
+                    let newest = feed.shown.len() - 1;
+                    for (i, line) in feed.shown.iter().enumerate() {
+                        let color = if i == newest {
+                            egui::Color32::WHITE
+                        } else {
+                            egui::Color32::from_gray(200)
+                        };
+                        ui.label(egui::RichText::new(line).size(settings.text_size).color(color));
+                    }
+                    if feed.skipped > 0 {
+                        ui.label(
+                            egui::RichText::new(format!("{} minor events skipped", feed.skipped))
+                                .size(settings.text_size * 0.6)
+                                .color(egui::Color32::from_gray(200)),
+                        );
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::log::CombatLogEventType;
+    use crate::states::play_match::load_commentary_templates;
+
+    fn entry(data: StructuredEventData) -> CombatLogEntry {
+        CombatLogEntry {
+            timestamp: 12.0,
+            event_type: CombatLogEventType::MatchEvent,
+            message: String::new(),
+            position_data: None,
+            structured_data: Some(data),
+        }
+    }
+
+    #[test]
+    fn describes_major_events_in_plain_sentences() {
+        let templates = load_commentary_templates().expect("commentary.ron must load");
+
+        let (line, importance) = describe_event(
+            &entry(StructuredEventData::CrowdControl {
+                source: "Team 2 Rogue".to_string(),
+                target: "Team 1 Priest".to_string(),
+                cc_type: "Stun".to_string(),
+                duration_secs: 4.0,
+            }),
+            &templates,
+        )
+        .unwrap();
+        assert_eq!(line, "Team 2 Rogue used Stun on Team 1 Priest for 4 seconds.");
+        assert_eq!(importance, FeedImportance::Minor);
+
+        let (line, importance) = describe_event(
+            &entry(StructuredEventData::Death { victim: "Team 1 Priest".to_string(), killer: None }),
+            &templates,
+        )
+        .unwrap();
+        assert_eq!(line, "Team 1 Priest died.");
+        assert_eq!(importance, FeedImportance::Major);
+
+        let small_hit = StructuredEventData::Damage {
+            source: "Team 2 Rogue".to_string(),
+            target: "Team 1 Priest".to_string(),
+            ability: "Sinister Strike".to_string(),
+            amount: 80.0,
+            is_killing_blow: false,
+            is_crit: true,
+            is_periodic: false,
+        };
+        assert!(describe_event(&entry(small_hit), &templates).is_none());
+    }
+
+    #[test]
+    fn feed_paces_lines_and_skips_minor_ones_first() {
+        let mut feed = EventFeed::default();
+        feed.push("death".to_string(), FeedImportance::Major);
+        for i in 0..FEED_MAX_PENDING {
+            feed.push(format!("cc {}", i), FeedImportance::Minor);
+        }
+        // One over the limit: the oldest minor line goes, the death stays
+        assert_eq!(feed.skipped, 1);
+
+        assert!(feed.advance(0.0, 3.0));
+        assert!(!feed.advance(2.0, 3.0));
+        assert!(feed.advance(3.0, 3.0));
+        assert_eq!(feed.shown, ["death", "cc 1"]);
+    }
+}
@@ -0,0 +1,280 @@
+//! Match Highlights
+//!
+//! Picks the moments worth re-watching out of a finished match's combat log,
+//! each as a short time window around the key event:
+//! - kills
+//! - crits of at least `BIG_CRIT_DAMAGE`
+//! - clutch interrupts: on a healer, or followed by a death on the
+//!   interrupted combatant's team within `CLUTCH_INTERRUPT_SECS`
+//! - clutch dispels: a friendly dispel that frees a teammate from crowd control
+//! - CC chains: `CC_CHAIN_MIN` or more crowd controls on one target, each
+//!   landing within `CC_CHAIN_GAP_SECS` of the previous one's (nominal) end
+//!
+//! Used by the Results screen (highlight chips that jump the timeline
+//! scrubber) and by headless mode (`*.highlights.json` next to the match log).
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::combat::log::{CombatLog, CombatLogEventType, CombatantId, StructuredEventData};
+use super::camera::BIG_CRIT_DAMAGE;
+use super::utils::{class_from_combatant_id, team_from_combatant_id};
+
+/// Seconds of lead-in before a highlight's key event
+const HIGHLIGHT_LEAD_SECS: f32 = 3.0;
+/// Seconds of follow-through after a highlight's key event
+const HIGHLIGHT_TAIL_SECS: f32 = 1.0;
+/// An interrupt followed by a death on the target's team within this many
+/// seconds counts as clutch
+const CLUTCH_INTERRUPT_SECS: f32 = 8.0;
+/// Maximum gap between one crowd control ending and the next landing for the
+/// two to count as one chain
+const CC_CHAIN_GAP_SECS: f32 = 0.5;
+/// Crowd controls needed for a chain to be a highlight
+const CC_CHAIN_MIN: usize = 3;
+
+/// What made a moment a highlight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HighlightKind {
+    Kill,
+    BigCrit,
+    ClutchInterrupt,
+    ClutchDispel,
+    CcChain,
+}
+
+impl HighlightKind {
+    /// Short label for the Results screen chips
+    pub fn label(self) -> &'static str {
+        match self {
+            HighlightKind::Kill => "Kill",
+            HighlightKind::BigCrit => "Big crit",
+            HighlightKind::ClutchInterrupt => "Interrupt",
+            HighlightKind::ClutchDispel => "Dispel",
+            HighlightKind::CcChain => "CC chain",
+        }
+    }
+}
+
+/// One highlight window. Times are match seconds.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Highlight {
+    pub kind: HighlightKind,
+    /// When the key event happened
+    pub time: f32,
+    /// Window start (clamped to the start of the match)
+    pub start: f32,
+    /// Window end
+    pub end: f32,
+    /// One-line description, e.g. "Team 1 Rogue kills Team 2 Priest"
+    pub description: String,
+}
+
+impl Highlight {
+    fn around(kind: HighlightKind, time: f32, description: String) -> Self {
+        Highlight {
+            kind,
+            time,
+            start: (time - HIGHLIGHT_LEAD_SECS).max(0.0),
+            end: time + HIGHLIGHT_TAIL_SECS,
+            description,
+        }
+    }
+}
+
+/// A crowd control chain being built up on one target.
+struct CcChain {
+    start: f32,
+    end: f32,
+    links: Vec<String>,
+}
+
+impl CcChain {
+    fn into_highlight(self, target: &str) -> Option<Highlight> {
+        (self.links.len() >= CC_CHAIN_MIN).then(|| Highlight {
+            kind: HighlightKind::CcChain,
+            time: self.start,
+            start: (self.start - HIGHLIGHT_LEAD_SECS).max(0.0),
+            end: self.end,
+            description: format!(
+                "{} chain-CC'd for {:.1}s: {}",
+                target,
+                self.end - self.start,
+                self.links.join(" > ")
+            ),
+        })
+    }
+}
+
+/// Find every highlight in the log, ordered by time.
+pub fn find_highlights(log: &CombatLog) -> Vec<Highlight> {
+    let mut highlights = Vec::new();
+    let mut chains: HashMap<CombatantId, CcChain> = HashMap::new();
+
+    // Death times per team, for judging interrupts
+    let deaths: Vec<(f32, Option<u8>)> = log
+        .query()
+        .event_type(CombatLogEventType::Death)
+        .entries()
+        .filter_map(|entry| match &entry.structured_data {
+            Some(StructuredEventData::Death { victim, .. }) => {
+                Some((entry.timestamp, team_from_combatant_id(victim)))
+            }
+            _ => None,
+        })
+        .collect();
+
+    for entry in &log.entries {
+        let time = entry.timestamp;
+        match &entry.structured_data {
+            Some(StructuredEventData::Death { victim, killer }) => {
+                let description = match killer {
+                    Some(killer) => format!("{} kills {}", killer, victim),
+                    None => format!("{} dies", victim),
+                };
+                highlights.push(Highlight::around(HighlightKind::Kill, time, description));
+            }
+            Some(StructuredEventData::Damage { source, target, ability, amount, is_crit: true, .. })
+                if *amount >= BIG_CRIT_DAMAGE =>
+            {
+                highlights.push(Highlight::around(
+                    HighlightKind::BigCrit,
+                    time,
+                    format!("{} crits {} with {} for {:.0}", source, target, ability, amount),
+                ));
+            }
+            Some(StructuredEventData::Interrupt { source, target, ability }) => {
+                let on_healer = class_from_combatant_id(target).is_some_and(|c| c.is_healer());
+                let target_team = team_from_combatant_id(target);
+                let leads_to_kill = deaths.iter().any(|(death_time, team)| {
+                    *team == target_team && *death_time >= time && *death_time - time <= CLUTCH_INTERRUPT_SECS
+                });
+                if on_healer || leads_to_kill {
+                    highlights.push(Highlight::around(
+                        HighlightKind::ClutchInterrupt,
+                        time,
+                        format!("{} interrupts {}'s {}", source, target, ability),
+                    ));
+                }
+            }
+            Some(StructuredEventData::Dispel { source, target, aura, removed_crowd_control: true })
+                if team_from_combatant_id(source) == team_from_combatant_id(target) =>
+            {
+                highlights.push(Highlight::around(
+                    HighlightKind::ClutchDispel,
+                    time,
+                    format!("{} frees {} from {}", source, target, aura),
+                ));
+            }
+            Some(StructuredEventData::CrowdControl { target, cc_type, duration_secs, .. }) => {
+                let cc_end = time + duration_secs;
+                match chains.get_mut(target) {
+                    Some(chain) if time <= chain.end + CC_CHAIN_GAP_SECS => {
+                        chain.end = chain.end.max(cc_end);
+                        chain.links.push(cc_type.clone());
+                    }
+                    _ => {
+                        let chain = CcChain { start: time, end: cc_end, links: vec![cc_type.clone()] };
+                        if let Some(finished) = chains.insert(target.clone(), chain) {
+                            highlights.extend(finished.into_highlight(target));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    highlights.extend(chains.into_iter().filter_map(|(target, chain)| chain.into_highlight(&target)));
+    highlights.sort_by(|a, b| a.time.total_cmp(&b.time).then_with(|| a.description.cmp(&b.description)));
+    highlights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROGUE: &str = "Team 1 Rogue";
+    const MAGE: &str = "Team 1 Mage";
+    const PRIEST: &str = "Team 2 Priest";
+    const WARLOCK: &str = "Team 2 Warlock";
+
+    fn kinds(log: &CombatLog) -> Vec<(HighlightKind, f32)> {
+        find_highlights(log).iter().map(|h| (h.kind, h.time)).collect()
+    }
+
+    fn cc(log: &mut CombatLog, at: f32, target: &str, cc: &str, duration: f32) {
+        log.match_time = at;
+        log.log_crowd_control(ROGUE.into(), target.into(), cc.into(), duration, String::new());
+    }
+
+    #[test]
+    fn kills_and_big_crits_are_highlights() {
+        let mut log = CombatLog::default();
+        log.match_time = 2.0;
+        log.log_damage(MAGE.into(), WARLOCK.into(), "Frostbolt".into(), BIG_CRIT_DAMAGE - 1.0, false, true, String::new());
+        log.match_time = 5.0;
+        log.log_damage(MAGE.into(), WARLOCK.into(), "Frostbolt".into(), BIG_CRIT_DAMAGE, true, true, String::new());
+        log.log_death(WARLOCK.into(), Some(MAGE.into()), String::new());
+
+        let highlights = find_highlights(&log);
+        assert_eq!(kinds(&log), vec![(HighlightKind::BigCrit, 5.0), (HighlightKind::Kill, 5.0)]);
+        let kill = &highlights[1];
+        assert_eq!((kill.start, kill.end), (2.0, 6.0));
+        assert_eq!(kill.description, "Team 1 Mage kills Team 2 Warlock");
+    }
+
+    #[test]
+    fn only_clutch_interrupts_and_dispels_are_highlights() {
+        let mut log = CombatLog::default();
+        log.match_time = 1.0;
+        // On a healer: clutch
+        log.log_interrupt(ROGUE.into(), PRIEST.into(), "Flash Heal".into(), String::new());
+        // On a DPS with no kill following: not
+        log.match_time = 2.0;
+        log.log_interrupt(ROGUE.into(), WARLOCK.into(), "Shadow Bolt".into(), String::new());
+        // Enemy purge, and a friendly dispel of a slow: not
+        log.log_dispel(PRIEST.into(), MAGE.into(), "Ice Barrier".into(), false, String::new());
+        log.log_dispel(PRIEST.into(), WARLOCK.into(), "Crippling Poison".into(), false, String::new());
+        // Friendly dispel of crowd control: clutch
+        log.match_time = 4.0;
+        log.log_dispel(PRIEST.into(), WARLOCK.into(), "Polymorph".into(), true, String::new());
+        // On a DPS that dies shortly after: clutch
+        log.match_time = 10.0;
+        log.log_interrupt(MAGE.into(), WARLOCK.into(), "Fear".into(), String::new());
+        log.match_time = 15.0;
+        log.log_death(WARLOCK.into(), Some(ROGUE.into()), String::new());
+
+        assert_eq!(
+            kinds(&log),
+            vec![
+                (HighlightKind::ClutchInterrupt, 1.0),
+                (HighlightKind::ClutchDispel, 4.0),
+                (HighlightKind::ClutchInterrupt, 10.0),
+                (HighlightKind::Kill, 15.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn three_back_to_back_crowd_controls_make_a_chain() {
+        let mut log = CombatLog::default();
+        // Chain on the Priest: each lands as (or just after) the previous ends
+        cc(&mut log, 1.0, PRIEST, "Cheap Shot", 4.0);
+        cc(&mut log, 5.3, PRIEST, "Kidney Shot", 5.0);
+        cc(&mut log, 10.0, PRIEST, "Blind", 8.0);
+        // Two-link chain on the Warlock, then a gap too long to continue it
+        cc(&mut log, 1.0, WARLOCK, "Cheap Shot", 4.0);
+        cc(&mut log, 5.0, WARLOCK, "Gouge", 4.0);
+        cc(&mut log, 12.0, WARLOCK, "Blind", 8.0);
+
+        let highlights = find_highlights(&log);
+        assert_eq!(highlights.len(), 1);
+        let chain = &highlights[0];
+        assert_eq!(chain.kind, HighlightKind::CcChain);
+        assert_eq!((chain.time, chain.end), (1.0, 18.0));
+        assert_eq!(chain.description, "Team 2 Priest chain-CC'd for 17.0s: Cheap Shot > Kidney Shot > Blind");
+    }
+}
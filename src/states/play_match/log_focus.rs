@@ -0,0 +1,210 @@
+//! Click a combat log line to jump the camera to it.
+//!
+//! Clicking a line in the Combat Log tab records its participants in
+//! [`CombatLogFocus`]. [`apply_combat_log_focus`] then:
+//! - points the camera at the event: the recorded position when the entry has
+//!   one, otherwise it follows the line's main participant (the victim of a
+//!   death, the caster of a cast, the target of anything else)
+//! - puts a brief white ring ([`LogFocusRing`]) under every participant still
+//!   in the match
+//!
+//! Lines without structured data (match start, countdown messages) aren't
+//! clickable. Works the same while paused and inside a what-if branch.
+//!
+//! All systems here are graphical-only. Registration lives in
+//! `src/states/mod.rs` (`StatesPlugin::build()`).
+
+use bevy::color::LinearRgba;
+use bevy::prelude::*;
+
+use crate::combat::log::{CombatLogEntry, CombatantId, StructuredEventData};
+use super::components::{CameraController, CameraMode, Combatant, LogFocusRing, Pet, PlayMatchEntity, WalkAnim};
+use super::utils::combatant_id;
+
+/// How long the participant rings stay up, in real seconds
+const LOG_FOCUS_HIGHLIGHT_SECS: f32 = 2.5;
+/// Ring torus radii — a touch wider than the selection ring so both show
+const RING_INNER_RADIUS: f32 = 0.9;
+const RING_OUTER_RADIUS: f32 = 1.1;
+/// Same floor offset as the selection ring.
+const RING_GROUND_OFFSET_Y: f32 = -0.95 + 0.05;
+
+type RingTargetFilter = (With<Combatant>, Without<LogFocusRing>);
+
+/// A clicked combat log line waiting to be applied.
+#[derive(Resource, Default)]
+pub struct CombatLogFocus {
+    pub pending: Option<LogFocusTarget>,
+}
+
+/// What a combat log line points at.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogFocusTarget {
+    /// Participants, main one first
+    pub participants: Vec<CombatantId>,
+    /// Where the event happened, when the entry recorded it
+    pub position: Option<Vec3>,
+}
+
+impl LogFocusTarget {
+    /// The focus for a log entry, or `None` if it names no combatant.
+    pub fn from_entry(entry: &CombatLogEntry) -> Option<Self> {
+        let data = entry.structured_data.as_ref()?;
+        let main = match data {
+            StructuredEventData::Death { victim, .. } => victim,
+            StructuredEventData::AbilityCast { caster, .. } => caster,
+            StructuredEventData::Damage { target, .. }
+            | StructuredEventData::Healing { target, .. }
+            | StructuredEventData::CrowdControl { target, .. }
+            | StructuredEventData::Interrupt { target, .. }
+            | StructuredEventData::Dispel { target, .. } => target,
+        };
+        let mut participants = vec![main.clone()];
+        for id in data.participants() {
+            if !participants.contains(id) {
+                participants.push(id.clone());
+            }
+        }
+
+        let position = entry.position_data.as_ref().and_then(|data| {
+            let count = data.positions.len();
+            (count > 0).then(|| {
+                data.positions.iter().map(|&(x, y, z)| Vec3::new(x, y, z)).sum::<Vec3>() / count as f32
+            })
+        });
+
+        Some(Self { participants, position })
+    }
+}
+
+/// Moves the camera to a clicked log line and rings its participants.
+pub fn apply_combat_log_focus(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut focus: ResMut<CombatLogFocus>,
+    mut camera_controller: ResMut<CameraController>,
+    combatants: Query<(Entity, &Combatant, Option<&Pet>)>,
+    existing_rings: Query<Entity, With<LogFocusRing>>,
+) {
+    let Some(target) = focus.pending.take() else {
+        return;
+    };
+
+    let find = |id: &CombatantId| {
+        combatants.iter().find_map(|(entity, combatant, pet)| {
+            let entity_id = match pet {
+                Some(pet) => format!("Team {} {}", combatant.team, pet.pet_type.name()),
+                None => combatant_id(combatant.team, combatant.class),
+            };
+            (&entity_id == id).then_some(entity)
+        })
+    };
+    let entities: Vec<Entity> = target.participants.iter().filter_map(find).collect();
+
+    if let Some(position) = target.position {
+        camera_controller.mode = CameraMode::Manual;
+        camera_controller.manual_target = position;
+    } else if let Some(&main) = entities.first() {
+        camera_controller.mode = CameraMode::FollowCombatant(main);
+    }
+
+    for entity in existing_rings.iter() {
+        commands.entity(entity).despawn();
+    }
+    if entities.is_empty() {
+        return;
+    }
+    let mesh = meshes.add(Torus::new(RING_INNER_RADIUS, RING_OUTER_RADIUS));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 1.0, 1.0, 0.7),
+        emissive: LinearRgba::new(1.0, 1.0, 1.0, 1.0),
+        alpha_mode: AlphaMode::Add,
+        unlit: true,
+        ..default()
+    });
+    for entity in entities {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::default(),
+            LogFocusRing { target: entity, remaining: LOG_FOCUS_HIGHLIGHT_SECS },
+            PlayMatchEntity,
+        ));
+    }
+}
+
+/// Keeps each ring under its participant, shrinking it away as it runs out.
+/// Counts real time so the highlight still fades while paused.
+pub fn update_log_focus_rings(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    combatants: Query<(&Transform, Option<&WalkAnim>), RingTargetFilter>,
+    mut rings: Query<(Entity, &mut LogFocusRing, &mut Transform), Without<Combatant>>,
+) {
+    for (entity, mut ring, mut transform) in rings.iter_mut() {
+        ring.remaining -= time.delta_secs();
+        let Ok((target_transform, walk)) = combatants.get(ring.target) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if ring.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let ground_y = walk.map(|w| w.ground_y).unwrap_or(target_transform.translation.y);
+        transform.translation = Vec3::new(
+            target_transform.translation.x,
+            ground_y + RING_GROUND_OFFSET_Y,
+            target_transform.translation.z,
+        );
+        // Full size for most of its life, shrinking away over the last half second
+        transform.scale = Vec3::splat((ring.remaining / 0.5).min(1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::log::{CombatLogEventType, PositionData};
+
+    fn entry(data: StructuredEventData) -> CombatLogEntry {
+        CombatLogEntry {
+            timestamp: 12.0,
+            event_type: CombatLogEventType::Damage,
+            message: String::new(),
+            position_data: None,
+            structured_data: Some(data),
+        }
+    }
+
+    #[test]
+    fn death_focuses_the_victim_then_the_killer() {
+        let target = LogFocusTarget::from_entry(&entry(StructuredEventData::Death {
+            victim: "Team 2 Priest".to_string(),
+            killer: Some("Team 1 Rogue".to_string()),
+        }))
+        .unwrap();
+        assert_eq!(target.participants, vec!["Team 2 Priest".to_string(), "Team 1 Rogue".to_string()]);
+        assert_eq!(target.position, None);
+    }
+
+    #[test]
+    fn recorded_positions_are_averaged() {
+        let mut entry = entry(StructuredEventData::Death { victim: "Team 2 Mage".to_string(), killer: None });
+        entry.position_data = Some(PositionData {
+            entities: vec![],
+            positions: vec![(0.0, 1.0, 0.0), (4.0, 1.0, -2.0)],
+            distance: None,
+        });
+        let target = LogFocusTarget::from_entry(&entry).unwrap();
+        assert_eq!(target.position, Some(Vec3::new(2.0, 1.0, -1.0)));
+    }
+
+    #[test]
+    fn lines_without_structured_data_have_no_focus() {
+        let mut entry = entry(StructuredEventData::Death { victim: "Team 2 Mage".to_string(), killer: None });
+        entry.structured_data = None;
+        assert_eq!(LogFocusTarget::from_entry(&entry), None);
+    }
+}
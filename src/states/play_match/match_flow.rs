@@ -7,10 +7,11 @@
 //! - Victory celebration and transition to Results
 
 use bevy::prelude::*;
-use crate::combat::log::{CombatLog, CombatLogEventType, MatchMetadata, CombatantMetadata};
+use crate::combat::log::{CombatLog, CombatLogEventType, MatchReport, CombatantMetadata};
 use crate::states::GameState;
 use super::match_config::MatchConfig;
 use super::components::*;
+use super::branch::MatchBranch;
 
 /// Update the pre-combat countdown timer.
 /// 
@@ -158,6 +159,7 @@ pub fn check_match_end(
     config: Res<MatchConfig>,
     combat_log: Res<CombatLog>,
     celebration: Option<Res<VictoryCelebration>>,
+    branch: Res<MatchBranch>,
     projectiles: Query<Entity, With<Projectile>>,
     spell_effects: Query<Entity, With<SpellImpactEffect>>,
     traps: Query<Entity, With<Trap>>,
@@ -283,24 +285,26 @@ pub fn check_match_end(
             commands.entity(ice_entity).despawn();
         }
 
-        // Save combat log to file for debugging.
-        // Graphical mode currently runs unseeded (no CLI flag exposes a seed
-        // here); headless mode writes its own MatchMetadata in runner.rs with
-        // the actual seed.
-        let match_metadata = MatchMetadata {
+        // Save combat log to file for debugging. The log's MatchMetadata was
+        // captured in setup_play_match (graphical mode runs unseeded).
+        // A what-if branch's ending never happened, so it isn't saved.
+        let report = MatchReport {
             arena_name: config.map.name().to_string(),
             winner,
-            random_seed: None,
             team1: team1_metadata,
             team2: team2_metadata,
         };
         
-        match combat_log.save_to_file(&match_metadata, None) {
-            Ok(filename) => {
-                info!("Combat log saved to: {}", filename);
-            }
-            Err(e) => {
-                error!("Failed to save combat log: {}", e);
+        if branch.is_active() {
+            info!("What-if branch ended the match - combat log not saved");
+        } else {
+            match combat_log.save_to_file(&report, None) {
+                Ok(filename) => {
+                    info!("Combat log saved to: {}", filename);
+                }
+                Err(e) => {
+                    error!("Failed to save combat log: {}", e);
+                }
             }
         }
         
@@ -330,9 +334,13 @@ pub fn check_match_end(
 /// When timer reaches 0:
 /// - Store match results for Results scene
 /// - Transition to Results state
+///
+/// In a what-if branch the celebration keeps going until the viewer returns
+/// to the fork point.
 pub fn update_victory_celebration(
     time: Res<Time>,
     celebration: Option<ResMut<VictoryCelebration>>,
+    branch: Res<MatchBranch>,
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
     mut celebrating_combatants: Query<(&mut Transform, &Celebrating)>,
@@ -356,7 +364,7 @@ pub fn update_victory_celebration(
     }
     
     // Check if celebration finished
-    if celebration.time_remaining <= 0.0 {
+    if celebration.time_remaining <= 0.0 && !branch.is_active() {
         // Store match results for Results scene
         commands.insert_resource(celebration.match_results.clone());
         
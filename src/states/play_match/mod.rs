@@ -36,6 +36,7 @@ pub mod movement_config;
 pub mod equipment;
 pub mod components;
 pub mod camera;
+pub mod camera_path;
 pub mod projectiles;
 pub mod rendering;
 pub mod auras;
@@ -52,6 +53,20 @@ pub mod class_ai;
 pub mod constants;
 pub mod decision_trace;
 pub mod selection;
+pub mod aura_source;
+pub mod log_focus;
+pub mod sandbox;
+pub mod branch;
+pub mod restart;
+pub mod effect_pool;
+pub mod commentary;
+#[cfg(feature = "tts")]
+pub mod commentary_speech;
+pub mod event_feed;
+pub mod win_probability;
+pub mod highlights;
+pub mod arena_dressing;
+pub mod arena_lighting;
 
 // Re-exports
 pub use abilities::*;
@@ -76,6 +91,36 @@ pub use selection::{
     pick_selected_combatant, sync_selection_ring, follow_selection_ring,
     reset_selection_on_exit, Selection,
 };
+pub use effect_pool::{EffectCommandsExt, EffectPool, Pooled};
+pub use commentary::{
+    load_commentary_templates, match_summary, render_commentary_ticker, reset_commentary_feed,
+    update_commentary_feed, CommentaryFeed, CommentaryTemplates,
+};
+#[cfg(feature = "tts")]
+pub use commentary_speech::{speak_commentary, stop_commentary_speech, CommentarySpeech};
+pub use event_feed::{render_event_feed, reset_event_feed, update_event_feed, EventFeed};
+pub use win_probability::{render_win_odds_bar, reset_win_probability, update_win_probability, WinProbability};
+pub use arena_dressing::{ArenaDressing, ArenaProp};
+pub use highlights::{find_highlights, Highlight, HighlightKind};
+pub use sandbox::{
+    close_sandbox_console, dispatch_sandbox_events, process_sandbox_effects, render_sandbox_console,
+    SandboxConsole, SandboxEvent, SandboxQueue,
+};
+pub use aura_source::{
+    follow_aura_source_highlight, sync_aura_source_highlight, AuraSourceHighlight, HoveredAura,
+};
+pub use camera_path::{
+    apply_camera_path, finish_camera_path_recording, handle_camera_path_hotkeys, record_camera_path,
+    render_camera_path_status, start_camera_path, CameraKeyframe, CameraPath, CameraPathPlayer,
+};
+pub use log_focus::{apply_combat_log_focus, update_log_focus_rings, CombatLogFocus, LogFocusTarget};
+pub use branch::{
+    discard_match_branch, fork_match_branch, handle_branch_hotkey, render_branch_banner,
+    return_from_branch, MatchBranch,
+};
+pub use restart::{
+    handle_restart_hotkeys, render_pause_menu, request_match_restart, resume_restarted_match, MatchRestart,
+};
 
 use bevy::prelude::*;
 use bevy::core_pipeline::bloom::Bloom;
@@ -88,6 +133,7 @@ use bevy::render::render_asset::RenderAssetUsages;
 use super::match_config::{self, MatchConfig};
 use super::GameState;
 use crate::combat::log::{CombatLog, CombatLogEventType};
+use crate::combat::MatchMetadata;
 use equipment::{ItemDefinitions, DefaultLoadouts, ItemSlot, ItemId, resolve_loadout, enforce_two_hand_conflicts, format_loadout};
 
 // ============================================================================
@@ -257,25 +303,47 @@ pub fn setup_play_match(
     game_settings: Res<crate::settings::GameSettings>,
     item_defs: Res<ItemDefinitions>,
     default_loadouts: Res<DefaultLoadouts>,
+    restart: Option<Res<MatchRestart>>,
 ) {
     info!("Setting up Play Match scene with config: {:?}", *config);
 
+    // Every match is seeded so it can be restarted from the same seed.
+    // A same-seed restart carries the seed over; anything else rolls a new one.
+    let match_seed = restart.and_then(|r| r.seed).unwrap_or_else(rand::random);
+    commands.remove_resource::<MatchRestart>();
+    info!("Match seed: {}", match_seed);
+
     // Clear combat log for new match
     combat_log.clear();
+    combat_log.metadata = Some(MatchMetadata::capture(config.fingerprint(), Some(match_seed)));
     combat_log.log(CombatLogEventType::MatchEvent, "Match started!".to_string());
 
     // Initialize combat panel view (for tabbed Combat Log / Timeline UI)
     commands.insert_resource(CombatPanelView::default());
 
+    // Nothing hovered yet (see aura_source)
+    commands.insert_resource(AuraSourceHighlight::default());
+    commands.insert_resource(CombatLogFocus::default());
+
     // Initialize spell icons resources (for ability timeline)
     commands.insert_resource(SpellIcons::default());
     commands.insert_resource(SpellIconHandles::default());
 
+    // Lighting/weather preset: the map's default unless the config overrides
+    // it; the plain default when presets are turned off for performance
+    let lighting = if game_settings.ignore_lighting_presets {
+        match_config::ArenaLighting::Day
+    } else {
+        config.lighting.unwrap_or_else(|| config.map.default_lighting())
+    };
+    let preset = arena_lighting::lighting_preset(lighting);
+    info!("Arena lighting: {}", lighting.name());
+
     // Spawn 3D camera with isometric-ish view.
     // HDR + tonemapping + bloom let the pre-scaled emissive effects (shields,
     // heal columns, traps, drain beams — all authored at 2-4x) actually glow
     // instead of clipping to flat white.
-    commands.spawn((
+    let camera = commands.spawn((
         Camera3d::default(),
         Camera {
             hdr: true,
@@ -286,15 +354,18 @@ pub fn setup_play_match(
         Transform::from_xyz(0.0, 40.0, 50.0).looking_at(Vec3::ZERO, Vec3::Y),
         ArenaCamera,
         PlayMatchEntity,
-    ));
+    )).id();
+    if let Some(fog) = &preset.fog {
+        commands.entity(camera).insert(arena_lighting::distance_fog(fog));
+    }
 
-    // Add directional light (sun-like) - warm golden sunlight.
+    // Add directional light (sun-like), colored by the preset.
     // Shadows grounded to the ~76-unit arena via a 2-cascade config so units
     // cast contact shadows that anchor them to the floor.
     commands.spawn((
         DirectionalLight {
-            illuminance: 25000.0,
-            color: Color::srgb(1.0, 0.95, 0.85), // Warm golden sunlight
+            illuminance: preset.sun_illuminance,
+            color: preset.sun_color,
             shadows_enabled: true,
             ..default()
         },
@@ -304,41 +375,50 @@ pub fn setup_play_match(
             ..default()
         }
         .build(),
-        Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+        Transform::from_translation(preset.sun_position).looking_at(Vec3::ZERO, Vec3::Y),
         PlayMatchEntity,
     ));
 
-    // Add ambient light for overall scene brightness - warm atmospheric glow.
-    // Kept low so the directional light + shadows carry the contrast and the
-    // emissive effects pop under bloom (was 400.0, which flattened everything).
+    // Ambient light for overall scene brightness
     commands.insert_resource(AmbientLight {
-        color: Color::srgb(0.9, 0.85, 0.7), // Warm peachy ambient light
-        brightness: 250.0,
+        color: preset.ambient_color,
+        brightness: preset.ambient_brightness,
         affects_lightmapped_meshes: true,
     });
 
-    // Deep cool background so the warm sandy arena reads against a cohesive
-    // backdrop instead of Bevy's default flat gray.
-    commands.insert_resource(ClearColor(Color::srgb(0.05, 0.06, 0.09)));
+    // Backdrop behind the arena
+    commands.insert_resource(ClearColor(preset.clear_color));
+
+    if preset.torches {
+        arena_lighting::spawn_torches(&mut commands, &mut meshes, &mut materials);
+    }
     
     // Initialize simulation speed control
     commands.insert_resource(SimulationSpeed { multiplier: 1.0 });
     
-    // Initialize camera controller
-    commands.insert_resource(CameraController::default());
+    // Initialize camera controller, zoomed out further for 4v4/5v5
+    commands.insert_resource(CameraController::for_team_size(config.team1_size.max(config.team2_size)));
     
-    // Initialize match countdown (10 seconds before gates open)
-    commands.insert_resource(MatchCountdown::default());
+    // Initialize match countdown (10 seconds before gates open, 5 in a duel)
+    commands.insert_resource(MatchCountdown {
+        time_remaining: config.mode.countdown_secs(),
+        ..default()
+    });
 
     // Initialize Shadow Sight state (for stealth stalemate breaking)
     commands.insert_resource(ShadowSightState::default());
 
-    // Initialize random number generator (non-deterministic for graphical mode)
-    commands.insert_resource(GameRng::default());
+    // Initialize random number generator (frame timing still varies in graphical mode)
+    commands.insert_resource(GameRng::from_seed(match_seed));
+
+    // Recycles floating text, particle and impact entities (see effect_pool)
+    commands.insert_resource(EffectPool::default());
 
     // Initialize display settings from game settings
     commands.insert_resource(DisplaySettings {
         show_aura_icons: game_settings.show_aura_icons,
+        merge_periodic_ticks: game_settings.merge_periodic_ticks,
+        show_win_odds: game_settings.show_win_odds,
     });
 
     // Spawn arena floor - octagonal shape matching the wall boundary
@@ -347,12 +427,20 @@ pub fn setup_play_match(
     let arena_width = ARENA_FLOOR_HALF_Z * 2.0;
     let corner_cut = ARENA_FLOOR_CORNER_CUT;
     
+    // Seeded cosmetic dressing (floor tint, banners, props); none in plain mode
+    let dressing = (!game_settings.plain_arena).then(|| {
+        let seed = config.arena_seed.unwrap_or(match_seed);
+        info!("Arena dressing seed: {}", seed);
+        ArenaDressing::generate(seed)
+    });
+
     // Create custom octagonal mesh. UV scale tiles the procedural dirt texture
     // ~every 12 world units (square texels), giving the floor grain/variation
     // without an external asset.
     let octagon_mesh = create_octagon_mesh(arena_length, arena_width, corner_cut, 1.0 / 12.0);
     // Sandy dirt: blotches + grain, no courses.
-    let floor_texture = images.add(create_surface_texture([0.79, 0.66, 0.46], 0.12, 0.06, 0));
+    let floor_color = dressing.as_ref().map_or(arena_dressing::BASE_FLOOR_COLOR, |d| d.floor_color);
+    let floor_texture = images.add(create_surface_texture(floor_color, 0.12, 0.06, 0));
 
     commands.spawn((
         Mesh3d(meshes.add(octagon_mesh)),
@@ -480,14 +568,29 @@ pub fn setup_play_match(
         PlayMatchEntity,
     ));
 
-    // Count class occurrences per team to apply darkening to duplicates
-    use std::collections::HashMap;
-    let mut team1_class_counts: HashMap<match_config::CharacterClass, usize> = HashMap::new();
-    let mut team2_class_counts: HashMap<match_config::CharacterClass, usize> = HashMap::new();
+    if let Some(dressing) = &dressing {
+        let team_colors = crate::settings::TeamColors::from_settings(&game_settings.team_colors);
+        arena_dressing::spawn_arena_dressing(
+            &mut commands, &mut meshes, &mut materials, dressing, &team_colors, wall_height, wall_thickness,
+        );
+    }
+
+    // Circular maps (Duel Ring) get their own ring wall inside the arena
+    if let Some(radius) = config.map.ring_radius() {
+        spawn_duel_ring(&mut commands, &mut meshes, &mut materials, radius, wall_texture);
+    }
+
+    // Count class occurrences per team to apply darkening to duplicates.
+    // BTreeMap like every other sim-side map, so any future iteration over
+    // the counts stays in a fixed order.
+    use std::collections::BTreeMap;
+    let mut team1_class_counts: BTreeMap<match_config::CharacterClass, usize> = BTreeMap::new();
+    let mut team2_class_counts: BTreeMap<match_config::CharacterClass, usize> = BTreeMap::new();
 
     // Spawn Team 1 combatants (left side of arena, in starting pen)
-    // Teams start further back (-35/+35) and will move forward when gates open
-    let team1_spawn_x = -35.0;
+    // Teams start further back (-35/+35, or just inside the Duel Ring) and
+    // will move forward when gates open
+    let team1_spawn_x = -config.map.spawn_x();
     for (i, character_opt) in config.team1.iter().enumerate() {
         if let Some(character) = character_opt {
             let count = *team1_class_counts.get(character).unwrap_or(&0);
@@ -507,13 +610,14 @@ pub fn setup_play_match(
             let warrior_shout = config.team1_warrior_shouts.get(i).copied().unwrap_or_default();
             let mage_armor = config.team1_mage_armors.get(i).copied().unwrap_or_default();
             let paladin_aura = config.team1_paladin_auras.get(i).copied().unwrap_or_default();
+            let personality = config.team1_personalities.get(i).copied().unwrap_or_default();
 
             // Resolve equipment loadout (defaults + overrides), enforcing 2H constraints
             let equipment_overrides = config.team1_equipment.get(i).cloned().unwrap_or_default();
             let mut loadout = resolve_loadout(*character, &default_loadouts, &equipment_overrides);
             enforce_two_hand_conflicts(&mut loadout, &item_defs);
 
-            let position = Vec3::new(team1_spawn_x, 1.0, (i as f32 - 1.0) * 3.0);
+            let position = Vec3::new(team1_spawn_x, 1.0, formation_z(i, config.team1.len()));
             let (entity, combatant) = spawn_combatant(
                 &mut commands,
                 &mut meshes,
@@ -529,6 +633,7 @@ pub fn setup_play_match(
                 warrior_shout,
                 mage_armor,
                 paladin_aura,
+                personality,
                 &loadout,
                 &item_defs,
             );
@@ -578,7 +683,7 @@ pub fn setup_play_match(
     }
 
     // Spawn Team 2 combatants (right side of arena, in starting pen)
-    let team2_spawn_x = 35.0;
+    let team2_spawn_x = config.map.spawn_x();
     for (i, character_opt) in config.team2.iter().enumerate() {
         if let Some(character) = character_opt {
             let count = *team2_class_counts.get(character).unwrap_or(&0);
@@ -598,13 +703,14 @@ pub fn setup_play_match(
             let warrior_shout = config.team2_warrior_shouts.get(i).copied().unwrap_or_default();
             let mage_armor = config.team2_mage_armors.get(i).copied().unwrap_or_default();
             let paladin_aura = config.team2_paladin_auras.get(i).copied().unwrap_or_default();
+            let personality = config.team2_personalities.get(i).copied().unwrap_or_default();
 
             // Resolve equipment loadout (defaults + overrides), enforcing 2H constraints
             let equipment_overrides = config.team2_equipment.get(i).cloned().unwrap_or_default();
             let mut loadout = resolve_loadout(*character, &default_loadouts, &equipment_overrides);
             enforce_two_hand_conflicts(&mut loadout, &item_defs);
 
-            let position = Vec3::new(team2_spawn_x, 1.0, (i as f32 - 1.0) * 3.0);
+            let position = Vec3::new(team2_spawn_x, 1.0, formation_z(i, config.team2.len()));
             let (entity, combatant) = spawn_combatant(
                 &mut commands,
                 &mut meshes,
@@ -620,6 +726,7 @@ pub fn setup_play_match(
                 warrior_shout,
                 mage_armor,
                 paladin_aura,
+                personality,
                 &loadout,
                 &item_defs,
             );
@@ -725,6 +832,54 @@ fn spawn_gate_bars(
     }
 }
 
+/// Spawn the Duel Ring: a slightly darker packed-earth disc and a low ring of
+/// stone wall segments just outside the gameplay radius (`DUEL_RING_RADIUS`).
+/// The octagonal arena stays around it as scenery.
+fn spawn_duel_ring(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    radius: f32,
+    wall_texture: Handle<Image>,
+) {
+    let wall_radius = radius + 1.5;
+    let wall_height = 2.0;
+    let segments = 32;
+
+    // Floor disc, lifted just above the arena floor to avoid z-fighting
+    commands.spawn((
+        Mesh3d(meshes.add(Cylinder::new(wall_radius, 0.02))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.62, 0.5, 0.34),
+            perceptual_roughness: 0.95,
+            ..default()
+        })),
+        Transform::from_xyz(0.0, 0.01, 0.0),
+        PlayMatchEntity,
+    ));
+
+    // Wall segments: chord length plus a little overlap so there are no gaps
+    let segment_length = 2.0 * wall_radius * (std::f32::consts::PI / segments as f32).sin() + 0.3;
+    let wall_material = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        base_color_texture: Some(wall_texture),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    let wall_mesh = meshes.add(Cuboid::new(segment_length, wall_height, 1.0));
+    for i in 0..segments {
+        let angle = i as f32 / segments as f32 * std::f32::consts::TAU;
+        commands.spawn((
+            Mesh3d(wall_mesh.clone()),
+            MeshMaterial3d(wall_material.clone()),
+            Transform::from_xyz(wall_radius * angle.cos(), wall_height / 2.0, wall_radius * angle.sin())
+                // Face the segment's long side along the tangent
+                .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2 - angle)),
+            PlayMatchEntity,
+        ));
+    }
+}
+
 /// Deterministic per-entity walk-animation phase offset derived from the
 /// spawn XZ position. Two units at the same Z separated in X get different
 /// phases, so a 3v3 team that starts walking in lockstep does not bob in unison.
@@ -751,20 +906,12 @@ fn spawn_combatant(
     warrior_shout: match_config::WarriorShout,
     mage_armor: match_config::MageArmor,
     paladin_aura: match_config::PaladinAura,
+    personality: match_config::Personality,
     equipment_loadout: &std::collections::HashMap<ItemSlot, ItemId>,
     item_defs: &ItemDefinitions,
 ) -> (Entity, Combatant) {
     // Get vibrant class colors for 3D visibility
-    let base_color = match class {
-        match_config::CharacterClass::Warrior => Color::srgb(0.9, 0.6, 0.3), // Orange/brown
-        match_config::CharacterClass::Mage => Color::srgb(0.3, 0.6, 1.0),    // Bright blue
-        match_config::CharacterClass::Rogue => Color::srgb(1.0, 0.9, 0.2),   // Bright yellow
-        match_config::CharacterClass::Priest => Color::srgb(0.95, 0.95, 0.95), // White
-        match_config::CharacterClass::Warlock => Color::srgb(0.58, 0.41, 0.93), // Purple
-        match_config::CharacterClass::Paladin => Color::srgb(0.96, 0.55, 0.73), // Pink (WoW Paladin)
-        match_config::CharacterClass::Hunter => Color::srgb(0.67, 0.83, 0.45), // Green (WoW Hunter)
-        match_config::CharacterClass::Shaman => Color::srgb(0.0, 0.44, 0.87), // Blue (WoW Shaman)
-    };
+    let base_color = class_model_color(class);
     
     // Apply darkening for duplicate classes (0.65 multiplier per duplicate)
     let darken_factor = 0.65f32.powi(duplicate_index as i32);
@@ -789,6 +936,7 @@ fn spawn_combatant(
     combatant.warrior_shout = warrior_shout;
     combatant.mage_armor = mage_armor;
     combatant.paladin_aura = paladin_aura;
+    combatant.personality = personality;
     combatant.apply_equipment(equipment_loadout, item_defs);
     let combatant_clone = combatant.clone();
     let weapon_poison_buff = combatant.weapon_poison_self_buff();
@@ -893,6 +1041,7 @@ pub fn cleanup_play_match(
     commands.remove_resource::<MatchCountdown>();
     commands.remove_resource::<ShadowSightState>();
     commands.remove_resource::<DisplaySettings>();
+    commands.remove_resource::<EffectPool>();
     // Remove optional resources (may not exist if match didn't finish)
     commands.remove_resource::<VictoryCelebration>();
 }
@@ -25,6 +25,11 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::{AUTO_SHOT_RANGE, SAFE_KITING_DISTANCE};
+use crate::states::match_config::Personality;
+
+/// Range multiplier applied by `MovementConfig::for_personality` for
+/// `Personality::Cautious`
+pub const CAUTIOUS_RANGE_SCALE: f32 = 1.5;
 
 /// Position-scorer term weights (one block per healer class in movement.ron).
 ///
@@ -377,6 +382,37 @@ pub struct MovementConfig {
 }
 
 impl MovementConfig {
+    /// The config as seen by one combatant: `Cautious` scales every threat
+    /// radius, the healers' formation offset, the Paladin fallback and the
+    /// kiters' inner ring by `CAUTIOUS_RANGE_SCALE` and threat repulsion by the
+    /// same factor. Other personalities bias targeting only and see the
+    /// config unchanged.
+    pub fn for_personality(&self, personality: Personality) -> MovementConfig {
+        let mut config = *self;
+        if personality == Personality::Cautious {
+            let scale = CAUTIOUS_RANGE_SCALE;
+            config.shared.danger_radius *= scale;
+            config.shared.threat_intent_radius *= scale;
+            config.shared.formation_offset *= scale;
+            config.paladin.fallback_range *= scale;
+            for weights in [
+                &mut config.priest.weights,
+                &mut config.paladin.weights,
+                &mut config.shaman.weights,
+                &mut config.mage.weights,
+                &mut config.hunter.weights,
+            ] {
+                weights.threat_repulsion *= scale;
+            }
+            for dps in [&mut config.mage, &mut config.hunter] {
+                dps.range_band_min = (dps.range_band_min * scale).min(dps.range_band_max);
+                dps.kite_entry_radius *= scale;
+                dps.kite_sustain_radius *= scale;
+            }
+        }
+        config
+    }
+
     /// Check value sanity. Returns the list of violations on failure.
     pub fn validate(&self) -> Result<(), Vec<String>> {
         let mut issues: Vec<String> = Vec::new();
@@ -803,4 +839,18 @@ mod tests {
         assert_eq!(config.shared.danger_radius, 15.0);
         assert_eq!(config.shared.heal_range, 40.0, "unspecified fields use defaults");
     }
+
+    #[test]
+    fn cautious_personality_widens_ranges_and_still_validates() {
+        let base = MovementConfig::default();
+        let aggressive = base.for_personality(Personality::Aggressive);
+        assert_eq!(aggressive.shared.danger_radius, base.shared.danger_radius);
+
+        let cautious = base.for_personality(Personality::Cautious);
+        assert!(cautious.shared.danger_radius > base.shared.danger_radius);
+        assert!(cautious.paladin.fallback_range > base.paladin.fallback_range);
+        assert!(cautious.mage.range_band_min <= cautious.mage.range_band_max);
+        assert_eq!(cautious.shared.heal_range, base.shared.heal_range, "heal range is a hard limit");
+        cautious.validate().expect("cautious config must stay valid");
+    }
 }
@@ -9,28 +9,15 @@ use crate::combat::log::CombatLog;
 use super::match_config;
 use super::components::*;
 use super::abilities::AbilityType;
-use super::ability_config::AbilityDefinitions;
+use super::ability_config::{AbilityDefinitions, ProjectileShape};
 use super::constants::CRIT_DAMAGE_MULTIPLIER;
-use super::utils::{combatant_id, get_next_fct_offset};
-
-/// Returns true if the ability should use an arrow (cuboid) mesh instead of sphere.
-fn is_arrow_projectile(ability: AbilityType) -> bool {
-    matches!(
-        ability,
-        AbilityType::AimedShot
-            | AbilityType::ArcaneShot
-            | AbilityType::ConcussiveShot
-            | AbilityType::SerpentSting
-    )
-}
-
-/// Returns true if the ability should use a smaller web projectile mesh.
-fn is_web_projectile(ability: AbilityType) -> bool {
-    matches!(ability, AbilityType::SpiderWeb)
-}
+use super::effect_pool::EffectCommandsExt;
+use super::utils::{check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset, spawn_ability_impact_visuals, StaleReference};
 
 /// Spawn visual meshes for newly created projectiles.
-/// Creates a glowing sphere (casters) or elongated cuboid (Hunter arrows) that travels through the air.
+/// Shape and colors come from the ability's `visuals.projectile` descriptor
+/// (orbs for caster bolts, elongated cuboids for arrows and webs), falling
+/// back to a spell-school colored orb.
 /// Note: Projectiles already have a Transform (added in process_casting for headless compatibility).
 pub fn spawn_projectile_visuals(
     mut commands: Commands,
@@ -40,39 +27,20 @@ pub fn spawn_projectile_visuals(
     ability_defs: Res<AbilityDefinitions>,
 ) {
     for (projectile_entity, projectile) in new_projectiles.iter() {
-        // Choose mesh shape based on ability type
-        let mesh = if is_arrow_projectile(projectile.ability) {
-            // Arrow: elongated cuboid, long axis on Z (matches rotation_arc(Z, direction))
-            meshes.add(Cuboid::new(0.08, 0.08, 0.6))
-        } else if is_web_projectile(projectile.ability) {
-            // Web: slightly smaller elongated cuboid
-            meshes.add(Cuboid::new(0.06, 0.06, 0.4))
-        } else if projectile.ability == AbilityType::DeathCoil {
-            // Death Coil: chunky bright orb — it has almost no travel as a
-            // self-peel, so make the coil itself read at point-blank range.
-            meshes.add(Sphere::new(0.55))
-        } else {
-            // Default: sphere for caster projectiles
-            meshes.add(Sphere::new(0.3))
+        let Some(def) = ability_defs.get(&projectile.ability) else {
+            continue;
         };
+        let visuals = def.projectile_visuals();
 
-        // Try to get color from ability config first (projectile_visuals)
-        let (base_color, emissive) = if let Some(def) = ability_defs.get(&projectile.ability) {
-            if let Some(visuals) = &def.projectile_visuals {
-                (
-                    Color::srgb(visuals.color[0], visuals.color[1], visuals.color[2]),
-                    LinearRgba::rgb(visuals.emissive[0], visuals.emissive[1], visuals.emissive[2]),
-                )
-            } else {
-                default_projectile_colors(projectile.ability)
-            }
-        } else {
-            default_projectile_colors(projectile.ability)
+        let mesh = match visuals.shape {
+            ProjectileShape::Orb(radius) => meshes.add(Sphere::new(radius)),
+            // Long axis on Z (matches rotation_arc(Z, direction))
+            ProjectileShape::Bolt(width, length) => meshes.add(Cuboid::new(width, width, length)),
         };
 
         let material = materials.add(StandardMaterial {
-            base_color,
-            emissive,
+            base_color: Color::srgb(visuals.color[0], visuals.color[1], visuals.color[2]),
+            emissive: LinearRgba::rgb(visuals.emissive[0], visuals.emissive[1], visuals.emissive[2]),
             ..default()
         });
 
@@ -84,24 +52,6 @@ pub fn spawn_projectile_visuals(
     }
 }
 
-/// Fallback colors for projectiles without projectile_visuals config.
-fn default_projectile_colors(ability: AbilityType) -> (Color, LinearRgba) {
-    match ability {
-        AbilityType::Shadowbolt => (
-            Color::srgb(0.6, 0.3, 0.8),
-            LinearRgba::rgb(0.8, 0.4, 1.2),
-        ),
-        AbilityType::Frostbolt => (
-            Color::srgb(0.4, 0.7, 1.0),
-            LinearRgba::rgb(0.6, 0.9, 1.5),
-        ),
-        _ => (
-            Color::srgb(1.0, 0.8, 0.3),
-            LinearRgba::rgb(1.2, 1.0, 0.5),
-        ),
-    }
-}
-
 /// Move projectiles towards their targets.
 /// Projectiles travel in a straight line at their defined speed.
 pub fn move_projectiles(
@@ -158,19 +108,29 @@ pub fn process_projectile_hits(
     // Format: (projectile_entity, caster_entity, target_entity, ability, caster_team, caster_class, caster_pos, target_pos, ability_damage, ability_healing, is_crit)
     let mut hits_to_process: Vec<(Entity, Entity, Entity, AbilityType, u8, match_config::CharacterClass, Vec3, Vec3, f32, f32, bool)> = Vec::new();
     
-    for (projectile_entity, projectile, projectile_transform) in projectiles.iter() {
-        // Get target position (immutable borrow)
-        let Ok((target_transform, target, _)) = combatants.get(projectile.target) else {
-            // Target no longer exists, despawn projectile
-            commands.entity(projectile_entity).despawn();
-            continue;
-        };
-
-        if !target.is_alive() {
-            // Target already dead, despawn projectile
-            commands.entity(projectile_entity).despawn();
+    // Resolve in caster (team, slot) order rather than storage order: hits
+    // roll crits from the match RNG. Same-caster ties fall back to spawn order.
+    let mut order: Vec<(u8, u8, Entity)> = projectiles
+        .iter()
+        .map(|(entity, projectile, _)| {
+            let slot = combatants.get(projectile.caster).map_or(u8::MAX, |(_, c, _)| c.slot);
+            (projectile.caster_team, slot, entity)
+        })
+        .collect();
+    order.sort();
+
+    for (_, _, projectile_entity) in order {
+        let Ok((projectile_entity, projectile, projectile_transform)) = projectiles.get(projectile_entity) else { continue; };
+        // Target gone or already dead: the projectile fizzles as a logged no-op.
+        let target_lookup = combatants.get(projectile.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.map(|(_, c, _)| c)) {
+            let name = &abilities.get_unchecked(&projectile.ability).name;
+            discard_stale_effect(&mut commands, &mut combat_log, projectile_entity, name, "target", reason);
             continue;
         }
+        let Some((target_transform, _, _)) = target_lookup else {
+            continue;
+        };
 
         let target_pos = target_transform.translation + Vec3::new(0.0, 1.0, 0.0); // Center mass
         let projectile_pos = projectile_transform.translation;
@@ -179,9 +139,11 @@ pub fn process_projectile_hits(
         // Check if projectile has reached target
         if distance <= HIT_DISTANCE {
             // Get caster data (position, combatant stats, auras) in a single query
+            // A dead caster's in-flight projectile still lands (its stats are
+            // read from the corpse); only a despawned caster voids the hit.
             let Ok((caster_transform, caster_combatant, caster_auras)) = combatants.get(projectile.caster) else {
-                // Caster no longer exists, despawn projectile
-                commands.entity(projectile_entity).despawn();
+                let name = &abilities.get_unchecked(&projectile.ability).name;
+                discard_stale_effect(&mut commands, &mut combat_log, projectile_entity, name, "caster", StaleReference::Despawned);
                 continue;
             };
 
@@ -235,8 +197,15 @@ pub fn process_projectile_hits(
 
             // Get target info and apply damage
             let (actual_damage, absorbed, target_team, target_class, is_killing_blow, is_first_death) = {
-                let Ok((_, mut target, mut target_auras)) = combatants.get_mut(target_entity) else {
-                    commands.entity(projectile_entity).despawn();
+                // Re-check: an earlier hit in this same batch may have landed the
+                // kill shot. Without this the second projectile would log a
+                // second killing blow on the corpse.
+                let target_lookup = combatants.get_mut(target_entity).ok();
+                if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(_, c, _)| &**c)) {
+                    discard_stale_effect(&mut commands, &mut combat_log, projectile_entity, &def.name, "target", reason);
+                    continue;
+                }
+                let Some((_, mut target, mut target_auras)) = target_lookup else {
                     continue;
                 };
 
@@ -308,17 +277,17 @@ pub fn process_projectile_hits(
             } else {
                 (0.0, 0.0)
             };
-            commands.spawn((
-                FloatingCombatText {
-                    world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
-                    text: format!("{:.0}", actual_damage),
-                    color: egui::Color32::from_rgb(255, 255, 0), // Yellow
-                    lifetime: 1.5,
-                    vertical_offset: offset_y,
-                    is_crit,
-                },
-                PlayMatchEntity,
-            ));
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("{:.0}", actual_damage),
+                color: egui::Color32::from_rgb(255, 255, 0), // Yellow
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit,
+            });
+
+            // Impact visuals from the ability's descriptor
+            spawn_ability_impact_visuals(&mut commands, def, target_pos, &mut game_rng);
 
             // Spawn light blue floating combat text for absorbed damage
             if absorbed > 0.0 {
@@ -327,17 +296,14 @@ pub fn process_projectile_hits(
                 } else {
                     (0.0, 0.0)
                 };
-                commands.spawn((
-                    FloatingCombatText {
-                        world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
-                        text: format!("{:.0} absorbed", absorbed),
-                        color: egui::Color32::from_rgb(100, 180, 255), // Light blue
-                        lifetime: 1.5,
-                        vertical_offset: absorb_offset_y,
-                        is_crit: false,
-                    },
-                    PlayMatchEntity,
-                ));
+                commands.spawn_effect(FloatingCombatText {
+                    world_position: text_position + Vec3::new(absorb_offset_x, absorb_offset_y, 0.0),
+                    text: format!("{:.0} absorbed", absorbed),
+                    color: egui::Color32::from_rgb(100, 180, 255), // Light blue
+                    lifetime: 1.5,
+                    vertical_offset: absorb_offset_y,
+                    is_crit: false,
+                });
             }
 
             // Log the damage with structured data
@@ -392,6 +358,7 @@ pub fn process_projectile_hits(
                     Some(combatant_id(caster_team, caster_class)),
                     death_message,
                 );
+                commands.spawn(SoulShardPending { killer: caster_entity });
             }
             
             // Apply aura if ability has one (skip if target was killed — don't CC dead combatants)
@@ -441,3 +408,136 @@ pub fn process_projectile_hits(
     }
 }
 
+
+#[cfg(test)]
+mod stale_reference_tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn new_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(CombatLog::default());
+        world.insert_resource(GameRng::from_seed(7));
+        world.insert_resource(AbilityDefinitions::default());
+        world
+    }
+
+    fn spawn_target(world: &mut World, health: f32) -> Entity {
+        let mut target = Combatant::new(2, 0, match_config::CharacterClass::Mage);
+        target.current_health = health;
+        world
+            .spawn((Transform::default(), target, FloatingTextState { next_pattern_index: 0 }))
+            .id()
+    }
+
+    /// A projectile already at its target's center mass, so it hits on the
+    /// next `process_projectile_hits` run.
+    fn spawn_arrived_bolt(world: &mut World, caster: Entity, target: Entity) -> Entity {
+        world
+            .spawn((
+                Projectile {
+                    caster,
+                    target,
+                    ability: AbilityType::Shadowbolt,
+                    speed: 20.0,
+                    caster_team: 1,
+                    caster_class: match_config::CharacterClass::Warlock,
+                },
+                Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+            ))
+            .id()
+    }
+
+    fn stale_entries(world: &World) -> Vec<String> {
+        world
+            .resource::<CombatLog>()
+            .entries
+            .iter()
+            .filter(|e| e.message.starts_with("[STALE]"))
+            .map(|e| e.message.clone())
+            .collect()
+    }
+
+    /// Two bolts land on the same frame and the first is the kill shot. The
+    /// second must fizzle as a logged no-op instead of logging a second
+    /// killing blow on the corpse.
+    #[test]
+    fn second_projectile_on_kill_shot_frame_is_a_logged_no_op() {
+        let mut world = new_world();
+        let caster = world
+            .spawn((
+                Transform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+                Combatant::new(1, 0, match_config::CharacterClass::Warlock),
+            ))
+            .id();
+        let target = spawn_target(&mut world, 1.0);
+        let first = spawn_arrived_bolt(&mut world, caster, target);
+        let second = spawn_arrived_bolt(&mut world, caster, target);
+
+        world.run_system_once(process_projectile_hits).expect("process_projectile_hits ran");
+
+        let log = world.resource::<CombatLog>();
+        assert_eq!(log.killing_blows("Team 1 Warlock"), 1, "exactly one killing blow");
+        assert_eq!(
+            stale_entries(&world),
+            vec!["[STALE] Shadow Bolt discarded: target is dead".to_string()]
+        );
+        assert!(world.get_entity(first).is_err(), "landed bolt despawned");
+        assert!(world.get_entity(second).is_err(), "stale bolt despawned");
+        assert!(!world.get::<Combatant>(target).unwrap().is_alive());
+    }
+
+    /// A projectile whose target entity was despawned mid-flight is removed
+    /// and logged rather than left orbiting a dangling reference.
+    #[test]
+    fn projectile_with_despawned_target_is_a_logged_no_op() {
+        let mut world = new_world();
+        let caster = world
+            .spawn((
+                Transform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+                Combatant::new(1, 0, match_config::CharacterClass::Warlock),
+            ))
+            .id();
+        let target = spawn_target(&mut world, 100.0);
+        let bolt = spawn_arrived_bolt(&mut world, caster, target);
+        world.despawn(target);
+
+        world.run_system_once(process_projectile_hits).expect("process_projectile_hits ran");
+
+        assert!(world.get_entity(bolt).is_err(), "stale bolt despawned");
+        assert_eq!(
+            stale_entries(&world),
+            vec!["[STALE] Shadow Bolt discarded: target no longer exists".to_string()]
+        );
+    }
+
+    /// A pending aura queued onto the kill-shot victim (e.g. the bolt's own
+    /// debuff racing the death) is discarded with a log line, not applied.
+    #[test]
+    fn pending_aura_on_dead_target_is_a_logged_no_op() {
+        let mut world = new_world();
+        let target = spawn_target(&mut world, 0.0);
+        let pending = world
+            .spawn(AuraPending {
+                target,
+                aura: Aura {
+                    effect_type: AuraType::Stun,
+                    duration: 2.0,
+                    magnitude: 1.0,
+                    break_on_damage_threshold: -1.0,
+                    ability_name: "Kidney Shot".to_string(),
+                    ..Default::default()
+                },
+            })
+            .id();
+
+        world.run_system_once(super::super::auras::apply_pending_auras).expect("apply_pending_auras ran");
+
+        assert!(world.get_entity(pending).is_err(), "stale pending despawned");
+        assert!(world.get::<ActiveAuras>(target).is_none(), "no aura applied to the corpse");
+        assert_eq!(
+            stale_entries(&world),
+            vec!["[STALE] Kidney Shot discarded: target is dead".to_string()]
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! AI Plan Overlay
+//!
+//! Debug panel (F10 by default) listing each combatant's current plan: the
+//! ability it intends to use next, its kill and CC targets, its movement
+//! posture, and the top reason behind its last decision. Built from the
+//! decision trace events the class AI already emits every frame (no trace
+//! file needed), so it shows why the AI did something odd while it happens.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::settings::TeamColors;
+use crate::states::play_match::abilities::AbilityType;
+use crate::states::play_match::ability_config::AbilityDefinitions;
+use crate::states::play_match::components::*;
+use crate::states::play_match::decision_trace::{
+    AbilityOutcome, CandidateStatus, DecisionEvent, DecisionTrace, EventKind, EventPayload,
+    Posture, RejectionReason,
+};
+use crate::states::play_match::utils::combatant_id;
+use super::PresentationMode;
+
+/// One combatant's latest plan, as read from its decision events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AiPlan {
+    /// Ability chosen at the last decision, or the one it is waiting on
+    pub next_ability: Option<AbilityType>,
+    /// Whether `next_ability` was cast (false: waiting for cooldown/resources)
+    pub acted: bool,
+    /// Top reason behind the last decision
+    pub reason: String,
+    /// Posture after the last movement decision
+    pub posture: Option<Posture>,
+    /// Sim time of the last ability decision
+    pub decided_at: f32,
+}
+
+/// State of the AI plan overlay. Plans are keyed by `(team, slot)` so the
+/// panel lists combatants in a stable order; cleared when a match starts.
+#[derive(Resource, Default)]
+pub struct AiPlanOverlay {
+    pub open: bool,
+    pub plans: BTreeMap<(u8, u8), AiPlan>,
+}
+
+impl AiPlanOverlay {
+    /// Fold one decision event into the plans. Pet decisions are skipped:
+    /// the owner's row is the one worth reading.
+    pub fn record(&mut self, event: &DecisionEvent) {
+        let key = (event.actor.team, event.actor.slot);
+        match (&event.kind, &event.payload) {
+            (EventKind::AbilityDecision, EventPayload::Ability { candidates, outcome }) => {
+                let plan = self.plans.entry(key).or_default();
+                plan.decided_at = event.sim_time;
+                // Candidates in evaluation (priority) order; the first one
+                // rejected for a real reason is why the AI didn't do better
+                let top_rejection = candidates.iter().find_map(|c| match (&c.status, &c.reason) {
+                    (CandidateStatus::Rejected, Some(RejectionReason::LowerPriorityThanChosen { .. })) => None,
+                    (CandidateStatus::Rejected, Some(reason)) => Some((c.ability, reason)),
+                    _ => None,
+                });
+                match outcome {
+                    AbilityOutcome::ActionTaken { ability, .. } => {
+                        plan.next_ability = Some(*ability);
+                        plan.acted = true;
+                        plan.reason = match top_rejection {
+                            Some((skipped, reason)) => {
+                                format!("over {:?}: {}", skipped, describe_rejection(reason))
+                            }
+                            None => "top priority".to_string(),
+                        };
+                    }
+                    AbilityOutcome::NoAction { primary_reason } => {
+                        // The highest-priority option blocked only by time or
+                        // resources is the one it is waiting on
+                        plan.next_ability = candidates.iter().find_map(|c| match c.reason {
+                            Some(RejectionReason::OnCooldown { .. })
+                            | Some(RejectionReason::InsufficientMana { .. })
+                            | Some(RejectionReason::InsufficientResource { .. }) => Some(c.ability),
+                            _ => None,
+                        });
+                        plan.acted = false;
+                        plan.reason = match top_rejection {
+                            Some((skipped, reason)) => {
+                                format!("{:?}: {:?} {}", primary_reason, skipped, describe_rejection(reason))
+                            }
+                            None => format!("{:?}", primary_reason),
+                        };
+                    }
+                }
+            }
+            (EventKind::MovementDecision, EventPayload::Movement { posture, .. }) => {
+                self.plans.entry(key).or_default().posture = Some(*posture);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Short human-readable form of a rejection reason.
+pub fn describe_rejection(reason: &RejectionReason) -> String {
+    match reason {
+        RejectionReason::OutOfRange { distance, max } => format!("out of range ({:.0} > {:.0})", distance, max),
+        RejectionReason::WithinDeadZone { distance, min } => format!("too close ({:.0} < {:.0})", distance, min),
+        RejectionReason::OnCooldown { remaining } => format!("on cooldown ({:.1}s)", remaining),
+        RejectionReason::InsufficientMana { have, need } => format!("mana {:.0}/{:.0}", have, need),
+        RejectionReason::InsufficientResource { resource, have, need } => {
+            format!("{:?} {:.0}/{:.0}", resource, have, need)
+        }
+        RejectionReason::SilencedOrLocked { school } => format!("{:?} locked", school),
+        RejectionReason::TargetImmune => "target immune".to_string(),
+        RejectionReason::TargetAlreadyCCd { cc_type } => format!("target already {:?}", cc_type),
+        RejectionReason::DRImmune { category } => format!("DR immune ({:?})", category),
+        RejectionReason::FriendlyBreakableCC => "would break friendly CC".to_string(),
+        RejectionReason::SelfIncapacitated => "incapacitated".to_string(),
+        RejectionReason::Rooted => "rooted".to_string(),
+        RejectionReason::LowerPriorityThanChosen { chosen } => format!("{:?} chosen", chosen),
+        RejectionReason::AlreadyApplied => "already applied".to_string(),
+        RejectionReason::NoValidTarget => "no valid target".to_string(),
+        RejectionReason::PreconditionUnmet { note } => note.clone(),
+        RejectionReason::LowHealthHeel => "heeling (low health)".to_string(),
+    }
+}
+
+/// Clear plans from the previous match.
+pub fn reset_ai_plan_overlay(mut overlay: ResMut<AiPlanOverlay>) {
+    overlay.plans.clear();
+}
+
+/// Fold this frame's decision events into the plans. Runs before
+/// `flush_decision_trace_system` drains them.
+pub fn record_ai_plans(trace: Res<DecisionTrace>, mut overlay: ResMut<AiPlanOverlay>) {
+    for event in &trace.pending_events {
+        overlay.record(event);
+    }
+}
+
+/// AI plan overlay: toggles on its hotkey and, while open, draws one row per
+/// living combatant at the left edge of the screen.
+#[allow(clippy::too_many_arguments)]
+pub fn render_ai_plan_overlay(
+    mut contexts: EguiContexts,
+    mut overlay: ResMut<AiPlanOverlay>,
+    combatants: Query<&Combatant, Without<Pet>>,
+    abilities: Res<AbilityDefinitions>,
+    team_colors: Res<TeamColors>,
+    presentation: Res<PresentationMode>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if keybindings.action_just_pressed(GameAction::ToggleAiPlans, &keyboard) {
+        overlay.open = !overlay.open;
+    }
+    if !overlay.open || presentation.enabled {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let label_color = egui::Color32::from_rgb(170, 170, 180);
+    let value_color = egui::Color32::from_rgb(230, 230, 230);
+    let waiting_color = egui::Color32::from_rgb(200, 180, 120);
+
+    // Entity -> display name, for resolving targets
+    let mut living: Vec<&Combatant> = combatants.iter().filter(|c| c.is_alive()).collect();
+    living.sort_by_key(|c| (c.team, c.slot));
+    let name_of = |entity: Option<Entity>| -> String {
+        entity
+            .and_then(|e| combatants.get(e).ok())
+            .map(|c| combatant_id(c.team, c.class))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let ability_name = |ability: AbilityType| -> String {
+        abilities
+            .get(&ability)
+            .map(|def| def.name.clone())
+            .unwrap_or_else(|| format!("{:?}", ability))
+    };
+
+    egui::Window::new("AI Plans")
+        .anchor(egui::Align2::LEFT_CENTER, egui::vec2(10.0, 0.0))
+        .resizable(false)
+        .collapsible(false)
+        .title_bar(false)
+        .frame(egui::Frame::window(&ctx.style())
+            .fill(egui::Color32::from_black_alpha(200))
+            .stroke(egui::Stroke::NONE))
+        .show(ctx, |ui| {
+            ui.set_width(330.0);
+            ui.label(egui::RichText::new("AI PLANS").size(12.0).strong().color(value_color));
+
+            for combatant in &living {
+                let plan = overlay.plans.get(&(combatant.team, combatant.slot));
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(combatant_id(combatant.team, combatant.class))
+                        .size(12.0)
+                        .strong()
+                        .color(team_colors.egui(combatant.team)),
+                );
+
+                let (next, next_color) = match plan.and_then(|p| p.next_ability.map(|a| (a, p.acted))) {
+                    Some((ability, true)) => (ability_name(ability), value_color),
+                    Some((ability, false)) => (format!("waiting on {}", ability_name(ability)), waiting_color),
+                    None => ("-".to_string(), label_color),
+                };
+                let posture = plan
+                    .and_then(|p| p.posture)
+                    .map(|p| format!("  [{:?}]", p))
+                    .unwrap_or_default();
+                ui.label(egui::RichText::new(format!("Next: {}{}", next, posture)).size(11.0).color(next_color));
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Kill: {}   CC: {}",
+                        name_of(combatant.target),
+                        name_of(combatant.cc_target)
+                    ))
+                    .size(11.0)
+                    .color(label_color),
+                );
+                if let Some(plan) = plan.filter(|p| !p.reason.is_empty()) {
+                    ui.label(
+                        egui::RichText::new(format!("Why: {} ({:.1}s)", plan.reason, plan.decided_at))
+                            .size(10.0)
+                            .monospace()
+                            .color(label_color),
+                    );
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::match_config::CharacterClass;
+    use crate::states::play_match::decision_trace::{AbilityCandidate, ActorView, NoActionReason};
+
+    fn event(payload: EventPayload, kind: EventKind) -> DecisionEvent {
+        DecisionEvent {
+            frame: 1,
+            sim_time: 4.0,
+            seed: 0,
+            kind,
+            actor: ActorView {
+                entity_id: 7,
+                team: 1,
+                slot: 0,
+                class: CharacterClass::Mage,
+                hp_pct: 1.0,
+                mana_pct: 1.0,
+                position: [0.0; 3],
+            },
+            target: None,
+            payload,
+        }
+    }
+
+    fn rejected(ability: AbilityType, reason: RejectionReason) -> AbilityCandidate {
+        AbilityCandidate { ability, status: CandidateStatus::Rejected, reason: Some(reason) }
+    }
+
+    #[test]
+    fn plan_tracks_choice_and_the_reason_better_options_lost() {
+        let mut overlay = AiPlanOverlay::default();
+        overlay.record(&event(
+            EventPayload::Ability {
+                candidates: vec![
+                    rejected(AbilityType::Polymorph, RejectionReason::OnCooldown { remaining: 2.5 }),
+                    AbilityCandidate { ability: AbilityType::Frostbolt, status: CandidateStatus::Chosen, reason: None },
+                ],
+                outcome: AbilityOutcome::ActionTaken { ability: AbilityType::Frostbolt, target_id: None, was_instant: false },
+            },
+            EventKind::AbilityDecision,
+        ));
+        let plan = &overlay.plans[&(1, 0)];
+        assert_eq!(plan.next_ability, Some(AbilityType::Frostbolt));
+        assert!(plan.acted);
+        assert_eq!(plan.reason, "over Polymorph: on cooldown (2.5s)");
+
+        // Nothing castable: the plan waits on the first time/resource-blocked option
+        overlay.record(&event(
+            EventPayload::Ability {
+                candidates: vec![
+                    rejected(AbilityType::Polymorph, RejectionReason::TargetImmune),
+                    rejected(AbilityType::Frostbolt, RejectionReason::InsufficientMana { have: 10.0, need: 40.0 }),
+                ],
+                outcome: AbilityOutcome::NoAction { primary_reason: NoActionReason::AllCandidatesRejected },
+            },
+            EventKind::AbilityDecision,
+        ));
+        let plan = &overlay.plans[&(1, 0)];
+        assert_eq!(plan.next_ability, Some(AbilityType::Frostbolt));
+        assert!(!plan.acted);
+        assert_eq!(plan.reason, "AllCandidatesRejected: Polymorph target immune");
+    }
+}
@@ -0,0 +1,247 @@
+//! Spectator Alerts
+//!
+//! Screen-edge vignette plus a short tone for the moments that decide a
+//! match, so they aren't missed at high simulation speeds:
+//! - a combatant on the followed team drops below the low-health threshold
+//! - the followed team's healer is crowd controlled
+//! - a combatant on the followed team dies
+//!
+//! The followed team is the team of the combatant the camera follows, else of
+//! the selected combatant; with neither, both teams raise alerts. Everything
+//! is configured through `GameSettings::alerts`. Tones are generated sine
+//! beeps (`bevy::audio::Pitch`), so there are no sound assets to ship.
+//!
+//! Alert timing uses real time: a vignette lasts the same on screen whatever
+//! the sim speed.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use bevy::audio::{Pitch, Volume};
+use bevy::prelude::*;
+use bevy::time::Real;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::combat::log::{CombatLog, StructuredEventData};
+use crate::settings::GameSettings;
+use crate::states::play_match::components::*;
+use crate::states::play_match::selection::Selection;
+use crate::states::play_match::utils::{class_from_combatant_id, team_from_combatant_id};
+
+/// Real seconds a vignette takes to fade out
+const VIGNETTE_SECS: f32 = 1.2;
+/// Vignette band width as a fraction of the smaller screen dimension
+const VIGNETTE_WIDTH_FRACTION: f32 = 0.12;
+/// Minimum real seconds between two tones of the same kind
+const ALERT_SOUND_COOLDOWN_SECS: f32 = 0.6;
+/// Health fraction above the threshold at which the low-health alert re-arms
+/// (stops a combatant hovering around the threshold from spamming alerts)
+const LOW_HEALTH_REARM_MARGIN: f32 = 0.05;
+
+/// The kinds of alert, in increasing priority (a higher-priority alert
+/// replaces a lower one's vignette).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertKind {
+    LowHealth,
+    HealerCc,
+    Kill,
+}
+
+impl AlertKind {
+    const COUNT: usize = 3;
+
+    /// Vignette color
+    fn color(self) -> egui::Color32 {
+        match self {
+            AlertKind::LowHealth => egui::Color32::from_rgb(220, 40, 40),
+            AlertKind::HealerCc => egui::Color32::from_rgb(150, 80, 255),
+            AlertKind::Kill => egui::Color32::from_rgb(255, 240, 220),
+        }
+    }
+
+    /// Tone frequency (Hz) and length
+    fn tone(self) -> (f32, Duration) {
+        match self {
+            AlertKind::LowHealth => (880.0, Duration::from_millis(120)),
+            AlertKind::HealerCc => (330.0, Duration::from_millis(260)),
+            AlertKind::Kill => (523.0, Duration::from_millis(400)),
+        }
+    }
+}
+
+/// Per-match alert state.
+#[derive(Resource, Clone, Default)]
+pub struct AlertState {
+    /// Combatants currently below the low-health threshold
+    low_health: HashSet<Entity>,
+    /// Index of the next combat log entry to check
+    log_cursor: usize,
+    /// Vignette on screen: kind and the real time it fired
+    vignette: Option<(AlertKind, f32)>,
+    /// Real time each kind last played its tone
+    last_sound: [Option<f32>; AlertKind::COUNT],
+    /// Tone assets, created on first use
+    tones: Option<[Handle<Pitch>; AlertKind::COUNT]>,
+}
+
+/// Start each match with no alerts pending.
+pub fn reset_alert_state(mut state: ResMut<AlertState>) {
+    let tones = state.tones.take();
+    *state = AlertState { tones, ..default() };
+}
+
+/// The team alerts are raised for: the followed combatant's, else the
+/// selected combatant's. None means both teams.
+pub fn followed_team(
+    mode: &CameraMode,
+    selected: Option<Entity>,
+    team_of: impl Fn(Entity) -> Option<u8>,
+) -> Option<u8> {
+    let followed = match mode {
+        CameraMode::FollowCombatant(entity) => Some(*entity),
+        _ => None,
+    };
+    followed.and_then(&team_of).or_else(|| selected.and_then(&team_of))
+}
+
+/// Check for new alerts this frame: health crossings on the followed team,
+/// and healer CC / deaths from the combat log entries added since last frame.
+#[allow(clippy::too_many_arguments)]
+pub fn detect_match_alerts(
+    mut commands: Commands,
+    mut state: ResMut<AlertState>,
+    mut pitches: ResMut<Assets<Pitch>>,
+    settings: Res<GameSettings>,
+    camera_controller: Res<CameraController>,
+    selection: Res<Selection>,
+    combat_log: Res<CombatLog>,
+    time: Res<Time<Real>>,
+    combatants: Query<(Entity, &Combatant), Without<Pet>>,
+) {
+    let settings = &settings.alerts;
+    let team = followed_team(&camera_controller.mode, selection.entity, |entity| {
+        combatants.get(entity).ok().map(|(_, c)| c.team)
+    });
+    let on_followed_team = |t: u8| team.is_none_or(|followed| followed == t);
+
+    let mut fired: Vec<AlertKind> = Vec::new();
+
+    for (entity, combatant) in combatants.iter() {
+        if !on_followed_team(combatant.team) || !combatant.is_alive() {
+            continue;
+        }
+        let fraction = combatant.current_health / combatant.max_health.max(1.0);
+        if fraction < settings.low_health_threshold {
+            if state.low_health.insert(entity) {
+                fired.push(AlertKind::LowHealth);
+            }
+        } else if fraction >= settings.low_health_threshold + LOW_HEALTH_REARM_MARGIN {
+            state.low_health.remove(&entity);
+        }
+    }
+
+    // The log was cleared under us (new match before the reset ran)
+    if state.log_cursor > combat_log.entries.len() {
+        state.log_cursor = 0;
+    }
+    for entry in &combat_log.entries[state.log_cursor..] {
+        match &entry.structured_data {
+            Some(StructuredEventData::CrowdControl { target, .. }) if settings.healer_cc => {
+                let is_healer = class_from_combatant_id(target).is_some_and(|c| c.is_healer());
+                if is_healer && team_from_combatant_id(target).is_some_and(on_followed_team) {
+                    fired.push(AlertKind::HealerCc);
+                }
+            }
+            Some(StructuredEventData::Death { victim, .. })
+                if settings.kills && team_from_combatant_id(victim).is_some_and(on_followed_team) =>
+            {
+                fired.push(AlertKind::Kill);
+            }
+            _ => {}
+        }
+    }
+    state.log_cursor = combat_log.entries.len();
+
+    let now = time.elapsed_secs();
+    fired.sort();
+    fired.dedup();
+    for kind in fired {
+        if settings.vignette && state.vignette.is_none_or(|(current, at)| {
+            kind >= current || now - at >= VIGNETTE_SECS
+        }) {
+            state.vignette = Some((kind, now));
+        }
+
+        let last = &mut state.last_sound[kind as usize];
+        if settings.sound && last.is_none_or(|at| now - at >= ALERT_SOUND_COOLDOWN_SECS) {
+            *last = Some(now);
+            let tones = state.tones.get_or_insert_with(|| {
+                [AlertKind::LowHealth, AlertKind::HealerCc, AlertKind::Kill].map(|kind| {
+                    let (frequency, duration) = kind.tone();
+                    pitches.add(Pitch::new(frequency, duration))
+                })
+            });
+            commands.spawn((
+                AudioPlayer(tones[kind as usize].clone()),
+                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.volume.clamp(0.0, 1.0))),
+            ));
+        }
+    }
+}
+
+/// Draw the fading alert vignette around the screen edge, behind the HUD.
+pub fn render_alert_vignette(
+    mut contexts: EguiContexts,
+    state: Res<AlertState>,
+    time: Res<Time<Real>>,
+) {
+    let Some((kind, at)) = state.vignette else { return; };
+    let strength = 1.0 - (time.elapsed_secs() - at) / VIGNETTE_SECS;
+    if strength <= 0.0 {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let screen = ctx.screen_rect();
+    let width = screen.width().min(screen.height()) * VIGNETTE_WIDTH_FRACTION;
+    let inner = screen.shrink(width);
+    let [r, g, b, _] = kind.color().to_array();
+    let edge = egui::Color32::from_rgba_unmultiplied(r, g, b, (strength * 170.0) as u8);
+
+    // Ring between the screen edge (colored) and the inner rect (clear);
+    // vertex colors give the gradient
+    let mut mesh = egui::Mesh::default();
+    let outer_corners = [screen.left_top(), screen.right_top(), screen.right_bottom(), screen.left_bottom()];
+    let inner_corners = [inner.left_top(), inner.right_top(), inner.right_bottom(), inner.left_bottom()];
+    for (outer, inner) in outer_corners.into_iter().zip(inner_corners) {
+        mesh.colored_vertex(outer, edge);
+        mesh.colored_vertex(inner, egui::Color32::TRANSPARENT);
+    }
+    for side in 0..4u32 {
+        let (o0, i0) = (side * 2, side * 2 + 1);
+        let (o1, i1) = ((side * 2 + 2) % 8, (side * 2 + 3) % 8);
+        mesh.add_triangle(o0, o1, i1);
+        mesh.add_triangle(o0, i1, i0);
+    }
+
+    ctx.layer_painter(egui::LayerId::new(egui::Order::Background, egui::Id::new("alert_vignette")))
+        .add(egui::Shape::mesh(mesh));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn followed_team_prefers_camera_follow_over_selection() {
+        let followed = Entity::from_raw(1);
+        let selected = Entity::from_raw(2);
+        let team_of = |entity: Entity| Some(if entity == followed { 2 } else { 1 });
+
+        assert_eq!(followed_team(&CameraMode::FollowCombatant(followed), Some(selected), team_of), Some(2));
+        assert_eq!(followed_team(&CameraMode::FollowCenter, Some(selected), team_of), Some(1));
+        assert_eq!(followed_team(&CameraMode::Manual, None, team_of), None);
+    }
+}
@@ -4,8 +4,13 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use crate::combat::log::{CombatLog, CombatLogEventType};
-use crate::states::play_match::components::{CombatPanelView, SpellIcons};
+use crate::combat::log::{CoalescedRows, CombatLog, CombatLogEventType, LogDisplayRow};
+use crate::settings::TeamColors;
+use crate::states::play_match::components::{CombatPanelView, DisplaySettings, SpellIcons};
+use crate::states::play_match::utils::class_from_combatant_id;
+use crate::states::play_match::aura_source::{applying_cast_index, AuraSourceHighlight};
+use crate::states::play_match::log_focus::{CombatLogFocus, LogFocusTarget};
+use super::{paint_class_portrait, ClassPortraits};
 
 // ==============================================================================
 // Timeline Constants
@@ -17,6 +22,8 @@ const TIMELINE_ICON_SIZE: f32 = 28.0;
 const TIMELINE_TIME_TICK_INTERVAL: f32 = 5.0;
 /// Top padding so icons at t=0 aren't cut off
 const TIMELINE_TOP_PADDING: f32 = 18.0;
+/// Class portrait size in the timeline column headers
+const TIMELINE_HEADER_PORTRAIT_SIZE: f32 = 18.0;
 /// Minimum vertical spacing between icons to avoid overlap
 const TIMELINE_MIN_ICON_SPACING: f32 = 32.0;
 
@@ -28,13 +35,22 @@ const TIMELINE_MIN_ICON_SPACING: f32 = 32.0;
 ///
 /// Displays on the left side of the screen with:
 /// - Tabbed interface to switch between Combat Log and Timeline views
-/// - Combat Log: scrollable list of combat events, color-coded by type
+/// - Combat Log: scrollable list of combat events, color-coded by type, with
+///   runs of periodic ticks optionally merged into one row; click a line to
+///   jump the camera to it
 /// - Timeline: columnar visualization of ability casts per combatant
+#[allow(clippy::too_many_arguments)]
 pub fn render_combat_panel(
     mut contexts: EguiContexts,
     combat_log: Res<CombatLog>,
     mut panel_view: ResMut<CombatPanelView>,
+    mut display_settings: ResMut<DisplaySettings>,
     spell_icons: Res<SpellIcons>,
+    portraits: Res<ClassPortraits>,
+    team_colors: Res<TeamColors>,
+    aura_source: Res<AuraSourceHighlight>,
+    mut log_focus: ResMut<CombatLogFocus>,
+    mut coalesced: Local<CoalescedRows>,
 ) {
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
@@ -91,19 +107,52 @@ pub fn render_combat_panel(
 
             // Render the selected view
             match *panel_view {
-                CombatPanelView::CombatLog => render_combat_log_content(ui, &combat_log),
-                CombatPanelView::Timeline => render_timeline_content(ui, &combat_log, &spell_icons),
+                CombatPanelView::CombatLog => render_combat_log_content(ui, &combat_log, &mut coalesced, &mut display_settings, &mut log_focus),
+                CombatPanelView::Timeline => render_timeline_content(ui, &combat_log, &spell_icons, &portraits, &team_colors, &aura_source),
             }
         });
 }
 
 /// Render the combat log content (used by the tabbed panel).
-fn render_combat_log_content(ui: &mut egui::Ui, combat_log: &CombatLog) {
+///
+/// Merging periodic ticks only changes what is drawn; `combat_log.entries`
+/// still holds every tick.
+fn render_combat_log_content(
+    ui: &mut egui::Ui,
+    combat_log: &CombatLog,
+    coalesced: &mut CoalescedRows,
+    display_settings: &mut DisplaySettings,
+    log_focus: &mut CombatLogFocus,
+) {
+    ui.checkbox(
+        &mut display_settings.merge_periodic_ticks,
+        egui::RichText::new("Merge periodic ticks")
+            .size(11.0)
+            .color(egui::Color32::from_rgb(150, 150, 150)),
+    );
+
+    let rows: Vec<LogDisplayRow> = if display_settings.merge_periodic_ticks {
+        coalesced.update(combat_log);
+        coalesced.rows(combat_log).collect()
+    } else {
+        combat_log
+            .entries
+            .iter()
+            .map(|entry| LogDisplayRow {
+                entry,
+                first_timestamp: entry.timestamp,
+                count: 1,
+                total: 0.0,
+            })
+            .collect()
+    };
+
     egui::ScrollArea::vertical()
         .auto_shrink([false, false])
         .stick_to_bottom(true)
         .show(ui, |ui| {
-            for entry in &combat_log.entries {
+            for row in &rows {
+                let entry = row.entry;
                 // Color based on event type
                 let color = match entry.event_type {
                     CombatLogEventType::Damage => egui::Color32::from_rgb(255, 180, 180),
@@ -122,18 +171,43 @@ fn render_combat_log_content(ui: &mut egui::Ui, combat_log: &CombatLog) {
                             .size(11.0)
                             .color(egui::Color32::from_rgb(150, 150, 150))
                     );
-                    ui.label(
-                        egui::RichText::new(&entry.message)
+                    // Lines naming combatants jump the camera to them (see log_focus)
+                    let clickable = entry.structured_data.is_some();
+                    let mut message = egui::Label::new(
+                        egui::RichText::new(row.message())
                             .size(12.0)
                             .color(color)
                     );
+                    if clickable {
+                        message = message.sense(egui::Sense::click());
+                    }
+                    let mut message = ui.add(message);
+                    if clickable {
+                        message = message.on_hover_cursor(egui::CursorIcon::PointingHand);
+                    }
+                    if row.is_merged() {
+                        message = message.on_hover_text(format!(
+                            "{} ticks from {:.1}s to {:.1}s",
+                            row.count, row.first_timestamp, entry.timestamp
+                        ));
+                    }
+                    if message.clicked() {
+                        log_focus.pending = LogFocusTarget::from_entry(entry);
+                    }
                 });
             }
         });
 }
 
 /// Render the timeline content (columnar ability visualization).
-fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icons: &SpellIcons) {
+fn render_timeline_content(
+    ui: &mut egui::Ui,
+    combat_log: &CombatLog,
+    spell_icons: &SpellIcons,
+    portraits: &ClassPortraits,
+    team_colors: &TeamColors,
+    aura_source: &AuraSourceHighlight,
+) {
     // Get all combatants and sort: Team 1 first, then Team 2
     let mut combatants = combat_log.all_combatants();
     combatants.sort_by(|a, b| {
@@ -171,6 +245,11 @@ fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icon
     };
     let total_width = TIMELINE_TIME_COLUMN_WIDTH + (num_combatants as f32 * combatant_column_width);
 
+    // Flash for the caster of a hovered aura icon (see aura_source)
+    let flash_alpha = (140.0 + 100.0 * (ui.input(|i| i.time) as f32 * 8.0).sin()) as u8;
+    let flash_color = egui::Color32::from_rgba_unmultiplied(255, 190, 50, flash_alpha);
+    let hovered_caster = aura_source.hovered.as_ref().map(|h| h.caster_id.as_str());
+
     // Fixed header row with combatant names
     ui.horizontal(|ui| {
         // Remove default spacing so headers align with painted columns
@@ -182,23 +261,31 @@ fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icon
         // Combatant column headers
         for combatant_id in &combatants {
             let short_name = shorten_combatant_name(combatant_id);
-            let team_color = if combatant_id.starts_with("Team 1") {
-                egui::Color32::from_rgb(100, 150, 255) // Blue
-            } else {
-                egui::Color32::from_rgb(255, 100, 100) // Red
-            };
+            let team_color = team_colors.for_combatant_id(combatant_id);
 
-            ui.allocate_ui_with_layout(
+            // Class portrait + short name, centered as a group in the column
+            let (rect, _) = ui.allocate_exact_size(
                 egui::vec2(combatant_column_width, 24.0),
-                egui::Layout::centered_and_justified(egui::Direction::TopDown),
-                |ui| {
-                    ui.label(
-                        egui::RichText::new(short_name)
-                            .size(12.0)
-                            .color(team_color)
-                            .strong()
-                    );
-                }
+                egui::Sense::hover()
+            );
+            if hovered_caster == Some(combatant_id.as_str()) {
+                ui.painter().rect_stroke(rect.shrink(1.0), 3.0, egui::Stroke::new(2.0, flash_color), egui::StrokeKind::Inside);
+            }
+            let galley = ui.painter().layout_no_wrap(short_name, egui::FontId::proportional(12.0), team_color);
+            let class = class_from_combatant_id(combatant_id);
+            let portrait_width = if class.is_some() { TIMELINE_HEADER_PORTRAIT_SIZE + 3.0 } else { 0.0 };
+            let left = rect.center().x - (portrait_width + galley.size().x) / 2.0;
+            if let Some(class) = class {
+                let portrait_rect = egui::Rect::from_center_size(
+                    egui::pos2(left + TIMELINE_HEADER_PORTRAIT_SIZE / 2.0, rect.center().y),
+                    egui::vec2(TIMELINE_HEADER_PORTRAIT_SIZE, TIMELINE_HEADER_PORTRAIT_SIZE)
+                );
+                paint_class_portrait(ui.painter(), portraits, class, portrait_rect);
+            }
+            ui.painter().galley(
+                egui::pos2(left + portrait_width, rect.center().y - galley.size().y / 2.0),
+                galley,
+                team_color
             );
         }
     });
@@ -266,6 +353,9 @@ fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icon
                 // First pass: calculate base y positions and detect overlaps
                 // We'll push overlapping icons down to avoid collision
                 let mut icon_positions: Vec<(f32, f32, &str, bool)> = Vec::new(); // (timestamp, adjusted_y, ability_name, interrupted)
+                let flashing_cast = aura_source.hovered.as_ref()
+                    .filter(|h| aura_source.matches_cast(combatant_id, &h.ability_name))
+                    .and_then(|h| applying_cast_index(&casts, &h.ability_name, current_time));
 
                 for (timestamp, ability_name, interrupted) in &casts {
                     let base_y = rect.min.y + TIMELINE_TOP_PADDING + timestamp * TIMELINE_PIXELS_PER_SECOND;
@@ -283,7 +373,7 @@ fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icon
                 }
 
                 // Second pass: draw icons at adjusted positions
-                for (timestamp, y, ability_name, interrupted) in icon_positions {
+                for (cast_idx, (timestamp, y, ability_name, interrupted)) in icon_positions.into_iter().enumerate() {
                     let icon_rect = egui::Rect::from_center_size(
                         egui::pos2(col_center_x, y),
                         egui::vec2(TIMELINE_ICON_SIZE, TIMELINE_ICON_SIZE)
@@ -335,6 +425,16 @@ fn render_timeline_content(ui: &mut egui::Ui, combat_log: &CombatLog, spell_icon
                         );
                     }
 
+                    // The cast that applied the hovered aura
+                    if flashing_cast == Some(cast_idx) {
+                        painter.rect_stroke(
+                            icon_rect.expand(3.0),
+                            4.0,
+                            egui::Stroke::new(3.0, flash_color),
+                            egui::StrokeKind::Outside,
+                        );
+                    }
+
                     // Check hover for tooltip
                     if let Some(hover_pos) = response.hover_pos() {
                         if icon_rect.contains(hover_pos) {
@@ -426,12 +526,17 @@ fn get_ability_abbreviation(ability: &str) -> &'static str {
     match ability {
         "Frostbolt" => "FB",
         "Frost Nova" => "FN",
+        "Ice Block" => "IB",
+        "Cold Snap" => "CS",
         "Flash Heal" => "FH",
         "Mind Blast" => "MB",
         "Power Word: Fortitude" => "PF",
         "Charge" => "CH",
+        "Intercept" => "IC",
+        "Intervene" => "IV",
         "Rend" => "RD",
         "Mortal Strike" => "MS",
+        "Execute" => "EX",
         "Heroic Strike" => "HS",
         "Ambush" => "AM",
         "Sinister Strike" => "SS",
@@ -439,9 +544,15 @@ fn get_ability_abbreviation(ability: &str) -> &'static str {
         "Corruption" => "CO",
         "Serpent Sting" => "ST",
         "Shadow Bolt" => "SB",
+        "Shadowburn" => "SH",
+        "Drain Soul" => "DS",
+        "Soulstone" => "SS",
         "Fear" => "FE",
         "Pummel" => "PM",
         "Kick" => "KI",
+        "Evasion" => "EV",
+        "Sprint" => "SP",
+        "Vanish" => "VA",
         _ => {
             // Return first 2 chars as fallback
             "??"
@@ -453,15 +564,15 @@ fn get_ability_abbreviation(ability: &str) -> &'static str {
 fn get_ability_icon_color(ability: &str) -> egui::Color32 {
     match ability {
         // Frost (blue)
-        "Frostbolt" | "Frost Nova" => egui::Color32::from_rgb(60, 120, 180),
+        "Frostbolt" | "Frost Nova" | "Ice Block" | "Cold Snap" => egui::Color32::from_rgb(60, 120, 180),
         // Holy (yellow/gold)
         "Flash Heal" | "Power Word: Fortitude" => egui::Color32::from_rgb(200, 180, 80),
         // Shadow (purple)
-        "Mind Blast" | "Shadow Bolt" | "Corruption" | "Fear" => egui::Color32::from_rgb(120, 80, 160),
+        "Mind Blast" | "Shadow Bolt" | "Corruption" | "Fear" | "Shadowburn" | "Drain Soul" | "Soulstone" => egui::Color32::from_rgb(120, 80, 160),
         // Physical (brown/orange)
-        "Charge" | "Rend" | "Mortal Strike" | "Heroic Strike" | "Pummel" => egui::Color32::from_rgb(160, 100, 60),
+        "Charge" | "Intercept" | "Intervene" | "Rend" | "Mortal Strike" | "Execute" | "Heroic Strike" | "Pummel" => egui::Color32::from_rgb(160, 100, 60),
         // Rogue (yellow)
-        "Ambush" | "Sinister Strike" | "Kidney Shot" | "Kick" => egui::Color32::from_rgb(180, 160, 60),
+        "Ambush" | "Sinister Strike" | "Kidney Shot" | "Kick" | "Evasion" | "Sprint" | "Vanish" => egui::Color32::from_rgb(180, 160, 60),
         // Nature (green)
         "Serpent Sting" => egui::Color32::from_rgb(100, 170, 60),
         // Default
@@ -0,0 +1,412 @@
+//! Diagnostics Overlay
+//!
+//! Debug panel (F9 by default) showing frame rate, simulation tick rate,
+//! live entity counts by category, combat log size and process memory.
+//! Meant for catching leaks: visual effects that never despawn show up as a
+//! category whose count keeps climbing over a long match, and is flagged once
+//! it has grown for `LEAK_STREAK_SAMPLES` samples in a row.
+//!
+//! Also shows egui's draw calls and vertices for the last frame, and how many
+//! overlay items each culled renderer drew and skipped (see `overlay_budget`).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::time::Real;
+use bevy_egui::{egui, EguiContexts, EguiRenderOutput};
+
+use crate::combat::log::{CombatLog, CombatLogEntry};
+use crate::states::play_match::components::*;
+use crate::states::play_match::effect_pool::Pooled;
+use super::overlay_budget::{OverlayCullStats, OverlayKind};
+use super::PresentationMode;
+
+/// Real seconds between samples (counting entities every frame is wasteful
+/// and makes the numbers unreadable)
+pub const DIAGNOSTICS_SAMPLE_SECS: f32 = 0.5;
+
+/// Consecutive growing samples before a category is flagged as a suspected
+/// leak (10 samples = 5s of uninterrupted growth)
+pub const LEAK_STREAK_SAMPLES: u32 = 10;
+
+/// Entity categories tracked by the overlay, in display order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntityCategory {
+    Combatants,
+    Pets,
+    Projectiles,
+    Particles,
+    Effects,
+    FloatingText,
+    Pooled,
+    MatchEntities,
+    Total,
+}
+
+impl EntityCategory {
+    pub const ALL: [EntityCategory; 9] = [
+        EntityCategory::Combatants,
+        EntityCategory::Pets,
+        EntityCategory::Projectiles,
+        EntityCategory::Particles,
+        EntityCategory::Effects,
+        EntityCategory::FloatingText,
+        EntityCategory::Pooled,
+        EntityCategory::MatchEntities,
+        EntityCategory::Total,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EntityCategory::Combatants => "Combatants",
+            EntityCategory::Pets => "Pets",
+            EntityCategory::Projectiles => "Projectiles",
+            EntityCategory::Particles => "Particles",
+            EntityCategory::Effects => "Effects",
+            EntityCategory::FloatingText => "Floating text",
+            EntityCategory::Pooled => "Pooled (idle)",
+            EntityCategory::MatchEntities => "Match entities",
+            EntityCategory::Total => "All entities",
+        }
+    }
+}
+
+/// Per-category count plus the bookkeeping for leak detection.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CategoryStats {
+    pub current: usize,
+    pub peak: usize,
+    /// Consecutive samples in which the count went up
+    pub growth_streak: u32,
+}
+
+impl CategoryStats {
+    /// Record a new sample. A flat sample keeps the streak (effects are often
+    /// spawned in bursts), a drop resets it.
+    pub fn record(&mut self, count: usize) {
+        if count > self.current {
+            self.growth_streak += 1;
+        } else if count < self.current {
+            self.growth_streak = 0;
+        }
+        self.current = count;
+        self.peak = self.peak.max(count);
+    }
+
+    pub fn suspected_leak(&self) -> bool {
+        self.growth_streak >= LEAK_STREAK_SAMPLES
+    }
+}
+
+/// State of the diagnostics overlay. Persists across matches so it stays
+/// open once toggled on; the stats are reset when a match starts.
+#[derive(Resource, Default)]
+pub struct DiagnosticsOverlay {
+    pub open: bool,
+    /// Real time accumulated since the last sample
+    pub window_real_secs: f32,
+    /// Virtual (sim) time accumulated since the last sample
+    pub window_sim_secs: f32,
+    /// Rendered frames since the last sample
+    pub window_frames: u32,
+    /// Frames since the last sample in which the simulation advanced
+    pub window_sim_ticks: u32,
+    pub fps: f32,
+    pub sim_ticks_per_sec: f32,
+    /// Sim seconds per real second (matches the speed setting unless the
+    /// frame rate can't keep up)
+    pub sim_rate: f32,
+    pub categories: [CategoryStats; EntityCategory::ALL.len()],
+    pub log_entries: usize,
+    pub log_bytes: usize,
+    pub process_rss_bytes: Option<u64>,
+    /// egui paint jobs (one draw call each) in the last rendered frame
+    pub egui_draw_calls: usize,
+    pub egui_vertices: usize,
+    pub overlay_culling: OverlayCullStats,
+}
+
+impl DiagnosticsOverlay {
+    pub fn stats(&self, category: EntityCategory) -> &CategoryStats {
+        &self.categories[category as usize]
+    }
+}
+
+/// Entities counted as projectiles
+type ProjectileFilter = Or<(With<Projectile>, With<TrapLaunchProjectile>)>;
+
+/// Entities counted as particles (short-lived per-spawn sprites)
+type ParticleFilter = Or<(With<FlameParticle>, With<DrainParticle>, With<DotDrip>)>;
+
+/// Entities counted as effects (bursts, trails, beams and bubbles that
+/// despawn on their own timer)
+type EffectFilter = Or<(
+    With<SpellImpactEffect>,
+    With<HealingLightColumn>,
+    With<DispelBurst>,
+    With<DispelRibbon>,
+    With<ScreamBurst>,
+    With<DeathCoilBurst>,
+    With<BacklashBurst>,
+    With<TrapBurst>,
+    With<DisengageTrail>,
+    With<ChargeTrail>,
+    With<WindfuryTornado>,
+    With<ShieldBubble>,
+    With<DrainLifeBeam>,
+    With<SpeechBubble>,
+)>;
+
+/// Entity counts for every category, bundled so the overlay system stays
+/// within a readable parameter list.
+#[derive(SystemParam)]
+pub struct EntityCensus<'w, 's> {
+    combatants: Query<'w, 's, (), (With<Combatant>, Without<Pet>)>,
+    pets: Query<'w, 's, (), With<Pet>>,
+    projectiles: Query<'w, 's, (), ProjectileFilter>,
+    particles: Query<'w, 's, (), ParticleFilter>,
+    effects: Query<'w, 's, (), EffectFilter>,
+    floating_text: Query<'w, 's, (), With<FloatingCombatText>>,
+    pooled: Query<'w, 's, (), With<Pooled>>,
+    match_entities: Query<'w, 's, (), With<PlayMatchEntity>>,
+    all: Query<'w, 's, Entity>,
+}
+
+impl EntityCensus<'_, '_> {
+    fn count(&self, category: EntityCategory) -> usize {
+        match category {
+            EntityCategory::Combatants => self.combatants.iter().count(),
+            EntityCategory::Pets => self.pets.iter().count(),
+            EntityCategory::Projectiles => self.projectiles.iter().count(),
+            EntityCategory::Particles => self.particles.iter().count(),
+            EntityCategory::Effects => self.effects.iter().count(),
+            EntityCategory::FloatingText => self.floating_text.iter().count(),
+            EntityCategory::Pooled => self.pooled.iter().count(),
+            EntityCategory::MatchEntities => self.match_entities.iter().count(),
+            EntityCategory::Total => self.all.iter().count(),
+        }
+    }
+}
+
+/// Approximate heap footprint of the combat log: the entry structs plus
+/// their message strings.
+pub fn combat_log_bytes(entries: &[CombatLogEntry]) -> usize {
+    std::mem::size_of_val(entries)
+        + entries.iter().map(|e| e.message.capacity()).sum::<usize>()
+}
+
+/// Draw calls and vertices of one egui frame's paint jobs.
+fn egui_paint_cost(output: &EguiRenderOutput) -> (usize, usize) {
+    let vertices = output
+        .paint_jobs
+        .iter()
+        .map(|job| match &job.primitive {
+            egui::epaint::Primitive::Mesh(mesh) => mesh.vertices.len(),
+            egui::epaint::Primitive::Callback(_) => 0,
+        })
+        .sum();
+    (output.paint_jobs.len(), vertices)
+}
+
+/// Resident set size of this process, where the platform exposes it cheaply.
+fn process_rss_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|l| l.starts_with("VmRSS:"))?;
+        let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kib * 1024)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const KIB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b >= KIB * KIB {
+        format!("{:.1} MiB", b / (KIB * KIB))
+    } else if b >= KIB {
+        format!("{:.1} KiB", b / KIB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Reset the overlay's counters at match start so peaks and leak streaks
+/// from the previous match don't carry over.
+pub fn reset_diagnostics_overlay(mut overlay: ResMut<DiagnosticsOverlay>) {
+    let open = overlay.open;
+    *overlay = DiagnosticsOverlay { open, ..default() };
+}
+
+/// Diagnostics overlay: toggles on its hotkey, samples every
+/// `DIAGNOSTICS_SAMPLE_SECS` and, while open, draws the panel at the top
+/// center of the screen.
+#[allow(clippy::too_many_arguments)]
+pub fn render_diagnostics_overlay(
+    mut contexts: EguiContexts,
+    mut overlay: ResMut<DiagnosticsOverlay>,
+    census: EntityCensus,
+    combat_log: Res<CombatLog>,
+    real_time: Res<Time<Real>>,
+    sim_time: Res<Time<Virtual>>,
+    presentation: Res<PresentationMode>,
+    cull_stats: Res<OverlayCullStats>,
+    egui_output: Query<&EguiRenderOutput>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if keybindings.action_just_pressed(GameAction::ToggleDiagnostics, &keyboard) {
+        overlay.open = !overlay.open;
+    }
+
+    // Sample even while closed so the leak streaks are meaningful the moment
+    // the overlay is opened mid-match
+    overlay.window_real_secs += real_time.delta_secs();
+    overlay.window_sim_secs += sim_time.delta_secs();
+    overlay.window_frames += 1;
+    if sim_time.delta_secs() > 0.0 {
+        overlay.window_sim_ticks += 1;
+    }
+    if overlay.window_real_secs >= DIAGNOSTICS_SAMPLE_SECS {
+        let window = overlay.window_real_secs;
+        overlay.fps = overlay.window_frames as f32 / window;
+        overlay.sim_ticks_per_sec = overlay.window_sim_ticks as f32 / window;
+        overlay.sim_rate = overlay.window_sim_secs / window;
+        overlay.window_real_secs = 0.0;
+        overlay.window_sim_secs = 0.0;
+        overlay.window_frames = 0;
+        overlay.window_sim_ticks = 0;
+
+        for category in EntityCategory::ALL {
+            let count = census.count(category);
+            overlay.categories[category as usize].record(count);
+        }
+        overlay.log_entries = combat_log.entries.len();
+        overlay.log_bytes = combat_log_bytes(&combat_log.entries);
+        let (draw_calls, vertices) = egui_output
+            .iter()
+            .map(egui_paint_cost)
+            .fold((0, 0), |(calls, verts), (c, v)| (calls + c, verts + v));
+        overlay.egui_draw_calls = draw_calls;
+        overlay.egui_vertices = vertices;
+        overlay.overlay_culling = *cull_stats;
+        if overlay.open {
+            overlay.process_rss_bytes = process_rss_bytes();
+        }
+    }
+
+    if !overlay.open || presentation.enabled {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let label_color = egui::Color32::from_rgb(170, 170, 180);
+    let value_color = egui::Color32::from_rgb(230, 230, 230);
+    let leak_color = egui::Color32::from_rgb(255, 150, 60);
+
+    egui::Window::new("Diagnostics")
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 10.0))
+        .resizable(false)
+        .collapsible(false)
+        .title_bar(false)
+        .frame(egui::Frame::window(&ctx.style())
+            .fill(egui::Color32::from_black_alpha(200))
+            .stroke(egui::Stroke::NONE))
+        .show(ctx, |ui| {
+            ui.set_width(230.0);
+            ui.label(egui::RichText::new("DIAGNOSTICS").size(12.0).strong().color(value_color));
+
+            egui::Grid::new("diagnostics_grid")
+                .num_columns(3)
+                .spacing(egui::vec2(10.0, 1.0))
+                .show(ui, |ui| {
+                    let row = |ui: &mut egui::Ui, label: &str, value: String, extra: String, color: egui::Color32| {
+                        ui.label(egui::RichText::new(label).size(11.0).color(label_color));
+                        ui.label(egui::RichText::new(value).size(11.0).monospace().color(color));
+                        ui.label(egui::RichText::new(extra).size(10.0).monospace().color(label_color));
+                        ui.end_row();
+                    };
+
+                    row(ui, "FPS", format!("{:.0}", overlay.fps), String::new(), value_color);
+                    row(
+                        ui,
+                        "Sim ticks/s",
+                        format!("{:.0}", overlay.sim_ticks_per_sec),
+                        format!("{:.2}x", overlay.sim_rate),
+                        value_color,
+                    );
+
+                    for category in EntityCategory::ALL {
+                        let stats = overlay.stats(category);
+                        let (color, extra) = if stats.suspected_leak() {
+                            (leak_color, format!("peak {} ▲", stats.peak))
+                        } else {
+                            (value_color, format!("peak {}", stats.peak))
+                        };
+                        row(ui, category.label(), stats.current.to_string(), extra, color);
+                    }
+
+                    row(
+                        ui,
+                        "Combat log",
+                        overlay.log_entries.to_string(),
+                        format_bytes(overlay.log_bytes as u64),
+                        value_color,
+                    );
+                    if let Some(rss) = overlay.process_rss_bytes {
+                        row(ui, "Process RSS", format_bytes(rss), String::new(), value_color);
+                    }
+
+                    row(
+                        ui,
+                        "egui draw calls",
+                        overlay.egui_draw_calls.to_string(),
+                        format!("{} verts", overlay.egui_vertices),
+                        value_color,
+                    );
+                    let culling = &overlay.overlay_culling;
+                    for kind in OverlayKind::ALL {
+                        row(
+                            ui,
+                            kind.label(),
+                            culling.drawn[kind as usize].to_string(),
+                            format!("culled {}", culling.culled[kind as usize]),
+                            value_color,
+                        );
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leak_flag_needs_uninterrupted_growth() {
+        let mut stats = CategoryStats::default();
+        for n in 1..LEAK_STREAK_SAMPLES as usize {
+            stats.record(n);
+        }
+        assert!(!stats.suspected_leak());
+
+        // A flat sample doesn't break the streak, one more rise trips it
+        stats.record(LEAK_STREAK_SAMPLES as usize - 1);
+        stats.record(LEAK_STREAK_SAMPLES as usize);
+        assert!(stats.suspected_leak());
+        assert_eq!(stats.peak, LEAK_STREAK_SAMPLES as usize);
+
+        // Effects despawning resets it; the peak is kept
+        stats.record(2);
+        assert!(!stats.suspected_leak());
+        assert_eq!(stats.current, 2);
+        assert_eq!(stats.peak, LEAK_STREAK_SAMPLES as usize);
+    }
+}
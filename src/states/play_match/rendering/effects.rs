@@ -10,10 +10,15 @@ use bevy::render::render_resource::PrimitiveTopology;
 use bevy_egui::{egui, EguiContexts};
 use crate::states::play_match::abilities::SpellSchool;
 use crate::states::play_match::components::*;
+use crate::states::play_match::effect_pool::EffectCommandsExt;
 use crate::states::play_match::constants::{
     ARENA_FLOOR_CORNER_CUT, ARENA_FLOOR_HALF_X, ARENA_FLOOR_HALF_Z,
 };
 use crate::states::match_config::CharacterClass;
+use super::overlay_budget::{
+    apply_overlay_budget, cull_overlay_anchor, OverlayCullStats, OverlayKind, FLOATING_TEXT_BUDGET,
+    OVERLAY_MAX_CAMERA_DISTANCE, SPEECH_BUBBLE_BUDGET,
+};
 
 // ==============================================================================
 // Floating Combat Text Systems
@@ -47,6 +52,7 @@ pub fn render_floating_combat_text(
     mut contexts: EguiContexts,
     floating_texts: Query<&FloatingCombatText>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut cull_stats: ResMut<OverlayCullStats>,
 ) {
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
@@ -55,123 +61,129 @@ pub fn render_floating_combat_text(
         return;
     };
 
+    // Project 3D positions to 2D screen space, culling off-screen and distant
+    // text, then keep the newest within the frame budget
+    let viewport = ctx.screen_rect();
+    let mut visible = Vec::new();
+    let mut culled = 0;
+    for fct in floating_texts.iter().filter(|fct| fct.lifetime > 0.0) {
+        match cull_overlay_anchor(camera, camera_transform, fct.world_position, viewport, OVERLAY_MAX_CAMERA_DISTANCE) {
+            Some(screen_pos) => visible.push((fct, screen_pos)),
+            None => culled += 1,
+        }
+    }
+    culled += apply_overlay_budget(&mut visible, FLOATING_TEXT_BUDGET, |(fct, _)| fct.lifetime);
+    cull_stats.set(OverlayKind::FloatingText, visible.len(), culled);
+
     egui::Area::new(egui::Id::new("floating_combat_text"))
         .fixed_pos(egui::pos2(0.0, 0.0))
         .show(ctx, |ui| {
-            for fct in floating_texts.iter() {
-                // Only render if still alive
-                if fct.lifetime <= 0.0 {
-                    continue;
-                }
-
-                // Project 3D position to 2D screen space
-                if let Ok(screen_pos) = camera.world_to_viewport(camera_transform, fct.world_position) {
-                    // Calculate alpha based on remaining lifetime
-                    // Fade out in the last 0.5 seconds
-                    let alpha = if fct.lifetime < 0.5 {
-                        (fct.lifetime / 0.5 * 255.0) as u8
-                    } else {
-                        255
-                    };
-
-                    // Apply alpha to color
-                    let color_with_alpha = egui::Color32::from_rgba_unmultiplied(
-                        fct.color.r(),
-                        fct.color.g(),
-                        fct.color.b(),
-                        alpha,
-                    );
-                    let outline_color = egui::Color32::from_rgba_unmultiplied(0, 0, 0, alpha);
-
-                    // Check if this is absorbed text - render number and label separately
-                    if let Some(number_str) = fct.text.strip_suffix(" absorbed") {
-                        // Render number at 24pt
-                        let number_font = egui::FontId::proportional(24.0);
-                        let label_font = egui::FontId::proportional(14.0);
-
-                        // Calculate positions - number centered, label to the right
-                        let number_galley = ui.painter().layout_no_wrap(number_str.to_string(), number_font.clone(), color_with_alpha);
-                        let label_galley = ui.painter().layout_no_wrap("absorbed".to_string(), label_font.clone(), color_with_alpha);
-                        let total_width = number_galley.size().x + 4.0 + label_galley.size().x;
-                        let number_x = screen_pos.x - total_width / 2.0 + number_galley.size().x / 2.0;
-                        let label_x = number_x + number_galley.size().x / 2.0 + 4.0 + label_galley.size().x / 2.0;
-
-                        // Draw number outline
-                        for (dx, dy) in [
-                            (-2.0, 0.0), (2.0, 0.0), (0.0, -2.0), (0.0, 2.0),
-                            (-1.5, -1.5), (1.5, -1.5), (-1.5, 1.5), (1.5, 1.5),
-                        ] {
-                            ui.painter().text(
-                                egui::pos2(number_x + dx, screen_pos.y + dy),
-                                egui::Align2::CENTER_CENTER,
-                                number_str,
-                                number_font.clone(),
-                                outline_color,
-                            );
-                        }
-                        // Draw number
+            for (fct, screen_pos) in visible {
+                // Calculate alpha based on remaining lifetime
+                // Fade out in the last 0.5 seconds
+                let alpha = if fct.lifetime < 0.5 {
+                    (fct.lifetime / 0.5 * 255.0) as u8
+                } else {
+                    255
+                };
+
+                // Apply alpha to color
+                let color_with_alpha = egui::Color32::from_rgba_unmultiplied(
+                    fct.color.r(),
+                    fct.color.g(),
+                    fct.color.b(),
+                    alpha,
+                );
+                let outline_color = egui::Color32::from_rgba_unmultiplied(0, 0, 0, alpha);
+
+                // Check if this is absorbed text - render number and label separately
+                if let Some(number_str) = fct.text.strip_suffix(" absorbed") {
+                    // Render number at 24pt
+                    let number_font = egui::FontId::proportional(24.0);
+                    let label_font = egui::FontId::proportional(14.0);
+
+                    // Calculate positions - number centered, label to the right
+                    let number_galley = ui.painter().layout_no_wrap(number_str.to_string(), number_font.clone(), color_with_alpha);
+                    let label_galley = ui.painter().layout_no_wrap("absorbed".to_string(), label_font.clone(), color_with_alpha);
+                    let total_width = number_galley.size().x + 4.0 + label_galley.size().x;
+                    let number_x = screen_pos.x - total_width / 2.0 + number_galley.size().x / 2.0;
+                    let label_x = number_x + number_galley.size().x / 2.0 + 4.0 + label_galley.size().x / 2.0;
+
+                    // Draw number outline
+                    for (dx, dy) in [
+                        (-2.0, 0.0), (2.0, 0.0), (0.0, -2.0), (0.0, 2.0),
+                        (-1.5, -1.5), (1.5, -1.5), (-1.5, 1.5), (1.5, 1.5),
+                    ] {
                         ui.painter().text(
-                            egui::pos2(number_x, screen_pos.y),
+                            egui::pos2(number_x + dx, screen_pos.y + dy),
                             egui::Align2::CENTER_CENTER,
                             number_str,
-                            number_font,
-                            color_with_alpha,
+                            number_font.clone(),
+                            outline_color,
                         );
+                    }
+                    // Draw number
+                    ui.painter().text(
+                        egui::pos2(number_x, screen_pos.y),
+                        egui::Align2::CENTER_CENTER,
+                        number_str,
+                        number_font,
+                        color_with_alpha,
+                    );
 
-                        // Draw label outline (smaller offset for smaller text)
-                        for (dx, dy) in [
-                            (-1.5, 0.0), (1.5, 0.0), (0.0, -1.5), (0.0, 1.5),
-                            (-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0),
-                        ] {
-                            ui.painter().text(
-                                egui::pos2(label_x + dx, screen_pos.y + 2.0 + dy),
-                                egui::Align2::CENTER_CENTER,
-                                "absorbed",
-                                label_font.clone(),
-                                outline_color,
-                            );
-                        }
-                        // Draw label (slightly lower to align with number baseline)
+                    // Draw label outline (smaller offset for smaller text)
+                    for (dx, dy) in [
+                        (-1.5, 0.0), (1.5, 0.0), (0.0, -1.5), (0.0, 1.5),
+                        (-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0),
+                    ] {
                         ui.painter().text(
-                            egui::pos2(label_x, screen_pos.y + 2.0),
+                            egui::pos2(label_x + dx, screen_pos.y + 2.0 + dy),
                             egui::Align2::CENTER_CENTER,
                             "absorbed",
-                            label_font,
-                            color_with_alpha,
+                            label_font.clone(),
+                            outline_color,
                         );
+                    }
+                    // Draw label (slightly lower to align with number baseline)
+                    ui.painter().text(
+                        egui::pos2(label_x, screen_pos.y + 2.0),
+                        egui::Align2::CENTER_CENTER,
+                        "absorbed",
+                        label_font,
+                        color_with_alpha,
+                    );
+                } else {
+                    // Regular text - 32pt for crits, 24pt for normal
+                    let font_size = if fct.is_crit { 32.0 } else { 24.0 };
+                    let display_text = if fct.is_crit {
+                        format!("{}!", fct.text)
                     } else {
-                        // Regular text - 32pt for crits, 24pt for normal
-                        let font_size = if fct.is_crit { 32.0 } else { 24.0 };
-                        let display_text = if fct.is_crit {
-                            format!("{}!", fct.text)
-                        } else {
-                            fct.text.clone()
-                        };
-                        let font_id = egui::FontId::proportional(font_size);
-
-                        // Draw thick black outline (8 directions for smooth outline)
-                        for (dx, dy) in [
-                            (-2.0, 0.0), (2.0, 0.0), (0.0, -2.0), (0.0, 2.0),
-                            (-1.5, -1.5), (1.5, -1.5), (-1.5, 1.5), (1.5, 1.5),
-                        ] {
-                            ui.painter().text(
-                                egui::pos2(screen_pos.x + dx, screen_pos.y + dy),
-                                egui::Align2::CENTER_CENTER,
-                                &display_text,
-                                font_id.clone(),
-                                outline_color,
-                            );
-                        }
-
-                        // Draw main text
+                        fct.text.clone()
+                    };
+                    let font_id = egui::FontId::proportional(font_size);
+
+                    // Draw thick black outline (8 directions for smooth outline)
+                    for (dx, dy) in [
+                        (-2.0, 0.0), (2.0, 0.0), (0.0, -2.0), (0.0, 2.0),
+                        (-1.5, -1.5), (1.5, -1.5), (-1.5, 1.5), (1.5, 1.5),
+                    ] {
                         ui.painter().text(
-                            egui::pos2(screen_pos.x, screen_pos.y),
+                            egui::pos2(screen_pos.x + dx, screen_pos.y + dy),
                             egui::Align2::CENTER_CENTER,
                             &display_text,
-                            font_id,
-                            color_with_alpha,
+                            font_id.clone(),
+                            outline_color,
                         );
                     }
+
+                    // Draw main text
+                    ui.painter().text(
+                        egui::pos2(screen_pos.x, screen_pos.y),
+                        egui::Align2::CENTER_CENTER,
+                        &display_text,
+                        font_id,
+                        color_with_alpha,
+                    );
                 }
             }
         });
@@ -186,7 +198,7 @@ pub fn cleanup_expired_floating_text(
 ) {
     for (entity, fct) in floating_texts.iter() {
         if fct.lifetime <= 0.0 {
-            commands.entity(entity).despawn();
+            commands.release_effect::<FloatingCombatText>(entity);
         }
     }
 }
@@ -195,21 +207,43 @@ pub fn cleanup_expired_floating_text(
 // Spell Impact Visual Effects Systems
 // ==============================================================================
 
+/// Material handle a recycled effect entity already carries.
+type StandardMaterialHandle = MeshMaterial3d<StandardMaterial>;
+
 /// Spawn visual meshes for newly created spell impact effects.
+/// All impacts share one unit sphere (scaled per effect); each gets its own
+/// material since it fades individually. Impacts recycled from the effect
+/// pool already carry both and are only moved into place.
 pub fn spawn_spell_impact_visuals(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    new_effects: Query<(Entity, &SpellImpactEffect), (Added<SpellImpactEffect>, Without<Mesh3d>)>,
-) {
-    for (effect_entity, effect) in new_effects.iter() {
-        // Create a sphere mesh
-        let mesh = meshes.add(Sphere::new(effect.initial_scale));
+    mut sphere: Local<Option<Handle<Mesh>>>,
+    new_effects: Query<(Entity, &SpellImpactEffect, Option<&StandardMaterialHandle>), Added<SpellImpactEffect>>,
+) {
+    for (effect_entity, effect, recycled_material) in new_effects.iter() {
+        // Centered at chest height, at the size of the first
+        // `update_spell_impact_effects` frame
+        let transform = Transform::from_translation(effect.position + Vec3::new(0.0, 1.0, 0.0))
+            .with_scale(Vec3::splat(effect.initial_scale * effect.initial_scale));
+
+        // Descriptor color with emissive glow and transparency
+        let base_color = effect.color.with_alpha(0.8);
+
+        if let Some(material_handle) = recycled_material {
+            if let Some(material) = materials.get_mut(&material_handle.0) {
+                material.base_color = base_color;
+                material.emissive = effect.emissive;
+            }
+            commands.entity(effect_entity).try_insert(transform);
+            continue;
+        }
+
+        let mesh = sphere.get_or_insert_with(|| meshes.add(Sphere::new(1.0))).clone();
 
-        // Purple/shadow color with emissive glow and transparency
         let material = materials.add(StandardMaterial {
-            base_color: Color::srgba(0.5, 0.2, 0.8, 0.8), // Purple with alpha
-            emissive: LinearRgba::rgb(0.8, 0.3, 1.5), // Bright purple/magenta glow
+            base_color,
+            emissive: effect.emissive,
             alpha_mode: AlphaMode::Blend,
             ..default()
         });
@@ -220,7 +254,7 @@ pub fn spawn_spell_impact_visuals(
         commands.entity(effect_entity).try_insert((
             Mesh3d(mesh),
             MeshMaterial3d(material),
-            Transform::from_translation(effect.position + Vec3::new(0.0, 1.0, 0.0)), // Centered at chest height
+            transform,
         ));
     }
 }
@@ -243,14 +277,15 @@ pub fn update_spell_impact_effects(
         // Calculate progress (1.0 = just spawned, 0.0 = expired)
         let progress = effect.lifetime / effect.initial_lifetime;
 
-        // Scale: expand from initial to final
+        // Scale: expand from initial to final (the mesh is a unit sphere, so
+        // the initial scale also stands in for its radius)
         let current_scale = effect.initial_scale + (effect.final_scale - effect.initial_scale) * (1.0 - progress);
-        transform.scale = Vec3::splat(current_scale);
+        transform.scale = Vec3::splat(current_scale * effect.initial_scale);
 
         // Fade out: alpha goes from 1.0 to 0.0
         if let Some(material) = materials.get_mut(&material_handle.0) {
             let alpha = progress * 0.8; // Max alpha 0.8 for translucency
-            material.base_color = Color::srgba(0.5, 0.2, 0.8, alpha);
+            material.base_color = effect.color.with_alpha(alpha);
             material.alpha_mode = AlphaMode::Blend;
         }
     }
@@ -263,7 +298,7 @@ pub fn cleanup_expired_spell_impacts(
 ) {
     for (entity, effect) in effects.iter() {
         if effect.lifetime <= 0.0 {
-            commands.entity(entity).despawn();
+            commands.release_effect::<SpellImpactEffect>(entity);
         }
     }
 }
@@ -278,6 +313,7 @@ pub fn render_speech_bubbles(
     speech_bubbles: Query<&SpeechBubble>,
     combatants: Query<&Transform, With<Combatant>>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut cull_stats: ResMut<OverlayCullStats>,
 ) {
     let Ok((camera, camera_transform)) = camera_query.single() else {
         return;
@@ -286,6 +322,12 @@ pub fn render_speech_bubbles(
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
 
+    // Project to screen space, culling off-screen and distant bubbles, then
+    // keep the nearest within the frame budget
+    let viewport = ctx.screen_rect();
+    let camera_pos = camera_transform.translation();
+    let mut visible = Vec::new();
+    let mut culled = 0;
     for bubble in speech_bubbles.iter() {
         // Get owner's position
         let Ok(owner_transform) = combatants.get(bubble.owner) else {
@@ -294,12 +336,15 @@ pub fn render_speech_bubbles(
 
         // Position above the combatant's head
         let bubble_world_pos = owner_transform.translation + Vec3::new(0.0, 4.0, 0.0);
+        match cull_overlay_anchor(camera, camera_transform, bubble_world_pos, viewport, OVERLAY_MAX_CAMERA_DISTANCE) {
+            Some(screen_pos) => visible.push((bubble, screen_pos, camera_pos.distance(bubble_world_pos))),
+            None => culled += 1,
+        }
+    }
+    culled += apply_overlay_budget(&mut visible, SPEECH_BUBBLE_BUDGET, |&(_, _, distance)| -distance);
+    cull_stats.set(OverlayKind::SpeechBubbles, visible.len(), culled);
 
-        // Project to screen space
-        let Ok(screen_pos) = camera.world_to_viewport(camera_transform, bubble_world_pos) else {
-            continue;
-        };
-
+    for (bubble, screen_pos, _) in visible {
         // Measure text to make bubble fit snugly
         let font_id = egui::FontId::proportional(14.0);
         let galley = ctx.fonts(|f| f.layout_no_wrap(bubble.text.clone(), font_id.clone(), egui::Color32::BLACK));
@@ -553,7 +598,8 @@ pub fn update_polymorph_visuals(
 // Flame Particle Visual Effects (Immolate)
 // ==============================================================================
 
-/// Update flame particles: move upward, shrink, and despawn when expired.
+/// Update flame particles: move upward, shrink, and return to the effect pool
+/// when expired.
 pub fn update_flame_particles(
     mut commands: Commands,
     time: Res<Time>,
@@ -565,7 +611,7 @@ pub fn update_flame_particles(
         particle.lifetime -= dt;
 
         if particle.lifetime <= 0.0 {
-            commands.entity(entity).despawn();
+            commands.release_effect::<FlameParticle>(entity);
             continue;
         }
 
@@ -579,24 +625,31 @@ pub fn update_flame_particles(
 }
 
 /// Spawn visual meshes for newly created flame particles.
-/// Creates small glowing orange/red spheres.
+/// Creates small glowing orange/red spheres. Every flame shares one mesh and
+/// material; particles recycled from the effect pool keep theirs and are
+/// skipped here.
 pub fn spawn_flame_visuals(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    new_particles: Query<(Entity, &FlameParticle), (Added<FlameParticle>, Without<Mesh3d>)>,
-) {
-    for (entity, _particle) in new_particles.iter() {
-        // Create a small sphere mesh for the flame particle
-        let mesh = meshes.add(Sphere::new(0.15));
-
-        // Fire colors - orange base with bright emissive glow
-        let material = materials.add(StandardMaterial {
-            base_color: Color::srgba(1.0, 0.4, 0.1, 0.9),
-            emissive: LinearRgba::rgb(2.0, 0.8, 0.1),  // Bright orange glow
-            alpha_mode: AlphaMode::Blend,
-            ..default()
-        });
+    mut shared: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
+    new_particles: Query<Entity, (Added<FlameParticle>, Without<Mesh3d>)>,
+) {
+    for entity in new_particles.iter() {
+        let (mesh, material) = shared
+            .get_or_insert_with(|| {
+                (
+                    meshes.add(Sphere::new(0.15)),
+                    // Fire colors - orange base with bright emissive glow
+                    materials.add(StandardMaterial {
+                        base_color: Color::srgba(1.0, 0.4, 0.1, 0.9),
+                        emissive: LinearRgba::rgb(2.0, 0.8, 0.1),  // Bright orange glow
+                        alpha_mode: AlphaMode::Blend,
+                        ..default()
+                    }),
+                )
+            })
+            .clone();
 
         // Add visual mesh to the particle entity
         commands.entity(entity).try_insert((
@@ -610,22 +663,24 @@ pub fn spawn_flame_visuals(
 // Drain Life Beam Visual Effects
 // ==============================================================================
 
-use crate::states::play_match::abilities::AbilityType;
+use crate::states::play_match::ability_config::AbilityDefinitions;
 
-/// Spawn Drain Life beams when a combatant starts channeling Drain Life.
-/// Detects newly added ChannelingState components with DrainLife ability.
+/// Spawn channel beams when a combatant starts channeling an ability with a
+/// `visuals.beam` descriptor (Drain Life, Drain Soul).
+/// Detects newly added ChannelingState components.
 pub fn spawn_drain_life_beams(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    abilities: Res<AbilityDefinitions>,
     new_channels: Query<(Entity, &ChannelingState), Added<ChannelingState>>,
     existing_beams: Query<&DrainLifeBeam>,
 ) {
     for (caster_entity, channeling) in new_channels.iter() {
-        // Only create beam for Drain Life
-        if channeling.ability != AbilityType::DrainLife {
+        // Only channels with a beam descriptor get one
+        let Some(beam_visual) = abilities.get(&channeling.ability).and_then(|d| d.visuals.beam.as_ref()) else {
             continue;
-        }
+        };
 
         // Check if beam already exists for this caster (avoid duplicates)
         let beam_exists = existing_beams.iter().any(|beam| beam.caster == caster_entity);
@@ -635,12 +690,14 @@ pub fn spawn_drain_life_beams(
 
         // Create cylinder mesh for the beam
         // Cylinder height is 1.0 by default, we'll scale it to match distance
-        let mesh = meshes.add(Cylinder::new(0.15, 1.0));
+        let mesh = meshes.add(Cylinder::new(beam_visual.width, 1.0));
 
-        // Purple shadow color with bright emissive glow
+        // Descriptor color, translucent, with emissive glow
+        let [r, g, b] = beam_visual.color;
+        let [er, eg, eb] = beam_visual.emissive;
         let material = materials.add(StandardMaterial {
-            base_color: Color::srgba(0.7, 0.3, 0.9, 0.8),
-            emissive: LinearRgba::rgb(3.0, 1.0, 4.0),
+            base_color: Color::srgba(r, g, b, 0.8),
+            emissive: LinearRgba::rgb(er, eg, eb),
             alpha_mode: AlphaMode::Blend,
             ..default()
         });
@@ -653,6 +710,8 @@ pub fn spawn_drain_life_beams(
             DrainLifeBeam {
                 caster: caster_entity,
                 target: channeling.target,
+                ability: channeling.ability,
+                particles: beam_visual.particles,
                 particle_spawn_timer: 0.0,
             },
             PlayMatchEntity,
@@ -702,10 +761,9 @@ pub fn update_drain_life_beams(
 }
 
 /// Spawn particles along the Drain Life beam at regular intervals.
+/// Their meshes are attached by `spawn_drain_visuals`.
 pub fn spawn_drain_particles(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     time: Res<Time>,
     mut beams: Query<(Entity, &mut DrainLifeBeam, &Transform)>,
     positions: Query<&Transform, (With<Combatant>, Without<DrainLifeBeam>)>,
@@ -713,6 +771,10 @@ pub fn spawn_drain_particles(
     let dt = time.delta_secs();
 
     for (beam_entity, mut beam, _beam_transform) in beams.iter_mut() {
+        if !beam.particles {
+            continue;
+        }
+
         // Decrement spawn timer
         beam.particle_spawn_timer -= dt;
 
@@ -727,33 +789,52 @@ pub fn spawn_drain_particles(
 
             let particle_pos = target_transform.translation + Vec3::Y * 0.5;
 
-            // Create sphere mesh for particle
-            let mesh = meshes.add(Sphere::new(0.18));
-
-            // Bright purple/magenta with strong emissive glow
-            let material = materials.add(StandardMaterial {
-                base_color: Color::srgba(0.9, 0.5, 1.0, 1.0),
-                emissive: LinearRgba::rgb(4.0, 2.0, 5.0),
-                alpha_mode: AlphaMode::Blend,
-                ..default()
-            });
-
             // Spawn particle at target position (progress = 0.0)
-            commands.spawn((
-                Mesh3d(mesh),
-                MeshMaterial3d(material),
-                Transform::from_translation(particle_pos),
+            commands.spawn_effect_at(
                 DrainParticle {
                     progress: 0.0,
                     speed: 0.4, // ~2.5 second travel time
                     beam: beam_entity,
                 },
-                PlayMatchEntity,
-            ));
+                Transform::from_translation(particle_pos),
+            );
         }
     }
 }
 
+/// Spawn visual meshes for newly created Drain particles. Mirrors
+/// `spawn_flame_visuals`: one shared mesh and material, and recycled
+/// particles keep theirs.
+pub fn spawn_drain_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut shared: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
+    new_particles: Query<Entity, (Added<DrainParticle>, Without<Mesh3d>)>,
+) {
+    for entity in new_particles.iter() {
+        let (mesh, material) = shared
+            .get_or_insert_with(|| {
+                (
+                    meshes.add(Sphere::new(0.18)),
+                    // Bright purple/magenta with strong emissive glow
+                    materials.add(StandardMaterial {
+                        base_color: Color::srgba(0.9, 0.5, 1.0, 1.0),
+                        emissive: LinearRgba::rgb(4.0, 2.0, 5.0),
+                        alpha_mode: AlphaMode::Blend,
+                        ..default()
+                    }),
+                )
+            })
+            .clone();
+
+        commands.entity(entity).try_insert((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+        ));
+    }
+}
+
 /// Move Drain particles along the beam from target to caster.
 pub fn update_drain_particles(
     mut commands: Commands,
@@ -768,26 +849,26 @@ pub fn update_drain_particles(
         // Get the beam this particle belongs to
         let Ok(beam) = beams.get(particle.beam) else {
             // Beam was despawned, remove particle
-            commands.entity(entity).despawn();
+            commands.release_effect::<DrainParticle>(entity);
             continue;
         };
 
         // Increment progress
         particle.progress += particle.speed * dt;
 
-        // Despawn when reached caster
+        // Release when reached caster
         if particle.progress >= 1.0 {
-            commands.entity(entity).despawn();
+            commands.release_effect::<DrainParticle>(entity);
             continue;
         }
 
         // Get caster and target positions
         let Ok(caster_transform) = positions.get(beam.caster) else {
-            commands.entity(entity).despawn();
+            commands.release_effect::<DrainParticle>(entity);
             continue;
         };
         let Ok(target_transform) = positions.get(beam.target) else {
-            commands.entity(entity).despawn();
+            commands.release_effect::<DrainParticle>(entity);
             continue;
         };
 
@@ -800,7 +881,7 @@ pub fn update_drain_particles(
     }
 }
 
-/// Cleanup Drain Life beams when the channel ends or is interrupted.
+/// Cleanup channel beams when the channel ends or is interrupted.
 pub fn cleanup_drain_life_beams(
     mut commands: Commands,
     beams: Query<(Entity, &DrainLifeBeam)>,
@@ -811,14 +892,14 @@ pub fn cleanup_drain_life_beams(
         // Check if caster still has a Drain Life channel active
         let still_channeling = channeling_query
             .get(beam.caster)
-            .map(|c| c.ability == AbilityType::DrainLife && !c.interrupted)
+            .map(|c| c.ability == beam.ability && !c.interrupted)
             .unwrap_or(false);
 
         if !still_channeling {
             // Despawn all particles belonging to this beam
             for (particle_entity, particle) in particles.iter() {
                 if particle.beam == beam_entity {
-                    commands.entity(particle_entity).despawn();
+                    commands.release_effect::<DrainParticle>(particle_entity);
                 }
             }
 
@@ -1420,6 +1501,74 @@ pub fn cleanup_expired_windfury_tornados(
     }
 }
 
+// ==============================================================================
+// Ground Decals
+// ==============================================================================
+
+/// Peak opacity of a ground decal, before the lifetime fade.
+const GROUND_DECAL_ALPHA: f32 = 0.6;
+
+/// Ground decals that don't have their disc mesh yet.
+type NewGroundDecalFilter = (Added<GroundDecal>, Without<Mesh3d>);
+
+/// Attach a flat disc mesh when a `GroundDecal` marker is spawned (at the
+/// impact site, in core). Graphical-only — registered solely in `states/mod.rs`.
+pub fn spawn_ground_decal_visuals(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    new_decals: Query<(Entity, &GroundDecal), NewGroundDecalFilter>,
+) {
+    for (entity, decal) in new_decals.iter() {
+        let mesh = meshes.add(Circle::new(decal.radius));
+        // Unlit so the scorch mark reads the same under any arena lighting
+        let material = materials.add(StandardMaterial {
+            base_color: decal.color.with_alpha(GROUND_DECAL_ALPHA),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        });
+
+        // Circle is built in the XY plane; lay it flat just above the floor
+        let position = Vec3::new(decal.position.x, 0.02, decal.position.z);
+        commands.entity(entity).try_insert((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_translation(position)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        ));
+    }
+}
+
+/// Count down ground decals and fade them out over their lifetime.
+pub fn update_ground_decals(
+    time: Res<Time>,
+    mut decals: Query<(&mut GroundDecal, &MeshMaterial3d<StandardMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (mut decal, material_handle) in decals.iter_mut() {
+        decal.lifetime -= time.delta_secs();
+        let progress = (decal.lifetime / decal.initial_lifetime).clamp(0.0, 1.0);
+
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.base_color = decal.color.with_alpha(GROUND_DECAL_ALPHA * progress);
+        }
+    }
+}
+
+/// Despawn expired ground decals.
+pub fn cleanup_expired_ground_decals(
+    mut commands: Commands,
+    decals: Query<(Entity, &GroundDecal)>,
+) {
+    for (entity, decal) in decals.iter() {
+        if decal.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 #[cfg(test)]
 mod dispel_ribbon_mesh_tests {
     use super::*;
@@ -1976,7 +2125,7 @@ pub fn update_ice_blocks(
     }
 }
 
-/// Cleanup ice blocks when the Incapacitate aura breaks or target dies.
+/// Cleanup ice blocks when the Incapacitate / IceBlock aura ends or target dies.
 pub fn cleanup_ice_blocks(
     mut commands: Commands,
     time: Res<Time>,
@@ -1996,9 +2145,12 @@ pub fn cleanup_ice_blocks(
                 if !combatant.is_alive() {
                     true
                 } else {
-                    // Despawn if target no longer has Incapacitate aura
+                    // Despawn if target is no longer frozen (Freezing Trap) or
+                    // encased (Mage Ice Block)
                     auras.map_or(true, |a| {
-                        !a.auras.iter().any(|aura| aura.effect_type == AuraType::Incapacitate)
+                        !a.auras.iter().any(|aura| {
+                            matches!(aura.effect_type, AuraType::Incapacitate | AuraType::IceBlock)
+                        })
                     })
                 }
             }
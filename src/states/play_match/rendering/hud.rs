@@ -1,13 +1,21 @@
 //! HUD Rendering Systems
 //!
-//! Health bars, resource bars, cast bars, and time controls.
+//! Health bars (clamped to the screen, with edge arrows for off-screen
+//! combatants), resource bars, cast bars, kill feed, and time controls.
 
 use bevy::prelude::*;
 use bevy::time::Real;
 use bevy_egui::{egui, EguiContexts};
+use crate::settings::TeamColors;
+use crate::states::match_config::CharacterClass;
+use crate::combat::log::{CombatLog, StructuredEventData};
+use crate::states::play_match::constants::{MAX_SOUL_SHARDS, PET_SLOT_BASE};
+use crate::states::play_match::utils::{class_from_combatant_id, combatant_id};
+use crate::states::play_match::aura_source::{AuraSourceHighlight, HoveredAura};
 use crate::states::play_match::ability_config::AbilityDefinitions;
 use crate::states::play_match::components::*;
-use super::{get_aura_icon_key, is_buff_aura, AURA_ICON_SIZE, AURA_ICON_SPACING};
+use super::overlay_budget::{OverlayCullStats, OverlayKind, AURA_ICON_BUDGET, AURA_ICON_MAX_CAMERA_DISTANCE};
+use super::{get_aura_icon_key, is_buff_aura, paint_class_portrait, ClassPortraits, PresentationMode, AURA_ICON_SIZE, AURA_ICON_SPACING};
 
 // ==============================================================================
 // Zoom Scaling Constants
@@ -20,6 +28,20 @@ const MIN_UI_SCALE: f32 = 0.5;
 /// Maximum scale factor (prevents UI from getting too large when zoomed in)
 const MAX_UI_SCALE: f32 = 1.5;
 
+// ==============================================================================
+// Off-Screen Nameplate Constants
+// ==============================================================================
+
+/// Nameplates are kept at least this far (before zoom scaling) inside the screen edge
+const NAMEPLATE_EDGE_MARGIN: f32 = 40.0;
+/// How far past the edge a nameplate can be pulled back in, fading as it goes,
+/// before it is replaced by an edge arrow
+const NAMEPLATE_FADE_DISTANCE: f32 = 120.0;
+/// Edge arrows sit this far inside the screen edge
+const OFFSCREEN_INDICATOR_INSET: f32 = 28.0;
+/// Length of an edge arrow, tip to base
+const OFFSCREEN_ARROW_SIZE: f32 = 14.0;
+
 // ==============================================================================
 // Low HP Highlighting Constants
 // ==============================================================================
@@ -45,6 +67,7 @@ pub fn render_time_controls(
     mut sim_speed: ResMut<SimulationSpeed>,
     mut time: ResMut<Time<Virtual>>,
     mut display_settings: ResMut<DisplaySettings>,
+    presentation: Res<PresentationMode>,
     keybindings: Res<crate::keybindings::Keybindings>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
@@ -55,6 +78,9 @@ pub fn render_time_controls(
         display_settings.show_aura_icons = !display_settings.show_aura_icons;
         info!("Aura icons toggled to: {}", display_settings.show_aura_icons);
     }
+    if presentation.enabled {
+        return;
+    }
 
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
@@ -171,6 +197,16 @@ pub fn render_time_controls(
                         .color(egui::Color32::from_rgb(200, 200, 200))
                 );
             });
+
+            // Win odds bar toggle
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut display_settings.show_win_odds, "");
+                ui.label(
+                    egui::RichText::new("Win odds")
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(200, 200, 200))
+                );
+            });
         });
 }
 
@@ -189,15 +225,21 @@ pub fn render_time_controls(
 ///   - Rage (red): Warriors - starts at 0, builds from attacks and taking damage
 /// - **Cast bar** (when casting): Orange bar with spell name showing cast progress
 /// - **Channel bar** (when channeling): Green bar that drains down as channel progresses
+#[allow(clippy::too_many_arguments)]
 pub fn render_health_bars(
     mut contexts: EguiContexts,
     abilities: Res<AbilityDefinitions>,
-    combatants: Query<(&Combatant, &Transform, Option<&CastingState>, Option<&ChannelingState>, Option<&ActiveAuras>)>,
+    combatants: Query<(Entity, &Combatant, &Transform, Option<&CastingState>, Option<&ChannelingState>, Option<&ActiveAuras>)>,
+    casters: Query<(&Combatant, Option<&Pet>)>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
     time: Res<Time<Real>>,
     spell_icons: Res<SpellIcons>,
     camera_controller: Res<CameraController>,
     display_settings: Res<DisplaySettings>,
+    portraits: Res<ClassPortraits>,
+    team_colors: Res<TeamColors>,
+    mut aura_source: ResMut<AuraSourceHighlight>,
+    mut cull_stats: ResMut<OverlayCullStats>,
 ) {
     // Use try_ctx_mut to gracefully handle window close
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
@@ -206,6 +248,15 @@ pub fn render_health_bars(
         return;
     };
 
+    // Aura icon under the cursor, found while drawing the icons below
+    let hover_pos = ctx.pointer_hover_pos();
+    let mut hovered_aura: Option<HoveredAura> = None;
+
+    // Combatants too far off screen for a nameplate get an edge arrow instead
+    let viewport = ctx.screen_rect();
+    let mut offscreen: Vec<(egui::Pos2, egui::Vec2, &Combatant)> = Vec::new();
+    let (mut nameplates_drawn, mut auras_drawn, mut auras_culled) = (0, 0, 0);
+
     // Calculate UI scale based on camera zoom distance
     // When zoomed out (larger distance), UI elements get smaller
     let ui_scale = (BASE_ZOOM_DISTANCE / camera_controller.zoom_distance).clamp(MIN_UI_SCALE, MAX_UI_SCALE);
@@ -217,7 +268,7 @@ pub fn render_health_bars(
     egui::Area::new(egui::Id::new("health_bars"))
         .fixed_pos(egui::pos2(0.0, 0.0))
         .show(ctx, |ui| {
-            for (combatant, transform, casting_state, channeling_state, active_auras) in combatants.iter() {
+            for (entity, combatant, transform, casting_state, channeling_state, active_auras) in combatants.iter() {
                 if !combatant.is_alive() {
                     continue;
                 }
@@ -227,7 +278,33 @@ pub fn render_health_bars(
                 let health_bar_offset = Vec3::new(0.0, 3.5, 0.0); // Above head
                 let world_pos = transform.translation + health_bar_offset;
 
-                if let Ok(screen_pos) = camera.world_to_viewport(camera_transform, world_pos) {
+                // Behind the camera there is no projection; the view-space
+                // offset still says which edge the combatant is past
+                let projected = camera
+                    .world_to_viewport(camera_transform, world_pos)
+                    .ok()
+                    .map(|p| egui::pos2(p.x, p.y));
+                let view_pos = camera_transform.affine().inverse().transform_point3(world_pos);
+                let placement = place_nameplate(
+                    projected,
+                    egui::vec2(view_pos.x, -view_pos.y),
+                    viewport,
+                    NAMEPLATE_EDGE_MARGIN * ui_scale,
+                    NAMEPLATE_FADE_DISTANCE,
+                );
+                let on_screen = match placement {
+                    NameplatePlacement::OnScreen { pos, opacity } => {
+                        ui.set_opacity(opacity);
+                        Some(Vec2::new(pos.x, pos.y))
+                    }
+                    NameplatePlacement::OffScreen { edge, direction } => {
+                        offscreen.push((edge, direction, combatant));
+                        None
+                    }
+                };
+
+                if let Some(screen_pos) = on_screen {
+                    nameplates_drawn += 1;
                     let health_percent = combatant.current_health / combatant.max_health;
 
                     // Health bar dimensions (scaled by zoom)
@@ -295,6 +372,13 @@ pub fn render_health_bars(
                             let silence_text = format!("SILENCE {:.1}s", silence_aura.duration);
                             render_status_label(ui, &bar_pos, bar_width, &mut status_offset, &silence_text, status_color, ui_scale);
                         }
+
+                        // WEAKENED SOUL timer — explains why a Priest isn't
+                        // shielding this combatant even when it is being focused.
+                        if let Some(ws_aura) = auras.auras.iter().find(|a| a.effect_type == AuraType::WeakenedSoul) {
+                            let ws_text = format!("WEAKENED SOUL {:.1}s", ws_aura.duration);
+                            render_status_label(ui, &bar_pos, bar_width, &mut status_offset, &ws_text, status_color, ui_scale);
+                        }
                     }
 
                     // Scaled corner radius
@@ -308,13 +392,7 @@ pub fn render_health_bars(
                     );
 
                     // Health bar fill (color based on health %)
-                    let health_color = if health_percent > 0.5 {
-                        egui::Color32::from_rgb(0, 200, 0) // Green
-                    } else if health_percent > 0.25 {
-                        egui::Color32::from_rgb(255, 200, 0) // Yellow
-                    } else {
-                        egui::Color32::from_rgb(200, 0, 0) // Red
-                    };
+                    let health_color = health_bar_color(health_percent);
 
                     ui.painter().rect_filled(
                         egui::Rect::from_min_size(
@@ -354,14 +432,14 @@ pub fn render_health_bars(
                         }
                     }
 
-                    // Health bar border (pulsing red if low HP)
+                    // Health bar border in the team color (pulsing red if low HP)
                     let is_low_hp = health_percent < LOW_HP_THRESHOLD;
                     let border_color = if is_low_hp {
                         // Pulsing red border for low HP
                         let red_intensity = (200.0 + 55.0 * pulse_intensity) as u8;
                         egui::Color32::from_rgb(red_intensity, 50, 50)
                     } else {
-                        egui::Color32::from_rgb(200, 200, 200)
+                        team_colors.egui(combatant.team)
                     };
                     let border_width = if is_low_hp { 2.0 * ui_scale } else { 1.0 * ui_scale };
 
@@ -372,6 +450,16 @@ pub fn render_health_bars(
                         egui::StrokeKind::Outside,
                     );
 
+                    // Class portrait to the left of the health bar (pets have none)
+                    if combatant.slot < PET_SLOT_BASE {
+                        let portrait_size = 16.0 * ui_scale;
+                        let portrait_rect = egui::Rect::from_min_size(
+                            egui::pos2(bar_pos.x - portrait_size - 3.0 * ui_scale, bar_pos.y),
+                            egui::vec2(portrait_size, portrait_size),
+                        );
+                        paint_class_portrait(ui.painter(), &portraits, combatant.class, portrait_rect);
+                    }
+
                     // Low HP outer glow effect (pulsing red halo)
                     if is_low_hp {
                         let glow_alpha = (80.0 * pulse_intensity) as u8;
@@ -441,6 +529,33 @@ pub fn render_health_bars(
                         next_bar_y_offset += resource_bar_height + bar_spacing;
                     }
 
+                    // Soul Shard pips (Warlock secondary resource)
+                    if combatant.class == CharacterClass::Warlock {
+                        let pip_size = 5.0 * ui_scale;
+                        let pip_gap = 3.0 * ui_scale;
+                        let pips_width = MAX_SOUL_SHARDS as f32 * pip_size + (MAX_SOUL_SHARDS - 1) as f32 * pip_gap;
+                        let pips_x = bar_pos.x + (bar_width - pips_width) / 2.0;
+                        for i in 0..MAX_SOUL_SHARDS {
+                            let pip_rect = egui::Rect::from_min_size(
+                                egui::pos2(pips_x + i as f32 * (pip_size + pip_gap), bar_pos.y + next_bar_y_offset),
+                                egui::vec2(pip_size, pip_size),
+                            );
+                            let fill = if i < combatant.soul_shards {
+                                egui::Color32::from_rgb(180, 100, 255) // Soul purple
+                            } else {
+                                egui::Color32::from_rgb(20, 20, 30)
+                            };
+                            ui.painter().rect_filled(pip_rect, pip_size / 2.0, fill);
+                            ui.painter().rect_stroke(
+                                pip_rect,
+                                pip_size / 2.0,
+                                egui::Stroke::new(1.0 * ui_scale, egui::Color32::from_rgb(150, 120, 200)),
+                                egui::StrokeKind::Outside,
+                            );
+                        }
+                        next_bar_y_offset += pip_size + bar_spacing;
+                    }
+
                     // Cast bar (only when actively casting)
                     if let Some(casting) = casting_state {
                         let ability_def = abilities.get_unchecked(&casting.ability);
@@ -634,24 +749,175 @@ pub fn render_health_bars(
                     // Aura icons (below cast bar or resource bar) - only if enabled
                     if display_settings.show_aura_icons {
                         if let Some(auras) = active_auras {
-                            if !auras.auras.is_empty() {
-                                render_aura_icons(
+                            let aura_count = auras.auras.len();
+                            let camera_distance = camera_transform.translation().distance(world_pos);
+                            if camera_distance > AURA_ICON_MAX_CAMERA_DISTANCE {
+                                auras_culled += aura_count;
+                            } else if aura_count > 0 {
+                                let (shown, dropped) = aura_icon_order(auras);
+                                auras_drawn += shown.len();
+                                auras_culled += dropped;
+                                let hovered = render_aura_icons(
                                     ui.painter(),
                                     bar_pos,
                                     bar_width,
                                     next_bar_y_offset,
                                     auras,
+                                    &shown,
                                     &spell_icons,
                                     &abilities,
                                     ui_scale,
                                     pulse_intensity,
+                                    hover_pos,
                                 );
+                                if let Some(aura) = hovered.map(|i| &auras.auras[i]) {
+                                    hovered_aura = aura_source_of(aura, entity, &casters);
+                                }
                             }
                         }
                     }
                 }
             }
+
+            ui.set_opacity(1.0);
+            cull_stats.set(OverlayKind::Nameplates, nameplates_drawn, offscreen.len());
+            cull_stats.set(OverlayKind::AuraIcons, auras_drawn, auras_culled);
+            for (edge, direction, combatant) in offscreen {
+                render_offscreen_indicator(ui.painter(), edge, direction, combatant, &portraits, &team_colors);
+            }
         });
+
+    if aura_source.hovered != hovered_aura {
+        aura_source.hovered = hovered_aura;
+    }
+}
+
+/// Health bar fill: green above half, yellow above a quarter, red below.
+fn health_bar_color(health_percent: f32) -> egui::Color32 {
+    if health_percent > 0.5 {
+        egui::Color32::from_rgb(0, 200, 0) // Green
+    } else if health_percent > 0.25 {
+        egui::Color32::from_rgb(255, 200, 0) // Yellow
+    } else {
+        egui::Color32::from_rgb(200, 0, 0) // Red
+    }
+}
+
+/// Where a combatant's nameplate goes this frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameplatePlacement {
+    /// Drawn at `pos`: the projected anchor, or pulled in from just past the
+    /// edge, fading the further it had to be pulled
+    OnScreen { pos: egui::Pos2, opacity: f32 },
+    /// Replaced by an arrow at `edge` pointing toward the combatant
+    OffScreen { edge: egui::Pos2, direction: egui::Vec2 },
+}
+
+/// Place a nameplate whose anchor projects to `projected` (`None` when behind
+/// the camera, in which case `behind_direction` — the view-space offset — says
+/// where it is). Anchors inside the viewport shrunk by `margin` stay put;
+/// anchors up to `fade_distance` outside it are clamped in and faded; anything
+/// further gets an edge arrow.
+pub fn place_nameplate(
+    projected: Option<egui::Pos2>,
+    behind_direction: egui::Vec2,
+    viewport: egui::Rect,
+    margin: f32,
+    fade_distance: f32,
+) -> NameplatePlacement {
+    let inner = viewport.shrink(margin);
+    let center = viewport.center();
+
+    let direction = match projected {
+        Some(pos) => {
+            let clamped = inner.clamp(pos);
+            let overshoot = pos.distance(clamped);
+            if overshoot < fade_distance {
+                return NameplatePlacement::OnScreen {
+                    pos: clamped,
+                    opacity: 1.0 - overshoot / fade_distance,
+                };
+            }
+            pos - center
+        }
+        None => behind_direction,
+    };
+    let direction = if direction.length_sq() > f32::EPSILON { direction.normalized() } else { egui::Vec2::DOWN };
+
+    // Walk from the center along the direction until the inset rectangle's edge
+    let inset = viewport.shrink(OFFSCREEN_INDICATOR_INSET.min(viewport.width().min(viewport.height()) / 2.0));
+    let half = inset.size() / 2.0;
+    let scale_x = if direction.x.abs() > f32::EPSILON { half.x / direction.x.abs() } else { f32::INFINITY };
+    let scale_y = if direction.y.abs() > f32::EPSILON { half.y / direction.y.abs() } else { f32::INFINITY };
+    NameplatePlacement::OffScreen {
+        edge: inset.center() + direction * scale_x.min(scale_y),
+        direction,
+    }
+}
+
+/// Edge-of-screen arrow for an off-screen combatant: a team-colored arrow
+/// pointing at it, with its class portrait and a mini health bar beside.
+fn render_offscreen_indicator(
+    painter: &egui::Painter,
+    edge: egui::Pos2,
+    direction: egui::Vec2,
+    combatant: &Combatant,
+    portraits: &ClassPortraits,
+    team_colors: &TeamColors,
+) {
+    let team_color = team_colors.egui(combatant.team);
+    let side = direction.rot90();
+    let tip = edge + direction * (OFFSCREEN_ARROW_SIZE / 2.0);
+    let base = edge - direction * (OFFSCREEN_ARROW_SIZE / 2.0);
+    painter.add(egui::Shape::convex_polygon(
+        vec![tip, base + side * (OFFSCREEN_ARROW_SIZE / 2.0), base - side * (OFFSCREEN_ARROW_SIZE / 2.0)],
+        team_color,
+        egui::Stroke::new(1.0, egui::Color32::BLACK),
+    ));
+
+    // Portrait and HP bar sit on the inward side of the arrow
+    let card_center = edge - direction * (OFFSCREEN_ARROW_SIZE + 12.0);
+    let portrait_size = 16.0;
+    let bar_size = egui::vec2(30.0, 4.0);
+    let portrait_rect = egui::Rect::from_center_size(
+        card_center - egui::vec2(0.0, 4.0),
+        egui::vec2(portrait_size, portrait_size),
+    );
+    painter.rect_filled(portrait_rect.expand(1.0), 2.0, team_color);
+    paint_class_portrait(painter, portraits, combatant.class, portrait_rect);
+
+    let bar_rect = egui::Rect::from_center_size(
+        egui::pos2(card_center.x, portrait_rect.bottom() + 2.0 + bar_size.y / 2.0),
+        bar_size,
+    );
+    let health_percent = (combatant.current_health / combatant.max_health).clamp(0.0, 1.0);
+    painter.rect_filled(bar_rect, 1.0, egui::Color32::from_black_alpha(200));
+    painter.rect_filled(
+        egui::Rect::from_min_size(bar_rect.min, egui::vec2(bar_size.x * health_percent, bar_size.y)),
+        1.0,
+        health_bar_color(health_percent),
+    );
+}
+
+/// Who applied `aura` (worn by `target`), for the hover highlight. Auras with
+/// no recorded caster, or whose caster has despawned, highlight nothing.
+fn aura_source_of(
+    aura: &Aura,
+    target: Entity,
+    casters: &Query<(&Combatant, Option<&Pet>)>,
+) -> Option<HoveredAura> {
+    let caster = aura.caster?;
+    let (combatant, pet) = casters.get(caster).ok()?;
+    let caster_id = match pet {
+        Some(pet) => format!("Team {} {}", combatant.team, pet.pet_type.name()),
+        None => combatant_id(combatant.team, combatant.class),
+    };
+    Some(HoveredAura {
+        target,
+        caster,
+        caster_id,
+        ability_name: aura.ability_name.clone(),
+    })
 }
 
 /// Helper to render a status label above the health bar with outline
@@ -701,6 +967,82 @@ fn render_status_label(
     *status_offset -= 10.0 * ui_scale; // Move next label up
 }
 
+// ==============================================================================
+// Kill Feed
+// ==============================================================================
+
+/// Seconds of match time a kill stays in the feed
+const KILL_FEED_DURATION: f32 = 8.0;
+/// Maximum kills shown at once (newest at the bottom)
+const KILL_FEED_MAX_ENTRIES: usize = 4;
+/// Portrait size in the kill feed
+const KILL_FEED_PORTRAIT_SIZE: f32 = 22.0;
+
+/// Render recent kills under the time controls: killer portrait, an arrow
+/// marker, then the victim portrait, each followed by the combatant's name.
+pub fn render_kill_feed(
+    mut contexts: EguiContexts,
+    combat_log: Res<CombatLog>,
+    portraits: Res<ClassPortraits>,
+    team_colors: Res<TeamColors>,
+) {
+    let kills: Vec<_> = combat_log
+        .entries
+        .iter()
+        .rev()
+        .take_while(|e| combat_log.match_time - e.timestamp <= KILL_FEED_DURATION)
+        .filter_map(|e| match &e.structured_data {
+            Some(StructuredEventData::Death { victim, killer }) => Some((killer.as_deref(), victim.as_str())),
+            _ => None,
+        })
+        .take(KILL_FEED_MAX_ENTRIES)
+        .collect();
+    if kills.is_empty() {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    egui::Area::new(egui::Id::new("kill_feed"))
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-10.0, 150.0))
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(160))
+                .corner_radius(4.0)
+                .inner_margin(egui::Margin::same(6))
+                .show(ui, |ui| {
+                    for (killer, victim) in kills.iter().rev() {
+                        ui.horizontal(|ui| {
+                            if let Some(killer) = killer {
+                                kill_feed_combatant(ui, &portraits, &team_colors, killer);
+                            }
+                            ui.label(
+                                egui::RichText::new("▶")
+                                    .size(14.0)
+                                    .color(egui::Color32::from_rgb(200, 100, 100)),
+                            );
+                            kill_feed_combatant(ui, &portraits, &team_colors, victim);
+                        });
+                    }
+                });
+        });
+}
+
+/// Portrait (if the ID is a class) plus team-colored name for one kill feed side
+fn kill_feed_combatant(ui: &mut egui::Ui, portraits: &ClassPortraits, team_colors: &TeamColors, id: &str) {
+    if let Some(class) = class_from_combatant_id(id) {
+        let (rect, _) = ui.allocate_exact_size(
+            egui::vec2(KILL_FEED_PORTRAIT_SIZE, KILL_FEED_PORTRAIT_SIZE),
+            egui::Sense::hover(),
+        );
+        paint_class_portrait(ui.painter(), portraits, class, rect);
+    }
+    let team_color = team_colors.for_combatant_id(id);
+    let name = id.split_once(' ').and_then(|(_, rest)| rest.split_once(' ')).map_or(id, |(_, name)| name);
+    ui.label(egui::RichText::new(name).size(13.0).color(team_color));
+}
+
 // ==============================================================================
 // Aura Icons
 // ==============================================================================
@@ -715,25 +1057,52 @@ fn is_hard_cc_aura(aura_type: &AuraType) -> bool {
     )
 }
 
+/// Which auras get an icon, in display order: hard CC first, then other
+/// debuffs, then buffs (application order within each group), cut to
+/// `AURA_ICON_BUDGET`. Returns indices into `auras.auras` and how many auras
+/// were dropped, so a crowded nameplate loses buffs before it loses a stun.
+fn aura_icon_order(auras: &ActiveAuras) -> (Vec<usize>, usize) {
+    let rank = |aura_type: &AuraType| {
+        if is_hard_cc_aura(aura_type) {
+            0
+        } else if !is_buff_aura(aura_type) {
+            1
+        } else {
+            2
+        }
+    };
+    let mut order: Vec<usize> = (0..auras.auras.len()).collect();
+    order.sort_by_key(|&i| rank(&auras.auras[i].effect_type));
+    let dropped = order.len().saturating_sub(AURA_ICON_BUDGET);
+    order.truncate(AURA_ICON_BUDGET);
+    (order, dropped)
+}
+
 /// Render aura icons with countdown timers below the health/cast bars.
 /// Icons are centered below the health bar, with buffs using gold borders
 /// and debuffs using red borders. Countdown timers use OmniCC-style coloring.
 /// Hard CC effects (stun, fear, polymorph, root) get a pulsing glow.
+/// Only the auras in `shown` (see [`aura_icon_order`]) are drawn, in that order.
+/// Returns the index of the icon under `hover_pos`, which gets an amber outline.
+#[allow(clippy::too_many_arguments)]
 fn render_aura_icons(
     painter: &egui::Painter,
     bar_pos: egui::Pos2,
     bar_width: f32,
     y_offset: f32,
     auras: &ActiveAuras,
+    shown: &[usize],
     spell_icons: &SpellIcons,
     ability_definitions: &AbilityDefinitions,
     ui_scale: f32,
     pulse_intensity: f32,
-) {
-    let num_auras = auras.auras.len();
+    hover_pos: Option<egui::Pos2>,
+) -> Option<usize> {
+    let num_auras = shown.len();
     if num_auras == 0 {
-        return;
+        return None;
     }
+    let mut hovered = None;
 
     // Scale icon size and spacing
     let icon_size = AURA_ICON_SIZE * ui_scale;
@@ -748,8 +1117,9 @@ fn render_aura_icons(
     let start_x = bar_pos.x + (bar_width - total_width) / 2.0;
     let icons_y = bar_pos.y + y_offset + 2.0 * ui_scale; // Small gap below bars
 
-    for (i, aura) in auras.auras.iter().enumerate() {
-        let icon_x = start_x + (i as f32 * (icon_size + icon_spacing));
+    for (position, &i) in shown.iter().enumerate() {
+        let aura = &auras.auras[i];
+        let icon_x = start_x + (position as f32 * (icon_size + icon_spacing));
         let icon_rect = egui::Rect::from_min_size(
             egui::pos2(icon_x, icons_y),
             egui::vec2(icon_size, icon_size),
@@ -818,6 +1188,17 @@ fn render_aura_icons(
             );
         }
 
+        // Hovered: amber outline matching the caster ring in the scene
+        if hover_pos.is_some_and(|pos| icon_rect.contains(pos)) {
+            hovered = Some(i);
+            painter.rect_stroke(
+                icon_rect.expand(2.0 * ui_scale),
+                corner_radius,
+                egui::Stroke::new(2.0 * ui_scale, egui::Color32::from_rgb(255, 190, 50)),
+                egui::StrokeKind::Outside,
+            );
+        }
+
         // Draw countdown timer (OmniCC style)
         // Skip timer for long-duration buffs (>60s) - they won't expire naturally
         let seconds_remaining = aura.duration.ceil() as i32;
@@ -865,6 +1246,8 @@ fn render_aura_icons(
             );
         }
     }
+
+    hovered
 }
 
 /// Get a fallback color for an aura type when no icon is available.
@@ -882,12 +1265,17 @@ fn get_aura_fallback_color(aura_type: &AuraType) -> egui::Color32 {
         AuraType::AttackPowerIncrease => egui::Color32::from_rgb(255, 69, 0), // Orange red
         AuraType::SpellSchoolLockout => egui::Color32::from_rgb(128, 0, 0), // Maroon
         AuraType::WeakenedSoul => egui::Color32::from_rgb(169, 169, 169), // Dark gray
+        AuraType::Intervene => egui::Color32::from_rgb(160, 100, 60), // Warrior brown
+        AuraType::DodgeChanceIncrease => egui::Color32::from_rgb(180, 160, 60), // Rogue yellow
+        AuraType::MovementSpeedIncrease => egui::Color32::from_rgb(0, 255, 127), // Spring green
         AuraType::Polymorph => egui::Color32::from_rgb(255, 182, 193), // Light pink
         AuraType::ShadowSight => egui::Color32::from_rgb(75, 0, 130), // Indigo
         AuraType::DamageReduction => egui::Color32::from_rgb(139, 69, 19), // Saddle brown (debuff)
         AuraType::CastTimeIncrease => egui::Color32::from_rgb(128, 0, 128), // Purple (curse)
         AuraType::DamageTakenReduction => egui::Color32::from_rgb(255, 215, 0), // Gold (Paladin aura)
         AuraType::DamageImmunity => egui::Color32::from_rgb(255, 215, 0), // Gold (Divine Shield)
+        AuraType::IceBlock => egui::Color32::from_rgb(135, 206, 250), // Light sky blue (encased)
+        AuraType::SoulstoneResurrection => egui::Color32::from_rgb(180, 100, 255), // Soul purple
         AuraType::Incapacitate => egui::Color32::from_rgb(135, 206, 250), // Light sky blue (frozen)
         AuraType::SpellResistanceBuff => egui::Color32::from_rgb(100, 200, 100), // Green (resistance buff)
         AuraType::AttackPowerReduction => egui::Color32::from_rgb(178, 34, 34), // Firebrick (debuff)
@@ -903,3 +1291,79 @@ fn get_aura_fallback_color(aura_type: &AuraType) -> egui::Color32 {
         AuraType::WindfuryBuff => egui::Color32::from_rgb(135, 206, 250), // Light sky blue (Windfury Totem buff)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport() -> egui::Rect {
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 600.0))
+    }
+
+    #[test]
+    fn nameplates_in_view_stay_put() {
+        let placement = place_nameplate(Some(egui::pos2(400.0, 300.0)), egui::Vec2::ZERO, viewport(), 40.0, 120.0);
+        assert_eq!(placement, NameplatePlacement::OnScreen { pos: egui::pos2(400.0, 300.0), opacity: 1.0 });
+    }
+
+    #[test]
+    fn nameplates_just_past_the_edge_are_clamped_and_faded() {
+        let placement = place_nameplate(Some(egui::pos2(820.0, 300.0)), egui::Vec2::ZERO, viewport(), 40.0, 120.0);
+        assert_eq!(placement, NameplatePlacement::OnScreen { pos: egui::pos2(760.0, 300.0), opacity: 0.5 });
+    }
+
+    #[test]
+    fn far_off_combatants_get_an_edge_arrow_pointing_at_them() {
+        let NameplatePlacement::OffScreen { edge, direction } =
+            place_nameplate(Some(egui::pos2(-2000.0, 300.0)), egui::Vec2::ZERO, viewport(), 40.0, 120.0)
+        else {
+            panic!("expected an off-screen indicator");
+        };
+        assert_eq!(direction, egui::vec2(-1.0, 0.0));
+        assert_eq!(edge, egui::pos2(OFFSCREEN_INDICATOR_INSET, 300.0));
+
+        // Behind the camera: the view-space direction decides the edge
+        let NameplatePlacement::OffScreen { edge, .. } =
+            place_nameplate(None, egui::vec2(0.0, 5.0), viewport(), 40.0, 120.0)
+        else {
+            panic!("expected an off-screen indicator");
+        };
+        assert_eq!(edge, egui::pos2(400.0, 600.0 - OFFSCREEN_INDICATOR_INSET));
+    }
+
+    fn aura(effect_type: AuraType) -> Aura {
+        Aura {
+            effect_type,
+            duration: 10.0,
+            magnitude: 1.0,
+            break_on_damage_threshold: 0.0,
+            accumulated_damage: 0.0,
+            tick_interval: 0.0,
+            time_until_next_tick: 0.0,
+            caster: None,
+            ability_name: "Test".to_string(),
+            fear_direction: (0.0, 0.0),
+            fear_direction_timer: 0.0,
+            spell_school: None,
+            applied_this_frame: false,
+            backlash_damage: None,
+            dr_category_override: None,
+            dispel_type: DispelType::Auto,
+        }
+    }
+
+    #[test]
+    fn crowded_aura_row_keeps_hard_cc_and_debuffs_over_buffs() {
+        // Eight buffs applied first, then a DoT and a stun
+        let mut auras: Vec<Aura> = (0..AURA_ICON_BUDGET).map(|_| aura(AuraType::AttackPowerIncrease)).collect();
+        auras.push(aura(AuraType::DamageOverTime));
+        auras.push(aura(AuraType::Stun));
+        let auras = ActiveAuras { auras };
+
+        let (shown, dropped) = aura_icon_order(&auras);
+        assert_eq!(shown.len(), AURA_ICON_BUDGET);
+        assert_eq!(dropped, 2);
+        assert_eq!(&shown[..3], &[AURA_ICON_BUDGET + 1, AURA_ICON_BUDGET, 0]);
+        assert_eq!(shown.last(), Some(&(AURA_ICON_BUDGET - 3)), "the last buffs applied are the ones dropped");
+    }
+}
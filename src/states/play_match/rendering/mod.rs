@@ -6,17 +6,38 @@
 //! - `combat_log`: Combat log panel and ability timeline
 //! - `overlays`: Countdown and victory celebration screens
 //! - `effects`: Floating combat text, spell impacts, speech bubbles, shield bubbles
-
+//! - `portraits`: Render-to-texture class portraits used as class identifiers
+//! - `diagnostics`: FPS / entity-count / memory debug overlay
+//! - `ai_plans`: Per-combatant AI plan debug overlay
+//! - `alerts`: Low-health / healer-CC / kill vignette and tones
+//! - `silhouettes`: Team-colored silhouettes for combatants hidden behind walls
+//! - `presentation`: Presentation mode (large class labels, debug UI hidden)
+//! - `overlay_budget`: Frustum/distance culling and per-frame budgets for egui overlays
+
+pub mod ai_plans;
+pub mod alerts;
 pub mod combat_log;
+pub mod diagnostics;
 pub mod effects;
 pub mod hud;
+pub mod overlay_budget;
 pub mod overlays;
+pub mod portraits;
+pub mod presentation;
+pub mod silhouettes;
 
 // Re-export all public items for backwards compatibility
+pub use ai_plans::*;
+pub use alerts::*;
 pub use combat_log::*;
+pub use diagnostics::*;
 pub use effects::*;
 pub use hud::*;
+pub use overlay_budget::*;
 pub use overlays::*;
+pub use portraits::*;
+pub use presentation::*;
+pub use silhouettes::*;
 
 use bevy::prelude::*;
 use bevy_egui::egui;
@@ -71,6 +92,9 @@ pub fn get_aura_icon_key(aura: &Aura, ability_definitions: &AbilityDefinitions)
         AuraType::AttackPowerIncrease => "aura_max_health".to_string(), // Reuse buff icon
         AuraType::SpellSchoolLockout => "aura_lockout".to_string(),
         AuraType::WeakenedSoul => "aura_weakened_soul".to_string(),
+        AuraType::Intervene => "aura_absorb".to_string(), // Protective buff, reuse absorb icon
+        AuraType::DodgeChanceIncrease => "aura_absorb".to_string(), // Defensive buff, reuse absorb icon
+        AuraType::MovementSpeedIncrease => "aura_max_health".to_string(), // Buff, reuse buff icon
         AuraType::Polymorph => "aura_stun".to_string(), // Reuse stun icon as fallback
         AuraType::ShadowSight => "aura_dot".to_string(), // Reuse DoT icon as fallback
         AuraType::DamageReduction => "aura_dot".to_string(), // Curse debuff, reuse DoT icon
@@ -78,6 +102,8 @@ pub fn get_aura_icon_key(aura: &Aura, ability_definitions: &AbilityDefinitions)
         AuraType::DamageTakenReduction => "aura_max_health".to_string(), // Devotion Aura buff, reuse buff icon
         AuraType::DamageImmunity => "aura_absorb".to_string(), // Divine Shield, reuse absorb icon as fallback
         AuraType::Incapacitate => "aura_stun".to_string(), // Reuse stun icon (frozen in place)
+        AuraType::IceBlock => "aura_stun".to_string(), // Self-encasement, reuse stun icon
+        AuraType::SoulstoneResurrection => "aura_max_health".to_string(), // Reuse health buff icon
         AuraType::SpellResistanceBuff => "aura_max_health".to_string(), // Resistance buff, reuse buff icon
         AuraType::AttackPowerReduction => "aura_dot".to_string(), // Debuff, reuse DoT icon
         AuraType::CritChanceIncrease => "aura_max_health".to_string(), // Buff, reuse buff icon
@@ -104,6 +130,8 @@ pub fn is_buff_aura(aura_type: &AuraType) -> bool {
         AuraType::ShadowSight |
         AuraType::DamageTakenReduction |
         AuraType::DamageImmunity |
+        AuraType::IceBlock |
+        AuraType::SoulstoneResurrection |
         AuraType::CritChanceIncrease |
         AuraType::ManaRegenIncrease |
         AuraType::LockoutDurationReduction |
@@ -112,7 +140,10 @@ pub fn is_buff_aura(aura_type: &AuraType) -> bool {
         AuraType::WeaponPoison |
         AuraType::SpellPowerIncrease |
         AuraType::HealingOverTime |
-        AuraType::WindfuryBuff
+        AuraType::WindfuryBuff |
+        AuraType::Intervene |
+        AuraType::DodgeChanceIncrease |
+        AuraType::MovementSpeedIncrease
     )
 }
 
@@ -0,0 +1,127 @@
+//! Overlay Culling and Budgets
+//!
+//! Floating combat text, speech bubbles and aura icons are drawn with egui
+//! every frame. In long fights at 3x speed they pile up faster than they
+//! expire, so their renderers skip what nobody can read:
+//! - **frustum**: anchors projecting outside the screen (plus a margin for
+//!   text that straddles the edge) or behind the camera
+//! - **distance**: anchors farther from the camera than their max distance
+//! - **budget**: past a per-frame count, the lowest-priority items (oldest
+//!   floating text, farthest bubbles, buffs before debuffs before hard CC
+//!   on a crowded nameplate) are dropped
+//!
+//! Each renderer reports what it drew and what it culled to
+//! [`OverlayCullStats`]; the diagnostics overlay shows those counts next to
+//! egui's per-frame draw calls and vertices.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Anchors this far (px) outside the screen still draw, so text centred
+/// just off the edge doesn't pop
+pub const OVERLAY_SCREEN_MARGIN: f32 = 80.0;
+/// Floating text and speech bubbles past this camera distance are culled.
+/// Covers the far side of the arena at max zoom.
+pub const OVERLAY_MAX_CAMERA_DISTANCE: f32 = 190.0;
+/// Aura icons past this camera distance are culled (illegible by then)
+pub const AURA_ICON_MAX_CAMERA_DISTANCE: f32 = 110.0;
+/// Most floating combat texts drawn per frame (newest first)
+pub const FLOATING_TEXT_BUDGET: usize = 40;
+/// Most speech bubbles drawn per frame (nearest first)
+pub const SPEECH_BUBBLE_BUDGET: usize = 6;
+/// Most aura icons drawn per nameplate
+pub const AURA_ICON_BUDGET: usize = 8;
+
+/// Overlays that report culling, in display order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlayKind {
+    Nameplates,
+    AuraIcons,
+    FloatingText,
+    SpeechBubbles,
+}
+
+impl OverlayKind {
+    pub const ALL: [OverlayKind; 4] = [
+        OverlayKind::Nameplates,
+        OverlayKind::AuraIcons,
+        OverlayKind::FloatingText,
+        OverlayKind::SpeechBubbles,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OverlayKind::Nameplates => "Nameplates",
+            OverlayKind::AuraIcons => "Aura icons",
+            OverlayKind::FloatingText => "Floating text",
+            OverlayKind::SpeechBubbles => "Speech bubbles",
+        }
+    }
+}
+
+/// Items drawn and culled by each overlay on the last frame.
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct OverlayCullStats {
+    pub drawn: [usize; OverlayKind::ALL.len()],
+    pub culled: [usize; OverlayKind::ALL.len()],
+}
+
+impl OverlayCullStats {
+    pub fn set(&mut self, kind: OverlayKind, drawn: usize, culled: usize) {
+        self.drawn[kind as usize] = drawn;
+        self.culled[kind as usize] = culled;
+    }
+
+    pub fn total_drawn(&self) -> usize {
+        self.drawn.iter().sum()
+    }
+
+    pub fn total_culled(&self) -> usize {
+        self.culled.iter().sum()
+    }
+}
+
+/// Screen position of `world_pos` if it passes the frustum and distance
+/// checks, `None` if it should be culled.
+pub fn cull_overlay_anchor(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    world_pos: Vec3,
+    viewport: egui::Rect,
+    max_distance: f32,
+) -> Option<egui::Pos2> {
+    if camera_transform.translation().distance(world_pos) > max_distance {
+        return None;
+    }
+    let screen = camera.world_to_viewport(camera_transform, world_pos).ok()?;
+    let pos = egui::pos2(screen.x, screen.y);
+    viewport.expand(OVERLAY_SCREEN_MARGIN).contains(pos).then_some(pos)
+}
+
+/// Keep the `budget` highest-priority items, dropping the rest. Returns how
+/// many were dropped.
+pub fn apply_overlay_budget<T>(items: &mut Vec<T>, budget: usize, priority: impl Fn(&T) -> f32) -> usize {
+    if items.len() <= budget {
+        return 0;
+    }
+    items.sort_by(|a, b| priority(b).total_cmp(&priority(a)));
+    let dropped = items.len() - budget;
+    items.truncate(budget);
+    dropped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_keeps_the_highest_priority_items() {
+        let mut items = vec![1.0, 5.0, 3.0, 4.0, 2.0];
+        assert_eq!(apply_overlay_budget(&mut items, 3, |&p| p), 2);
+        assert_eq!(items, vec![5.0, 4.0, 3.0]);
+
+        let mut few = vec![1.0, 2.0];
+        assert_eq!(apply_overlay_budget(&mut few, 3, |&p| p), 0);
+        assert_eq!(few, vec![1.0, 2.0]);
+    }
+}
@@ -4,6 +4,7 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
+use crate::settings::TeamColors;
 use crate::states::play_match::components::{MatchCountdown, VictoryCelebration};
 use super::draw_text_with_outline;
 
@@ -20,6 +21,7 @@ pub fn render_countdown(
     countdown: Res<MatchCountdown>,
     match_config: Res<crate::states::match_config::MatchConfig>,
     class_icons: Res<crate::states::configure_match_ui::ClassIcons>,
+    team_colors: Res<TeamColors>,
 ) {
     // Only show countdown if gates haven't opened yet
     if countdown.gates_opened {
@@ -87,14 +89,14 @@ pub fn render_countdown(
     let icon_size = 40.0;
     let icon_spacing = 50.0; // Vertical spacing between icons
 
-    // Team 1 (left side, blue)
+    // Team 1 (left side)
     let team1_x = center.x - team_spacing;
     draw_text_with_outline(
         &painter,
         egui::pos2(team1_x, preview_y),
         "Team 1",
         egui::FontId::proportional(24.0),
-        egui::Color32::from_rgb(100, 150, 255), // Blue
+        team_colors.egui(1),
         egui::Align2::CENTER_CENTER,
         2.0,
     );
@@ -116,7 +118,7 @@ pub fn render_countdown(
             painter.rect_stroke(
                 icon_rect.expand(2.0),
                 4.0,
-                egui::Stroke::new(2.0, egui::Color32::from_rgb(100, 150, 255)),
+                egui::Stroke::new(2.0, team_colors.egui(1)),
                 egui::StrokeKind::Outside,
             );
             painter.image(
@@ -150,14 +152,14 @@ pub fn render_countdown(
         2.0,
     );
 
-    // Team 2 (right side, red)
+    // Team 2 (right side)
     let team2_x = center.x + team_spacing;
     draw_text_with_outline(
         &painter,
         egui::pos2(team2_x, preview_y),
         "Team 2",
         egui::FontId::proportional(24.0),
-        egui::Color32::from_rgb(255, 100, 100), // Red
+        team_colors.egui(2),
         egui::Align2::CENTER_CENTER,
         2.0,
     );
@@ -190,7 +192,7 @@ pub fn render_countdown(
             painter.rect_stroke(
                 icon_rect.expand(2.0),
                 4.0,
-                egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 100, 100)),
+                egui::Stroke::new(2.0, team_colors.egui(2)),
                 egui::StrokeKind::Outside,
             );
             painter.image(
@@ -226,6 +228,7 @@ pub fn render_countdown(
 pub fn render_victory_celebration(
     mut contexts: EguiContexts,
     celebration: Option<Res<VictoryCelebration>>,
+    team_colors: Res<TeamColors>,
 ) {
     // Only render if celebration is active
     let Some(celebration) = celebration else {
@@ -244,8 +247,8 @@ pub fn render_victory_celebration(
 
     // Victory text based on winner
     let (victory_text, victory_color) = match celebration.winner {
-        Some(1) => ("TEAM 1 WINS!", egui::Color32::from_rgb(100, 150, 255)), // Blue
-        Some(2) => ("TEAM 2 WINS!", egui::Color32::from_rgb(255, 100, 100)), // Red
+        Some(1) => ("TEAM 1 WINS!", team_colors.egui(1)),
+        Some(2) => ("TEAM 2 WINS!", team_colors.egui(2)),
         None => ("DRAW!", egui::Color32::from_rgb(200, 200, 100)),           // Yellow
         _ => ("MATCH OVER", egui::Color32::from_rgb(200, 200, 200)),        // Gray
     };
@@ -0,0 +1,190 @@
+//! Class Portraits
+//!
+//! Small 3D portrait thumbnails of each class model, used as class
+//! identifiers in nameplates, the kill feed, timeline headers and the Results
+//! tables.
+//!
+//! Portraits are rendered to texture: at match setup each class in the match
+//! that doesn't have a portrait yet gets an off-screen "stage" (its capsule
+//! model, a key light and a camera on a dedicated render layer) whose camera
+//! draws into an image. After `PORTRAIT_CAPTURE_FRAMES` the stage is despawned
+//! and the image is kept, so every class is rendered once per session.
+
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+use bevy_egui::{egui, EguiContexts};
+use std::collections::HashMap;
+
+use crate::states::match_config::{CharacterClass, MatchConfig};
+use crate::states::play_match::utils::class_model_color;
+
+/// Render layer reserved for portrait stages (the arena uses layer 0)
+pub const PORTRAIT_LAYER: usize = 7;
+
+/// Portrait texture size in pixels (square)
+pub const PORTRAIT_SIZE: u32 = 96;
+
+/// Frames a stage stays alive before its image is considered final. Gives
+/// the mesh upload and pipeline compile a few frames to settle.
+pub const PORTRAIT_CAPTURE_FRAMES: u32 = 30;
+
+/// Stages sit far below the arena so they never show up in its bounds
+const STAGE_ORIGIN: Vec3 = Vec3::new(0.0, -500.0, 0.0);
+
+/// Spacing between stages along X
+const STAGE_SPACING: f32 = 10.0;
+
+/// Cached class portrait textures, registered with egui.
+/// Persists across matches; classes are only rendered the first time they
+/// appear in a match.
+#[derive(Resource, Default)]
+pub struct ClassPortraits {
+    /// Map of class to egui texture ID
+    pub textures: HashMap<CharacterClass, egui::TextureId>,
+    /// Render target images, kept alive for the session
+    pub handles: Vec<Handle<Image>>,
+}
+
+impl ClassPortraits {
+    /// Portrait texture for a class, if one has been rendered
+    pub fn get(&self, class: CharacterClass) -> Option<egui::TextureId> {
+        self.textures.get(&class).copied()
+    }
+}
+
+/// Root of an off-screen portrait stage, despawned once captured.
+#[derive(Component)]
+pub struct PortraitStage {
+    pub frames_left: u32,
+}
+
+/// Render target image for a portrait.
+fn portrait_image() -> Image {
+    let size = Extent3d {
+        width: PORTRAIT_SIZE,
+        height: PORTRAIT_SIZE,
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+/// Setup system: spawn a portrait stage for every class in the match that
+/// doesn't have a cached portrait yet.
+pub fn spawn_class_portraits(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut portraits: ResMut<ClassPortraits>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    config: Res<MatchConfig>,
+) {
+    let layer = RenderLayers::layer(PORTRAIT_LAYER);
+
+    for &class in CharacterClass::all() {
+        let in_match = config.team1.iter().chain(config.team2.iter()).any(|c| *c == Some(class));
+        if !in_match || portraits.textures.contains_key(&class) {
+            continue;
+        }
+
+        let handle = images.add(portrait_image());
+        let origin = STAGE_ORIGIN + Vec3::X * STAGE_SPACING * portraits.handles.len() as f32;
+
+        commands
+            .spawn((
+                Transform::from_translation(origin),
+                Visibility::default(),
+                PortraitStage { frames_left: PORTRAIT_CAPTURE_FRAMES },
+            ))
+            .with_children(|stage| {
+                // Same capsule and material as the in-arena model
+                stage.spawn((
+                    Mesh3d(meshes.add(Capsule3d::new(0.5, 1.5))),
+                    MeshMaterial3d(materials.add(StandardMaterial {
+                        base_color: class_model_color(class),
+                        perceptual_roughness: 0.5,
+                        metallic: 0.2,
+                        ..default()
+                    })),
+                    Transform::default(),
+                    layer.clone(),
+                ));
+                stage.spawn((
+                    PointLight {
+                        intensity: 60_000.0,
+                        range: 10.0,
+                        ..default()
+                    },
+                    Transform::from_xyz(1.5, 2.0, 2.5),
+                    layer.clone(),
+                ));
+                // Head-and-shoulders framing, slightly above eye level
+                stage.spawn((
+                    Camera3d::default(),
+                    Camera {
+                        target: RenderTarget::Image(handle.clone().into()),
+                        order: -1,
+                        clear_color: ClearColorConfig::Custom(Color::NONE),
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, 1.1, 2.6).looking_at(Vec3::new(0.0, 0.6, 0.0), Vec3::Y),
+                    layer.clone(),
+                ));
+            });
+
+        let texture_id = contexts.add_image(handle.clone());
+        portraits.textures.insert(class, texture_id);
+        portraits.handles.push(handle);
+    }
+}
+
+/// Despawn portrait stages once their image has been captured. Runs in every
+/// state so a stage left behind by an early exit from the match still ends.
+pub fn finish_class_portraits(
+    mut commands: Commands,
+    mut stages: Query<(Entity, &mut PortraitStage)>,
+) {
+    for (entity, mut stage) in stages.iter_mut() {
+        if stage.frames_left == 0 {
+            commands.entity(entity).despawn();
+        } else {
+            stage.frames_left -= 1;
+        }
+    }
+}
+
+/// Paint a class portrait into `rect`, falling back to a class-colored square
+/// while the portrait isn't available.
+pub fn paint_class_portrait(
+    painter: &egui::Painter,
+    portraits: &ClassPortraits,
+    class: CharacterClass,
+    rect: egui::Rect,
+) {
+    // Dark backing so the transparent portrait background reads on any panel
+    painter.rect_filled(rect, 3.0, egui::Color32::from_rgb(24, 24, 32));
+    if let Some(texture_id) = portraits.get(class) {
+        painter.image(
+            texture_id,
+            rect,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+            egui::Color32::WHITE,
+        );
+    } else {
+        let c = class.color().to_srgba();
+        let color = egui::Color32::from_rgb((c.red * 255.0) as u8, (c.green * 255.0) as u8, (c.blue * 255.0) as u8);
+        painter.rect_filled(rect.shrink(2.0), 2.0, color);
+    }
+}
@@ -0,0 +1,102 @@
+//! Presentation Mode
+//!
+//! A clean view for recording balance-demo footage, toggled with F8 (by
+//! default) or enabled at launch with `--presentation`:
+//! - a large team-colored class name floats above every living combatant
+//!   (pets show their pet name)
+//! - the debug UI is hidden: time controls, camera help, and the diagnostics
+//!   and AI plan overlays
+//!
+//! Hotkeys keep working while their panels are hidden. The mode persists
+//! across matches until toggled off.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::settings::TeamColors;
+use crate::states::play_match::components::{Combatant, Pet};
+
+/// Label font size, in points
+const LABEL_FONT_SIZE: f32 = 26.0;
+/// Labels sit this far above a combatant's origin — just above its nameplate
+const LABEL_HEIGHT: f32 = 4.6;
+/// Width of the dark outline around label text, in points
+const LABEL_OUTLINE: f32 = 2.0;
+
+/// Whether presentation mode is on. Lives for the whole app, not per match.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PresentationMode {
+    pub enabled: bool,
+}
+
+/// Text of a combatant's presentation label.
+pub fn presentation_label(combatant: &Combatant, pet: Option<&Pet>) -> &'static str {
+    match pet {
+        Some(pet) => pet.pet_type.name(),
+        None => combatant.class.name(),
+    }
+}
+
+/// Presentation mode hotkey.
+pub fn toggle_presentation_mode(
+    mut presentation: ResMut<PresentationMode>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if keybindings.action_just_pressed(GameAction::TogglePresentationMode, &keyboard) {
+        presentation.enabled = !presentation.enabled;
+        info!("Presentation mode toggled to: {}", presentation.enabled);
+    }
+}
+
+/// Large outlined class-name labels above each living, on-screen combatant.
+pub fn render_presentation_labels(
+    mut contexts: EguiContexts,
+    presentation: Res<PresentationMode>,
+    combatants: Query<(&Combatant, Option<&Pet>, &Transform)>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    team_colors: Res<TeamColors>,
+) {
+    if !presentation.enabled {
+        return;
+    }
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+
+    let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Background, egui::Id::new("presentation_labels")));
+    let font = egui::FontId::proportional(LABEL_FONT_SIZE);
+    let viewport = ctx.screen_rect();
+
+    for (combatant, pet, transform) in combatants.iter() {
+        if !combatant.is_alive() {
+            continue;
+        }
+        let world_pos = transform.translation + Vec3::Y * LABEL_HEIGHT;
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world_pos) else {
+            continue;
+        };
+        let pos = egui::pos2(screen.x, screen.y);
+        if !viewport.contains(pos) {
+            continue;
+        }
+
+        let text = presentation_label(combatant, pet);
+        let color = team_colors.egui(combatant.team);
+        let outline = egui::Color32::from_black_alpha(220);
+        for (dx, dy) in [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (-1.0, -1.0), (1.0, 1.0), (-1.0, 1.0), (1.0, -1.0)] {
+            painter.text(
+                pos + egui::vec2(dx, dy) * LABEL_OUTLINE,
+                egui::Align2::CENTER_BOTTOM,
+                text,
+                font.clone(),
+                outline,
+            );
+        }
+        painter.text(pos, egui::Align2::CENTER_BOTTOM, text, font.clone(), color);
+    }
+}
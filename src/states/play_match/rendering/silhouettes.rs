@@ -0,0 +1,120 @@
+//! Occluded Combatant Silhouettes
+//!
+//! Draws a flat team-colored silhouette wherever a combatant is hidden behind
+//! a wall, pillar or arena prop, so spectators never lose track of a unit.
+//!
+//! Each combatant (pets included) gets a child entity sharing its mesh with an
+//! [`OccludedSilhouetteMaterial`]. That material's pipeline flips the depth
+//! test: the child runs in the transparent pass after all opaque geometry has
+//! written depth, and only fragments *behind* the stored depth pass. Where the
+//! unit is in plain view nothing is drawn.
+//!
+//! Combatant materials are alpha-blended (for stealth fading) and don't write
+//! depth, so units never cast a silhouette through each other — they already
+//! show through one another.
+//!
+//! Graphical-mode only — registered in `StatesPlugin::build()`.
+
+use bevy::pbr::{MaterialPipeline, MaterialPipelineKey, NotShadowCaster};
+use bevy::prelude::*;
+use bevy::render::mesh::MeshVertexBufferLayoutRef;
+use bevy::render::render_resource::{
+    AsBindGroup, CompareFunction, RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+};
+use crate::settings::TeamColors;
+use crate::states::play_match::components::Combatant;
+
+/// Shader asset path (relative to `assets/`)
+const SILHOUETTE_SHADER: &str = "shaders/occluded_silhouette.wgsl";
+
+/// Silhouette opacity — strong enough to read through a wall, faint enough
+/// that the wall still reads as a wall.
+const SILHOUETTE_ALPHA: f32 = 0.55;
+
+/// Unlit flat color drawn only where its mesh is occluded.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct OccludedSilhouetteMaterial {
+    #[uniform(0)]
+    pub color: LinearRgba,
+}
+
+impl Material for OccludedSilhouetteMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SILHOUETTE_SHADER.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            // Bevy uses reverse-Z (nearer = larger depth), so "behind whatever
+            // is already there" is Less
+            depth_stencil.depth_compare = CompareFunction::Less;
+            depth_stencil.depth_write_enabled = false;
+        }
+        Ok(())
+    }
+}
+
+/// Child of a combatant that carries its silhouette.
+#[derive(Component)]
+pub struct OccludedSilhouette;
+
+/// Silhouette color for a team.
+pub fn silhouette_color(team_colors: &TeamColors, team: u8) -> LinearRgba {
+    team_colors.bevy(team).to_linear().with_alpha(SILHOUETTE_ALPHA)
+}
+
+/// Gives every combatant without one a silhouette child.
+///
+/// Checked every frame rather than on `Added<Combatant>` so a pet restored
+/// from a what-if branch snapshot (which doesn't copy children) gets one too.
+pub fn attach_occluded_silhouettes(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<OccludedSilhouetteMaterial>>,
+    team_colors: Res<TeamColors>,
+    combatants: Query<(Entity, &Combatant, &Mesh3d, Option<&Children>)>,
+    silhouettes: Query<(), With<OccludedSilhouette>>,
+) {
+    for (entity, combatant, mesh, children) in combatants.iter() {
+        let has_silhouette = children.is_some_and(|children| children.iter().any(|c| silhouettes.contains(c)));
+        if has_silhouette {
+            continue;
+        }
+        let material = materials.add(OccludedSilhouetteMaterial {
+            color: silhouette_color(&team_colors, combatant.team),
+        });
+        commands.entity(entity).with_child((
+            Mesh3d(mesh.0.clone()),
+            MeshMaterial3d(material),
+            Transform::default(),
+            NotShadowCaster,
+            OccludedSilhouette,
+        ));
+    }
+}
+
+/// Keeps each silhouette's mesh in step with its combatant's (Polymorph swaps
+/// it) and hides the silhouette of a dead combatant.
+pub fn sync_occluded_silhouettes(
+    combatants: Query<(&Combatant, &Mesh3d), Without<OccludedSilhouette>>,
+    mut silhouettes: Query<(&ChildOf, &mut Mesh3d, &mut Visibility), With<OccludedSilhouette>>,
+) {
+    for (child_of, mut mesh, mut visibility) in silhouettes.iter_mut() {
+        let Ok((combatant, owner_mesh)) = combatants.get(child_of.parent()) else {
+            continue;
+        };
+        if mesh.0 != owner_mesh.0 {
+            mesh.0 = owner_mesh.0.clone();
+        }
+        let wanted = if combatant.is_alive() { Visibility::Inherited } else { Visibility::Hidden };
+        visibility.set_if_neq(wanted);
+    }
+}
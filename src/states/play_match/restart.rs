@@ -0,0 +1,170 @@
+//! Instant match restarts
+//!
+//! Restarts the current match without going back through ConfigureMatch,
+//! either with the seed the match was started from (to re-watch an
+//! interaction) or with a fresh one. `MatchConfig` is left untouched.
+//!
+//! Every graphical match is seeded: `setup_play_match` rolls a seed, records it
+//! in the match metadata and seeds `GameRng` (and, without an explicit arena
+//! seed, the arena dressing) from it. A restart stores the seed to reuse in a
+//! [`MatchRestart`] resource, which `setup_play_match` consumes.
+//!
+//! Bevy skips OnExit/OnEnter for a transition from a state to itself, so a
+//! restart goes PlayMatch → RestartMatch → PlayMatch. The extra frame lets
+//! cleanup despawn the old match before setup spawns the new one.
+//!
+//! Graphical frame timing still varies between runs, so a same-seed restart
+//! follows the same script but can drift from the original over a long match.
+//!
+//! Entry points: the restart hotkeys (R same seed, N new seed) and the buttons
+//! in the pause menu.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use super::components::{GameRng, SimulationSpeed};
+use crate::states::GameState;
+
+/// A pending restart. Present from the restart request until the new match's
+/// setup has consumed it. Loading a share code with a seed (the Load from code
+/// dialog, `--code`) inserts one too, to play that seed.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MatchRestart {
+    /// Seed to start the new match from; `None` rolls a fresh one
+    pub seed: Option<u64>,
+}
+
+/// Restart the current match, keeping its seed when `same_seed` is set.
+pub fn request_match_restart(
+    commands: &mut Commands,
+    next_state: &mut NextState<GameState>,
+    rng: Option<&GameRng>,
+    same_seed: bool,
+) {
+    let seed = if same_seed { rng.and_then(|rng| rng.seed) } else { None };
+    match seed {
+        Some(seed) => info!("Restarting match with seed {}", seed),
+        None => info!("Restarting match with a new seed"),
+    }
+    commands.insert_resource(MatchRestart { seed });
+    next_state.set(GameState::RestartMatch);
+}
+
+/// Restart hotkeys: same seed or new seed.
+pub fn handle_restart_hotkeys(
+    mut commands: Commands,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    rng: Option<Res<GameRng>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    use crate::keybindings::GameAction;
+
+    let same_seed = keybindings.action_just_pressed(GameAction::RestartSameSeed, &keyboard);
+    let new_seed = keybindings.action_just_pressed(GameAction::RestartNewSeed, &keyboard);
+    if same_seed || new_seed {
+        request_match_restart(&mut commands, &mut next_state, rng.as_deref(), same_seed);
+    }
+}
+
+/// Pause menu: shown while the match is paused, with resume and restart buttons.
+pub fn render_pause_menu(
+    mut contexts: EguiContexts,
+    mut commands: Commands,
+    mut sim_speed: ResMut<SimulationSpeed>,
+    mut time: ResMut<Time<Virtual>>,
+    rng: Option<Res<GameRng>>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if !sim_speed.is_paused() {
+        return;
+    }
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    egui::Area::new(egui::Id::new("pause_menu"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -24.0))
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(200))
+                .inner_margin(egui::Margin::symmetric(12, 8))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new("PAUSED")
+                                .size(14.0)
+                                .strong()
+                                .color(egui::Color32::from_rgb(255, 100, 100)),
+                        );
+                        ui.add_space(8.0);
+
+                        if ui.button("▶ Resume").clicked() {
+                            sim_speed.multiplier = 1.0;
+                            time.set_relative_speed(sim_speed.multiplier);
+                        }
+
+                        let same_seed = format!(
+                            "⟲ Restart [{}]",
+                            keybindings.binding_display(GameAction::RestartSameSeed)
+                        );
+                        let same_seed_btn = ui.button(same_seed).on_hover_text(match rng.as_ref().and_then(|rng| rng.seed) {
+                            Some(seed) => format!("Replay this match from seed {}", seed),
+                            None => "Replay this match".to_string(),
+                        });
+                        if same_seed_btn.clicked() {
+                            request_match_restart(&mut commands, &mut next_state, rng.as_deref(), true);
+                        }
+
+                        let new_seed = format!(
+                            "🎲 New Seed [{}]",
+                            keybindings.binding_display(GameAction::RestartNewSeed)
+                        );
+                        if ui.button(new_seed).on_hover_text("Restart this matchup with a fresh seed").clicked() {
+                            request_match_restart(&mut commands, &mut next_state, rng.as_deref(), false);
+                        }
+                    });
+                });
+        });
+}
+
+/// OnEnter(RestartMatch): the old match has been cleaned up, start the new one.
+pub fn resume_restarted_match(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::PlayMatch);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    fn request(same_seed: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin).init_state::<GameState>();
+        app.insert_resource(GameRng::from_seed(42));
+        app.world_mut()
+            .run_system_once(
+                move |mut commands: Commands, mut next: ResMut<NextState<GameState>>, rng: Res<GameRng>| {
+                    request_match_restart(&mut commands, &mut next, Some(&rng), same_seed);
+                },
+            )
+            .unwrap();
+        app
+    }
+
+    #[test]
+    fn same_seed_restart_keeps_the_seed() {
+        let app = request(true);
+        assert_eq!(app.world().resource::<MatchRestart>().seed, Some(42));
+    }
+
+    #[test]
+    fn new_seed_restart_rolls_a_fresh_seed() {
+        let app = request(false);
+        assert_eq!(app.world().resource::<MatchRestart>().seed, None);
+    }
+}
@@ -0,0 +1,497 @@
+//! Sandbox event injection
+//!
+//! Lets tests, tools and the in-game console push damage, healing and auras
+//! straight into a running match to see how the UI and combat systems react,
+//! e.g. "hit team 1 slot 0 for 500".
+//!
+//! Injected events are never applied directly. `dispatch_sandbox_events`
+//! turns each queued event into the same kind of pending entity the abilities
+//! use (`AuraPending`, or `SandboxDamagePending` / `SandboxHealPending`), so
+//! absorbs, armor and resistances, immunities, DR, aura breaks, rage gain,
+//! floating text, the combat log and death handling all behave exactly as
+//! for a real cast.
+//!
+//! Entry points:
+//! - In-process: `SandboxQueue::inject(SandboxEvent::Damage { .. })`
+//! - Console text: `SandboxQueue::inject_command("damage 1 0 500")`
+//!   (see [`SandboxEvent::parse`] for the syntax)
+//! - Graphical: the sandbox console (backquote by default)
+//! - Headless: the `sandbox` list in the match config JSON
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiGlobalSettings};
+
+use crate::combat::log::{CombatLog, CombatLogEventType};
+use super::abilities::SpellSchool;
+use super::combat_core::apply_damage_with_absorb;
+use super::components::*;
+use super::effect_pool::EffectCommandsExt;
+use super::utils::{check_effect_reference, combatant_id, discard_stale_effect, get_next_fct_offset};
+
+/// Combat log source ID for injected damage and healing
+pub const SANDBOX_SOURCE: &str = "Sandbox";
+
+/// Ability name used for injected damage, healing and auras in the log
+const SANDBOX_ABILITY: &str = "Sandbox";
+
+/// Number of console lines kept in the sandbox console history
+const CONSOLE_HISTORY_LINES: usize = 12;
+
+/// An event to inject into the running match. Targets are addressed by team
+/// (1 or 2) and 0-based slot; pets are not targetable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SandboxEvent {
+    /// Direct damage, mitigated like ability damage of `school`
+    Damage {
+        team: u8,
+        slot: usize,
+        amount: f32,
+        school: SpellSchool,
+        is_crit: bool,
+    },
+    /// Direct healing, reduced by healing-reduction debuffs
+    Healing { team: u8, slot: usize, amount: f32 },
+    /// An aura applied through the normal `AuraPending` path
+    AuraApplied {
+        team: u8,
+        slot: usize,
+        aura: AuraType,
+        duration: f32,
+        magnitude: f32,
+    },
+}
+
+impl SandboxEvent {
+    /// Parse a console command:
+    ///
+    /// ```text
+    /// damage <team> <slot> <amount> [school] [crit]
+    /// heal   <team> <slot> <amount>
+    /// aura   <team> <slot> <AuraType> <duration> [magnitude]
+    /// ```
+    ///
+    /// `team` is `1`/`2` (or `team1`/`team2`), `slot` is 0-based, `school`
+    /// and `AuraType` use their config names (`Fire`, `Stun`, ...).
+    /// School defaults to Physical and magnitude to 1.0.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&verb, args)) = words.split_first() else {
+            return Err("empty command".to_string());
+        };
+        let verb = verb.to_ascii_lowercase();
+        if !matches!(verb.as_str(), "damage" | "dmg" | "heal" | "aura") {
+            return Err(format!("unknown command '{}' (expected damage, heal or aura)", verb));
+        }
+        let arg = |i: usize, what: &str| {
+            args.get(i).copied().ok_or_else(|| format!("{}: missing {}", verb, what))
+        };
+        let team = parse_team(arg(0, "team")?)?;
+        let slot = arg(1, "slot")?
+            .parse::<usize>()
+            .map_err(|_| format!("invalid slot '{}'", args[1]))?;
+
+        let event = match verb.as_str() {
+            "damage" | "dmg" => {
+                let amount = parse_positive(arg(2, "amount")?, "amount")?;
+                let mut school = SpellSchool::Physical;
+                let mut is_crit = false;
+                for &extra in &args[3..] {
+                    if extra.eq_ignore_ascii_case("crit") {
+                        is_crit = true;
+                    } else {
+                        school = ron::from_str(extra).map_err(|_| format!("unknown spell school '{}'", extra))?;
+                    }
+                }
+                SandboxEvent::Damage { team, slot, amount, school, is_crit }
+            }
+            "heal" => {
+                let amount = parse_positive(arg(2, "amount")?, "amount")?;
+                SandboxEvent::Healing { team, slot, amount }
+            }
+            "aura" => {
+                let name = arg(2, "aura type")?;
+                let aura = ron::from_str(name).map_err(|_| format!("unknown aura type '{}'", name))?;
+                let duration = parse_positive(arg(3, "duration")?, "duration")?;
+                let magnitude = match args.get(4) {
+                    Some(m) => m.parse::<f32>().map_err(|_| format!("invalid magnitude '{}'", m))?,
+                    None => 1.0,
+                };
+                SandboxEvent::AuraApplied { team, slot, aura, duration, magnitude }
+            }
+            _ => unreachable!("verb checked above"),
+        };
+        Ok(event)
+    }
+
+    /// Target `(team, slot)` of the event
+    pub fn target(&self) -> (u8, usize) {
+        match *self {
+            SandboxEvent::Damage { team, slot, .. }
+            | SandboxEvent::Healing { team, slot, .. }
+            | SandboxEvent::AuraApplied { team, slot, .. } => (team, slot),
+        }
+    }
+}
+
+fn parse_team(word: &str) -> Result<u8, String> {
+    match word.to_ascii_lowercase().trim_start_matches("team") {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid team '{}' (expected 1 or 2)", word)),
+    }
+}
+
+fn parse_positive(word: &str, what: &str) -> Result<f32, String> {
+    match word.parse::<f32>() {
+        Ok(v) if v.is_finite() && v > 0.0 => Ok(v),
+        _ => Err(format!("{} must be a positive number, got '{}'", what, word)),
+    }
+}
+
+/// Events waiting to be injected on the next combat frame.
+#[derive(Resource, Default)]
+pub struct SandboxQueue {
+    pub pending: Vec<SandboxEvent>,
+}
+
+impl SandboxQueue {
+    /// Queue an event for the next combat frame
+    pub fn inject(&mut self, event: SandboxEvent) {
+        self.pending.push(event);
+    }
+
+    /// Parse a console command and queue it
+    pub fn inject_command(&mut self, line: &str) -> Result<(), String> {
+        self.inject(SandboxEvent::parse(line)?);
+        Ok(())
+    }
+}
+
+/// Turn queued sandbox events into pending effect entities on their targets.
+/// Runs in Phase 1 ahead of `apply_pending_auras`, so injected auras land on
+/// the same frame. Events whose slot has no living combatant are logged and
+/// dropped.
+pub fn dispatch_sandbox_events(
+    mut commands: Commands,
+    mut queue: ResMut<SandboxQueue>,
+    mut combat_log: ResMut<CombatLog>,
+    combatants: Query<(Entity, &Combatant), Without<Pet>>,
+) {
+    for event in std::mem::take(&mut queue.pending) {
+        let (team, slot) = event.target();
+        let target = combatants
+            .iter()
+            .find(|(_, c)| c.team == team && c.slot as usize == slot && c.is_alive());
+        let Some((entity, combatant)) = target else {
+            combat_log.log(
+                CombatLogEventType::MatchEvent,
+                format!("[SANDBOX] {:?} skipped: no living combatant in team {} slot {}", event, team, slot),
+            );
+            continue;
+        };
+        let target_id = combatant_id(combatant.team, combatant.class);
+
+        match event {
+            SandboxEvent::Damage { amount, school, is_crit, .. } => {
+                combat_log.log(
+                    CombatLogEventType::MatchEvent,
+                    format!("[SANDBOX] {:.0} {:?} damage on {}", amount, school, target_id),
+                );
+                commands.spawn(SandboxDamagePending { target: entity, amount, school, is_crit });
+            }
+            SandboxEvent::Healing { amount, .. } => {
+                combat_log.log(
+                    CombatLogEventType::MatchEvent,
+                    format!("[SANDBOX] {:.0} healing on {}", amount, target_id),
+                );
+                commands.spawn(SandboxHealPending { target: entity, amount });
+            }
+            SandboxEvent::AuraApplied { aura, duration, magnitude, .. } => {
+                combat_log.log(
+                    CombatLogEventType::MatchEvent,
+                    format!("[SANDBOX] {:?} ({:.1}s, magnitude {}) on {}", aura, duration, magnitude, target_id),
+                );
+                // Polymorph and Incapacitate break on any damage, like their
+                // real counterparts; everything else holds for the full duration.
+                let break_on_damage_threshold = match aura {
+                    AuraType::Polymorph | AuraType::Incapacitate => 0.0,
+                    _ => -1.0,
+                };
+                commands.spawn(AuraPending {
+                    target: entity,
+                    aura: Aura {
+                        effect_type: aura,
+                        duration,
+                        magnitude,
+                        break_on_damage_threshold,
+                        ability_name: SANDBOX_ABILITY.to_string(),
+                        ..Default::default()
+                    },
+                });
+            }
+        }
+    }
+}
+
+/// Resolve pending sandbox damage and healing.
+///
+/// Mirrors `process_holy_shock_damage` / `process_holy_shock_heals` minus the
+/// ability roll: the injected amount is the raw amount before mitigation.
+pub fn process_sandbox_effects(
+    mut commands: Commands,
+    mut combat_log: ResMut<CombatLog>,
+    pending_damage: Query<(Entity, &SandboxDamagePending)>,
+    pending_heals: Query<(Entity, &SandboxHealPending)>,
+    mut combatants: Query<(&mut Combatant, &Transform, Option<&mut ActiveAuras>)>,
+    mut fct_states: Query<&mut FloatingTextState>,
+) {
+    for (pending_entity, pending) in pending_damage.iter() {
+        let target_lookup = combatants.get_mut(pending.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(c, _, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Sandbox damage", "target", reason);
+            continue;
+        }
+        if let Some((mut target, target_transform, mut target_auras)) = target_lookup {
+            let (actual_damage, absorbed) =
+                apply_damage_with_absorb(pending.amount, &mut target, target_auras.as_deref_mut(), pending.school);
+
+            // Track damage for aura breaking
+            commands.entity(pending.target).insert(DamageTakenThisFrame {
+                amount: actual_damage,
+            });
+
+            // Warriors generate Rage from taking damage
+            if actual_damage > 0.0 && target.resource_type == ResourceType::Rage {
+                let rage_gain = actual_damage * 0.15;
+                target.current_mana = (target.current_mana + rage_gain).min(target.max_mana);
+            }
+
+            let text_position = target_transform.translation + Vec3::new(0.0, super::FCT_HEIGHT, 0.0);
+            let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(pending.target) {
+                get_next_fct_offset(&mut fct_state)
+            } else {
+                (0.0, 0.0)
+            };
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("{:.0}", actual_damage),
+                color: egui::Color32::from_rgb(255, 255, 0), // Yellow for ability damage
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: pending.is_crit,
+            });
+
+            let target_id = combatant_id(target.team, target.class);
+            let is_killing_blow = !target.is_alive();
+            let is_first_death = is_killing_blow && !target.is_dead;
+            if is_first_death {
+                target.is_dead = true;
+            }
+            let verb = if pending.is_crit { "CRITS" } else { "hits" };
+            let message = if absorbed > 0.0 {
+                format!(
+                    "{} {} {} for {:.0} damage ({:.0} absorbed)",
+                    SANDBOX_SOURCE, verb, target_id, actual_damage, absorbed
+                )
+            } else {
+                format!("{} {} {} for {:.0} damage", SANDBOX_SOURCE, verb, target_id, actual_damage)
+            };
+            combat_log.log_damage(
+                SANDBOX_SOURCE.to_string(),
+                target_id.clone(),
+                SANDBOX_ABILITY.to_string(),
+                actual_damage,
+                is_killing_blow,
+                pending.is_crit,
+                message,
+            );
+
+            if is_first_death {
+                // Cancel any in-progress cast or channel so dead combatants can't finish spells
+                commands.entity(pending.target).remove::<CastingState>();
+                commands.entity(pending.target).remove::<ChannelingState>();
+                combat_log.log_death(
+                    target_id.clone(),
+                    None,
+                    format!("{} has been eliminated by sandbox damage", target_id),
+                );
+            }
+        }
+        commands.entity(pending_entity).despawn();
+    }
+
+    for (pending_entity, pending) in pending_heals.iter() {
+        let target_lookup = combatants.get_mut(pending.target).ok();
+        if let Err(reason) = check_effect_reference(target_lookup.as_ref().map(|(c, _, _)| &**c)) {
+            discard_stale_effect(&mut commands, &mut combat_log, pending_entity, "Sandbox heal", "target", reason);
+            continue;
+        }
+        if let Some((mut target, target_transform, target_auras)) = target_lookup {
+            let mut heal_amount = pending.amount;
+            if let Some(auras) = target_auras {
+                for aura in &auras.auras {
+                    if aura.effect_type == AuraType::HealingReduction {
+                        // Magnitude is a multiplier (e.g., 0.65 = 35% reduction)
+                        heal_amount *= aura.magnitude;
+                    }
+                }
+            }
+
+            let old_health = target.current_health;
+            target.current_health = (target.current_health + heal_amount).min(target.max_health);
+            let actual_heal = target.current_health - old_health;
+
+            let text_position = target_transform.translation + Vec3::new(0.0, super::FCT_HEIGHT, 0.0);
+            let (offset_x, offset_y) = if let Ok(mut fct_state) = fct_states.get_mut(pending.target) {
+                get_next_fct_offset(&mut fct_state)
+            } else {
+                (0.0, 0.0)
+            };
+            commands.spawn_effect(FloatingCombatText {
+                world_position: text_position + Vec3::new(offset_x, offset_y, 0.0),
+                text: format!("+{:.0}", actual_heal),
+                color: egui::Color32::from_rgb(0, 255, 0), // Green for healing
+                lifetime: 1.5,
+                vertical_offset: offset_y,
+                is_crit: false,
+            });
+
+            let target_id = combatant_id(target.team, target.class);
+            combat_log.log_healing(
+                SANDBOX_SOURCE.to_string(),
+                target_id.clone(),
+                SANDBOX_ABILITY.to_string(),
+                actual_heal,
+                false,
+                format!("{} heals {} for {:.0}", SANDBOX_SOURCE, target_id, actual_heal),
+            );
+        }
+        commands.entity(pending_entity).despawn();
+    }
+}
+
+// ============================================================================
+// Sandbox Console (graphical only)
+// ============================================================================
+
+/// State of the in-game sandbox console.
+#[derive(Resource, Default)]
+pub struct SandboxConsole {
+    pub open: bool,
+    pub input: String,
+    /// Submitted commands and their results, oldest first
+    pub history: Vec<String>,
+}
+
+/// Sandbox console: a small command line at the bottom of the match view.
+/// While it is open egui absorbs keyboard input, so typing doesn't trigger
+/// camera or speed hotkeys.
+pub fn render_sandbox_console(
+    mut contexts: EguiContexts,
+    mut console: ResMut<SandboxConsole>,
+    mut queue: ResMut<SandboxQueue>,
+    mut egui_settings: ResMut<EguiGlobalSettings>,
+    keybindings: Res<crate::keybindings::Keybindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    use crate::keybindings::GameAction;
+
+    if keybindings.action_just_pressed(GameAction::ToggleSandboxConsole, &keyboard) {
+        console.open = !console.open;
+        console.input.clear();
+    }
+    if egui_settings.enable_absorb_bevy_input_system != console.open {
+        egui_settings.enable_absorb_bevy_input_system = console.open;
+    }
+    if !console.open {
+        return;
+    }
+
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let mut submitted = None;
+    egui::Window::new("Sandbox Console")
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -10.0))
+        .resizable(false)
+        .collapsible(false)
+        .title_bar(false)
+        .frame(egui::Frame::window(&ctx.style())
+            .fill(egui::Color32::from_black_alpha(220))
+            .stroke(egui::Stroke::NONE))
+        .show(ctx, |ui| {
+            ui.set_width(480.0);
+            for line in &console.history {
+                ui.label(egui::RichText::new(line).size(12.0).monospace().color(egui::Color32::from_rgb(200, 200, 200)));
+            }
+            ui.label(
+                egui::RichText::new("damage <team> <slot> <amount> [school] [crit] | heal <team> <slot> <amount> | aura <team> <slot> <Type> <secs> [magnitude]")
+                    .size(10.0)
+                    .color(egui::Color32::from_rgb(130, 130, 140)),
+            );
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut console.input)
+                    .desired_width(f32::INFINITY)
+                    .font(egui::TextStyle::Monospace)
+                    .hint_text("damage 1 0 500"),
+            );
+            response.request_focus();
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                submitted = Some(std::mem::take(&mut console.input));
+            }
+        });
+
+    if let Some(line) = submitted.filter(|l| !l.trim().is_empty()) {
+        let result = match queue.inject_command(&line) {
+            Ok(()) => format!("> {}", line.trim()),
+            Err(e) => format!("> {}  [error: {}]", line.trim(), e),
+        };
+        console.history.push(result);
+        let overflow = console.history.len().saturating_sub(CONSOLE_HISTORY_LINES);
+        console.history.drain(..overflow);
+    }
+}
+
+/// Leaving the match closes the console and hands keyboard input back to Bevy.
+pub fn close_sandbox_console(
+    mut console: ResMut<SandboxConsole>,
+    mut egui_settings: ResMut<EguiGlobalSettings>,
+) {
+    console.open = false;
+    egui_settings.enable_absorb_bevy_input_system = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_console_commands() {
+        assert_eq!(
+            SandboxEvent::parse("damage 1 0 500").unwrap(),
+            SandboxEvent::Damage { team: 1, slot: 0, amount: 500.0, school: SpellSchool::Physical, is_crit: false }
+        );
+        assert_eq!(
+            SandboxEvent::parse("damage team2 1 120 Fire crit").unwrap(),
+            SandboxEvent::Damage { team: 2, slot: 1, amount: 120.0, school: SpellSchool::Fire, is_crit: true }
+        );
+        assert_eq!(
+            SandboxEvent::parse("heal 2 0 300").unwrap(),
+            SandboxEvent::Healing { team: 2, slot: 0, amount: 300.0 }
+        );
+        assert_eq!(
+            SandboxEvent::parse("aura 1 2 Stun 4").unwrap(),
+            SandboxEvent::AuraApplied { team: 1, slot: 2, aura: AuraType::Stun, duration: 4.0, magnitude: 1.0 }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        assert!(SandboxEvent::parse("").is_err());
+        assert!(SandboxEvent::parse("damage 3 0 500").is_err());
+        assert!(SandboxEvent::parse("damage 1 0 -5").is_err());
+        assert!(SandboxEvent::parse("damage 1 0 50 Chaos").is_err());
+        assert!(SandboxEvent::parse("aura 1 0 Sleepy 4").is_err());
+        assert!(SandboxEvent::parse("explode 1 0").is_err());
+    }
+}
@@ -13,6 +13,21 @@
 //! 2. **CombatAndMovement** - Target acquisition, ability decisions, casting, projectiles
 //! 3. **CombatResolution** - Auto-attacks, death checks, visual effects
 //!
+//! ## Stale References
+//!
+//! Deferred effects (`*Pending` entities, projectiles, pet owner links) hold
+//! `Entity` references that may be dead or despawned by the time they resolve
+//! — typically because the kill shot landed earlier on the same frame. Every
+//! consumer checks its reference with `utils::check_effect_reference` and,
+//! on failure, resolves via `utils::discard_stale_effect`: the effect entity
+//! is despawned and a `[STALE]` match event is logged. Resolution order:
+//!
+//! 1. Phase 1 drains pendings queued last frame (divine shield, auras,
+//!    dispels, backlash, Holy Shock), after deaths from Phase 3 are final.
+//! 2. Phase 2 drains interrupts, then projectile hits (re-checking the target
+//!    between hits in the same batch), then orphans pets whose owner died.
+//! 3. Anything spawned in Phase 2/3 is resolved by next frame's Phase 1.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -40,7 +55,11 @@ pub use super::effects::process_dispels;
 pub use super::effects::process_holy_shock_heals;
 pub use super::effects::process_holy_shock_damage;
 pub use super::effects::process_divine_shield;
+pub use super::effects::process_ice_block;
+pub use super::effects::process_vanish;
 pub use super::effects::process_backlash;
+// Sandbox event injection (console / headless / in-process API)
+pub use super::sandbox::{dispatch_sandbox_events, process_sandbox_effects};
 
 // === Phase 2: Combat and Movement ===
 pub use super::auras::process_aura_breaks;
@@ -56,9 +75,11 @@ pub use super::combat_core::process_channeling;
 pub use super::projectiles::move_projectiles;
 pub use super::projectiles::process_projectile_hits;
 pub use super::combat_core::move_to_target;
+pub use super::combat_core::confine_to_ring_map;
 pub use super::traps::trap_system;
 pub use super::traps::move_trap_launch_projectiles;
 pub use super::combat_core::despawn_pets_of_dead_owners;
+pub use super::combat_core::{award_soul_shards, process_soulstone_revivals};
 
 // === Phase 1 (additional): Slow Zone ===
 pub use super::traps::slow_zone_system;
@@ -134,6 +155,7 @@ where
     // Initialize DecisionTrace resource (idempotent — safe to call from both
     // headless and graphical setup paths).
     app.init_resource::<super::decision_trace::DecisionTrace>();
+    app.init_resource::<super::sandbox::SandboxQueue>();
 
     // Phase 1: Resources and Auras
     app.add_systems(
@@ -147,8 +169,11 @@ where
             update_auras,
             slow_zone_system,       // Zone slow refresh before aura processing
             totem_pulse_system,     // Totem dedup + buff pulse on allies (after slow_zone_system)
+            dispatch_sandbox_events, // Injected events -> pending effects, before they are applied
             process_divine_shield,  // Must run BEFORE apply_pending_auras so DamageImmunity blocks CC
+            process_ice_block,      // Same ordering reason as Divine Shield
             apply_pending_auras,
+            process_vanish,         // After apply_pending_auras, so a slow landing this frame is shed too
             process_dispels,
             // Must run AFTER process_dispels (consumes BacklashPending events that
             // process_dispels spawns) and in the same Phase 1 chain so backlash
@@ -156,6 +181,7 @@ where
             process_backlash,
             process_holy_shock_heals,
             process_holy_shock_damage,
+            process_sandbox_effects,
         )
             .chain()
             .in_set(CombatSystemPhase::ResourcesAndAuras)
@@ -195,6 +221,7 @@ where
             move_trap_launch_projectiles,  // Arc travel for launched traps — before trap_system
             process_projectile_hits,
             move_to_target,
+            confine_to_ring_map,  // Duel Ring: clamp to the circle after movement
             trap_system,  // After movement — needs current positions for proximity check
             despawn_pets_of_dead_owners,  // Kill pets whose owner has died
         )
@@ -208,6 +235,8 @@ where
         Update,
         (
             combat_auto_attack,
+            award_soul_shards,           // After every damage path has queued this frame's kills
+            process_soulstone_revivals,
             flush_decision_trace_system,
         )
             .chain()
@@ -18,6 +18,7 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use crate::combat::log::{CombatLog, CombatLogEventType};
 use super::components::*;
+use super::utils::stable_combatant_order;
 
 /// Short window (seconds) the buff aura is refreshed to each pulse. Allies that
 /// leave the radius keep the buff for at most this long before it expires.
@@ -97,6 +98,7 @@ pub fn totem_pulse_system(
     }
 
     // --- Tick + pulse (winners only) ----------------------------------------
+    let ally_order = stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c)));
     for (entity, mut totem, totem_transform) in totems.iter_mut() {
         if !keep.contains(&entity) {
             continue; // loser — already queued for despawn above
@@ -119,7 +121,8 @@ pub fn totem_pulse_system(
         let buff_name = totem.element.buff_name();
         let totem_pos = totem_transform.translation;
 
-        for (ally_entity, ally, ally_transform, active_auras) in combatants.iter_mut() {
+        for &ally_entity in &ally_order {
+            let Ok((ally_entity, ally, ally_transform, active_auras)) = combatants.get_mut(ally_entity) else { continue; };
             if !ally.is_alive() {
                 continue;
             }
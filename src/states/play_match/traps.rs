@@ -11,6 +11,7 @@ use crate::combat::log::{CombatLog, CombatLogEventType};
 use super::components::*;
 use super::constants::*;
 use super::abilities::SpellSchool;
+use super::utils::stable_combatant_order;
 
 /// Single system handling the full trap lifecycle:
 /// 1. Decrement arm_timer, consider armed when timer hits 0
@@ -31,6 +32,9 @@ pub fn trap_system(
 
     let dt = time.delta_secs();
 
+    // Fixed target order: the first enemy in range springs the trap
+    let target_order = stable_combatant_order(combatants.iter().map(|(e, c, _)| (e, c)));
+
     for (trap_entity, mut trap, trap_transform) in traps.iter_mut() {
         // Skip already triggered traps
         if trap.triggered {
@@ -49,7 +53,8 @@ pub fn trap_system(
         let trap_pos = trap_transform.translation;
         let mut triggered_by: Option<(Entity, u8, String)> = None;
 
-        for (target_entity, target_combatant, target_transform) in combatants.iter_mut() {
+        for &target_entity in &target_order {
+            let Ok((target_entity, target_combatant, target_transform)) = combatants.get_mut(target_entity) else { continue; };
             // Skip dead combatants
             if !target_combatant.is_alive() {
                 continue;
@@ -189,6 +194,7 @@ pub fn slow_zone_system(
     }
 
     let dt = time.delta_secs();
+    let target_order = stable_combatant_order(combatants.iter().map(|(e, c, ..)| (e, c)));
 
     for (zone_entity, mut zone, zone_transform) in zones.iter_mut() {
         // Tick zone duration
@@ -201,7 +207,8 @@ pub fn slow_zone_system(
         let zone_pos = zone_transform.translation;
 
         // Check all enemy combatants for proximity
-        for (target_entity, target_combatant, target_transform, active_auras) in combatants.iter_mut() {
+        for &target_entity in &target_order {
+            let Ok((target_entity, target_combatant, target_transform, active_auras)) = combatants.get_mut(target_entity) else { continue; };
             // Skip dead combatants
             if !target_combatant.is_alive() {
                 continue;
@@ -4,9 +4,14 @@
 //! Having them here breaks circular dependencies between combat_ai and combat_core.
 
 use bevy::prelude::*;
-use crate::combat::log::{CombatLog, CombatantId};
+use crate::combat::log::{CombatLog, CombatLogEventType, CombatantId};
 use super::match_config::{self, CharacterClass};
-use super::components::{FloatingTextState, SpeechBubble, PlayMatchEntity};
+use super::ability_config::{school_visual_colors, AbilityConfig, ImpactVisual};
+use super::components::{
+    Combatant, FlameParticle, FloatingTextState, GameRng, GroundDecal, SpeechBubble, SpellImpactEffect,
+    PlayMatchEntity,
+};
+use super::effect_pool::EffectCommandsExt;
 
 /// Floating combat text horizontal spread (multiplied by -0.5 to +0.5 range)
 /// Adjust this to control how far left/right numbers can appear from their spawn point
@@ -23,6 +28,60 @@ pub fn combatant_id(team: u8, class: match_config::CharacterClass) -> CombatantI
     format!("Team {} {}", team, class.name())
 }
 
+/// Recover the class from a combat log ID built by `combatant_id`.
+/// Returns None for pets ("Team 2 Felhunter") and malformed IDs.
+pub fn class_from_combatant_id(id: &str) -> Option<CharacterClass> {
+    let (_, name) = id.strip_prefix("Team ")?.split_once(' ')?;
+    CharacterClass::all().iter().copied().find(|c| c.name() == name)
+}
+
+/// Team number of a combat log ID ("Team 2 Priest" -> 2), pets included.
+pub fn team_from_combatant_id(id: &str) -> Option<u8> {
+    id.strip_prefix("Team ")?.split_once(' ')?.0.parse().ok()
+}
+
+/// Body color of a class's 3D model (vibrant variants of the class colors so
+/// the capsules read well under the arena lighting).
+pub fn class_model_color(class: CharacterClass) -> Color {
+    match class {
+        CharacterClass::Warrior => Color::srgb(0.9, 0.6, 0.3), // Orange/brown
+        CharacterClass::Mage => Color::srgb(0.3, 0.6, 1.0),    // Bright blue
+        CharacterClass::Rogue => Color::srgb(1.0, 0.9, 0.2),   // Bright yellow
+        CharacterClass::Priest => Color::srgb(0.95, 0.95, 0.95), // White
+        CharacterClass::Warlock => Color::srgb(0.58, 0.41, 0.93), // Purple
+        CharacterClass::Paladin => Color::srgb(0.96, 0.55, 0.73), // Pink (WoW Paladin)
+        CharacterClass::Hunter => Color::srgb(0.67, 0.83, 0.45), // Green (WoW Hunter)
+        CharacterClass::Shaman => Color::srgb(0.0, 0.44, 0.87), // Blue (WoW Shaman)
+    }
+}
+
+/// Spacing between teammates in the starting pen (world units along Z).
+pub const FORMATION_SPACING: f32 = 3.0;
+
+/// Starting Z offset for a team slot, shared by graphical and headless setup.
+///
+/// Teams of up to 3 keep the historical layout (slot 0 at -3, slot 1 on the
+/// centre line, slot 2 at +3) so existing seeds replay unchanged. Larger
+/// teams (4v4, 5v5) are centred on Z = 0 at the same spacing.
+pub fn formation_z(slot: usize, team_size: usize) -> f32 {
+    let center = if team_size <= 3 { 1.0 } else { (team_size as f32 - 1.0) / 2.0 };
+    (slot as f32 - center) * FORMATION_SPACING
+}
+
+/// Entities of the given combatants in stable `(team, slot)` order (pets sort
+/// after primaries via `PET_SLOT_BASE`).
+///
+/// Query iteration follows ECS storage layout, which shifts whenever a
+/// component such as `CastingState` or `ActiveAuras` is inserted or removed.
+/// Gameplay loops whose results depend on processing order (RNG draws,
+/// first-come "this frame" guards, log order) walk this list and fetch each
+/// entity with `get`/`get_mut` instead of iterating the query directly.
+pub fn stable_combatant_order<'a>(combatants: impl Iterator<Item = (Entity, &'a Combatant)>) -> Vec<Entity> {
+    let mut order: Vec<(u8, u8, Entity)> = combatants.map(|(entity, c)| (c.team, c.slot, entity)).collect();
+    order.sort_by_key(|&(team, slot, _)| (team, slot));
+    order.into_iter().map(|(_, _, entity)| entity).collect()
+}
+
 /// Helper to log an ability cast with consistent formatting.
 ///
 /// Builds caster/target IDs from team + class, formats the message, and delegates
@@ -62,6 +121,85 @@ pub fn spawn_speech_bubble(commands: &mut Commands, owner: Entity, ability_name:
     ));
 }
 
+/// Spawn the impact visuals configured on an ability (`visuals.impact` and
+/// `visuals.ground_decal`) at the target position.
+///
+/// Flames roll their count and placement from the match RNG, so this is
+/// called from the core combat systems in every mode (headless included),
+/// not from rendering.
+pub fn spawn_ability_impact_visuals(
+    commands: &mut Commands,
+    def: &AbilityConfig,
+    target_pos: Vec3,
+    game_rng: &mut GameRng,
+) {
+    match &def.visuals.impact {
+        Some(ImpactVisual::Burst { color, emissive, duration, start_scale, end_scale }) => {
+            commands.spawn_effect(SpellImpactEffect {
+                position: target_pos,
+                color: Color::srgb(color[0], color[1], color[2]),
+                emissive: LinearRgba::rgb(emissive[0], emissive[1], emissive[2]),
+                lifetime: *duration,
+                initial_lifetime: *duration,
+                initial_scale: *start_scale,
+                final_scale: *end_scale,
+            });
+        }
+        Some(ImpactVisual::Generic) => {
+            let (color, emissive) = school_visual_colors(def.spell_school);
+            commands.spawn_effect(SpellImpactEffect {
+                position: target_pos,
+                color: Color::srgb(color[0], color[1], color[2]),
+                emissive: LinearRgba::rgb(emissive[0], emissive[1], emissive[2]),
+                lifetime: 0.5,
+                initial_lifetime: 0.5,
+                initial_scale: 0.5,
+                final_scale: 2.0,
+            });
+        }
+        Some(ImpactVisual::Flames { count_min, count_max }) => {
+            let spread = (count_max.saturating_sub(*count_min) + 1) as f32;
+            let particle_count = count_min + (game_rng.random_f32() * spread) as u32;
+            for _ in 0..particle_count {
+                // Randomize position slightly around target
+                let offset = Vec3::new(
+                    (game_rng.random_f32() - 0.5) * 1.0,  // -0.5 to 0.5
+                    game_rng.random_f32() * 0.5,          // 0 to 0.5 (start near ground)
+                    (game_rng.random_f32() - 0.5) * 1.0,
+                );
+                let velocity = Vec3::new(
+                    (game_rng.random_f32() - 0.5) * 0.5,  // Slight horizontal drift
+                    2.0 + game_rng.random_f32() * 1.5,    // Upward: 2.0-3.5 units/sec
+                    (game_rng.random_f32() - 0.5) * 0.5,
+                );
+                let lifetime = 0.6 + game_rng.random_f32() * 0.4;  // 0.6-1.0 sec
+                commands.spawn_effect_at(
+                    FlameParticle {
+                        velocity,
+                        lifetime,
+                        initial_lifetime: lifetime,
+                    },
+                    Transform::from_translation(target_pos + offset),
+                );
+            }
+        }
+        None => {}
+    }
+
+    if let Some(decal) = &def.visuals.ground_decal {
+        commands.spawn((
+            GroundDecal {
+                position: target_pos,
+                radius: decal.radius,
+                color: Color::srgb(decal.color[0], decal.color[1], decal.color[2]),
+                lifetime: decal.duration,
+                initial_lifetime: decal.duration,
+            },
+            PlayMatchEntity,
+        ));
+    }
+}
+
 /// Helper function to get next floating combat text offset and update pattern state.
 ///
 /// Returns (x_offset, y_offset) based on deterministic alternating pattern.
@@ -81,7 +219,8 @@ pub fn get_next_fct_offset(state: &mut FloatingTextState) -> (f32, f32) {
 }
 
 /// Whether an aura type is an incapacitating CC (prevents all actions).
-/// Root does NOT count — it only prevents movement.
+/// Root does NOT count — it only prevents movement. The self-cast Ice Block
+/// encasement counts too: the Mage can't act until it ends or is cancelled.
 pub fn is_incapacitating(aura_type: &super::components::AuraType) -> bool {
     matches!(
         aura_type,
@@ -89,6 +228,7 @@ pub fn is_incapacitating(aura_type: &super::components::AuraType) -> bool {
             | super::components::AuraType::Fear
             | super::components::AuraType::Polymorph
             | super::components::AuraType::Incapacitate
+            | super::components::AuraType::IceBlock
     )
 }
 
@@ -100,6 +240,59 @@ pub fn is_incapacitated(auras: Option<&super::components::ActiveAuras>) -> bool
     })
 }
 
+/// Why a deferred effect (projectile, `*Pending` entity, pet owner link) could
+/// not resolve against an entity it references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleReference {
+    /// The entity no longer exists, or no longer carries a `Combatant`.
+    Despawned,
+    /// The combatant still exists but is already dead (e.g. killed earlier on
+    /// the same frame by another hit).
+    Dead,
+}
+
+impl StaleReference {
+    fn describe(self) -> &'static str {
+        match self {
+            StaleReference::Despawned => "no longer exists",
+            StaleReference::Dead => "is dead",
+        }
+    }
+}
+
+/// Single validity check for entities referenced by deferred effects.
+///
+/// Pass the `Combatant` from whatever `Query::get` / `get_mut` the system
+/// already did (`.ok()` on the lookup). `Ok(())` means the effect may resolve;
+/// `Err` says why it must become a no-op instead — see `discard_stale_effect`.
+pub fn check_effect_reference(combatant: Option<&Combatant>) -> Result<(), StaleReference> {
+    match combatant {
+        None => Err(StaleReference::Despawned),
+        Some(c) if !c.is_alive() => Err(StaleReference::Dead),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Resolve a deferred effect whose referenced entity is stale as a logged
+/// no-op: despawn the effect entity and record a `[STALE]` match event.
+///
+/// `role` names the reference that failed ("target", "caster", "owner"), so
+/// the log line reads e.g. "[STALE] Frostbolt discarded: target is dead".
+pub fn discard_stale_effect(
+    commands: &mut Commands,
+    combat_log: &mut CombatLog,
+    effect_entity: Entity,
+    effect_name: &str,
+    role: &str,
+    reason: StaleReference,
+) {
+    commands.entity(effect_entity).despawn();
+    combat_log.log(
+        CombatLogEventType::MatchEvent,
+        format!("[STALE] {} discarded: {} {}", effect_name, role, reason.describe()),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +306,45 @@ mod tests {
         assert_eq!(id2, "Team 2 Mage");
     }
 
+    #[test]
+    fn test_class_from_combatant_id_round_trips() {
+        for &class in match_config::CharacterClass::all() {
+            assert_eq!(class_from_combatant_id(&combatant_id(2, class)), Some(class));
+        }
+        assert_eq!(class_from_combatant_id("Team 2 Felhunter"), None);
+        assert_eq!(class_from_combatant_id("Mage"), None);
+    }
+
+    #[test]
+    fn test_formation_z_keeps_small_teams_and_centres_large_ones() {
+        assert_eq!(formation_z(0, 1), -3.0);
+        assert_eq!(formation_z(2, 3), 3.0);
+        let five: Vec<f32> = (0..5).map(|slot| formation_z(slot, 5)).collect();
+        assert_eq!(five, vec![-6.0, -3.0, 0.0, 3.0, 6.0]);
+        assert_eq!(formation_z(0, 4), -4.5);
+    }
+
+    #[test]
+    fn test_stable_combatant_order_ignores_storage_order() {
+        use match_config::CharacterClass::{Hunter, Mage, Priest};
+        let pet = Combatant::new(1, super::super::constants::PET_SLOT_BASE, Hunter);
+        let t2_mage = Combatant::new(2, 0, Mage);
+        let t1_priest = Combatant::new(1, 1, Priest);
+        let t1_hunter = Combatant::new(1, 0, Hunter);
+        // Entity ids deliberately out of slot order
+        let input = [
+            (Entity::from_raw(1), &pet),
+            (Entity::from_raw(2), &t2_mage),
+            (Entity::from_raw(3), &t1_priest),
+            (Entity::from_raw(4), &t1_hunter),
+        ];
+        let order = stable_combatant_order(input.iter().copied());
+        let reversed = stable_combatant_order(input.iter().rev().copied());
+        let expected: Vec<Entity> = [4, 3, 1, 2].into_iter().map(Entity::from_raw).collect();
+        assert_eq!(order, expected);
+        assert_eq!(reversed, expected);
+    }
+
     #[test]
     fn test_fct_offset_pattern_cycles() {
         let mut state = FloatingTextState { next_pattern_index: 0 };
@@ -0,0 +1,319 @@
+//! Live Win Probability
+//!
+//! A heuristic estimate of each team's chance to win from the current state
+//! of the arena, shown during the match as a shifting odds bar (percentages
+//! and decimal betting odds) and graphed on the Results screen as the match's
+//! momentum.
+//!
+//! Each team gets a strength score, summed over its living combatants (pets
+//! don't count):
+//! - [`WEIGHT_ALIVE`] for being on their feet
+//! - [`WEIGHT_HEALTH`] × health fraction
+//! - [`WEIGHT_MANA`] × mana fraction for mana users, doubled for healers
+//!   (a dry healer usually decides the match)
+//! - minus [`WEIGHT_CROWD_CONTROL`] while incapacitated
+//! - minus [`WEIGHT_COOLDOWNS`] × the share of their cooldowns still ticking
+//!
+//! Team 1's probability is a logistic of the score difference. The weights
+//! are hand-tuned rather than fitted to batch results: an even matchup sits
+//! at 50%, losing a combatant is worth about 25 points, and a healer out of
+//! mana about 7.
+//!
+//! The bar is toggled in Options and in-match (`DisplaySettings::show_win_odds`).
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::combat::log::CombatLog;
+use crate::settings::TeamColors;
+use super::components::{ActiveAuras, Combatant, DisplaySettings, Pet, ResourceType, VictoryCelebration};
+use super::rendering::PresentationMode;
+use super::utils::is_incapacitated;
+
+/// Strength of each living combatant
+pub const WEIGHT_ALIVE: f32 = 1.0;
+/// Strength of a full health bar
+pub const WEIGHT_HEALTH: f32 = 1.0;
+/// Strength of a full mana bar (doubled for healers)
+pub const WEIGHT_MANA: f32 = 0.25;
+/// Strength lost while stunned, feared, polymorphed or otherwise incapacitated
+pub const WEIGHT_CROWD_CONTROL: f32 = 0.4;
+/// Strength lost with every cooldown ticking
+pub const WEIGHT_COOLDOWNS: f32 = 0.3;
+/// Logistic steepness: probability per point of strength difference
+const STEEPNESS: f32 = 0.55;
+/// Cooldown seconds past which a cooldown counts as fully ticking
+const COOLDOWN_HORIZON_SECS: f32 = 60.0;
+/// Match seconds between samples kept for the Results graph
+pub const HISTORY_INTERVAL: f32 = 0.5;
+/// Seconds for the displayed bar to close most of the gap to a new estimate
+const SMOOTHING_SECS: f32 = 0.6;
+/// Width of the in-match odds bar, in points
+const ODDS_BAR_WIDTH: f32 = 320.0;
+
+/// The parts of a combatant the estimate looks at.
+#[derive(Clone, Copy, Debug)]
+pub struct CombatantOutlook {
+    pub team: u8,
+    pub health_fraction: f32,
+    /// `None` for energy and rage users
+    pub mana_fraction: Option<f32>,
+    pub is_healer: bool,
+    pub incapacitated: bool,
+    /// 0 with every ability ready, 1 with every ability on a long cooldown
+    pub cooldown_load: f32,
+}
+
+impl CombatantOutlook {
+    pub fn from_combatant(combatant: &Combatant, auras: Option<&ActiveAuras>) -> Self {
+        let fraction = |current: f32, max: f32| if max > 0.0 { (current / max).clamp(0.0, 1.0) } else { 0.0 };
+        let cooldown_load = if combatant.ability_cooldowns.is_empty() {
+            0.0
+        } else {
+            combatant
+                .ability_cooldowns
+                .values()
+                .map(|remaining| remaining.clamp(0.0, COOLDOWN_HORIZON_SECS) / COOLDOWN_HORIZON_SECS)
+                .sum::<f32>()
+                / combatant.ability_cooldowns.len() as f32
+        };
+        Self {
+            team: combatant.team,
+            health_fraction: fraction(combatant.current_health, combatant.max_health),
+            mana_fraction: (combatant.resource_type == ResourceType::Mana)
+                .then(|| fraction(combatant.current_mana, combatant.max_mana)),
+            is_healer: combatant.class.is_healer(),
+            incapacitated: is_incapacitated(auras),
+            cooldown_load,
+        }
+    }
+
+    fn strength(&self) -> f32 {
+        let mana_weight = if self.is_healer { 2.0 * WEIGHT_MANA } else { WEIGHT_MANA };
+        let mut strength = WEIGHT_ALIVE + WEIGHT_HEALTH * self.health_fraction;
+        strength += self.mana_fraction.map_or(0.0, |mana| mana_weight * mana);
+        if self.incapacitated {
+            strength -= WEIGHT_CROWD_CONTROL;
+        }
+        strength - WEIGHT_COOLDOWNS * self.cooldown_load
+    }
+}
+
+/// Team 1's chance to win given its living combatants and team 2's.
+pub fn team1_win_probability(living: &[CombatantOutlook]) -> f32 {
+    let score = |team: u8| living.iter().filter(|c| c.team == team).map(CombatantOutlook::strength).sum::<f32>();
+    1.0 / (1.0 + (-STEEPNESS * (score(1) - score(2))).exp())
+}
+
+/// Live win probability for the current match. Left in place after the match
+/// so the Results screen can graph it.
+#[derive(Resource, Debug, Clone)]
+pub struct WinProbability {
+    /// Team 1's chance to win as shown, eased toward the latest estimate
+    pub team1: f32,
+    /// (match time, team 1's estimated chance), every `HISTORY_INTERVAL`,
+    /// ending with the final result
+    pub history: Vec<(f32, f32)>,
+    /// Set once the match is decided; the value no longer changes
+    finished: bool,
+}
+
+impl Default for WinProbability {
+    fn default() -> Self {
+        Self {
+            team1: 0.5,
+            history: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl WinProbability {
+    fn record(&mut self, time: f32, team1: f32) {
+        if self.history.last().is_none_or(|&(last, _)| time >= last + HISTORY_INTERVAL) {
+            self.history.push((time, team1));
+        }
+    }
+}
+
+/// Decimal betting odds for a win probability (stake included in the payout).
+pub fn decimal_odds(probability: f32) -> f32 {
+    1.0 / probability.clamp(0.01, 0.99)
+}
+
+/// Start each match at even odds with an empty graph.
+pub fn reset_win_probability(mut win_probability: ResMut<WinProbability>) {
+    *win_probability = WinProbability::default();
+}
+
+/// Re-estimate the win probability from the living combatants. Once the match
+/// is decided it snaps to the result and stops.
+pub fn update_win_probability(
+    time: Res<Time>,
+    combat_log: Res<CombatLog>,
+    celebration: Option<Res<VictoryCelebration>>,
+    combatants: Query<(&Combatant, Option<&ActiveAuras>), Without<Pet>>,
+    mut win_probability: ResMut<WinProbability>,
+) {
+    if win_probability.finished {
+        return;
+    }
+    let match_time = combat_log.match_time;
+
+    if let Some(celebration) = celebration {
+        let final_value = match celebration.winner {
+            Some(1) => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        };
+        win_probability.team1 = final_value;
+        win_probability.history.push((match_time, final_value));
+        win_probability.finished = true;
+        return;
+    }
+
+    let living: Vec<CombatantOutlook> = combatants
+        .iter()
+        .filter(|(combatant, _)| combatant.is_alive())
+        .map(|(combatant, auras)| CombatantOutlook::from_combatant(combatant, auras))
+        .collect();
+    let estimate = team1_win_probability(&living);
+
+    let ease = 1.0 - (-time.delta_secs() / SMOOTHING_SECS).exp();
+    win_probability.team1 += (estimate - win_probability.team1) * ease;
+    win_probability.record(match_time, estimate);
+}
+
+/// Odds bar at the top centre: each team's share in its color, with its
+/// percentage and decimal odds.
+pub fn render_win_odds_bar(
+    mut contexts: EguiContexts,
+    win_probability: Res<WinProbability>,
+    display_settings: Res<DisplaySettings>,
+    presentation: Res<PresentationMode>,
+    team_colors: Res<TeamColors>,
+) {
+    if !display_settings.show_win_odds || presentation.enabled {
+        return;
+    }
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let team1 = win_probability.team1;
+    let team2 = 1.0 - team1;
+    egui::Area::new(egui::Id::new("win_odds_bar"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 10.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(170))
+                .corner_radius(4.0)
+                .inner_margin(egui::Margin::symmetric(8, 5))
+                .show(ui, |ui| {
+                    ui.set_width(ODDS_BAR_WIDTH);
+
+                    let (rect, _) = ui.allocate_exact_size(egui::vec2(ODDS_BAR_WIDTH, 18.0), egui::Sense::hover());
+                    let split = rect.left() + rect.width() * team1;
+                    let painter = ui.painter();
+                    painter.rect_filled(
+                        egui::Rect::from_min_max(rect.min, egui::pos2(split, rect.bottom())),
+                        0.0,
+                        team_colors.egui(1),
+                    );
+                    painter.rect_filled(
+                        egui::Rect::from_min_max(egui::pos2(split, rect.top()), rect.max),
+                        0.0,
+                        team_colors.egui(2),
+                    );
+                    let percent_font = egui::FontId::proportional(13.0);
+                    for (team, share, align, x) in [
+                        (1, team1, egui::Align2::LEFT_CENTER, rect.left() + 4.0),
+                        (2, team2, egui::Align2::RIGHT_CENTER, rect.right() - 4.0),
+                    ] {
+                        painter.text(
+                            egui::pos2(x, rect.center().y),
+                            align,
+                            format!("T{} {:.0}%", team, share * 100.0),
+                            percent_font.clone(),
+                            egui::Color32::WHITE,
+                        );
+                    }
+
+                    ui.horizontal(|ui| {
+                        let odds = |share: f32| {
+                            egui::RichText::new(format!("{:.2}", decimal_odds(share)))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(200, 200, 200))
+                        };
+                        ui.label(odds(team1));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(odds(team2));
+                            ui.centered_and_justified(|ui| {
+                                ui.label(
+                                    egui::RichText::new("WIN ODDS")
+                                        .size(10.0)
+                                        .color(egui::Color32::from_rgb(140, 140, 158)),
+                                );
+                            });
+                        });
+                    });
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outlook(team: u8) -> CombatantOutlook {
+        CombatantOutlook {
+            team,
+            health_fraction: 1.0,
+            mana_fraction: None,
+            is_healer: false,
+            incapacitated: false,
+            cooldown_load: 0.0,
+        }
+    }
+
+    fn healer(team: u8, mana: f32) -> CombatantOutlook {
+        CombatantOutlook { mana_fraction: Some(mana), is_healer: true, ..outlook(team) }
+    }
+
+    #[test]
+    fn even_state_is_a_coin_flip_and_advantages_shift_it() {
+        let even = [outlook(1), healer(1, 1.0), outlook(2), healer(2, 1.0)];
+        assert!((team1_win_probability(&even) - 0.5).abs() < 1e-6);
+
+        // Team 2's melee is down: worth about 25 points
+        let man_up = [outlook(1), healer(1, 1.0), healer(2, 1.0)];
+        let p = team1_win_probability(&man_up);
+        assert!((0.72..0.78).contains(&p), "{}", p);
+
+        // Team 2's healer is dry: worth about 7 points
+        let dry = [outlook(1), healer(1, 1.0), outlook(2), healer(2, 0.0)];
+        let p = team1_win_probability(&dry);
+        assert!((0.55..0.60).contains(&p), "{}", p);
+
+        // Lower health, crowd control and spent cooldowns all count against a team
+        let hurt = [outlook(1), CombatantOutlook { health_fraction: 0.3, ..outlook(2) }];
+        let cc = [outlook(1), CombatantOutlook { incapacitated: true, ..outlook(2) }];
+        let spent = [outlook(1), CombatantOutlook { cooldown_load: 1.0, ..outlook(2) }];
+        for state in [hurt, cc, spent] {
+            assert!(team1_win_probability(&state) > 0.5);
+        }
+        assert_eq!(team1_win_probability(&[]), 0.5);
+    }
+
+    #[test]
+    fn history_is_sampled_and_odds_are_clamped() {
+        let mut win_probability = WinProbability::default();
+        for step in 0..10 {
+            win_probability.record(step as f32 * 0.1, 0.5);
+        }
+        assert_eq!(win_probability.history.len(), 2);
+
+        assert!((decimal_odds(0.5) - 2.0).abs() < 1e-6);
+        assert!((decimal_odds(0.0) - 100.0).abs() < 1e-3);
+    }
+}
@@ -2,17 +2,31 @@
 //!
 //! Displays match results after a battle concludes:
 //! - Compact winner banner (victor color, match duration)
+//! - One-paragraph match summary from the commentary templates
+//!   (`play_match::commentary`)
 //! - Two aligned, face-off team panels (loser panel dimmed)
 //! - Per-combatant rows with class icon, aligned stat columns, a relative
 //!   damage mini-bar, survival tag, and a click-to-expand ability breakdown
 //! - Team Σ TOTAL subtotal row
+//! - Match timeline with a scrubber and highlight markers (kills, big crits,
+//!   clutch interrupts/dispels, CC chains from `play_match::highlights`);
+//!   clicking a highlight chip jumps the scrubber to it, and the log lines
+//!   around the scrubbed time are listed below. Tab to the track and
+//!   Left/Right step the scrubber
 //! - Return-to-menu button
+//! - Duel ladder strip (Duel mode only): rating change for both classes plus
+//!   the current per-class standings
+//! - Share code strip: the setup and seed as a copyable code that replays
+//!   this exact match (see `headless::share_code`)
+//! - Momentum strip: team 1's live win probability over the match (see
+//!   `play_match::win_probability`), shaded toward whichever team led
 //!
 //! ## Data Source
 //! Reads the `MatchResults` resource inserted at match end (winner, duration,
 //! per-combatant `CombatantStats`) plus the `CombatLog` for per-ability
-//! damage/healing, killing blows, and CC time. Class icons come from the
-//! shared `ClassIcons` egui-texture resource loaded in ConfigureMatch.
+//! damage/healing, killing blows, and CC time. Class icons are the rendered
+//! `ClassPortraits` from the match, falling back to the shared `ClassIcons`
+//! egui-texture resource loaded in ConfigureMatch.
 //!
 //! ## UI Structure
 //! ```text
@@ -29,10 +43,12 @@
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use super::{GameState, play_match::{MatchResults, CombatantStats}};
+use super::{GameState, play_match::{find_highlights, match_summary, ClassPortraits, CommentaryTemplates, Highlight, HighlightKind, MatchResults, CombatantStats, WinProbability}};
 use super::configure_match_ui::ClassIcons;
-use super::match_config::CharacterClass;
+use super::match_config::{CharacterClass, MatchConfig};
+use super::duel_ladder::{DuelLadder, DuelLadderResult};
 use crate::combat::log::CombatLog;
+use crate::settings::TeamColors;
 
 // --- Layout constants (fixed widths keep numeric columns aligned across the
 //     header, every combatant row, and the Σ TOTAL row) ---
@@ -59,6 +75,8 @@ const C_DEAD: egui::Color32 = egui::Color32::from_rgb(205, 110, 110);
 /// Near-white text drawn on top of the colored ability-breakdown bars (kept
 /// independent of the bar fill color so it stays legible on a full bar).
 const BAR_TEXT: egui::Color32 = egui::Color32::from_rgb(244, 244, 248);
+const C_INTERRUPT: egui::Color32 = egui::Color32::from_rgb(110, 180, 255);
+const C_CC: egui::Color32 = egui::Color32::from_rgb(175, 120, 255);
 
 /// Dim factor applied to a defeated team's panel so the victor reads as dominant.
 const DIM_LOSER: f32 = 0.72;
@@ -67,29 +85,227 @@ const DIM_LOSER: f32 = 0.72;
 /// edge-to-edge on a wide window.
 const CONTENT_MAX_W: f32 = 1080.0;
 
+/// Height of the match timeline track.
+const TIMELINE_H: f32 = 26.0;
+/// Seconds one Left/Right key press moves the timeline scrubber
+const TIMELINE_KEY_STEP_SECS: f32 = 2.0;
+/// Seconds of combat log listed from the scrubbed time onward.
+const SCRUB_WINDOW_SECS: f32 = 4.0;
+/// Most log lines listed under the timeline.
+const SCRUB_MAX_LINES: usize = 8;
+/// Height of the win probability graph.
+const MOMENTUM_H: f32 = 64.0;
+
+/// Share code of the match on the Results screen, encoded once on entering it.
+/// `None` for setups that can't be encoded (Gauntlet), which get no strip.
+#[derive(Resource, Default)]
+pub struct ResultsShareCode(pub Option<String>);
+
+/// Encode the finished match's config and seed for the share-code strip.
+pub fn capture_results_share_code(
+    config: Res<MatchConfig>,
+    combat_log: Res<CombatLog>,
+    mut share_code: ResMut<ResultsShareCode>,
+) {
+    let seed = combat_log.metadata.as_ref().and_then(|metadata| metadata.random_seed);
+    share_code.0 = crate::headless::encode_share_code(&config, seed).ok();
+}
+
 /// Main UI system for the Results screen.
 ///
 /// Thin Bevy wrapper: grabs the egui context + resources and delegates the
 /// actual drawing to [`draw_results_screen`] (which is pure egui, so it can be
 /// snapshot-tested offscreen). Applies the DONE action on click.
+#[allow(clippy::too_many_arguments)]
 pub fn results_ui(
     mut contexts: EguiContexts,
     results: Option<Res<MatchResults>>,
     combat_log: Res<CombatLog>,
     class_icons: Res<ClassIcons>,
+    portraits: Res<ClassPortraits>,
+    duel_result: Option<Res<DuelLadderResult>>,
+    ladder: Res<DuelLadder>,
+    commentary: Option<Res<CommentaryTemplates>>,
+    team_colors: Res<TeamColors>,
+    share_code: Res<ResultsShareCode>,
+    win_probability: Res<WinProbability>,
     mut next_state: ResMut<NextState<GameState>>,
     mut commands: Commands,
 ) {
     let Some(ctx) = contexts.try_ctx_mut() else { return; };
 
-    let done = draw_results_screen(ctx, results.as_deref(), &combat_log, &class_icons);
+    // Bottom panels must be added before the CentralPanel fills the rest
+    if let Some(code) = &share_code.0 {
+        draw_share_code_panel(ctx, code);
+    }
+    if let Some(duel_result) = duel_result.as_deref() {
+        draw_duel_ladder_panel(ctx, duel_result, &ladder);
+    }
+    if win_probability.history.len() >= 2 {
+        draw_momentum_panel(ctx, &win_probability.history, &team_colors);
+    }
+
+    let icons = icons_with_portraits(&class_icons, &portraits);
+    let summary = results.as_deref().zip(commentary.as_deref()).map(|(results, templates)| {
+        match_summary(&combat_log, results.winner, results.duration_secs, templates)
+    });
+    let done = draw_results_screen(ctx, results.as_deref(), &combat_log, &icons, &team_colors, summary.as_deref());
 
     if done {
         commands.remove_resource::<MatchResults>();
+        commands.remove_resource::<DuelLadderResult>();
         next_state.set(GameState::MainMenu);
     }
 }
 
+/// Class icons for the Results tables: the rendered class portraits where
+/// available, otherwise the flat class icons.
+fn icons_with_portraits(class_icons: &ClassIcons, portraits: &ClassPortraits) -> ClassIcons {
+    let mut textures = class_icons.textures.clone();
+    textures.extend(portraits.textures.iter().map(|(class, id)| (*class, *id)));
+    ClassIcons {
+        textures,
+        loaded: class_icons.loaded,
+    }
+}
+
+/// Share code strip along the bottom of the Results screen: the code that
+/// replays this match, with a copy button.
+fn draw_share_code_panel(ctx: &egui::Context, code: &str) {
+    egui::TopBottomPanel::bottom("share_code_panel")
+        .frame(egui::Frame::new().fill(PANEL_BG).inner_margin(egui::Margin::symmetric(12, 6)))
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("SHARE CODE").size(12.0).strong().color(HEADER_GREY));
+                if ui.button("Copy").on_hover_text("Paste into Load from code or pass to --code").clicked() {
+                    ui.ctx().copy_text(code.to_string());
+                }
+                ui.add(egui::Label::new(egui::RichText::new(code).size(11.0).monospace().color(HEADER_GREY)).truncate());
+            });
+        });
+}
+
+/// Momentum strip along the bottom of the Results screen: team 1's win
+/// probability over the match, above the 50% line while team 1 was favored
+/// and below it while team 2 was. Hover for the value at a time.
+fn draw_momentum_panel(ctx: &egui::Context, history: &[(f32, f32)], team_colors: &TeamColors) {
+    egui::TopBottomPanel::bottom("momentum_panel")
+        .frame(egui::Frame::new().fill(PANEL_BG).inner_margin(egui::Margin::symmetric(12, 6)))
+        .show(ctx, |ui| {
+            ui.label(egui::RichText::new("MOMENTUM").size(12.0).strong().color(HEADER_GREY));
+            let (rect, response) =
+                ui.allocate_exact_size(egui::vec2(ui.available_width(), MOMENTUM_H), egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 2.0, BG);
+
+            let duration = history.last().map_or(0.0, |&(time, _)| time).max(1.0);
+            let x_at = |time: f32| rect.left() + rect.width() * (time / duration).clamp(0.0, 1.0);
+            let y_at = |team1: f32| rect.bottom() - rect.height() * team1;
+            let mid = y_at(0.5);
+            let color_at = |team1: f32| team_colors.egui(if team1 >= 0.5 { 1 } else { 2 });
+
+            for pair in history.windows(2) {
+                let ((t0, p0), (t1, p1)) = (pair[0], pair[1]);
+                let (x0, x1) = (x_at(t0), x_at(t1));
+                let shade = egui::Rect::from_x_y_ranges(x0..=x1, y_at(p0).min(mid)..=y_at(p0).max(mid));
+                painter.rect_filled(shade, 0.0, color_at(p0).gamma_multiply(0.35));
+                painter.line_segment(
+                    [egui::pos2(x0, y_at(p0)), egui::pos2(x1, y_at(p1))],
+                    egui::Stroke::new(2.0, color_at((p0 + p1) / 2.0)),
+                );
+            }
+            painter.hline(rect.x_range(), mid, egui::Stroke::new(1.0, DIVIDER));
+            painter.text(
+                rect.left_top() + egui::vec2(4.0, 2.0),
+                egui::Align2::LEFT_TOP,
+                "TEAM 1",
+                egui::FontId::proportional(10.0),
+                team_colors.egui(1),
+            );
+            painter.text(
+                rect.left_bottom() + egui::vec2(4.0, -2.0),
+                egui::Align2::LEFT_BOTTOM,
+                "TEAM 2",
+                egui::FontId::proportional(10.0),
+                team_colors.egui(2),
+            );
+
+            if let Some(pointer) = response.hover_pos() {
+                let time = (pointer.x - rect.left()) / rect.width() * duration;
+                let index = history.partition_point(|&(t, _)| t <= time).saturating_sub(1);
+                let (sample_time, team1) = history[index];
+                painter.vline(x_at(sample_time), rect.y_range(), egui::Stroke::new(1.0, HEADER_GREY));
+                response.on_hover_text_at_pointer(format!(
+                    "{}  Team 1 {:.0}% · Team 2 {:.0}%",
+                    fmt_duration(sample_time),
+                    team1 * 100.0,
+                    (1.0 - team1) * 100.0,
+                ));
+            }
+        });
+}
+
+/// Duel ladder strip along the bottom of the Results screen: each class's new
+/// rating with the change from this duel, then the full standings.
+fn draw_duel_ladder_panel(ctx: &egui::Context, result: &DuelLadderResult, ladder: &DuelLadder) {
+    egui::TopBottomPanel::bottom("duel_ladder_panel")
+        .frame(egui::Frame::new().fill(PANEL_BG).inner_margin(egui::Margin::same(12)))
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(egui::RichText::new("DUEL LADDER").size(14.0).strong().color(HEADER_GREY));
+                ui.add_space(6.0);
+
+                let change = |class: CharacterClass, rating: f32, delta: f32| {
+                    let delta_color = if delta > 0.0 {
+                        C_ALIVE
+                    } else if delta < 0.0 {
+                        C_DEAD
+                    } else {
+                        HEADER_GREY
+                    };
+                    (
+                        egui::RichText::new(format!("{} {:.0}", class.name(), rating))
+                            .size(16.0)
+                            .color(class_color32(class)),
+                        egui::RichText::new(format!(" ({:+.0})", delta)).size(16.0).color(delta_color),
+                    )
+                };
+                ui.horizontal(|ui| {
+                    let (name1, delta1) = change(result.team1_class, result.team1_rating, result.team1_delta);
+                    let (name2, delta2) = change(result.team2_class, result.team2_rating, result.team2_delta);
+                    ui.label(name1);
+                    ui.label(delta1);
+                    ui.label(egui::RichText::new("   vs   ").size(14.0).color(HEADER_GREY));
+                    ui.label(name2);
+                    ui.label(delta2);
+                    if result.team1_class == result.team2_class {
+                        ui.label(egui::RichText::new("   mirror match — unrated").size(12.0).color(HEADER_GREY));
+                    }
+                });
+
+                ui.add_space(6.0);
+                ui.horizontal_wrapped(|ui| {
+                    for (rank, (class, rating)) in ladder.standings().iter().enumerate() {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{}. {} {:.0} ({}-{}-{})",
+                                rank + 1,
+                                class.name(),
+                                rating.rating,
+                                rating.wins,
+                                rating.losses,
+                                rating.draws
+                            ))
+                            .size(12.0)
+                            .color(dim(class_color32(*class), 0.85)),
+                        );
+                        ui.add_space(STAT_GAP);
+                    }
+                });
+            });
+        });
+}
+
 /// Render the entire Results screen into `ctx`. Returns `true` if the DONE
 /// button was clicked this frame.
 ///
@@ -102,6 +318,8 @@ pub fn draw_results_screen(
     results: Option<&MatchResults>,
     combat_log: &CombatLog,
     class_icons: &ClassIcons,
+    team_colors: &TeamColors,
+    summary: Option<&str>,
 ) -> bool {
     let mut style = (*ctx.style()).clone();
     style.visuals.window_fill = BG;
@@ -132,7 +350,11 @@ pub fn draw_results_screen(
                 return;
             };
 
-            render_banner(ui, results.winner, results.duration_secs);
+            render_banner(ui, results.winner, results.duration_secs, team_colors);
+            if let Some(summary) = summary {
+                ui.add_space(12.0);
+                ui.label(egui::RichText::new(summary).size(15.0).italics().color(HEADER_GREY));
+            }
             ui.add_space(24.0);
 
             // Bar scaling shared across both teams so lengths are comparable.
@@ -151,16 +373,19 @@ pub fn draw_results_screen(
             ui.columns(2, |columns| {
                 render_team_panel(
                     &mut columns[0], "TEAM 1", 1, &results.team1_combatants, combat_log,
-                    class_icons, egui::Color32::from_rgb(90, 140, 230),
+                    class_icons, team_colors.egui(1),
                     results.winner, max_damage,
                 );
                 render_team_panel(
                     &mut columns[1], "TEAM 2", 2, &results.team2_combatants, combat_log,
-                    class_icons, egui::Color32::from_rgb(230, 90, 90),
+                    class_icons, team_colors.egui(2),
                     results.winner, max_damage,
                 );
             });
 
+            ui.add_space(20.0);
+            render_timeline(ui, results.duration_secs, &find_highlights(combat_log), combat_log);
+
             ui.add_space(28.0);
 
             ui.vertical_centered(|ui| {
@@ -182,11 +407,11 @@ pub fn draw_results_screen(
 }
 
 /// Render the top winner banner: victory line (in winner color) + match duration.
-fn render_banner(ui: &mut egui::Ui, winner: Option<u8>, duration_secs: f32) {
+fn render_banner(ui: &mut egui::Ui, winner: Option<u8>, duration_secs: f32, team_colors: &TeamColors) {
     let (text, color) = match winner {
         None => ("DRAW".to_string(), egui::Color32::from_rgb(210, 200, 120)),
-        Some(1) => ("TEAM 1 VICTORY".to_string(), egui::Color32::from_rgb(110, 160, 255)),
-        Some(2) => ("TEAM 2 VICTORY".to_string(), egui::Color32::from_rgb(255, 110, 110)),
+        Some(1) => ("TEAM 1 VICTORY".to_string(), team_colors.egui(1)),
+        Some(2) => ("TEAM 2 VICTORY".to_string(), team_colors.egui(2)),
         Some(_) => ("MATCH COMPLETE".to_string(), HEADER_GREY),
     };
     let star = if winner.is_some() { "★ " } else { "" };
@@ -215,6 +440,127 @@ fn render_banner(ui: &mut egui::Ui, winner: Option<u8>, duration_secs: f32) {
         });
 }
 
+/// Render the match timeline: a scrubbable track with the highlight windows
+/// marked on it, one chip per highlight (click to jump the scrubber there), and
+/// the combat log lines from the scrubbed time onward.
+fn render_timeline(ui: &mut egui::Ui, duration_secs: f32, highlights: &[Highlight], combat_log: &CombatLog) {
+    let scrub_id = ui.id().with("results_timeline_scrub");
+    let mut scrub: f32 = ui.data(|d| d.get_temp(scrub_id)).unwrap_or(0.0);
+    let duration = combat_log
+        .entries
+        .last()
+        .map_or(duration_secs, |e| e.timestamp.max(duration_secs))
+        .max(1.0);
+
+    egui::Frame::new()
+        .fill(PANEL_BG)
+        .corner_radius(8)
+        .inner_margin(egui::Margin::symmetric(16, 12))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("TIMELINE").size(13.0).strong().color(HEADER_GREY));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("⏱ {}", fmt_duration(scrub)))
+                            .size(13.0)
+                            .color(HEADER_GREY),
+                    );
+                });
+            });
+            ui.add_space(6.0);
+
+            // Track: click or drag anywhere to scrub
+            let (rect, response) = ui.allocate_exact_size(
+                egui::vec2(ui.available_width(), TIMELINE_H),
+                egui::Sense::click_and_drag(),
+            );
+            if let Some(pos) = response.interact_pointer_pos() {
+                scrub = ((pos.x - rect.left()) / rect.width()).clamp(0.0, 1.0) * duration;
+            }
+            // Keyboard: with the track focused (Tab), Left/Right step the scrubber
+            if response.has_focus() {
+                ui.memory_mut(|m| {
+                    m.set_focus_lock_filter(
+                        response.id,
+                        egui::EventFilter { horizontal_arrows: true, ..Default::default() },
+                    )
+                });
+                let step = ui.input(|i| {
+                    i.num_presses(egui::Key::ArrowRight) as f32 - i.num_presses(egui::Key::ArrowLeft) as f32
+                });
+                scrub = (scrub + step * TIMELINE_KEY_STEP_SECS).clamp(0.0, duration);
+            }
+            let x_at = |t: f32| rect.left() + (t / duration).clamp(0.0, 1.0) * rect.width();
+            let painter = ui.painter();
+            painter.rect_filled(rect, 4.0, BG);
+            for highlight in highlights {
+                let color = highlight_color(highlight.kind);
+                let window = egui::Rect::from_x_y_ranges(
+                    x_at(highlight.start)..=x_at(highlight.end).max(x_at(highlight.start) + 2.0),
+                    rect.y_range(),
+                );
+                painter.rect_filled(window, 2.0, color.gamma_multiply(0.35));
+                let x = x_at(highlight.time);
+                painter.line_segment(
+                    [egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())],
+                    egui::Stroke::new(2.0, color),
+                );
+            }
+            let x = x_at(scrub);
+            painter.line_segment(
+                [egui::pos2(x, rect.top() - 3.0), egui::pos2(x, rect.bottom() + 3.0)],
+                egui::Stroke::new(2.0, BAR_TEXT),
+            );
+
+            ui.add_space(8.0);
+            if highlights.is_empty() {
+                ui.label(egui::RichText::new("No highlights this match").size(12.0).color(HEADER_GREY));
+            }
+            ui.horizontal_wrapped(|ui| {
+                for highlight in highlights {
+                    let chip = egui::Button::new(
+                        egui::RichText::new(format!("{} {}", fmt_duration(highlight.time), highlight.kind.label()))
+                            .size(12.0)
+                            .color(highlight_color(highlight.kind)),
+                    )
+                    .fill(BG);
+                    if ui.add(chip).on_hover_text(&highlight.description).clicked() {
+                        scrub = highlight.start;
+                    }
+                }
+            });
+
+            ui.add_space(8.0);
+            let lines = combat_log
+                .query()
+                .between(scrub, scrub + SCRUB_WINDOW_SECS)
+                .entries()
+                .take(SCRUB_MAX_LINES);
+            for entry in lines {
+                ui.label(
+                    egui::RichText::new(format!("[{:>6.2}s] {}", entry.timestamp, entry.message))
+                        .size(12.0)
+                        .monospace()
+                        .color(HEADER_GREY),
+                );
+            }
+        });
+
+    ui.data_mut(|d| d.insert_temp(scrub_id, scrub));
+}
+
+/// Marker color for a highlight kind.
+fn highlight_color(kind: HighlightKind) -> egui::Color32 {
+    match kind {
+        HighlightKind::Kill => C_KILL,
+        HighlightKind::BigCrit => C_DMG,
+        HighlightKind::ClutchInterrupt => C_INTERRUPT,
+        HighlightKind::ClutchDispel => C_HEAL,
+        HighlightKind::CcChain => C_CC,
+    }
+}
+
 /// Render one team's face-off panel.
 #[allow(clippy::too_many_arguments)]
 fn render_team_panel(
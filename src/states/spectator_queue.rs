@@ -0,0 +1,470 @@
+//! Spectator queue - watch a batch of matches unattended
+//!
+//! ConfigureMatch grows a queue strip: ADD TO QUEUE snapshots the current
+//! setup, and PLAY ALL plays every queued match back-to-back at the chosen
+//! speed. Between matches the Results screen shows an interstitial scoreboard
+//! (every result so far plus per-team-comp standings) and counts down to the
+//! next match; the last match ends on the final scoreboard.
+//!
+//! Flow:
+//! 1. `spectator_queue_setup_panel` (ConfigureMatch) edits the queue and
+//!    starts playback, loading the first queued config into `MatchConfig`
+//! 2. `apply_queue_speed` (OnEnter PlayMatch, after setup) applies the
+//!    chosen speed to each queued match
+//! 3. `record_queue_result` (OnEnter Results) appends the finished match
+//! 4. `spectator_queue_results_panel` draws the scoreboard and advances
+//! 5. `end_spectator_queue` (OnEnter MainMenu) stops playback and restores
+//!    the setup that was on screen when PLAY ALL was pressed
+//!
+//! Gauntlet setups can't be queued — a gauntlet run is its own chain of
+//! rounds. Graphical mode only.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::headless::report::comp_label;
+
+use super::duel_ladder::DuelLadderResult;
+use super::match_config::{MatchConfig, MatchMode};
+use super::play_match::components::SimulationSpeed;
+use super::play_match::MatchResults;
+use super::GameState;
+
+/// Wall-clock seconds the interstitial scoreboard stays up between matches.
+pub const INTERSTITIAL_SECS: f32 = 10.0;
+
+/// Playback speeds offered for queued matches.
+const SPEED_CHOICES: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+
+/// One finished queued match.
+#[derive(Clone, Debug)]
+pub struct QueuedResult {
+    pub team1: String,
+    pub team2: String,
+    /// None = draw, Some(1) / Some(2) = winning team
+    pub winner: Option<u8>,
+    pub duration_secs: f32,
+}
+
+/// A team composition's record across the queue (either side counts).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Standing {
+    pub comp: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+/// In-progress playback of the queue.
+#[derive(Clone, Debug)]
+pub struct QueuePlayback {
+    /// Index of the match being (or last) played
+    pub current: usize,
+    /// Results of the finished matches, in play order
+    pub results: Vec<QueuedResult>,
+    /// Seconds left on the interstitial scoreboard
+    pub interstitial_remaining: f32,
+    /// Setup on screen when PLAY ALL was pressed, restored afterwards
+    pub saved_config: MatchConfig,
+}
+
+/// Matches queued from ConfigureMatch and the playback state.
+#[derive(Resource, Debug)]
+pub struct SpectatorQueue {
+    pub matches: Vec<MatchConfig>,
+    /// Simulation speed every queued match runs at
+    pub speed: f32,
+    pub playback: Option<QueuePlayback>,
+}
+
+impl Default for SpectatorQueue {
+    fn default() -> Self {
+        Self { matches: Vec::new(), speed: 2.0, playback: None }
+    }
+}
+
+impl SpectatorQueue {
+    /// Whether PLAY ALL is currently running
+    pub fn is_playing(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    /// Start playback from the first queued match. Returns its config, or
+    /// None if the queue is empty.
+    pub fn start(&mut self, current_setup: &MatchConfig) -> Option<MatchConfig> {
+        let first = self.matches.first()?.clone();
+        self.playback = Some(QueuePlayback {
+            current: 0,
+            results: Vec::new(),
+            interstitial_remaining: INTERSTITIAL_SECS,
+            saved_config: current_setup.clone(),
+        });
+        Some(first)
+    }
+
+    /// Record the current match's result (once per match).
+    pub fn record(&mut self, config: &MatchConfig, winner: Option<u8>, duration_secs: f32) {
+        let Some(playback) = self.playback.as_mut() else { return; };
+        if playback.results.len() > playback.current {
+            return;
+        }
+        playback.results.push(QueuedResult {
+            team1: comp_label(config.team1.iter().flatten()),
+            team2: comp_label(config.team2.iter().flatten()),
+            winner,
+            duration_secs,
+        });
+        playback.interstitial_remaining = INTERSTITIAL_SECS;
+    }
+
+    /// Move to the next queued match. Returns its config, or None once the
+    /// queue is exhausted.
+    pub fn advance(&mut self) -> Option<MatchConfig> {
+        let playback = self.playback.as_mut()?;
+        let next = self.matches.get(playback.current + 1)?.clone();
+        playback.current += 1;
+        Some(next)
+    }
+
+    /// Whether every queued match has been played
+    pub fn is_complete(&self) -> bool {
+        self.playback
+            .as_ref()
+            .is_some_and(|p| p.results.len() >= self.matches.len())
+    }
+
+    /// Stop playback, returning the setup to restore.
+    pub fn stop(&mut self) -> Option<MatchConfig> {
+        self.playback.take().map(|p| p.saved_config)
+    }
+}
+
+/// Per-comp records across `results`, most wins first (ties: fewest losses,
+/// then name). A comp playing itself scores on both sides.
+pub fn standings(results: &[QueuedResult]) -> Vec<Standing> {
+    fn row<'a>(table: &'a mut Vec<Standing>, comp: &str) -> &'a mut Standing {
+        let i = match table.iter().position(|s| s.comp == comp) {
+            Some(i) => i,
+            None => {
+                table.push(Standing { comp: comp.to_string(), wins: 0, losses: 0, draws: 0 });
+                table.len() - 1
+            }
+        };
+        &mut table[i]
+    }
+
+    let mut table: Vec<Standing> = Vec::new();
+    for result in results {
+        match result.winner {
+            Some(1) => {
+                row(&mut table, &result.team1).wins += 1;
+                row(&mut table, &result.team2).losses += 1;
+            }
+            Some(2) => {
+                row(&mut table, &result.team1).losses += 1;
+                row(&mut table, &result.team2).wins += 1;
+            }
+            _ => {
+                row(&mut table, &result.team1).draws += 1;
+                row(&mut table, &result.team2).draws += 1;
+            }
+        }
+    }
+    table.sort_by(|a, b| {
+        b.wins.cmp(&a.wins).then(a.losses.cmp(&b.losses)).then_with(|| a.comp.cmp(&b.comp))
+    });
+    table
+}
+
+/// Queue strip along the bottom of ConfigureMatch: queued matchups, speed
+/// choice, ADD TO QUEUE and PLAY ALL. Runs before `configure_match_ui` so
+/// the bottom panel is laid out ahead of its CentralPanel.
+pub fn spectator_queue_setup_panel(
+    mut contexts: EguiContexts,
+    mut config: ResMut<MatchConfig>,
+    mut queue: ResMut<SpectatorQueue>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let muted = egui::Color32::from_rgb(140, 140, 158);
+    let mut remove: Option<usize> = None;
+    let mut play_all = false;
+
+    egui::TopBottomPanel::bottom("spectator_queue_setup")
+        .frame(egui::Frame::new().fill(egui::Color32::from_rgb(28, 28, 40)).inner_margin(egui::Margin::same(10)))
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("SPECTATOR QUEUE").size(16.0).strong());
+                ui.add_space(12.0);
+
+                let can_add = config.is_valid() && config.mode != MatchMode::Gauntlet;
+                let add = ui
+                    .add_enabled(can_add, egui::Button::new("ADD TO QUEUE"))
+                    .on_disabled_hover_text("Fill every slot first (gauntlet runs can't be queued)");
+                if add.clicked() {
+                    queue.matches.push(config.clone());
+                }
+
+                ui.add_space(12.0);
+                ui.label(egui::RichText::new("Speed").color(muted));
+                for speed in SPEED_CHOICES {
+                    if ui.selectable_label(queue.speed == speed, format!("{}x", speed)).clicked() {
+                        queue.speed = speed;
+                    }
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let label = format!("PLAY ALL ({})", queue.matches.len());
+                    play_all = ui
+                        .add_enabled(!queue.matches.is_empty(), egui::Button::new(egui::RichText::new(label).size(16.0)))
+                        .clicked();
+                    if !queue.matches.is_empty() && ui.button("CLEAR").clicked() {
+                        queue.matches.clear();
+                    }
+                });
+            });
+
+            if queue.matches.is_empty() {
+                ui.label(
+                    egui::RichText::new("Set up a match and ADD it; PLAY ALL watches the whole queue back-to-back.")
+                        .size(12.0)
+                        .color(muted),
+                );
+                return;
+            }
+            ui.add_space(4.0);
+            egui::ScrollArea::horizontal().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (i, queued) in queue.matches.iter().enumerate() {
+                        ui.group(|ui| {
+                            ui.label(format!(
+                                "{}. {} vs {} · {}",
+                                i + 1,
+                                comp_label(queued.team1.iter().flatten()),
+                                comp_label(queued.team2.iter().flatten()),
+                                queued.map.name()
+                            ));
+                            if ui.small_button("✕").on_hover_text("Remove from queue").clicked() {
+                                remove = Some(i);
+                            }
+                        });
+                    }
+                });
+            });
+        });
+
+    if let Some(i) = remove {
+        queue.matches.remove(i);
+    }
+    if play_all {
+        let current_setup = config.clone();
+        if let Some(first) = queue.start(&current_setup) {
+            info!("Spectator queue: playing {} matches at {}x", queue.matches.len(), queue.speed);
+            *config = first;
+            next_state.set(GameState::PlayMatch);
+        }
+    }
+}
+
+/// Run queued matches at the chosen speed. `setup_play_match` resets the
+/// speed to 1x, so this runs after it.
+pub fn apply_queue_speed(
+    queue: Res<SpectatorQueue>,
+    sim_speed: Option<ResMut<SimulationSpeed>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if !queue.is_playing() {
+        return;
+    }
+    let Some(mut sim_speed) = sim_speed else { return; };
+    sim_speed.multiplier = queue.speed;
+    time.set_relative_speed(queue.speed);
+}
+
+/// Append the finished match to the queue's scoreboard.
+pub fn record_queue_result(
+    config: Res<MatchConfig>,
+    results: Option<Res<MatchResults>>,
+    mut queue: ResMut<SpectatorQueue>,
+) {
+    let Some(results) = results else { return; };
+    queue.record(&config, results.winner, results.duration_secs);
+}
+
+/// Interstitial scoreboard along the bottom of the Results screen while the
+/// queue plays: every result so far, comp standings, and a countdown to the
+/// next match (NEXT NOW / STOP QUEUE skip or end it). Runs before
+/// `results_ui` so the bottom panel is laid out ahead of its CentralPanel.
+pub fn spectator_queue_results_panel(
+    mut contexts: EguiContexts,
+    real_time: Res<Time<Real>>,
+    mut config: ResMut<MatchConfig>,
+    mut queue: ResMut<SpectatorQueue>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+) {
+    if !queue.is_playing() {
+        return;
+    }
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    let complete = queue.is_complete();
+    let total = queue.matches.len();
+    let Some(playback) = queue.playback.as_mut() else { return; };
+    if !complete {
+        playback.interstitial_remaining -= real_time.delta_secs();
+    }
+    let played = playback.results.len();
+    let countdown = playback.interstitial_remaining.max(0.0);
+    let table = standings(&playback.results);
+
+    let muted = egui::Color32::from_rgb(140, 140, 158);
+    let mut next_now = false;
+    let mut stop = false;
+
+    egui::TopBottomPanel::bottom("spectator_queue_scoreboard")
+        .frame(egui::Frame::new().fill(egui::Color32::from_rgb(28, 28, 40)).inner_margin(egui::Margin::same(12)))
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                let headline = if complete {
+                    format!("QUEUE COMPLETE — {} matches", total)
+                } else {
+                    format!("MATCH {} / {} DONE — next in {:.0}s", played, total, countdown.ceil())
+                };
+                ui.label(
+                    egui::RichText::new(headline)
+                        .size(20.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(255, 205, 90)),
+                );
+            });
+            ui.add_space(6.0);
+
+            ui.columns(2, |columns| {
+                egui::Grid::new("queue_results").striped(true).show(&mut columns[0], |ui| {
+                    for (i, result) in playback.results.iter().enumerate() {
+                        let outcome = match result.winner {
+                            Some(1) => format!("{} wins", result.team1),
+                            Some(2) => format!("{} wins", result.team2),
+                            _ => "Draw".to_string(),
+                        };
+                        ui.label(egui::RichText::new(format!("{}.", i + 1)).color(muted));
+                        ui.label(format!("{} vs {}", result.team1, result.team2));
+                        ui.label(egui::RichText::new(outcome).strong());
+                        ui.label(egui::RichText::new(format!("{:.1}s", result.duration_secs)).color(muted));
+                        ui.end_row();
+                    }
+                });
+                egui::Grid::new("queue_standings").striped(true).show(&mut columns[1], |ui| {
+                    ui.label(egui::RichText::new("Comp").color(muted));
+                    ui.label(egui::RichText::new("W").color(muted));
+                    ui.label(egui::RichText::new("L").color(muted));
+                    ui.label(egui::RichText::new("D").color(muted));
+                    ui.end_row();
+                    for standing in &table {
+                        ui.label(&standing.comp);
+                        ui.label(standing.wins.to_string());
+                        ui.label(standing.losses.to_string());
+                        ui.label(standing.draws.to_string());
+                        ui.end_row();
+                    }
+                });
+            });
+
+            if !complete {
+                ui.add_space(6.0);
+                ui.vertical_centered(|ui| {
+                    ui.horizontal(|ui| {
+                        next_now = ui.button(egui::RichText::new("NEXT NOW").size(16.0)).clicked();
+                        stop = ui.button(egui::RichText::new("STOP QUEUE").size(16.0)).clicked();
+                    });
+                });
+            }
+        });
+
+    if stop {
+        if let Some(saved) = queue.stop() {
+            *config = saved;
+        }
+        return;
+    }
+    if complete || !(next_now || countdown <= 0.0) {
+        return;
+    }
+    if let Some(next) = queue.advance() {
+        *config = next;
+        commands.remove_resource::<MatchResults>();
+        commands.remove_resource::<DuelLadderResult>();
+        next_state.set(GameState::PlayMatch);
+    }
+}
+
+/// Back on the main menu: stop any playback and restore the setup that was
+/// on screen when PLAY ALL was pressed. The queued matches stay queued.
+pub fn end_spectator_queue(
+    mut config: ResMut<MatchConfig>,
+    mut queue: ResMut<SpectatorQueue>,
+) {
+    if let Some(saved) = queue.stop() {
+        *config = saved;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::match_config::CharacterClass;
+
+    fn config(team1: &[CharacterClass], team2: &[CharacterClass]) -> MatchConfig {
+        MatchConfig {
+            team1: team1.iter().copied().map(Some).collect(),
+            team2: team2.iter().copied().map(Some).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn playback_walks_the_queue_and_records_once_per_match() {
+        let mut queue = SpectatorQueue::default();
+        let setup = config(&[CharacterClass::Hunter], &[CharacterClass::Hunter]);
+        queue.matches.push(config(&[CharacterClass::Warrior], &[CharacterClass::Mage]));
+        queue.matches.push(config(&[CharacterClass::Rogue], &[CharacterClass::Priest]));
+
+        let first = queue.start(&setup).unwrap();
+        assert_eq!(first.team1, vec![Some(CharacterClass::Warrior)]);
+        queue.record(&first, Some(2), 30.0);
+        queue.record(&first, Some(2), 30.0);
+        assert_eq!(queue.playback.as_ref().unwrap().results.len(), 1);
+        assert!(!queue.is_complete());
+
+        let second = queue.advance().unwrap();
+        assert_eq!(second.team1, vec![Some(CharacterClass::Rogue)]);
+        queue.record(&second, None, 90.0);
+        assert!(queue.is_complete());
+        assert!(queue.advance().is_none());
+
+        let restored = queue.stop().unwrap();
+        assert_eq!(restored.team1, vec![Some(CharacterClass::Hunter)]);
+        assert!(!queue.is_playing());
+        assert_eq!(queue.matches.len(), 2, "stopping keeps the queue");
+    }
+
+    #[test]
+    fn standings_credit_both_sides() {
+        let result = |team1: &str, team2: &str, winner| QueuedResult {
+            team1: team1.to_string(),
+            team2: team2.to_string(),
+            winner,
+            duration_secs: 10.0,
+        };
+        let table = standings(&[
+            result("Warrior", "Mage", Some(2)),
+            result("Mage", "Rogue", Some(1)),
+            result("Rogue", "Warrior", None),
+        ]);
+        assert_eq!(table[0], Standing { comp: "Mage".into(), wins: 2, losses: 0, draws: 0 });
+        assert_eq!(table[1], Standing { comp: "Rogue".into(), wins: 0, losses: 1, draws: 1 });
+        assert_eq!(table[2], Standing { comp: "Warrior".into(), wins: 0, losses: 1, draws: 1 });
+    }
+}
@@ -10,7 +10,7 @@
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use std::collections::HashMap;
-use super::{GameState, match_config::{CharacterClass, HunterPetType, MatchConfig, MageArmor, PaladinAura, RogueOpener, WarriorShout, WarlockCurse}};
+use super::{GameState, match_config::{CharacterClass, HunterPetType, MatchConfig, MageArmor, PaladinAura, Personality, RogueOpener, WarriorShout, WarlockCurse}};
 use super::configure_match_ui::ClassIcons;
 use super::play_match::AbilityType;
 use super::play_match::abilities::{ScalingStat, SpellSchool};
@@ -237,8 +237,11 @@ fn get_class_abilities(class: CharacterClass) -> Vec<AbilityType> {
             AbilityType::DemoralizingShout,
             AbilityType::CommandingShout,
             AbilityType::Charge,
+            AbilityType::Intercept,
+            AbilityType::Intervene,
             AbilityType::Rend,
             AbilityType::MortalStrike,
+            AbilityType::Execute,
             AbilityType::Pummel,
             AbilityType::HeroicStrike,
         ],
@@ -251,6 +254,8 @@ fn get_class_abilities(class: CharacterClass) -> Vec<AbilityType> {
             AbilityType::MageArmorSpell,
             AbilityType::MoltenArmor,
             AbilityType::Polymorph,
+            AbilityType::IceBlock,
+            AbilityType::ColdSnap,
         ],
         CharacterClass::Rogue => vec![
             AbilityType::Ambush,
@@ -259,6 +264,9 @@ fn get_class_abilities(class: CharacterClass) -> Vec<AbilityType> {
             AbilityType::KidneyShot,
             AbilityType::Kick,
             AbilityType::CripplingPoison,
+            AbilityType::Evasion,
+            AbilityType::Sprint,
+            AbilityType::Vanish,
         ],
         CharacterClass::Priest => vec![
             AbilityType::FlashHeal,
@@ -276,6 +284,9 @@ fn get_class_abilities(class: CharacterClass) -> Vec<AbilityType> {
             AbilityType::DeathCoil,
             AbilityType::Immolate,
             AbilityType::DrainLife,
+            AbilityType::DrainSoul,
+            AbilityType::Shadowburn,
+            AbilityType::Soulstone,
             AbilityType::CurseOfAgony,
             AbilityType::CurseOfWeakness,
             AbilityType::CurseOfTongues,
@@ -332,14 +343,23 @@ fn get_ability_name(ability: AbilityType) -> &'static str {
         AbilityType::Rend => "Rend",
         AbilityType::MortalStrike => "Mortal Strike",
         AbilityType::Pummel => "Pummel",
+        AbilityType::Intercept => "Intercept",
+        AbilityType::Intervene => "Intervene",
+        AbilityType::Execute => "Execute",
         AbilityType::Kick => "Kick",
         AbilityType::CripplingPoison => "Crippling Poison",
+        AbilityType::Evasion => "Evasion",
+        AbilityType::Sprint => "Sprint",
+        AbilityType::Vanish => "Vanish",
         AbilityType::Corruption => "Corruption",
         AbilityType::Shadowbolt => "Shadow Bolt",
         AbilityType::Fear => "Fear",
         AbilityType::DeathCoil => "Death Coil",
         AbilityType::Immolate => "Immolate",
         AbilityType::DrainLife => "Drain Life",
+        AbilityType::DrainSoul => "Drain Soul",
+        AbilityType::Shadowburn => "Shadowburn",
+        AbilityType::Soulstone => "Soulstone",
         AbilityType::ArcaneIntellect => "Arcane Intellect",
         AbilityType::BattleShout => "Battle Shout",
         AbilityType::IceBarrier => "Ice Barrier",
@@ -379,6 +399,8 @@ fn get_ability_name(ability: AbilityType) -> &'static str {
         AbilityType::FrostArmor => "Frost Armor",
         AbilityType::MageArmorSpell => "Mage Armor",
         AbilityType::MoltenArmor => "Molten Armor",
+        AbilityType::IceBlock => "Ice Block",
+        AbilityType::ColdSnap => "Cold Snap",
         AbilityType::ShadowResistanceAura => "Shadow Resistance Aura",
         AbilityType::ConcentrationAura => "Concentration Aura",
         // Shaman abilities
@@ -914,6 +936,40 @@ pub fn view_combatant_ui(
                     );
                 }
 
+                // AI personality panel (all classes). No icons: the option
+                // keys miss the icon map and draw as blank tiles.
+                ui.add_space(15.0);
+                let personality_panel_height = 120.0;
+                ui.allocate_ui_with_layout(
+                    egui::vec2(content_width, personality_panel_height),
+                    egui::Layout::left_to_right(egui::Align::TOP),
+                    |ui| {
+                        let options: Vec<(&str, Personality)> =
+                            Personality::ALL.iter().map(|p| (p.name(), *p)).collect();
+                        render_strategic_option_panel(
+                            ui,
+                            content_width,
+                            personality_panel_height,
+                            "AI PERSONALITY",
+                            &view_state,
+                            &ability_icons,
+                            &options,
+                            |mc, team, slot| {
+                                if team == 1 {
+                                    mc.team1_personalities.get(slot).copied().unwrap_or_default()
+                                } else {
+                                    mc.team2_personalities.get(slot).copied().unwrap_or_default()
+                                }
+                            },
+                            |mc, team, slot, val| {
+                                let vec = if team == 1 { &mut mc.team1_personalities } else { &mut mc.team2_personalities };
+                                if let Some(v) = vec.get_mut(slot) { *v = val; }
+                            },
+                            &mut match_config,
+                        );
+                    },
+                );
+
                 ui.add_space(15.0);
 
                 // Equipment panel (full width, replaces Gear + Talents placeholders)
@@ -1479,6 +1535,17 @@ fn build_aura_description(aura: &super::play_match::ability_config::AuraEffect)
         AuraType::WeakenedSoul => {
             format!("Cannot receive Power Word: Shield for {:.0} sec.", aura.duration)
         }
+        AuraType::Intervene => {
+            format!("The next attack against the target within {:.0} sec hits the caster instead.", aura.duration)
+        }
+        AuraType::DodgeChanceIncrease => {
+            let dodge_pct = (aura.magnitude * 100.0) as i32;
+            format!("Increases dodge chance against melee attacks by {}% for {:.0} sec.", dodge_pct, aura.duration)
+        }
+        AuraType::MovementSpeedIncrease => {
+            let speed_pct = (aura.magnitude * 100.0) as i32;
+            format!("Increases movement speed by {}% for {:.0} sec.", speed_pct, aura.duration)
+        }
         AuraType::DamageReduction => {
             let reduction_pct = (aura.magnitude * 100.0) as i32;
             format!("Reduces physical damage dealt by {}% for {:.0} sec.", reduction_pct, aura.duration)
@@ -1494,6 +1561,12 @@ fn build_aura_description(aura: &super::play_match::ability_config::AuraEffect)
         AuraType::DamageImmunity => {
             format!("Immune to all damage for {:.0} sec. Reduces damage dealt by 50%.", aura.duration)
         }
+        AuraType::SoulstoneResurrection => {
+            format!("Resurrects with {:.0}% health after death.", aura.magnitude * 100.0)
+        }
+        AuraType::IceBlock => {
+            format!("Encased in ice for {:.0} sec: cannot move, attack or cast.", aura.duration)
+        }
         AuraType::Incapacitate => {
             if aura.break_on_damage > 0.0 {
                 format!("Incapacitates the target for {:.0} sec. Breaks on any damage.", aura.duration)
@@ -2198,6 +2271,11 @@ impl HasNameDescription for PaladinAura {
     fn description(&self) -> &str { self.description() }
 }
 
+impl HasNameDescription for Personality {
+    fn name(&self) -> &str { self.name() }
+    fn description(&self) -> &str { self.description() }
+}
+
 /// Render the Hunter Pet Type selection panel
 fn render_hunter_pet_panel(
     ui: &mut egui::Ui,
@@ -0,0 +1,77 @@
+//! Keyboard Navigation
+//!
+//! Every menu is egui, which already moves focus between widgets with Tab /
+//! Shift+Tab and the arrow keys, and clicks the focused widget with Enter or
+//! Space. Two gaps kept the menus from being usable without a mouse:
+//! - the arrow keys do nothing until some widget has focus, so in menus an
+//!   arrow press with nothing focused focuses the first (Down/Right) or last
+//!   (Up/Left) widget, as Tab and Shift+Tab do
+//! - egui's focus highlight is subtle, and absent on the custom-drawn class
+//!   slots and cards, so a high-contrast ring is drawn around the focused
+//!   widget while the keyboard is in use
+//!
+//! Esc (`GameAction::Back`) still leaves each screen.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::{egui, EguiContext, EguiContexts, EguiInput};
+
+/// Focus ring color: yellow reads against every panel color in the game
+const FOCUS_RING_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 220, 0);
+/// Focus ring stroke width, in points
+const FOCUS_RING_WIDTH: f32 = 3.0;
+
+/// With nothing focused, turn an arrow press into Tab (Down/Right) or
+/// Shift+Tab (Up/Left) so the arrows start keyboard navigation too. Runs
+/// between egui's input processing and the start of its pass.
+pub fn focus_first_widget_with_arrows(mut contexts: Query<(&mut EguiContext, &mut EguiInput), With<PrimaryWindow>>) {
+    for (mut context, mut input) in contexts.iter_mut() {
+        if context.get_mut().memory(|m| m.focused().is_some()) {
+            continue;
+        }
+        let backwards = input.events.iter().find_map(|event| match event {
+            egui::Event::Key { key: egui::Key::ArrowDown | egui::Key::ArrowRight, pressed: true, .. } => Some(false),
+            egui::Event::Key { key: egui::Key::ArrowUp | egui::Key::ArrowLeft, pressed: true, .. } => Some(true),
+            _ => None,
+        });
+        if let Some(backwards) = backwards {
+            input.events.push(egui::Event::Key {
+                key: egui::Key::Tab,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers { shift: backwards, ..Default::default() },
+            });
+        }
+    }
+}
+
+/// Draw a ring around the focused widget while navigating by keyboard. Any key
+/// press turns the ring on, moving or clicking the mouse turns it off.
+pub fn draw_keyboard_focus_ring(mut contexts: EguiContexts, mut keyboard_active: Local<bool>) {
+    // Use try_ctx_mut to gracefully handle window close
+    let Some(ctx) = contexts.try_ctx_mut() else { return; };
+
+    ctx.input(|i| {
+        if i.events.iter().any(|e| matches!(e, egui::Event::Key { pressed: true, .. })) {
+            *keyboard_active = true;
+        } else if i.pointer.is_moving() || i.pointer.any_pressed() {
+            *keyboard_active = false;
+        }
+    });
+    if !*keyboard_active {
+        return;
+    }
+
+    // The widget's rect from this pass if it's been laid out yet, else the last
+    let Some(focused) = ctx.memory(|m| m.focused()).and_then(|id| ctx.read_response(id)) else {
+        return;
+    };
+    ctx.layer_painter(egui::LayerId::new(egui::Order::Tooltip, egui::Id::new("keyboard_focus_ring")))
+        .rect_stroke(
+            focused.rect.expand(2.0),
+            4.0,
+            egui::Stroke::new(FOCUS_RING_WIDTH, FOCUS_RING_COLOR),
+            egui::StrokeKind::Outside,
+        );
+}
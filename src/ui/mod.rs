@@ -10,17 +10,30 @@
 //! All UI is implemented using immediate-mode egui rather than retained-mode Bevy UI.
 //! This provides better maintainability and is more suited to agentic development.
 
+pub mod keyboard_nav;
+
 use bevy::prelude::*;
+use bevy_egui::EguiPreUpdateSet;
+
+use crate::states::GameState;
 
 /// Plugin for UI management.
-/// 
-/// Currently, all UI logic is handled per-state in `states/mod.rs` and related modules.
-/// This plugin exists as a placeholder for future shared UI utilities.
+///
+/// Per-screen UI logic is handled per-state in `states/mod.rs` and related
+/// modules. This plugin holds what is shared by every screen: keyboard
+/// navigation (`keyboard_nav`).
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
-    fn build(&self, _app: &mut App) {
-        // UI setup is now handled per-state in states/mod.rs
-        // This plugin will hold shared UI utilities and components
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            keyboard_nav::focus_first_widget_with_arrows
+                .after(EguiPreUpdateSet::ProcessInput)
+                .before(EguiPreUpdateSet::BeginPass)
+                // In a match, Space pauses: don't hand focus to a button it would also click
+                .run_if(not(in_state(GameState::PlayMatch))),
+        )
+        .add_systems(Update, keyboard_nav::draw_keyboard_focus_ring);
     }
 }
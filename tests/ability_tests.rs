@@ -6,7 +6,8 @@
 //! - Damage/healing abilities have appropriate scaling
 //! - Spell schools are correctly assigned
 
-use arenasim::states::play_match::{AbilityType, AuraType, SpellSchool, ScalingStat, AbilityDefinitions};
+use arenasim::states::match_config::CharacterClass;
+use arenasim::states::play_match::{AbilityType, AuraType, SpellSchool, ScalingStat, AbilityDefinitions, Combatant};
 
 // =============================================================================
 // Ability Definition Validation Tests
@@ -213,6 +214,8 @@ fn test_frost_abilities_have_frost_school() {
         AbilityType::Frostbolt,
         AbilityType::FrostNova,
         AbilityType::IceBarrier,
+        AbilityType::IceBlock,
+        AbilityType::ColdSnap,
     ];
 
     for ability in frost_abilities {
@@ -226,6 +229,29 @@ fn test_frost_abilities_have_frost_school() {
     }
 }
 
+#[test]
+fn test_cold_snap_resets_frost_cooldowns_only() {
+    let abilities = load_abilities();
+    let mut mage = Combatant::new(1, 0, CharacterClass::Mage);
+    for ability in [
+        AbilityType::IceBlock,
+        AbilityType::FrostNova,
+        AbilityType::ColdSnap,
+        AbilityType::Polymorph,
+    ] {
+        mage.ability_cooldowns.insert(ability, 30.0);
+    }
+
+    let reset = mage.reset_school_cooldowns(SpellSchool::Frost, AbilityType::ColdSnap, &abilities);
+
+    assert_eq!(reset.len(), 2);
+    assert!(reset.contains(&AbilityType::IceBlock) && reset.contains(&AbilityType::FrostNova));
+    // Cold Snap keeps its own cooldown; Arcane Polymorph is untouched
+    assert!(mage.ability_cooldowns.contains_key(&AbilityType::ColdSnap));
+    assert!(mage.ability_cooldowns.contains_key(&AbilityType::Polymorph));
+    assert!(!mage.ability_cooldowns.contains_key(&AbilityType::IceBlock));
+}
+
 #[test]
 fn test_shadow_abilities_have_shadow_school() {
     let abilities = load_abilities();
@@ -234,6 +260,8 @@ fn test_shadow_abilities_have_shadow_school() {
         AbilityType::Corruption,
         AbilityType::Shadowbolt,
         AbilityType::Fear,
+        AbilityType::DrainSoul,
+        AbilityType::Shadowburn,
     ];
 
     for ability in shadow_abilities {
@@ -278,6 +306,7 @@ fn test_physical_abilities_have_physical_school() {
         AbilityType::KidneyShot,
         AbilityType::Rend,
         AbilityType::MortalStrike,
+        AbilityType::Execute,
         AbilityType::Pummel,
         AbilityType::Kick,
     ];
@@ -304,6 +333,7 @@ fn test_physical_damage_scales_with_attack_power() {
         AbilityType::Ambush,
         AbilityType::SinisterStrike,
         AbilityType::MortalStrike,
+        AbilityType::Execute,
     ];
 
     for ability in physical_damage_abilities {
@@ -392,6 +422,21 @@ fn test_instant_abilities_have_no_projectile() {
     }
 }
 
+#[test]
+fn test_execute_abilities_are_flagged_and_instant() {
+    let abilities = load_abilities();
+    for ability in [AbilityType::Execute, AbilityType::Shadowburn] {
+        let def = abilities.get_unchecked(&ability);
+        assert!(def.is_execute, "{:?} should be gated on execute range", ability);
+        assert_eq!(def.cast_time, 0.0, "{:?} should be instant", ability);
+        assert!(def.is_damage(), "{:?} should deal damage", ability);
+    }
+    for ability in [AbilityType::MortalStrike, AbilityType::HeroicStrike, AbilityType::Shadowbolt] {
+        let def = abilities.get_unchecked(&ability);
+        assert!(!def.is_execute, "{:?} should be usable at any target HP", ability);
+    }
+}
+
 // =============================================================================
 // Aura Application Tests
 // =============================================================================
@@ -573,3 +573,36 @@ fn classify_returns_out_of_range_when_only_range_fails() {
         reason
     );
 }
+
+#[test]
+fn execute_abilities_require_target_in_execute_range() {
+    let mut world = TestWorld::new(CharacterClass::Warrior);
+    world.target_pos = world.caster_pos + Vec3::new(1.0, 0.0, 0.0); // melee range
+    let combatant = caster_combatant(CharacterClass::Warrior);
+    let abilities = defs();
+    let execute = abilities.get_unchecked(&AbilityType::Execute);
+    let mortal_strike = abilities.get_unchecked(&AbilityType::MortalStrike);
+    let target = Some((world.target, world.target_pos));
+
+    // Healthy target: Execute is gated, Mortal Strike is not.
+    assert!(!pre_cast_ok(
+        AbilityType::Execute, execute, &combatant, world.caster_pos, None, target, &world.ctx(), PreCastOpts::default(),
+    ));
+    let reason = classify_pre_cast_failure(
+        AbilityType::Execute, execute, &combatant, world.caster_pos, None, target, &world.ctx(), PreCastOpts::default(),
+    );
+    assert!(
+        matches!(reason, RejectionReason::PreconditionUnmet { .. }),
+        "expected PreconditionUnmet above execute range, got: {:?}",
+        reason
+    );
+    assert!(pre_cast_ok(
+        AbilityType::MortalStrike, mortal_strike, &combatant, world.caster_pos, None, target, &world.ctx(), PreCastOpts::default(),
+    ));
+
+    // Below 20% HP the target is in execute range.
+    world.combatants.get_mut(&world.target).unwrap().current_health = 15.0;
+    assert!(pre_cast_ok(
+        AbilityType::Execute, execute, &combatant, world.caster_pos, None, target, &world.ctx(), PreCastOpts::default(),
+    ));
+}
@@ -929,3 +929,166 @@ fn movement_slow_multiplier_stacks_multiplicatively() {
         ctx.movement_slow_multiplier(me)
     );
 }
+
+// ============================================================================
+// predicted_kill_target / weakened_soul_remaining — Priest pre-shield inputs
+// ============================================================================
+
+#[test]
+fn predicted_kill_target_is_most_targeted_ally() {
+    let (mut snapshot, me) = priest_snapshot();
+    let ally = Entity::from_raw(2);
+    snapshot.combatants.insert(ally, info(ally, 1, CharacterClass::Warrior));
+    // Two enemies on the Warrior, one on me.
+    for (raw, target) in [(3, ally), (4, ally), (5, me)] {
+        let enemy = Entity::from_raw(raw);
+        snapshot.combatants.insert(enemy, CombatantInfo {
+            target: Some(target),
+            ..info(enemy, 2, CharacterClass::Rogue)
+        });
+    }
+
+    let ctx = snapshot.context_for(me);
+    assert_eq!(ctx.predicted_kill_target(me).map(|c| c.entity), Some(ally));
+}
+
+#[test]
+fn predicted_kill_target_breaks_ties_on_lower_hp_and_needs_an_attacker() {
+    let (mut snapshot, me) = priest_snapshot();
+    let ally = Entity::from_raw(2);
+    snapshot.combatants.insert(ally, CombatantInfo {
+        current_health: 60.0,
+        ..info(ally, 1, CharacterClass::Mage)
+    });
+
+    // Nobody is targeted yet: no prediction.
+    assert!(snapshot.context_for(me).predicted_kill_target(me).is_none());
+
+    for (raw, target) in [(3, me), (4, ally)] {
+        let enemy = Entity::from_raw(raw);
+        snapshot.combatants.insert(enemy, CombatantInfo {
+            target: Some(target),
+            ..info(enemy, 2, CharacterClass::Warrior)
+        });
+    }
+    let ctx = snapshot.context_for(me);
+    assert_eq!(
+        ctx.predicted_kill_target(me).map(|c| c.entity),
+        Some(ally),
+        "one attacker each — the lower-HP ally is the predicted kill"
+    );
+}
+
+#[test]
+fn weakened_soul_remaining_reads_any_ally() {
+    let (mut snapshot, me) = priest_snapshot();
+    let ally = Entity::from_raw(2);
+    snapshot.combatants.insert(ally, info(ally, 1, CharacterClass::Warrior));
+    let mut ws = aura_with(AuraType::WeakenedSoul, Some(me), -1.0);
+    ws.duration = 9.5;
+    snapshot.active_auras.insert(ally, vec![ws]);
+
+    let ctx = snapshot.context_for(me);
+    assert_eq!(ctx.weakened_soul_remaining(ally), Some(9.5));
+    assert_eq!(ctx.weakened_soul_remaining(me), None);
+}
+
+// ============================================================================
+// ally_healer — Warrior peel target
+// ============================================================================
+
+#[test]
+fn ally_healer_skips_self_pets_enemies_and_the_dead() {
+    let me = Entity::from_raw(1);
+    let mut snapshot = snapshot_for(me, 1, CharacterClass::Warrior);
+    let ctx = snapshot.context_for(me);
+    assert_eq!(ctx.ally_healer(), None, "no healer on the team");
+
+    let enemy_priest = Entity::from_raw(2);
+    let dead_paladin = Entity::from_raw(3);
+    let pet = Entity::from_raw(4);
+    let priest = Entity::from_raw(5);
+    snapshot.combatants.insert(enemy_priest, info(enemy_priest, 2, CharacterClass::Priest));
+    snapshot.combatants.insert(dead_paladin, CombatantInfo {
+        is_alive: false,
+        ..info(dead_paladin, 1, CharacterClass::Paladin)
+    });
+    snapshot.combatants.insert(pet, pet_info(pet, 1, CharacterClass::Priest));
+    snapshot.combatants.insert(priest, info(priest, 1, CharacterClass::Priest));
+
+    assert_eq!(snapshot.context_for(me).ally_healer(), Some(priest));
+    // A healer is never its own peel target.
+    assert_eq!(snapshot.context_for(priest).ally_healer(), None);
+}
+
+// ============================================================================
+// vanish_would_hide — Rogue Vanish vs Shadow Sight
+// ============================================================================
+
+#[test]
+fn vanish_would_hide_unless_shadow_sight_is_held() {
+    let rogue = Entity::from_raw(1);
+    let enemy = Entity::from_raw(2);
+    let ally = Entity::from_raw(3);
+    let mut snapshot = snapshot_for(rogue, 1, CharacterClass::Rogue);
+    snapshot.combatants.insert(enemy, info(enemy, 2, CharacterClass::Mage));
+    snapshot.combatants.insert(ally, info(ally, 1, CharacterClass::Priest));
+    assert!(snapshot.context_for(rogue).vanish_would_hide(rogue));
+
+    // A teammate holding Shadow Sight doesn't reveal the Rogue.
+    snapshot.active_auras.insert(ally, vec![aura_with(AuraType::ShadowSight, None, -1.0)]);
+    assert!(snapshot.context_for(rogue).vanish_would_hide(rogue));
+
+    // An enemy holding Shadow Sight sees through Vanish — unless it's dead.
+    snapshot.active_auras.insert(enemy, vec![aura_with(AuraType::ShadowSight, None, -1.0)]);
+    assert!(!snapshot.context_for(rogue).vanish_would_hide(rogue));
+    snapshot.combatants.get_mut(&enemy).unwrap().is_alive = false;
+    assert!(snapshot.context_for(rogue).vanish_would_hide(rogue));
+
+    // The Rogue holding Shadow Sight itself is revealed.
+    snapshot.active_auras.insert(rogue, vec![aura_with(AuraType::ShadowSight, None, -1.0)]);
+    assert!(!snapshot.context_for(rogue).vanish_would_hide(rogue));
+}
+
+// ============================================================================
+// facing_lethal_burst — Mage Ice Block trigger
+// ============================================================================
+
+#[test]
+fn facing_lethal_burst_needs_attackers_and_scales_with_focus() {
+    use arenasim::states::play_match::class_ai::mage::facing_lethal_burst;
+
+    // Low HP but nobody on the Mage: no reason to block.
+    assert!(!facing_lethal_burst(0.1, 0));
+    // One attacker: only below the solo threshold.
+    assert!(facing_lethal_burst(0.2, 1));
+    assert!(!facing_lethal_burst(0.3, 1));
+    // Focused by two: block earlier.
+    assert!(facing_lethal_burst(0.3, 2));
+    assert!(!facing_lethal_burst(0.5, 2));
+}
+
+// ============================================================================
+// in_execute_range — gates Execute/Shadowburn, opens DPS burst windows
+// ============================================================================
+
+#[test]
+fn in_execute_range_requires_living_target_below_threshold() {
+    let me = Entity::from_raw(1);
+    let enemy = Entity::from_raw(2);
+
+    let mut snapshot = snapshot_for(me, 1, CharacterClass::Warrior);
+    let mut enemy_info = info(enemy, 2, CharacterClass::Mage);
+    enemy_info.current_health = 25.0;
+    snapshot.combatants.insert(enemy, enemy_info.clone());
+    assert!(!snapshot.context_for(me).in_execute_range(enemy), "25% is above execute range");
+
+    enemy_info.current_health = 15.0;
+    snapshot.combatants.insert(enemy, enemy_info.clone());
+    assert!(snapshot.context_for(me).in_execute_range(enemy));
+
+    enemy_info.current_health = 0.0;
+    enemy_info.is_alive = false;
+    snapshot.combatants.insert(enemy, enemy_info);
+    assert!(!snapshot.context_for(me).in_execute_range(enemy), "corpses are not executable");
+}
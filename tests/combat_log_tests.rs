@@ -6,7 +6,7 @@
 //! - Tracks CC duration
 //! - Identifies surviving combatants
 
-use arenasim::combat::log::{CombatLog, CombatLogEventType};
+use arenasim::combat::log::{CoalescedRows, CombatLog, CombatLogEventType};
 
 fn create_test_log() -> CombatLog {
     CombatLog::default()
@@ -317,6 +317,33 @@ fn test_killing_blows_per_combatant() {
     assert_eq!(log.killing_blows("Team 2 Mage"), 0);
 }
 
+#[test]
+fn test_killing_blows_by_ability() {
+    let mut log = create_test_log();
+
+    for (source, target, ability, is_killing_blow) in [
+        ("Team 1 Warrior", "Team 2 Mage", "Mortal Strike", false),
+        ("Team 1 Warrior", "Team 2 Mage", "Execute", true),
+        ("Team 1 Warlock", "Team 2 Priest", "Shadowburn", true),
+        ("Team 2 Rogue", "Team 1 Warrior", "Execute", true),
+    ] {
+        log.log_damage(
+            source.to_string(),
+            target.to_string(),
+            ability.to_string(),
+            100.0,
+            is_killing_blow,
+            false,
+            "Test".to_string(),
+        );
+    }
+
+    let by_ability = log.killing_blows_by_ability();
+    assert_eq!(by_ability.get("Execute"), Some(&2), "counts across both teams");
+    assert_eq!(by_ability.get("Shadowburn"), Some(&1));
+    assert_eq!(by_ability.get("Mortal Strike"), None, "non-lethal hits are not counted");
+}
+
 // =============================================================================
 // CC Duration Tests
 // =============================================================================
@@ -563,3 +590,168 @@ fn test_recent_entries() {
     assert_eq!(recent[1].message, "Event 8");
     assert_eq!(recent[2].message, "Event 9");
 }
+
+// =============================================================================
+// Query API Tests
+// =============================================================================
+
+/// Warrior hits the Mage at 1s and 3s, Priest heals the Warrior at 2s, Mage
+/// dies to the Warrior at 4s
+fn create_query_log() -> CombatLog {
+    let mut log = create_test_log();
+    log.match_time = 1.0;
+    log.log_damage("Team 1 Warrior".into(), "Team 2 Mage".into(), "Mortal Strike".into(), 50.0, false, false, "Test".into());
+    log.match_time = 2.0;
+    log.log_healing("Team 1 Priest".into(), "Team 1 Warrior".into(), "Flash Heal".into(), 30.0, false, "Test".into());
+    log.match_time = 3.0;
+    log.log_damage("Team 1 Warrior".into(), "Team 2 Mage".into(), "Auto Attack".into(), 20.0, false, false, "Test".into());
+    log.log_damage("Team 2 Mage".into(), "Team 1 Warrior".into(), "Frostbolt".into(), 40.0, false, false, "Test".into());
+    log.match_time = 4.0;
+    log.log(CombatLogEventType::MatchEvent, "Unstructured".into());
+    log.log_death("Team 2 Mage".into(), Some("Team 1 Warrior".into()), "Test".into());
+    log
+}
+
+#[test]
+fn test_query_filters_combine() {
+    let log = create_query_log();
+
+    assert_eq!(log.query().count(), 6);
+    assert_eq!(log.query().combatant("Team 1 Warrior").count(), 5);
+    assert_eq!(log.query().combatant("Team 1 Priest").count(), 1);
+    assert_eq!(log.query().combatant("Team 2 Rogue").count(), 0);
+    assert_eq!(log.query().event_type(CombatLogEventType::Damage).count(), 3);
+    assert_eq!(log.query().between(2.0, 4.0).count(), 3, "range is half-open");
+
+    let hits: Vec<f32> = log
+        .query()
+        .combatant("Team 2 Mage")
+        .event_type(CombatLogEventType::Damage)
+        .between(0.0, 3.5)
+        .entries()
+        .map(|e| e.timestamp)
+        .collect();
+    assert_eq!(hits, vec![1.0, 3.0, 3.0]);
+}
+
+#[test]
+fn test_query_time_range_with_out_of_order_entries() {
+    let mut log = create_query_log();
+    log.match_time = 0.5;
+    log.log(CombatLogEventType::MatchEvent, "Late".into());
+
+    let in_range: Vec<&str> = log.query().between(0.0, 1.5).entries().map(|e| e.message.as_str()).collect();
+    assert_eq!(in_range, vec!["Test", "Late"]);
+}
+
+#[test]
+fn test_damage_by_pair() {
+    let log = create_query_log();
+    let pairs = log.damage_by_pair();
+
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[&("Team 1 Warrior".to_string(), "Team 2 Mage".to_string())], 70.0);
+    assert_eq!(pairs[&("Team 2 Mage".to_string(), "Team 1 Warrior".to_string())], 40.0);
+    assert_eq!(log.healing_by_pair()[&("Team 1 Priest".to_string(), "Team 1 Warrior".to_string())], 30.0);
+}
+
+#[test]
+fn test_clear_resets_index() {
+    let mut log = create_query_log();
+    log.clear();
+
+    assert_eq!(log.query().combatant("Team 1 Warrior").count(), 0);
+    assert!(log.all_combatants().is_empty());
+}
+
+// =============================================================================
+// Periodic Tick Coalescing Tests
+// =============================================================================
+
+fn log_corruption_tick(log: &mut CombatLog, time: f32, amount: f32) {
+    log.match_time = time;
+    log.log_periodic_damage(
+        "Team 2 Warlock".to_string(),
+        "Team 1 Priest".to_string(),
+        "Corruption".to_string(),
+        amount,
+        false,
+        format!("Corruption ticks for {:.0}", amount),
+    );
+}
+
+#[test]
+fn test_coalesced_rows_merge_a_run_of_ticks() {
+    let mut log = create_test_log();
+    log_corruption_tick(&mut log, 3.0, 10.0);
+    log.match_time = 4.0;
+    log.log(CombatLogEventType::MatchEvent, "Between ticks".into());
+    log_corruption_tick(&mut log, 6.0, 10.0);
+    log_corruption_tick(&mut log, 9.0, 0.0);
+
+    let rows = log.coalesced_rows();
+    let messages: Vec<String> = rows.iter().map(|row| row.message().into_owned()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Between ticks".to_string(),
+            "Team 2 Warlock's Corruption ticks x3 for 20 damage on Team 1 Priest".to_string(),
+        ]
+    );
+    assert_eq!(rows[1].first_timestamp, 3.0);
+    assert_eq!(rows[1].entry.timestamp, 9.0);
+
+    // The raw stream keeps every tick
+    assert_eq!(log.entries.len(), 4);
+    assert_eq!(log.damage_by_ability("Team 2 Warlock")["Corruption"], 20.0);
+}
+
+#[test]
+fn test_coalesced_rows_split_on_gap_and_skip_direct_hits() {
+    let mut log = create_test_log();
+    log_corruption_tick(&mut log, 3.0, 10.0);
+    log_corruption_tick(&mut log, 30.0, 10.0);
+    log.log_damage(
+        "Team 2 Warlock".to_string(),
+        "Team 1 Priest".to_string(),
+        "Corruption".to_string(),
+        10.0,
+        false,
+        false,
+        "Direct".to_string(),
+    );
+
+    let rows = log.coalesced_rows();
+    assert_eq!(rows.len(), 3);
+    assert!(rows.iter().all(|row| !row.is_merged()));
+    assert_eq!(rows[2].message(), "Direct");
+}
+
+#[test]
+fn test_coalesced_rows_update_incrementally_and_rebuild_after_clear() {
+    let messages = |coalesced: &CoalescedRows, log: &CombatLog| -> Vec<String> {
+        coalesced.rows(log).map(|row| row.message().into_owned()).collect()
+    };
+    let full = |log: &CombatLog| -> Vec<String> {
+        log.coalesced_rows().iter().map(|row| row.message().into_owned()).collect()
+    };
+
+    let mut log = create_test_log();
+    let mut coalesced = CoalescedRows::default();
+    log_corruption_tick(&mut log, 3.0, 10.0);
+    coalesced.update(&log);
+    log.match_time = 4.0;
+    log.log(CombatLogEventType::MatchEvent, "Between ticks".into());
+    log_corruption_tick(&mut log, 6.0, 10.0);
+    coalesced.update(&log);
+    log_corruption_tick(&mut log, 30.0, 5.0);
+    coalesced.update(&log);
+    assert_eq!(messages(&coalesced, &log), full(&log));
+    assert_eq!(coalesced.rows(&log).count(), 3);
+
+    // A replaced log starts over instead of indexing stale rows
+    log.clear();
+    log_corruption_tick(&mut log, 1.0, 7.0);
+    coalesced.update(&log);
+    assert_eq!(messages(&coalesced, &log), full(&log));
+}
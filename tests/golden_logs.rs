@@ -0,0 +1,106 @@
+//! Golden combat logs: cross-platform determinism gate
+//!
+//! Each case runs a seeded headless match and compares its combat log,
+//! byte for byte, against the copy committed under `tests/golden/`. The
+//! goldens are platform-independent: CI (`.github/workflows/golden_logs.yml`)
+//! runs this file on Linux, Windows and macOS against the same files, so a
+//! diff on one OS means a float or iteration order leak (see "Cross-platform
+//! determinism" in CLAUDE.md).
+//!
+//! Build- and time-dependent metadata lines are stripped before comparing.
+//! After an intentional behavior change (which also bumps `SIM_VERSION`),
+//! regenerate with:
+//!
+//! ```bash
+//! UPDATE_GOLDEN=1 cargo test --test golden_logs
+//! ```
+
+use std::path::PathBuf;
+
+use arenasim::headless::{run_headless_match_with, HeadlessMatchConfig};
+
+const GOLDEN_DIR: &str = "tests/golden";
+
+/// Metadata header lines that legitimately differ between machines/runs:
+/// build identity, wall-clock time, the config hash (covers the temp output
+/// path) and the ability file checksum (line endings differ per checkout).
+const VOLATILE_PREFIXES: &[&str] = &[
+    "Version: ",
+    "Git hash: ",
+    "Config hash: ",
+    "Ability config checksum: ",
+    "Start time: ",
+];
+
+fn normalize(log: &str) -> String {
+    log.lines()
+        .filter(|line| !VOLATILE_PREFIXES.iter().any(|prefix| line.starts_with(prefix)))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn check_golden(name: &str, team1: &[&str], team2: &[&str], seed: u64) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let log_path = dir.path().join(format!("{}.txt", name));
+    let config = HeadlessMatchConfig {
+        team1: team1.iter().map(|s| s.to_string()).collect(),
+        team2: team2.iter().map(|s| s.to_string()).collect(),
+        max_duration_secs: 60.0,
+        random_seed: Some(seed),
+        output_path: Some(log_path.to_string_lossy().into_owned()),
+        ..Default::default()
+    };
+    run_headless_match_with(config, false, None).expect("match should run");
+    let actual = normalize(&std::fs::read_to_string(&log_path).expect("combat log written"));
+
+    let golden_path = PathBuf::from(GOLDEN_DIR).join(format!("{}.txt", name));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(GOLDEN_DIR).expect("create golden dir");
+        std::fs::write(&golden_path, &actual).expect("write golden");
+        return;
+    }
+
+    let golden = std::fs::read_to_string(&golden_path).unwrap_or_else(|e| {
+        panic!("missing golden {} ({}); run with UPDATE_GOLDEN=1", golden_path.display(), e)
+    });
+    // Tolerate CRLF from a Windows checkout of the golden file
+    let golden = normalize(&golden);
+    if actual == golden {
+        return;
+    }
+
+    let (line, expected, got) = golden
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (a, b))| a != b)
+        .map(|(i, (a, b))| (i + 1, a.to_string(), b.to_string()))
+        .unwrap_or_else(|| {
+            let n = golden.lines().count().min(actual.lines().count());
+            (n + 1, "<end of log>".to_string(), "<end of log>".to_string())
+        });
+    panic!(
+        "{} diverged from its golden log at line {}\n  golden: {}\n  actual: {}\n\
+         ({} golden lines vs {} actual). If the change is intentional, bump SIM_VERSION \
+         and regenerate with UPDATE_GOLDEN=1",
+        name,
+        line,
+        expected,
+        got,
+        golden.lines().count(),
+        actual.lines().count()
+    );
+}
+
+/// Kiting (movement scorer compass), fear wander and facing: the trig-heavy
+/// paths that used to go through the platform libm.
+#[test]
+fn golden_kiting_and_fear() {
+    check_golden("kiting_and_fear", &["Warrior", "Priest"], &["Mage", "Warlock"], 0x5EED_0001);
+}
+
+/// Pets, traps, totem placement and paladin auras.
+#[test]
+fn golden_pets_and_totems() {
+    check_golden("pets_and_totems", &["Hunter", "Shaman"], &["Rogue", "Paladin"], 0x5EED_0002);
+}
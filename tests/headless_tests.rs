@@ -35,6 +35,8 @@ fn test_match_result_has_winner() {
         team1_combatants: vec![],
         team2_combatants: vec![],
         random_seed: Some(12345),
+        killing_blows: Default::default(),
+        highlights: vec![],
     };
 
     assert_eq!(result.winner, Some(1));
@@ -508,3 +510,162 @@ fn different_seeds_produce_different_matches() {
 
     assert!(differs, "seeds 1 and 2 produced identical results — RNG may not be wired");
 }
+
+#[test]
+fn test_cap_deferred_while_immune() {
+    use arenasim::headless::runner::{cap_reached, IMMUNITY_OVERTIME_MAX_SECS};
+
+    // Before the cap nothing ends, immune or not
+    assert!(!cap_reached(59.0, 60.0, false));
+    assert!(!cap_reached(59.0, 60.0, true));
+
+    // At the cap with nobody immune: draw immediately
+    assert!(cap_reached(60.0, 60.0, false));
+
+    // Someone immune: draw is deferred into overtime...
+    assert!(!cap_reached(60.0, 60.0, true));
+    assert!(!cap_reached(60.0 + IMMUNITY_OVERTIME_MAX_SECS - 0.1, 60.0, true));
+
+    // ...but overtime is bounded even if everyone stays immune
+    assert!(cap_reached(60.0 + IMMUNITY_OVERTIME_MAX_SECS, 60.0, true));
+}
+
+/// Two priests run each other out of mana and then wand-and-heal forever:
+/// the stalemate detector ends it as a draw long before the 300s cap.
+#[test]
+fn mirrored_healers_end_in_stalemate_before_the_cap() {
+    use arenasim::headless::EndReason;
+
+    let config = HeadlessMatchConfig {
+        max_duration_secs: 300.0,
+        stalemate_secs: 30.0,
+        ..create_config(vec!["Priest"], vec!["Priest"], Some(7))
+    };
+    let result = run_headless_match_with(config, true, None).expect("match runs");
+    assert_eq!(result.end_reason, EndReason::Stalemate);
+    assert_eq!(result.winner, None);
+    assert!(result.match_time < 200.0, "stalemate took {:.1}s", result.match_time);
+}
+
+#[test]
+fn duel_mode_is_one_v_one_and_confined_to_the_ring() {
+    use arenasim::headless::run_headless_match_observed;
+    use arenasim::states::play_match::DUEL_RING_RADIUS;
+
+    let mut duel = create_config(vec!["Mage"], vec!["Hunter"], Some(42));
+    duel.mode = "Duel".to_string();
+    duel.map = "DuelRing".to_string();
+    duel.validate().expect("1v1 duel config should validate");
+
+    let mut too_big = duel.clone();
+    too_big.team1.push("Priest".to_string());
+    let err = too_big.validate().unwrap_err();
+    assert!(err.contains("Duel mode"), "unexpected error: {}", err);
+
+    // Two kiting ranged classes: everyone (pet included) must stay inside the
+    // ring for the whole match, and gates open after the 5s duel countdown.
+    let mut first_open = None;
+    run_headless_match_observed(duel, true, None, |frame| {
+        if frame.gates_open && first_open.is_none() {
+            first_open = Some(frame.sim_time);
+        }
+        for c in frame.combatants.values() {
+            let dist = (c.position.x * c.position.x + c.position.z * c.position.z).sqrt();
+            assert!(
+                dist <= DUEL_RING_RADIUS + 0.01,
+                "{:?} left the duel ring at t={:.2} (dist {:.2})",
+                c.class,
+                frame.sim_time,
+                dist
+            );
+        }
+    })
+    .expect("duel run");
+    let first_open = first_open.expect("gates never opened");
+    assert!(first_open < 6.0, "duel countdown should be ~5s, gates opened at {:.2}", first_open);
+}
+
+#[test]
+fn five_v_five_spawns_full_teams_in_distinct_slots() {
+    use arenasim::headless::run_headless_match_observed;
+
+    let team1 = vec!["Warrior", "Hunter", "Warlock", "Priest", "Mage"];
+    let team2 = vec!["Rogue", "Hunter", "Warlock", "Paladin", "Shaman"];
+    let config = create_config(team1.clone(), team2.clone(), Some(5));
+    config.validate().expect("5v5 should validate");
+
+    let mut six = config.clone();
+    six.team1.push("Rogue".to_string());
+    let err = six.validate().unwrap_err();
+    assert!(err.contains("1-5"), "unexpected error: {}", err);
+
+    let mut first_frame = None;
+    let result = run_headless_match_observed(config, true, None, |frame| {
+        if first_frame.is_none() {
+            first_frame = Some(frame.combatants.values().filter(|c| !c.is_pet).map(|c| (c.team, c.position)).collect::<Vec<_>>());
+        }
+    })
+    .expect("5v5 run");
+
+    let spawned = first_frame.expect("no frames observed");
+    assert_eq!(spawned.len(), 10, "expected 10 non-pet combatants");
+    for (i, (team_a, a)) in spawned.iter().enumerate() {
+        for (team_b, b) in spawned.iter().skip(i + 1) {
+            assert!(team_a != team_b || a.distance(*b) > 1.0, "teammates spawned on top of each other at {:?}", a);
+        }
+    }
+    assert_eq!(result.team1_combatants.len(), 5);
+    assert_eq!(result.team2_combatants.len(), 5);
+}
+
+/// Scheduled sandbox events fire at their time: a lethal sandbox hit on the
+/// only team 2 combatant ends the match right after gates open, credited to
+/// nobody.
+#[test]
+fn scheduled_sandbox_damage_kills_target() {
+    use arenasim::headless::config::ScheduledSandboxEvent;
+
+    let mut config = create_config(vec!["Warrior"], vec!["Mage"], Some(7));
+    config.sandbox = vec![ScheduledSandboxEvent {
+        at_secs: 1.0,
+        command: "damage 2 0 100000 Fire".to_string(),
+    }];
+
+    let result = run_headless_match_with(config, true, None).expect("sandbox run");
+
+    assert_eq!(result.winner, Some(1));
+    assert!(result.match_time < 3.0, "sandbox hit fired late: {}s", result.match_time);
+    assert!(!result.team2_combatants[0].survived);
+}
+
+/// One run writes both outputs; replaying the replay file reproduces the match
+/// the results JSON describes.
+#[test]
+fn replay_file_reproduces_the_results_json() {
+    use arenasim::headless::export::{pin_seed, write_replay, write_results_json};
+
+    let dir = tempfile::tempdir().unwrap();
+    let results_path = dir.path().join("result.json");
+    let replay_path = dir.path().join("replays/replay.json");
+
+    let mut config = create_config(vec!["Warrior"], vec!["Priest"], None);
+    let seed = pin_seed(&mut config);
+    let result = run_headless_match_with(config.clone(), true, None).expect("original run");
+    write_results_json(&results_path, &config, &result).expect("results json");
+    write_replay(&replay_path, &config).expect("replay");
+
+    let results: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&results_path).unwrap()).unwrap();
+    assert_eq!(results["metadata"]["random_seed"], seed);
+    assert_eq!(results["end_reason"], result.end_reason.as_str());
+    assert_eq!(results["team1_combatants"][0]["class_name"], "Warrior");
+
+    let replay_text = std::fs::read_to_string(&replay_path).unwrap();
+    assert_eq!(replay_text.lines().count(), 1, "replay should be compact");
+    let replay = HeadlessMatchConfig::load_from_file(&replay_path, false).expect("load replay");
+    assert_eq!(replay.random_seed, Some(seed));
+
+    let replayed = run_headless_match_with(replay, true, None).expect("replayed run");
+    assert_eq!(replayed.winner, result.winner);
+    assert_eq!(replayed.match_time, result.match_time);
+    assert_eq!(replayed.killing_blows, result.killing_blows);
+}
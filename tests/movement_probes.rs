@@ -2773,7 +2773,9 @@ mod bucket_a_unit {
 
     use arenasim::states::match_config::CharacterClass;
     use arenasim::states::play_match::class_ai::combat_snapshot::CombatSnapshot;
-    use arenasim::states::play_match::class_ai::{select_softer_melee_target, CombatantInfo};
+    use arenasim::states::play_match::class_ai::{
+        select_kill_hungry_target, select_peel_target, select_softer_melee_target, CombatantInfo,
+    };
     use arenasim::states::play_match::{Aura, AuraType, DispelType};
     use bevy::prelude::*;
 
@@ -2916,6 +2918,27 @@ mod bucket_a_unit {
         assert_eq!(fwd, Some(lo));
         assert_eq!(rev, Some(lo), "tie-break is order-independent");
     }
+
+    // --- personality choosers (pure) ---
+
+    #[test]
+    fn kill_hungry_picks_lowest_enemy_in_execute_range() {
+        let a = Entity::from_raw(10);
+        let b = Entity::from_raw(11);
+        assert_eq!(select_kill_hungry_target(vec![(a, 0.25), (b, 0.1)], 0.3), Some(b));
+        assert_eq!(select_kill_hungry_target(vec![(a, 0.5), (b, 0.31)], 0.3), None);
+    }
+
+    #[test]
+    fn peel_picks_nearest_enemy_on_the_healer() {
+        let healer = Entity::from_raw(1);
+        let ally = Entity::from_raw(2);
+        let near = Entity::from_raw(10);
+        let far = Entity::from_raw(11);
+        let candidates = vec![(near, 5.0, Some(ally)), (far, 20.0, Some(healer)), (Entity::from_raw(12), 30.0, Some(healer))];
+        assert_eq!(select_peel_target(candidates, &[healer]), Some(far));
+        assert_eq!(select_peel_target(vec![(near, 5.0, None)], &[healer]), None);
+    }
 }
 
 // ---------------------------------------------------------------------------
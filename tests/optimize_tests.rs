@@ -0,0 +1,66 @@
+//! Integration tests for the counter-composition optimizer
+//!
+//! These tests verify that:
+//! - Class lists parse ("all" and comma-separated) and reject unknown names
+//! - Exhaustive enumeration yields every multiset exactly once
+//! - Hill-climb neighbors are single-slot swaps
+//! - Wilson intervals bracket the observed rate and stay inside [0, 1]
+
+use arenasim::headless::optimize::{enumerate_comps, parse_class_list, swap_neighbors, wilson_interval};
+use arenasim::states::match_config::CharacterClass;
+
+#[test]
+fn class_lists_parse() {
+    assert_eq!(parse_class_list("all").unwrap(), CharacterClass::all().to_vec());
+    assert_eq!(
+        parse_class_list("Warrior, Paladin,Rogue").unwrap(),
+        vec![CharacterClass::Warrior, CharacterClass::Paladin, CharacterClass::Rogue]
+    );
+    assert!(parse_class_list("Warrior,Druid").is_err());
+}
+
+#[test]
+fn exhaustive_enumerates_each_multiset_once() {
+    let pool = CharacterClass::all();
+    let comps = enumerate_comps(pool, 3);
+    // Multisets of size k from n classes: C(n + k - 1, k)
+    let n = pool.len();
+    assert_eq!(comps.len(), (n + 2) * (n + 1) * n / 6);
+
+    let mut labels: Vec<String> = comps
+        .iter()
+        .map(|c| c.iter().map(|x| x.name()).collect::<Vec<_>>().join("+"))
+        .collect();
+    labels.sort();
+    labels.dedup();
+    assert_eq!(labels.len(), comps.len(), "a comp was listed twice");
+}
+
+#[test]
+fn neighbors_differ_by_one_slot() {
+    let pool = [CharacterClass::Warrior, CharacterClass::Mage, CharacterClass::Priest];
+    let comp = [CharacterClass::Warrior, CharacterClass::Mage];
+    let neighbors = swap_neighbors(&comp, &pool);
+    // Warrior+Mage -> Mage+Mage, Priest+Mage (as Mage+Priest), Warrior+Warrior, Warrior+Priest
+    assert_eq!(neighbors.len(), 4);
+    assert!(!neighbors.contains(&comp.to_vec()));
+    for n in &neighbors {
+        let shared = comp.iter().filter(|c| n.contains(c)).count();
+        assert!(shared >= 1, "{:?} is not a single swap from {:?}", n, comp);
+    }
+}
+
+#[test]
+fn wilson_interval_brackets_rate() {
+    let (low, high) = wilson_interval(50, 100, 1.96);
+    assert!(low < 0.5 && high > 0.5);
+    assert!((high - low - 0.19).abs() < 0.02, "width {}", high - low);
+
+    let (low, high) = wilson_interval(0, 20, 1.96);
+    assert_eq!(low, 0.0);
+    assert!(high > 0.0 && high < 0.25);
+
+    let (low, high) = wilson_interval(20, 20, 1.96);
+    assert!(low > 0.75);
+    assert!(high <= 1.0);
+}
@@ -21,6 +21,7 @@
 use egui_kittest::Harness;
 
 use arenasim::combat::log::CombatLog;
+use arenasim::settings::TeamColors;
 use arenasim::states::configure_match_ui::ClassIcons;
 use arenasim::states::match_config::CharacterClass;
 use arenasim::states::play_match::{CombatantStats, MatchResults};
@@ -36,7 +37,7 @@ fn results_screen_2v2() {
     let mut harness = Harness::builder()
         .with_size([1500.0, 820.0])
         .build(move |ctx| {
-            draw_results_screen(ctx, Some(&results), &log, &icons);
+            draw_results_screen(ctx, Some(&results), &log, &icons, &TeamColors::default(), None);
         });
 
     harness.run();
@@ -80,7 +81,7 @@ fn results_screen_value_combos() {
     let mut harness = Harness::builder()
         .with_size([1500.0, 820.0])
         .build(move |ctx| {
-            draw_results_screen(ctx, Some(&results), &log, &icons);
+            draw_results_screen(ctx, Some(&results), &log, &icons, &TeamColors::default(), None);
         });
     harness.run();
     harness.snapshot("results_screen_value_combos");
@@ -0,0 +1,39 @@
+//! Integration tests for the ability auto-balancer's parameter plumbing
+//!
+//! These tests verify that:
+//! - Ability names parse from their abilities.ron keys
+//! - Numeric parameters (top-level and nested) read and write back
+//! - Unknown or non-numeric parameters are rejected
+
+use arenasim::headless::tune::{parse_ability, read_param, write_param};
+use arenasim::states::play_match::abilities::AbilityType;
+use arenasim::states::play_match::ability_config::AbilityDefinitions;
+
+#[test]
+fn ability_names_parse() {
+    assert_eq!(parse_ability("MortalStrike").unwrap(), AbilityType::MortalStrike);
+    assert!(parse_ability("Mortal Strike").is_err());
+}
+
+#[test]
+fn params_round_trip() {
+    let mut defs = AbilityDefinitions::default();
+    let ability = AbilityType::MortalStrike;
+
+    let original = read_param(&defs, ability, "damage_coefficient").unwrap();
+    write_param(&mut defs, ability, "damage_coefficient", original + 0.25).unwrap();
+    assert_eq!(read_param(&defs, ability, "damage_coefficient").unwrap(), original + 0.25);
+    assert_eq!(defs.get(&ability).unwrap().damage_coefficient, original + 0.25);
+
+    write_param(&mut defs, ability, "applies_aura.magnitude", 0.5).unwrap();
+    assert_eq!(defs.get(&ability).unwrap().applies_aura.as_ref().unwrap().magnitude, 0.5);
+    // Untouched fields survive the round trip
+    assert_eq!(defs.get(&ability).unwrap().name, "Mortal Strike");
+}
+
+#[test]
+fn bad_params_are_rejected() {
+    let mut defs = AbilityDefinitions::default();
+    assert!(read_param(&defs, AbilityType::MortalStrike, "damage_coeficient").is_err());
+    assert!(write_param(&mut defs, AbilityType::MortalStrike, "name", 1.0).is_err());
+}